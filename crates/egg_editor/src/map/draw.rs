@@ -696,7 +696,7 @@ impl MapViewer {    /// Draw the dock resize bars (the inner-edge splitter band
         match self.tool {
             EditorTool::Paint => self.draw_paint_overlay(draw_state, input, camera_pos),
             EditorTool::Select => self.draw_select_overlay(draw_state, camera_pos),
-            EditorTool::Interactables | EditorTool::Warps => {
+            EditorTool::Interactables | EditorTool::Warps | EditorTool::Creatures => {
                 self.draw_object_overlay(draw_state, input, map, camera_pos)
             }
             EditorTool::Layers => {}
@@ -815,9 +815,9 @@ impl MapViewer {    /// Draw the dock resize bars (the inner-edge splitter band
         );
     }
 
-    /// Object tools (Interactables / Warps) overlay: every object of the active
-    /// tab's kind outlined (warps 12, interactions 14, the selected one 11),
-    /// plus the in-progress new-object drag box.
+    /// Object tools (Interactables / Warps / Creatures) overlay: every object of
+    /// the active tab's kind outlined (warps 12, interactions 14, creatures 9,
+    /// the selected one 11), plus the in-progress new-object drag box.
     pub(super) fn draw_object_overlay(
         &self,
         draw_state: &mut DrawState,
@@ -828,7 +828,11 @@ impl MapViewer {    /// Draw the dock resize bars (the inner-edge splitter band
         let cx = i32::from(camera_pos.x);
         let cy = i32::from(camera_pos.y);
         let kind = self.obj_kind();
-        let base = draw_state.colour(if kind == ObjKind::Warp { 12 } else { 14 });
+        let base = draw_state.colour(match kind {
+            ObjKind::Warp => 12,
+            ObjKind::Interactable => 14,
+            ObjKind::Creature => 9,
+        });
         let sel = draw_state.colour(11);
         let canvas = draw_state.rgba(LayerId::BG);
         for (i, object) in map.objects.iter().enumerate() {
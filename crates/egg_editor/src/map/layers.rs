@@ -953,10 +953,11 @@ mod tests {
             }],
             ..MapInfo::default()
         };
-        let mut v = MapViewer::default();
-
         // All planes on: walk 0 → 1 → 2 → 3, clamping at the ends.
-        v.layer_index = 0;
+        let mut v = MapViewer {
+            layer_index: 0,
+            ..MapViewer::default()
+        };
         v.move_selection(&map, true);
         assert_eq!(v.layer_index, 1);
         v.move_selection(&map, true);
@@ -1015,8 +1016,11 @@ mod tests {
             }],
             ..MapInfo::default()
         };
-        let mut v = MapViewer::default();
-        v.layer_index = 0; // hold the selection constant across both builds
+        // Hold the selection constant across both builds.
+        let mut v = MapViewer {
+            layer_index: 0,
+            ..MapViewer::default()
+        };
 
         let count = |v: &MapViewer| {
             let mut b = UiBuilder::new();
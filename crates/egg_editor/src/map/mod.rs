@@ -17,8 +17,8 @@ use std::collections::BTreeSet;
 
 use egg_world::data::script::message::Message;
 use egg_world::data::{
-    eggdata,
-    save::SaveData,
+    eggdata::{self, PresetMove},
+    save::{SaveData, SaveTest},
     script::Script,
     sound::{self, SfxData},
     tiled::{TiledMap, TiledMapLayer},
@@ -41,12 +41,12 @@ use egg_render::{
 use egg_ui::dialogue::Dialogue;
 use egg_ui::layout::{NodeId, Rect, Ui, UiBuilder};
 use egg_world::world::animation::AnimFrame;
-use egg_world::world::interact::{InteractFn, Interaction};
+use egg_world::world::interact::{InteractFn, Interaction, Talk};
 use egg_world::world::map::{
-    Axis, LayerInfo, LayerKind, MapInfo, MapObject, MapStore, ObjectEffect, Plane, Trigger, Warp,
-    WarpMode, map_by_name,
+    Axis, LayerInfo, LayerKind, MapInfo, MapObject, MapStore, ObjectEffect, PlacedCreature, Plane,
+    Trigger, Warp, WarpMode, map_by_name,
 };
-use egg_world::world::player::Shell;
+use egg_world::world::player::{PresetId, Shell};
 
 // `pub(crate)` so the text editor can reuse the shared dock primitives (`Side`
 // and the resize-size constants) for its own outline dock — see
@@ -79,6 +79,17 @@ pub enum EditorTool {
     Select,
     Interactables,
     Warps,
+    /// Place and edit the map's starting creatures (Tiled `creature` objects).
+    /// The third Objects sub-tab.
+    Creatures,
+}
+
+impl EditorTool {
+    /// Whether this is one of the Objects panel's tools (Interacts / Warps /
+    /// Creatures), which select, drag, nudge and delete map objects.
+    fn edits_objects(self) -> bool {
+        matches!(self, Self::Interactables | Self::Warps | Self::Creatures)
+    }
 }
 
 /// A field the editor focuses for keyboard text/number entry.
@@ -108,6 +119,14 @@ enum EditField {
     Pitch,
     /// The `add_creatures` Func interaction's spawn count (a `usize`).
     Count,
+    /// A placed creature object's id (the name cutscenes and schedules
+    /// address it by; an empty buffer clears it), its patrol's `#path` name,
+    /// and its talk's dialogue key or cutscene name (an empty buffer clears
+    /// the talk). Creatures tab only.
+    CreatureId,
+    CreaturePath,
+    CreatureTalk,
+    CreatureScene,
     /// The `give_item` Func interaction's item key (the granted item's registry
     /// key, e.g. `"chegg"`; a free-text string, empty until typed).
     Item,
@@ -153,7 +172,8 @@ enum LayerProp {
 }
 
 /// An enum-field the editor advances with a click. [`Flip`](Self::Flip)/
-/// [`Mode`](Self::Mode)/[`Sound`](Self::Sound) live on the [`Warp`] effect;
+/// [`Mode`](Self::Mode)/[`Sound`](Self::Sound)/[`Transition`](Self::Transition)
+/// live on the [`Warp`] effect;
 /// [`Trigger`](Self::Trigger) lives on the owning [`MapObject`] and so shows on
 /// both object tabs.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -161,6 +181,10 @@ enum CycleField {
     Flip,
     Mode,
     Sound,
+    /// The warp's screen transition
+    /// ([`Transition`](egg_world::world::transition::Transition)), cut → fade →
+    /// dissolve → iris → dither.
+    Transition,
     Trigger,
     /// Whether the selected interaction object is a consume-on-interact pickup
     /// ([`MapObject::removable`](egg_world::world::map::MapObject::removable)) — toggled
@@ -176,15 +200,26 @@ enum CycleField {
     /// A warp's destination map: steps through `[same-map] + existing maps`, so a
     /// target can be picked without typing (and can't be a typo'd dangling name).
     WarpTarget,
+    /// A placed creature object's preset (stepping through the known
+    /// presets), move mode (the preset's own → each
+    /// [`PresetMove`]), facing (the preset's own → each
+    /// of [`PlacedCreature::FACINGS`]) and talk kind (none → dialogue →
+    /// cutscene, keeping the typed name across the switch). Creatures tab
+    /// only.
+    CreaturePreset,
+    CreatureMove,
+    CreatureFacing,
+    CreatureTalk,
 }
 
 /// Which kind of object a tool creates / filters its view to. The object lists
 /// are unified now, so this no longer routes between collections — it only
-/// distinguishes the two object tabs (Interacts vs. Warps).
+/// distinguishes the object tabs (Interacts vs. Warps vs. Creatures).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ObjKind {
     Interactable,
     Warp,
+    Creature,
 }
 impl ObjKind {
     /// Whether `object` belongs in this kind's filtered tab view.
@@ -192,6 +227,7 @@ impl ObjKind {
         match object.effect {
             ObjectEffect::Warp(_) => self == ObjKind::Warp,
             ObjectEffect::Interact(_) => self == ObjKind::Interactable,
+            ObjectEffect::Creature(_) => self == ObjKind::Creature,
         }
     }
 }
@@ -207,6 +243,7 @@ type ObjSnapshot = MapObject;
 /// before and/or after so they replay exactly. Object `index` is the real index
 /// into the single [`MapInfo::objects`] list (not a per-tab display row).
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)] // `Modify` carries two snapshots by design
 enum EditAction {
     /// Tiles changed by one paint stroke: `(x, y, old, new)` per cell, in the
     /// `(source, layer)` the stroke painted into.
@@ -1393,15 +1430,17 @@ fn actor_spawn_pos(def: &CutsceneDef, actor: &str) -> Option<Vec2> {
 /// Each actor's last-known absolute position is seeded by a `spawn` / `bind`
 /// init ([`actor_spawn_pos`]); the `content` is then walked in order, following
 /// only the motions with static geometry:
-/// * `walk` / `noclip` ([`Motion::MoveToPoint`] / [`Motion::MoveToPointNoclip`])
-///   append their target, starting the polyline from the last-known point;
+/// * `walk` / `noclip` / `walk!` ([`Motion::MoveToPoint`] /
+///   [`Motion::MoveToPointNoclip`] / [`Motion::PathToPoint`]) append their
+///   target, starting the polyline from the last-known point;
 /// * `teleport` ([`Motion::Teleport`]) ends the current polyline and starts a
 ///   fresh one at the jump point — a discontinuity, not a walk;
 /// * `record` ([`Motion::Record`]) integrates run-by-run from the last-known
 ///   point (`pos += heading × frames`, one point per run — the same arithmetic
 ///   as the cutscene skip-snap), and is skipped entirely with no known position;
-/// * `to` / `beside` ([`Motion::MoveToEntity`] / [`Motion::MoveBesideHorizontal`])
-///   are entity-relative, so the actor's position becomes unknown: the polyline
+/// * `to` / `to!` / `beside` ([`Motion::MoveToEntity`] /
+///   [`Motion::PathToEntity`] / [`Motion::MoveBesideHorizontal`]) are
+///   entity-relative, so the actor's position becomes unknown: the polyline
 ///   ends and nothing anchors again until the next absolute motion;
 /// * `face` and `pose` motions and non-`move` content don't move the actor.
 ///
@@ -1413,7 +1452,8 @@ fn actor_spawn_pos(def: &CutsceneDef, actor: &str) -> Option<Vec2> {
 ///
 /// Single-point polylines (a spawn with no motion) are kept — the overlay draws
 /// them as a marker dot. Collision isn't re-walked (a `walk` blocked in-game
-/// still draws its straight-line intent), hence "best-effort". Actors are walked
+/// still draws its straight-line intent, and a `walk!` skips its detour), hence
+/// "best-effort". Actors are walked
 /// in a stable order (init binds first, then content-first appearance) so the
 /// output — and the overlay's per-scene label anchor — is deterministic.
 fn scene_paths(def: &CutsceneDef) -> Vec<Vec<Vec2>> {
@@ -1463,7 +1503,9 @@ fn scene_paths(def: &CutsceneDef) -> Vec<Vec<Vec2>> {
                 }
                 for ins in &chain.instructions {
                     match &ins.motion {
-                        Motion::MoveToPoint(p) | Motion::MoveToPointNoclip(p) => {
+                        Motion::MoveToPoint(p)
+                        | Motion::MoveToPointNoclip(p)
+                        | Motion::PathToPoint(p) => {
                             if open.is_empty() && let Some(start) = pos {
                                 open.push(start);
                             }
@@ -1493,7 +1535,9 @@ fn scene_paths(def: &CutsceneDef) -> Vec<Vec<Vec2>> {
                             }
                             pos = Some(cur);
                         }
-                        Motion::MoveToEntity(_) | Motion::MoveBesideHorizontal { .. } => {
+                        Motion::MoveToEntity(_)
+                        | Motion::PathToEntity(_)
+                        | Motion::MoveBesideHorizontal { .. } => {
                             // Entity-relative: the absolute position is now unknown.
                             flush(&mut open, &mut paths);
                             pos = None;
@@ -1980,6 +2024,15 @@ fn sound_label(sound: &Option<SfxData>) -> &'static str {
     }
 }
 
+/// Label for the [`CycleField::CreatureTalk`] row.
+fn talk_kind_label(talk: Option<&Talk>) -> &'static str {
+    match talk {
+        None => "none",
+        Some(Talk::Dialogue(_)) => "dialogue",
+        Some(Talk::Cutscene(_)) => "cutscene",
+    }
+}
+
 /// Label for the [`CycleField::Removable`] toggle (consume-on-interact pickup).
 fn removable_label(removable: bool) -> &'static str {
     if removable { "yes" } else { "no" }
@@ -2023,6 +2076,40 @@ fn cycle_sound(sound: &Option<SfxData>) -> Option<SfxData> {
     }
 }
 
+/// Advance a talk kind: none → dialogue → cutscene → none, carrying the typed
+/// name across (a fresh dialogue starts empty).
+fn next_talk(talk: Option<&Talk>) -> Option<Talk> {
+    match talk {
+        None => Some(Talk::Dialogue(String::new())),
+        Some(Talk::Dialogue(name)) => Some(Talk::Cutscene(name.clone())),
+        Some(Talk::Cutscene(_)) => None,
+    }
+}
+
+/// Advance a placed creature's move mode: the preset's own → wander → amble →
+/// flee → approach → patrol → the preset's own. A fresh patrol's path starts
+/// empty (typed into the `path` field).
+fn cycle_move(mode: Option<PresetMove>) -> Option<PresetMove> {
+    match mode {
+        None => Some(PresetMove::Wander),
+        Some(PresetMove::Wander) => Some(PresetMove::Amble),
+        Some(PresetMove::Amble) => Some(PresetMove::Flee),
+        Some(PresetMove::Flee) => Some(PresetMove::Approach),
+        Some(PresetMove::Approach) => Some(PresetMove::Patrol(String::new())),
+        Some(PresetMove::Patrol(_)) => None,
+    }
+}
+
+/// Advance a placed creature's facing: the preset's own → each of
+/// [`PlacedCreature::FACINGS`] in turn → the preset's own.
+fn cycle_facing(facing: Option<(i8, i8)>) -> Option<(i8, i8)> {
+    let facings = PlacedCreature::FACINGS.map(|(_, dir)| dir);
+    match facing.and_then(|dir| facings.iter().position(|d| *d == dir)) {
+        None => Some(facings[0]),
+        Some(i) => facings.get(i + 1).copied(),
+    }
+}
+
 /// Re-parse the just-written map JSON back into the store, so re-entering the
 /// map rebuilds from the saved state. Tile paints edit the store's `TiledMap` in
 /// place, but object edits live only on the running [`MapInfo`] until a save —
@@ -32,10 +32,10 @@ impl MapViewer {
 
     /// The object kind the active object tool creates / filters its view to.
     pub(super) fn obj_kind(&self) -> ObjKind {
-        if self.tool == EditorTool::Warps {
-            ObjKind::Warp
-        } else {
-            ObjKind::Interactable
+        match self.tool {
+            EditorTool::Warps => ObjKind::Warp,
+            EditorTool::Creatures => ObjKind::Creature,
+            _ => ObjKind::Interactable,
         }
     }
 
@@ -75,12 +75,19 @@ impl MapViewer {
     }
 
     pub(super) fn create_object(&mut self, map: &mut MapInfo, hitbox: Hitbox) {
-        // The active tab decides the kind; both append to the one objects list.
-        let object = if self.obj_kind() == ObjKind::Warp {
-            let to = Vec2::new(hitbox.x, hitbox.y);
-            MapObject::warp(hitbox, Warp::new(None, to))
-        } else {
-            MapObject::dialogue(hitbox, "new_key")
+        // The active tab decides the kind; all append to the one objects list.
+        // A new creature is the first known preset, standing at the box's
+        // top-left (its size is the preset's; the box is just the handle).
+        let object = match self.obj_kind() {
+            ObjKind::Warp => {
+                let to = Vec2::new(hitbox.x, hitbox.y);
+                MapObject::warp(hitbox, Warp::new(None, to))
+            }
+            ObjKind::Creature => {
+                let preset = self.preset_defs.first().map_or("critter", |(name, _)| name.as_str());
+                MapObject::creature(hitbox, PlacedCreature::new(PresetId::new(preset)))
+            }
+            ObjKind::Interactable => MapObject::dialogue(hitbox, "new_key"),
         };
         map.objects.push(object);
         let index = map.objects.len() - 1;
@@ -253,6 +260,16 @@ impl MapViewer {
             // Seeds empty for an indexed background (there's no fixed hex to
             // show — the index follows the live palette).
             (_, EditField::BgRgb) => map.bg_colour.hex().unwrap_or_default(),
+            (Some(ObjectEffect::Creature(c)), EditField::CreatureId) => {
+                c.id.clone().unwrap_or_default()
+            }
+            (Some(ObjectEffect::Creature(c)), EditField::CreaturePath) => match &c.move_mode {
+                Some(PresetMove::Patrol(path)) => path.clone(),
+                _ => String::new(),
+            },
+            (Some(ObjectEffect::Creature(c)), EditField::CreatureTalk | EditField::CreatureScene) => {
+                c.talk.as_ref().map(|t| t.name().to_string()).unwrap_or_default()
+            }
             (Some(ObjectEffect::Interact(Interaction::Dialogue(k))), EditField::Key) => k.clone(),
             (Some(ObjectEffect::Interact(Interaction::Cutscene(n))), EditField::Scene) => n.clone(),
             (Some(ObjectEffect::Warp(w)), EditField::ToMap) => w.map.clone().unwrap_or_default(),
@@ -277,10 +294,12 @@ impl MapViewer {
             // every kind). An unset condition seeds empty, so leaving the field
             // blank commits back to `None`.
             (_, EditField::CondIf) => object
-                .and_then(|o| o.gate.if_flag.clone())
+                .and_then(|o| o.gate.if_flag.as_ref())
+                .map(SaveTest::to_string)
                 .unwrap_or_default(),
             (_, EditField::CondUnless) => object
-                .and_then(|o| o.gate.unless_flag.clone())
+                .and_then(|o| o.gate.unless_flag.as_ref())
+                .map(SaveTest::to_string)
                 .unwrap_or_default(),
             (_, EditField::Sets) => object
                 .and_then(|o| o.gate.sets.clone())
@@ -497,18 +516,35 @@ impl MapViewer {
                 // Empty buffer clears narration; otherwise it's the dialogue key.
                 w.narration = (!buffer.is_empty()).then(|| buffer.clone());
             }),
-            // Gate fields: the flag name is stored verbatim (empty buffer clears
-            // that condition to `None`). Validated against the `#flag` vocabulary
+            EditField::CreatureId => self.modify_creature(map, |c| {
+                c.id = (!buffer.is_empty()).then(|| buffer.clone());
+            }),
+            // Only a patrol has a path to name.
+            EditField::CreaturePath => self.modify_creature(map, |c| {
+                if let Some(PresetMove::Patrol(path)) = &mut c.move_mode {
+                    path.clone_from(&buffer);
+                }
+            }),
+            // The talk's name, by the field's kind; emptied clears the talk.
+            EditField::CreatureTalk => self.modify_creature(map, |c| {
+                c.talk = (!buffer.is_empty()).then(|| Talk::Dialogue(buffer.clone()));
+            }),
+            EditField::CreatureScene => self.modify_creature(map, |c| {
+                c.talk = (!buffer.is_empty()).then(|| Talk::Cutscene(buffer.clone()));
+            }),
+            // Gate fields: the flag name (or comparison) is parsed as typed
+            // (empty buffer clears that condition to `None`). Validated against the `#flag` vocabulary
             // only for display (the `?` marker), not on commit — so an author can
             // type a name before declaring it in the script.
             EditField::CondIf => self.modify_object(map, |map, i| {
                 if let Some(object) = map.objects.get_mut(i) {
-                    object.gate.if_flag = (!buffer.is_empty()).then(|| buffer.clone());
+                    object.gate.if_flag = (!buffer.is_empty()).then(|| SaveTest::parse(&buffer));
                 }
             }),
             EditField::CondUnless => self.modify_object(map, |map, i| {
                 if let Some(object) = map.objects.get_mut(i) {
-                    object.gate.unless_flag = (!buffer.is_empty()).then(|| buffer.clone());
+                    object.gate.unless_flag =
+                        (!buffer.is_empty()).then(|| SaveTest::parse(&buffer));
                 }
             }),
             EditField::Sets => self.modify_object(map, |map, i| {
@@ -695,6 +731,7 @@ impl MapViewer {
             CycleField::Flip => self.modify_warp(map, |w| w.flip = cycle_flip(&w.flip)),
             CycleField::Mode => self.modify_warp(map, |w| w.mode = cycle_mode(&w.mode)),
             CycleField::Sound => self.modify_warp(map, |w| w.sound = cycle_sound(&w.sound)),
+            CycleField::Transition => self.modify_warp(map, |w| w.transition = w.transition.next()),
             // Advance the interaction kind, rebuilding the effect in place and
             // carrying a sensible default param (piano's origin = the hitbox).
             CycleField::IntKind => self.modify_object(map, |map, i| {
@@ -728,7 +765,44 @@ impl MapViewer {
             // Handled in `handle_panel` (it needs the map store) — see
             // [`cycle_warp_target`](Self::cycle_warp_target).
             CycleField::WarpTarget => {}
+            CycleField::CreaturePreset => self.cycle_creature_preset(map),
+            CycleField::CreatureMove => {
+                self.modify_creature(map, |c| c.move_mode = cycle_move(c.move_mode.take()))
+            }
+            CycleField::CreatureFacing => {
+                self.modify_creature(map, |c| c.facing = cycle_facing(c.facing))
+            }
+            CycleField::CreatureTalk => {
+                self.modify_creature(map, |c| c.talk = next_talk(c.talk.as_ref()))
+            }
+        }
+    }
+
+    /// Mutate the selected object's [`PlacedCreature`] via `f` (no-op if it
+    /// isn't a creature), recording the change as one undo step.
+    pub(super) fn modify_creature(&mut self, map: &mut MapInfo, f: impl FnOnce(&mut PlacedCreature)) {
+        self.modify_object(map, |map, i| {
+            if let Some(ObjectEffect::Creature(c)) = map.objects.get_mut(i).map(|o| &mut o.effect) {
+                f(c);
+            }
+        });
+    }
+
+    /// Step the selected creature object's preset through the known presets
+    /// (the pushed [`preset_defs`](Self::preset_defs), name-sorted), so it
+    /// can't name one that doesn't exist. One undo step.
+    pub(super) fn cycle_creature_preset(&mut self, map: &mut MapInfo) {
+        let names: Vec<String> = self.preset_defs.iter().map(|(name, _)| name.clone()).collect();
+        if names.is_empty() {
+            return;
         }
+        self.modify_creature(map, move |c| {
+            let next = names
+                .iter()
+                .position(|n| n == c.preset.as_str())
+                .map_or(0, |i| (i + 1) % names.len());
+            c.preset = PresetId::new(names[next].clone());
+        });
     }
 
     /// Step the selected warp's destination through `[same-map] + the existing
@@ -961,8 +1035,10 @@ mod tests {
             ],
             ..MapInfo::default()
         };
-        let mut v = MapViewer::default();
-        v.taken = BTreeSet::from([SaveData::taken_key("town", 5)]);
+        let v = MapViewer {
+            taken: BTreeSet::from([SaveData::taken_key("town", 5)]),
+            ..MapViewer::default()
+        };
         assert!(v.is_object_taken(&map, &map.objects[0]), "id 5 is collected");
         assert!(
             !v.is_object_taken(&map, &map.objects[1]),
@@ -1027,6 +1103,62 @@ mod tests {
         assert_eq!(v.pending_taken_toggle, None, "id-less object parks nothing");
     }
 
+    /// The Creatures tab places a creature object (the first known preset)
+    /// and edits it in place: preset, id, move mode with its patrol path,
+    /// facing and talk, each one undo step. Only the Creatures tab grabs it.
+    #[test]
+    fn creature_objects_place_and_edit() {
+        let mut maps = MapStore::default();
+        let mut map = MapInfo::default();
+        let mut v = MapViewer {
+            tool: EditorTool::Creatures,
+            preset_defs: eggdata::Presets::builtin().named_defs(),
+            ..Default::default()
+        };
+        let creature = |map: &MapInfo| match &map.objects[0].effect {
+            ObjectEffect::Creature(c) => c.clone(),
+            other => panic!("not a creature: {other:?}"),
+        };
+        let edit = |v: &mut MapViewer, map: &mut MapInfo, maps: &mut MapStore, field, text: &str| {
+            v.editing = Some(TextEdit { field, buffer: TextField::new(text), target: 0 });
+            v.commit_edit(map, maps);
+            v.stop_editing();
+        };
+
+        v.create_object(&mut map, Hitbox::new(24, 16, 8, 8));
+        assert_eq!(creature(&map), PlacedCreature::new(PresetId::new("bro")));
+        assert_eq!(v.object_at(&map, Vec2::new(26, 18)), Some(0));
+
+        v.cycle(&mut map, CycleField::CreaturePreset);
+        assert_eq!(creature(&map).preset, PresetId::critter(), "the next preset by name");
+        edit(&mut v, &mut map, &mut maps, EditField::CreatureId, "pip");
+        for _ in 0..5 {
+            v.cycle(&mut map, CycleField::CreatureMove);
+        }
+        edit(&mut v, &mut map, &mut maps, EditField::CreaturePath, "beat");
+        v.cycle(&mut map, CycleField::CreatureFacing);
+        v.cycle(&mut map, CycleField::CreatureTalk);
+        edit(&mut v, &mut map, &mut maps, EditField::CreatureTalk, "pip_hello");
+        assert_eq!(
+            creature(&map),
+            PlacedCreature {
+                preset: PresetId::critter(),
+                id: Some("pip".into()),
+                move_mode: Some(PresetMove::Patrol("beat".into())),
+                facing: Some((0, 1)),
+                talk: Some(Talk::Dialogue("pip_hello".into())),
+            }
+        );
+
+        // Undo peels the last edit; the talk cycle still holds its empty key.
+        v.undo(&mut NullConsole::new(), &mut map, &mut maps);
+        assert_eq!(creature(&map).talk, Some(Talk::Dialogue(String::new())));
+
+        // The other tabs don't see it.
+        v.tool = EditorTool::Interactables;
+        assert_eq!(v.object_at(&map, Vec2::new(26, 18)), None);
+    }
+
     /// The feature-complete frame fields: offset / size / scale / palette-rotate
     /// (size & scale floored to 1, palette mod-16), flip + rotate cycles, and the
     /// `Option<u8>` transparent / outline (a number sets it, an empty buffer
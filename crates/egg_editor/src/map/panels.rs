@@ -14,7 +14,9 @@ impl MapViewer {
     /// checks. Both lists are refreshed each focused step and arrive sorted.
     pub(super) fn autocomplete_vocab(&self, field: EditField) -> Option<&[String]> {
         match field {
-            EditField::Key | EditField::Narration => Some(&self.dialogue_keys),
+            EditField::Key | EditField::Narration | EditField::CreatureTalk => {
+                Some(&self.dialogue_keys)
+            }
             EditField::CondIf | EditField::CondUnless | EditField::Sets => Some(&self.flag_names),
             _ => None,
         }
@@ -341,7 +343,8 @@ impl MapViewer {
         b.finish(root, (sw as f32, sh as f32))
     }
 
-    /// The Objects panel's sub-tabs: Interactables vs Warps, each a tool switch.
+    /// The Objects panel's sub-tabs: Interactables vs Warps vs Creatures, each a
+    /// tool switch.
     pub(super) fn build_obj_tabs(&self, b: &mut UiBuilder<EditorKey>, rows: &mut Vec<NodeId>) {
         // A tool tab is just a toggle button keyed by the tool it selects.
         let mk = |b: &mut UiBuilder<EditorKey>, tool: EditorTool, label: &str, sel: bool| {
@@ -354,7 +357,8 @@ impl MapViewer {
             self.tool == EditorTool::Interactables,
         );
         let wp = mk(b, EditorTool::Warps, "Warp", self.tool == EditorTool::Warps);
-        rows.push(b.row(1.0, [it, wp]).id());
+        let cr = mk(b, EditorTool::Creatures, "Crtr", self.tool == EditorTool::Creatures);
+        rows.push(b.row(1.0, [it, wp, cr]).id());
     }
 
     /// The Maps browser: a paged grid of map cells. A thumbnail is blitted over
@@ -573,7 +577,9 @@ impl MapViewer {
         Some(match self.tool {
             EditorTool::Layers => PanelKind::Layers,
             EditorTool::Paint | EditorTool::Select => PanelKind::Paint,
-            EditorTool::Interactables | EditorTool::Warps => PanelKind::Objects,
+            EditorTool::Interactables | EditorTool::Warps | EditorTool::Creatures => {
+                PanelKind::Objects
+            }
         })
     }
 
@@ -596,7 +602,7 @@ impl MapViewer {
                 },
             ),
             PanelKind::Objects => Some(
-                if matches!(current, EditorTool::Interactables | EditorTool::Warps) {
+                if current.edits_objects() {
                     current
                 } else {
                     EditorTool::Interactables
@@ -870,9 +876,13 @@ impl MapViewer {
         map: &MapInfo,
         rect: Rect,
     ) {
-        let warps = self.tool == EditorTool::Warps;
+        let title = match self.tool {
+            EditorTool::Warps => "WARPS:",
+            EditorTool::Creatures => "CREATURES:",
+            _ => "INTERACTS:",
+        };
         rows.push(
-            b.text(if warps { "WARPS:" } else { "INTERACTS:" })
+            b.text(title)
                 .color(13)
                 .full_width(8.0)
                 .id(),
@@ -903,6 +913,10 @@ impl MapViewer {
                 ObjectEffect::Interact(Interaction::Cutscene(n)) => format!("{row}: ~{n}"),
                 ObjectEffect::Interact(Interaction::Func(_)) => format!("{row}: <fn>"),
                 ObjectEffect::Interact(Interaction::None) => format!("{row}: <->"),
+                ObjectEffect::Creature(c) => match &c.id {
+                    Some(id) => format!("{row}: {id} ({})", c.preset),
+                    None => format!("{row}: {}", c.preset),
+                },
             };
             // A collected pickup stays listed (it's still in the map data) but
             // reads as "gone in this save": marked and dimmed, distinct from the
@@ -967,6 +981,7 @@ impl MapViewer {
                     self.cycle_row(b, rows, CycleField::Flip, "flip", axis_label(&w.flip));
                     self.cycle_row(b, rows, CycleField::Mode, "mode", mode_label(&w.mode));
                     self.cycle_row(b, rows, CycleField::Sound, "snd", sound_label(&w.sound));
+                    self.cycle_row(b, rows, CycleField::Transition, "fx", w.transition.name());
                     self.cycle_row(b, rows, CycleField::Trigger, "trig", object.trigger.name());
                     let narr = w.narration.as_deref().unwrap_or("-");
                     self.field_row(b, rows, EditField::Narration, "narr", narr);
@@ -1031,11 +1046,39 @@ impl MapViewer {
                         rows.push(b.row(2.0, [toggle]).id());
                     }
                 }
+                ObjectEffect::Creature(c) => {
+                    // The preset steps through the known ones; everything below
+                    // it overrides what that preset spawns with.
+                    self.cycle_row(b, rows, CycleField::CreaturePreset, "kind", c.preset.as_str());
+                    self.field_row(b, rows, EditField::CreatureId, "id", c.id.as_deref().unwrap_or("-"));
+                    let mode = c.move_mode.as_ref().map_or("preset", PresetMove::name);
+                    self.cycle_row(b, rows, CycleField::CreatureMove, "move", mode);
+                    if let Some(PresetMove::Patrol(path)) = &c.move_mode {
+                        self.field_row(b, rows, EditField::CreaturePath, "path", path);
+                    }
+                    let facing = c.facing_name().unwrap_or("preset");
+                    self.cycle_row(b, rows, CycleField::CreatureFacing, "face", facing);
+                    let talk = c.talk.as_ref();
+                    self.cycle_row(b, rows, CycleField::CreatureTalk, "talk", talk_kind_label(talk));
+                    match talk {
+                        Some(Talk::Dialogue(key)) => {
+                            self.field_row(b, rows, EditField::CreatureTalk, "key", key)
+                        }
+                        Some(Talk::Cutscene(name)) => {
+                            self.field_row(b, rows, EditField::CreatureScene, "scene", name)
+                        }
+                        None => {}
+                    }
+                }
             }
             // The flag gate (`if` / `unless` / `sets`) is common to every object
-            // kind — shown once below the per-kind params, above the sprite.
-            self.build_gate(b, rows, object);
-            self.build_sprite_frames(b, rows, object);
+            // kind that fires — shown once below the per-kind params, above the
+            // sprite. A creature does neither: it never fires, and it's drawn
+            // as its preset.
+            if !matches!(object.effect, ObjectEffect::Creature(_)) {
+                self.build_gate(b, rows, object);
+                self.build_sprite_frames(b, rows, object);
+            }
         }
     }
 
@@ -1053,34 +1096,37 @@ impl MapViewer {
     ) {
         rows.push(b.spacer(2.0).id());
         self.header_row(b, rows, "gate:", 7.0);
-        self.gate_field(b, rows, EditField::CondIf, "if", object.gate.if_flag.as_deref());
+        self.gate_field(b, rows, EditField::CondIf, "if", object.gate.if_flag.as_ref());
         self.gate_field(
             b,
             rows,
             EditField::CondUnless,
             "unless",
-            object.gate.unless_flag.as_deref(),
+            object.gate.unless_flag.as_ref(),
         );
-        self.gate_field(b, rows, EditField::Sets, "sets", object.gate.sets.as_deref());
+        let sets = object.gate.sets.clone().map(SaveTest::Flag);
+        self.gate_field(b, rows, EditField::Sets, "sets", sets.as_ref());
     }
 
-    /// One gate field row: `-` when unset, the flag name otherwise, with a
-    /// trailing `?` when that name isn't in the declared `#flag` vocabulary. The
-    /// marker is display-only (the edit buffer, and so what commits, is untouched).
+    /// One gate field row: `-` when unset, the condition otherwise, with a
+    /// trailing `?` when it reads a flag that isn't in the declared `#flag`
+    /// vocabulary. The marker is display-only (the edit buffer, and so what
+    /// commits, is untouched).
     pub(super) fn gate_field(
         &self,
         b: &mut UiBuilder<EditorKey>,
         rows: &mut Vec<NodeId>,
         field: EditField,
         label: &str,
-        flag: Option<&str>,
+        condition: Option<&SaveTest>,
     ) {
-        let undeclared =
-            flag.is_some_and(|f| !f.is_empty() && !self.flag_names.iter().any(|n| n == f));
-        let value = match flag {
-            Some(f) if !f.is_empty() && undeclared => format!("{f} ?"),
-            Some(f) if !f.is_empty() => f.to_string(),
-            _ => "-".to_string(),
+        let undeclared = condition
+            .and_then(SaveTest::flag)
+            .is_some_and(|f| !self.flag_names.iter().any(|n| n == f));
+        let value = match condition {
+            Some(c) if undeclared => format!("{c} ?"),
+            Some(c) => c.to_string(),
+            None => "-".to_string(),
         };
         self.field_row(b, rows, field, label, &value);
     }
@@ -257,6 +257,9 @@ impl MapViewer {
             .and_then(|o| match &o.effect {
                 ObjectEffect::Interact(Interaction::Dialogue(k)) => Some(k.clone()),
                 ObjectEffect::Warp(w) => w.narration.clone(),
+                ObjectEffect::Creature(PlacedCreature { talk: Some(Talk::Dialogue(k)), .. }) => {
+                    Some(k.clone())
+                }
                 _ => None,
             });
 
@@ -514,7 +517,7 @@ impl MapViewer {
 
         // Delete: removes the selected object, or clears the Select marquee.
         if input.keyp(ScanCode::Delete) {
-            if matches!(self.tool, EditorTool::Interactables | EditorTool::Warps) {
+            if self.tool.edits_objects() {
                 self.delete_object(map);
             } else if self.tool == EditorTool::Select {
                 self.selection_delete(maps, map);
@@ -530,7 +533,7 @@ impl MapViewer {
         }
         // Arrow keys nudge the selected object's hitbox (8px with Shift), each
         // press one undo step — the keyboard companion to the x/y/w/h fields.
-        if matches!(self.tool, EditorTool::Interactables | EditorTool::Warps) {
+        if self.tool.edits_objects() {
             let step = if shift { 8 } else { 1 };
             let (mut dx, mut dy) = (0i16, 0i16);
             if input.keyp(ScanCode::Left) {
@@ -556,7 +559,7 @@ impl MapViewer {
         }
 
         // Number-row tool switching, mirroring the tab order (5 = Select, the
-        // Paint panel's sub-mode).
+        // Paint panel's sub-mode; 6 = Creatures, the Objects panel's third tab).
         let tool = if input.keyp(ScanCode::Digit1) {
             Some(EditorTool::Layers)
         } else if input.keyp(ScanCode::Digit2) {
@@ -567,6 +570,8 @@ impl MapViewer {
             Some(EditorTool::Warps)
         } else if input.keyp(ScanCode::Digit5) {
             Some(EditorTool::Select)
+        } else if input.keyp(ScanCode::Digit6) {
+            Some(EditorTool::Creatures)
         } else {
             None
         };
@@ -1235,7 +1240,7 @@ fn resolve_if_carriers(messages: Vec<Message>, save: &SaveData) -> Vec<Message>
                 flag,
                 then,
                 otherwise,
-            }] => Some(if save.flag(flag) {
+            }] => Some(if save.test(flag) {
                 then.clone()
             } else {
                 otherwise.clone()
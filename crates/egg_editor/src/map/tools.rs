@@ -182,7 +182,7 @@ impl MapViewer {
         match self.tool {
             EditorTool::Paint => self.handle_paint(input, map, maps, camera_pos, mouse),
             EditorTool::Select => self.handle_select(camera_pos, mouse),
-            EditorTool::Interactables | EditorTool::Warps => {
+            EditorTool::Interactables | EditorTool::Warps | EditorTool::Creatures => {
                 let world = Vec2::new(mouse.pos().x + camera_pos.x, mouse.pos().y + camera_pos.y);
                 if just_pressed(mouse.left) {
                     if let Some(i) = self.object_at(map, world) {
@@ -104,7 +104,7 @@ enum HiRole {
 /// The eggscene verb keywords — the first word of an indented cutscene line.
 const EGGSCENE_VERBS: &[&str] = &[
     "wait", "dialogue", "set", "sound", "music", "walk", "move", "face", "camera", "shake",
-    "over",
+    "over", "if", "elif", "else", "give", "take", "has", "warp", "fade",
 ];
 
 /// Small (condensed) text, so a cramped view framebuffer still fits a useful
@@ -1972,13 +1972,15 @@ impl TextEditor {
     /// resets the typewriter. Silent (loads through a [`Muted`] console).
     fn reload_preview(&mut self, system: &mut impl ConsoleApi, font: &Font, panel_w: i32) {
         let width = (panel_w - 8).clamp(40, 220) as usize;
-        let messages = match (&self.preview.script, &self.preview.key) {
-            (Some(script), Some(key)) => script.get_dialogue(key),
+        let empty = Script::new();
+        let script = self.preview.script.as_ref().unwrap_or(&empty);
+        let messages = match &self.preview.key {
+            Some(key) if self.preview.script.is_some() => script.get_dialogue(key),
             _ => Vec::new(),
         };
         let small = self.preview.small_font;
         let mut m = Muted(system);
-        self.preview.pages = extract_pages(&messages, width, small, &mut m, font);
+        self.preview.pages = extract_pages(script, &messages, width, small, &mut m, font);
         self.preview.page = self
             .preview
             .page
@@ -2533,8 +2535,10 @@ fn advance_dialogue(
 
 /// Resolve a conversation into its per-turn snapshots (text fitted to `width` at
 /// `small_text`, plus the portrait/flip in effect on each turn), by silently
-/// playing it through to the end. `console` should be a [`Muted`] wrapper.
+/// playing it through to the end. `script` fills in its `{item:…}`/`{label:…}`
+/// placeholders. `console` should be a [`Muted`] wrapper.
 fn extract_pages(
+    script: &Script,
     messages: &[Message],
     width: usize,
     small_text: bool,
@@ -2546,7 +2550,7 @@ fn extract_pages(
         ..SaveData::default()
     };
     let mut d = Dialogue::default().with_width(width);
-    d.set_messages(console, font, &mut save, messages);
+    d.set_messages(console, font, &mut save, script, messages);
     let mut pages = Vec::new();
     while d.current_text.is_some() {
         // A message's text always appends onto its own page (see
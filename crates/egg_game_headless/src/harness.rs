@@ -16,32 +16,39 @@
 //! log, reads fall back to the read-only `assets/` tree), the RNG is seeded to a
 //! fixed constant unless `--seed` overrides it, and neutral input is empty, so
 //! the same command line produces the same pixels every run.
+//!
+//! Scripts can also *check* what they see: `expect` commands assert on flags,
+//! the map, the player's position, the open dialogue and single pixels, and a
+//! run with any failed expectation exits nonzero with a line-by-line report.
+//! The scripts under this crate's `scripts/` directory run as `cargo test`s.
+//! `--golden DIR` goes further and compares every shot with a stored reference
+//! frame, within a per-channel tolerance, writing a diff image on a mismatch.
 
 use std::collections::HashMap;
 use std::path::{Component, Path, PathBuf};
 
 use egg_core::EggState;
-use egg_core::data::save::SAVE_PATH;
+use egg_core::data::save::{SAVE_PATH, SaveTest};
 use egg_core::data::sound::music::MusicTrack;
 use egg_core::gamestate::GameMode;
+use egg_core::gamestate::smoke::SMOKE_FRAME_CAP;
 use egg_core::geometry::Vec2;
 use egg_core::platform::{ConsoleApi, EggInput, HEIGHT, ScanCode, SfxOptions, WIDTH};
 use egg_core::rand::Lcg64Xsh32;
+use egg_core::recorder::{DEFAULT_STREAM, KEY_NAMES};
 use egg_core::render::Font;
-use egg_core::render::image::RgbaImage;
+use egg_core::render::image::{Rgba, RgbaImage};
+use egg_core::ui::fit;
 use egg_editor::map::MapViewer;
 
 use crate::run_frame;
 
+mod fuzz;
+
 /// The map a `--map`-less run starts on — the new-game start map
 /// (`WalkaroundState::new_game`), so the default is "the beginning".
 const DEFAULT_MAP: &str = "bedroom";
 
-/// Fixed RNG seed for a run without `--seed`, so screenshots are reproducible.
-/// Distinct from [`Lcg64Xsh32::default`] only in that `--seed N` reuses this
-/// stream (`stream` = PCG's default) with `N` as the state.
-const DEFAULT_STREAM: u64 = 0x0a02_bdbf_7bb3_c0a7;
-
 const USAGE: &str = "\
 egg_game_headless — boot the game headless, script input, capture PNGs
 
@@ -54,6 +61,7 @@ OPTIONS:
     --pos X,Y         Place the player at map-pixel (X, Y) and frame the camera
                       there.
     --flag NAME       Set a save flag after boot (repeatable), e.g. --flag is_night.
+    --var NAME=N      Set a save counter after boot (repeatable), e.g. --var eggs=3.
     --seed N          Seed the RNG (default: a fixed constant, so runs are
                       deterministic).
     --save FILE       Pre-seed the in-memory save store with FILE's bytes before
@@ -65,6 +73,15 @@ OPTIONS:
     --script FILE     Run an input script (see below); --frames/--out are ignored
                       and shots land in --out-dir.
     --out-dir DIR     Directory for a script's `shot` commands (default: .).
+    --golden DIR      Compare every shot against the reference DIR/NAME.png
+                      (NAME = the shot's name, or --out's file stem); a mismatch
+                      writes a NAME.diff.png next to the shot (changed pixels
+                      red over a dimmed copy of the frame) and fails the run.
+    --tolerance N     With --golden: let each colour channel of a pixel differ
+                      from the golden by up to N before it counts as changed
+                      (default: 0).
+    --update-golden   With --golden: write every shot as the new reference
+                      instead of comparing.
     --assets DIR      Read bundled game assets from DIR instead of auto-detecting
                       (tries ./assets, then ../../assets).
     --editor          Open the map editor overlay (like pressing L) before frame 1.
@@ -72,13 +89,51 @@ OPTIONS:
     --check           Cross-reference the data web (dialogue/cutscene/map/
                       portrait/sound/preset/flag references), lint every
                       script/*.eggtext language overlay against the base
-                      script's skeleton, and print a report; exits nonzero
-                      iff it found any error.
+                      script's skeleton, lay out every dialogue page of every
+                      language and #if branch in the dialogue box to find text
+                      that overflows it, flood-fill every map from each warp
+                      landing to find warps, interactables and maps the player
+                      can't walk to, and print a report — each flag and
+                      counter with where it's set and read, then the
+                      findings; exits nonzero iff it found any error.
+    --fuzz N          Play N frames of random input (rolled from --seed, which
+                      also seeds the game), checking invariants after every
+                      frame: no panic, the player inside the map, nothing
+                      walking into collision, no stuck cutscene, and a save
+                      that reads back unchanged. On a failure, writes the
+                      smallest failing input it finds to
+                      <out-dir>/fuzz_repro.script and exits nonzero.
+    --coverage        Report each script/*.eggtext language overlay's
+                      translation coverage against the base script: labels,
+                      lists and dialogue missing from it, keys only it has,
+                      and entries whose English changed since their
+                      `#source` stamp.
+    --coverage-csv FILE
+                      With (or instead of) --coverage: also write every key of
+                      every overlay, with its status, current source hash and
+                      English text, to FILE as CSV for translators.
+    --po-export FILE  Write the base script's text to FILE as a gettext PO
+                      catalogue for --lang, one entry per string, prefilled
+                      from script/LANG.eggtext when it exists.
+    --po-import FILE  Read a translated PO catalogue for --lang, lint it
+                      against the base script, and write it back into
+                      script/LANG.eggtext (overwriting it) unless the lint
+                      finds an error, which exits nonzero and leaves the
+                      file alone.
+    --lang LANG       The language --po-export/--po-import work on.
+    --smoke           Play every cutscene in the registry to its end from the
+                      booted state (--map/--pos/--flag, with the dog in tow),
+                      auto-advancing dialogue, and report each one that never
+                      finishes, cancels on a blocked required `?` motion, or
+                      names an actor that resolves to nothing; exits nonzero
+                      iff any did. --frames N caps each scene (default: 36000).
     --help            Print this help and exit.
 
 SCRIPT (line-based; blank lines and `#` comments skipped):
     wait N                 advance N frames with neutral input
-    hold <btn> N           hold controller button <btn> for N frames
+    hold <btn> N           hold controller button <btn> for N frames; <btn> may
+                           join several inputs with `+`, each a button or a
+                           `key:<name>` key, e.g. `hold up+left+key:shift 8`
     press <btn>            hold <btn> for one frame
     key <name>             tap keyboard key <name> for one frame
     type TEXT              type TEXT (one frame)
@@ -86,6 +141,15 @@ SCRIPT (line-based; blank lines and `#` comments skipped):
     click X Y              move to (X, Y) and hold left for one frame
     editor on|off          toggle the map editor overlay (no frame)
     shot NAME              write <out-dir>/NAME.png now (no frame)
+    expect flag COND       COND (a flag, or a counter comparison like `eggs >= 3`)
+                           holds in the save (no frame)
+    expect map NAME        the walkaround is on map NAME
+    expect pos X Y [±T]    the player is within T (default 0) pixels of (X, Y)
+    expect dialogue KEY    dialogue KEY is open
+    expect pixel X Y #RRGGBB
+                           the frame's pixel at (X, Y) has that colour
+  A failed expect is reported with its line and frame, the script carries on,
+  and the run exits nonzero once it ends.
   <btn>: up down left right a b x y
   <name>: a-z, 0-9, arrow keys (up/down/left/right), escape, return, space, tab,
           backspace, delete, insert, home, end, pageup, pagedown, f1-f12, and the
@@ -134,9 +198,29 @@ pub fn run() {
         std::process::exit(run_check(&mut state, &mut console, &root));
     }
 
+    if args.coverage || args.coverage_csv.is_some() {
+        std::process::exit(run_coverage(&root, args.coverage_csv.as_deref()));
+    }
+
+    if let (Some(path), Some(lang)) = (&args.po_export, &args.lang) {
+        std::process::exit(run_po_export(&root, lang, path));
+    }
+    if let (Some(path), Some(lang)) = (&args.po_import, &args.lang) {
+        std::process::exit(run_po_import(&root, lang, path));
+    }
+
+    if let Some(frames) = args.fuzz {
+        std::process::exit(fuzz::run_fuzz(state, &root, &args, frames));
+    }
+
+    if args.smoke {
+        std::process::exit(run_smoke(state, &root, &args));
+    }
+
     let mut console = HeadlessConsole::with_root(root);
     // `--save` pre-seeds the in-memory store so the one-time save load below
-    // picks it up — exactly as the real console would serve save.json.
+    // picks it up — exactly as the real console would serve a legacy save.json,
+    // which slot 0 (the slot a run starts on) falls back to.
     if let Some(path) = &args.save {
         match std::fs::read(path) {
             Ok(bytes) => {
@@ -149,68 +233,13 @@ pub fn run() {
         }
     }
 
-    if let Some(seed) = args.seed {
-        state.rng = Lcg64Xsh32::new(seed, DEFAULT_STREAM);
-    }
-
-    // The one-time data + save load (and inventory rehydrate) that
-    // `EggState::run`'s first frame performs, done up front so `--flag` edits
-    // below survive it: a pre-seeded save would otherwise be read *over* them on
-    // frame 1, wiping the flags. Doing the read here makes frame 1's guarded
-    // load a no-op, so the sequence stays single-shot exactly as in `run`.
-    state.load_data(&mut console);
-    if state.load_save(&mut console) {
-        state
-            .walkaround
-            .load_inventory(&state.save.inventory, &state.items);
-    }
-    for flag in &args.flags {
-        state.save.set_flag(flag, true);
-    }
-
-    // Force walkaround (the default gamestate is the intro animation) and load
-    // the requested map through the tested path, mirroring the new-game route.
-    state.enter(GameMode::Walkaround);
-    let map = args.map.as_deref().unwrap_or(DEFAULT_MAP);
-    if !state.maps.contains(map) {
-        let mut names = state.maps.names();
-        names.sort();
-        eprintln!("fatal: no map named `{map}`. Loaded maps: {names:?}");
-        std::process::exit(1);
-    }
-    let input = EggInput::new();
-    {
-        let mut ctx = egg_core::Ctx {
-            draw: &mut state.draw_state,
-            system: &mut console,
-            input: &input,
-            maps: &mut state.maps,
-            rng: &mut state.rng,
-            script: &state.script,
-            scenes: &state.scenes,
-            save: &mut state.save,
-            items: &state.items,
-            presets: &state.presets,
-            font: &state.font,
-        };
-        state.walkaround.load_map_by_name(&mut ctx, map);
-    }
-    if let Some((x, y)) = args.pos {
-        state.walkaround.player().pos = Vec2::new(x, y);
-    }
-    let player_pos = state.walkaround.player_ref().pos;
-    state.walkaround.center_camera_on(player_pos, WIDTH, HEIGHT);
-
-    let mut runner = Runner {
-        state,
-        console,
-        input,
-        map_viewer: MapViewer::default(),
-        mouse: (0, 0),
-        frames: 0,
-        shots: 0,
+    let mut runner = match start(state, console, &args) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            std::process::exit(1);
+        }
     };
-    runner.map_viewer.focused = args.editor;
 
     let out_dir = PathBuf::from(args.out_dir.as_deref().unwrap_or("."));
     if let Some(script_path) = &args.script {
@@ -228,23 +257,20 @@ pub fn run() {
                 std::process::exit(1);
             }
         };
-        for cmd in &commands {
-            if let Err(e) = runner.exec(cmd, &out_dir) {
-                eprintln!("fatal: {e}");
-                std::process::exit(1);
-            }
+        if let Err(e) = runner.run_script(&commands, &out_dir) {
+            eprintln!("fatal: {e}");
+            std::process::exit(1);
         }
     } else {
         for _ in 0..args.frames.unwrap_or(60) {
             runner.frame(|_| {});
         }
         let out = PathBuf::from(args.out.as_deref().unwrap_or("headless_shot.png"));
-        if let Err(e) = runner.shot(&out) {
-            eprintln!("fatal: failed to write {}: {e}", out.display());
+        let name = out.file_stem().and_then(|s| s.to_str()).unwrap_or("headless_shot");
+        if let Err(e) = runner.take_shot(name, &out) {
+            eprintln!("fatal: {e}");
             std::process::exit(1);
         }
-        println!("shot: {}", out.display());
-        runner.shots += 1;
     }
 
     let writes = &runner.console.written;
@@ -254,9 +280,16 @@ pub fn run() {
         format!(", console writes captured (in memory, not disk): {writes:?}")
     };
     println!(
-        "done: {} frame(s) run, {} shot(s) written{write_note}",
-        runner.frames, runner.shots,
+        "done: {} frame(s) run, {} shot(s) written, {} check(s) made{write_note}",
+        runner.frames, runner.shots, runner.expects,
     );
+    if !runner.failures.is_empty() {
+        eprintln!("FAILED: {} of {} check(s):", runner.failures.len(), runner.expects);
+        for failure in &runner.failures {
+            eprintln!("  {failure}");
+        }
+        std::process::exit(1);
+    }
 }
 
 /// The in-memory, disk-isolated [`ConsoleApi`] the harness steps the game
@@ -324,6 +357,12 @@ impl ConsoleApi for HeadlessConsole {
         read_asset(&self.asset_root, path)
     }
 
+    /// Drop a path from the in-memory store (an erased save slot); like every
+    /// write here, never reaches disk.
+    fn remove_file(&mut self, path: &str) {
+        self.files.remove(path);
+    }
+
     fn output_image(&mut self) -> &mut RgbaImage {
         &mut self.output
     }
@@ -387,6 +426,40 @@ pub fn decode_png(bytes: &[u8]) -> Result<RgbaImage, String> {
     Ok(RgbaImage::from_vec(rgba.into_raw(), w, h))
 }
 
+/// Compare a finished `frame` with its golden `expected` frame. A pixel has
+/// changed when any channel differs by more than `tolerance`. Returns how many
+/// changed and a diff image — changed pixels solid red over a dimmed grey copy
+/// of the frame, so the eye lands on them — or `Err` if the sizes differ.
+fn golden_diff(
+    frame: &RgbaImage,
+    expected: &RgbaImage,
+    tolerance: u8,
+) -> Result<(usize, RgbaImage), String> {
+    let (w, h) = (frame.width(), frame.height());
+    if (expected.width(), expected.height()) != (w, h) {
+        return Err(format!(
+            "the frame is {w}x{h} but the golden is {}x{}",
+            expected.width(),
+            expected.height()
+        ));
+    }
+    let mut diff = RgbaImage::new(w, h);
+    let mut changed = 0;
+    for i in 0..(w * h) as usize {
+        let (got, want) = (frame.get_pixel_index(i).0, expected.get_pixel_index(i).0);
+        let differs = got.iter().zip(want).any(|(&a, b)| a.abs_diff(b) > tolerance);
+        let pixel = if differs {
+            changed += 1;
+            Rgba::new(255, 0, 0, 255)
+        } else {
+            let grey = ((u16::from(got[0]) + u16::from(got[1]) + u16::from(got[2])) / 3) as u8;
+            Rgba::new(grey / 3, grey / 3, grey / 3, 255)
+        };
+        diff.set_pixel_index(i, pixel);
+    }
+    Ok((changed, diff))
+}
+
 /// Build the engine [`Font`] from a decoded 128×(≥128) font atlas, copying the
 /// pixels exactly as the console's `set_font` does (a blank 128×128 font zipped
 /// against the source bytes, so a taller sheet is truncated to the first 128
@@ -428,6 +501,91 @@ fn map_stems(root: &Path) -> Result<Vec<String>, String> {
     Ok(stems)
 }
 
+/// Put a booted `state` into the state the command line asks for and wrap it
+/// in a [`Runner`]: seed the RNG, run the one-time save load, set `--flag`s
+/// and `--var`s, and load the start map in walkaround with the player at
+/// `--pos` (the camera framed on them). Shared by [`run`] and the tests that play scripts.
+fn start(
+    mut state: EggState,
+    mut console: HeadlessConsole,
+    args: &Args,
+) -> Result<Runner, String> {
+    if let Some(seed) = args.seed {
+        state.rng = Lcg64Xsh32::new(seed, DEFAULT_STREAM);
+    }
+
+    // The one-time data + save load (and inventory rehydrate) that
+    // `EggState::run`'s first frame performs, done up front so `--flag` edits
+    // below survive it: a pre-seeded save would otherwise be read *over* them on
+    // frame 1, wiping the flags. Doing the read here makes frame 1's guarded
+    // load a no-op, so the sequence stays single-shot exactly as in `run`.
+    state.load_data(&mut console);
+    if state.load_save(&mut console) {
+        state
+            .walkaround
+            .load_inventory(&state.save.inventory, &state.items);
+    }
+    for flag in &args.flags {
+        state.save.set_flag(flag, true);
+    }
+    for (name, value) in &args.vars {
+        state.save.set_var(name, *value);
+    }
+
+    // Force walkaround (the default gamestate is the intro animation) and load
+    // the requested map through the tested path, mirroring the new-game route.
+    state.enter(GameMode::Walkaround);
+    let map = args.map.as_deref().unwrap_or(DEFAULT_MAP);
+    if !state.maps.contains(map) {
+        let mut names = state.maps.names();
+        names.sort();
+        return Err(format!("no map named `{map}`. Loaded maps: {names:?}"));
+    }
+    let input = EggInput::new();
+    {
+        let mut ctx = egg_core::Ctx {
+            draw: &mut state.draw_state,
+            system: &mut console,
+            input: &input,
+            maps: &mut state.maps,
+            rng: &mut state.rng,
+            script: &state.script,
+            scenes: &state.scenes,
+            save: &mut state.save,
+            items: &state.items,
+            presets: &state.presets,
+            schedules: &state.schedules,
+            font: &state.font,
+        };
+        state.walkaround.load_map_by_name(&mut ctx, map);
+    }
+    if let Some((x, y)) = args.pos {
+        state.walkaround.player().pos = Vec2::new(x, y);
+    }
+    let player_pos = state.walkaround.player_ref().pos;
+    state.walkaround.center_camera_on(player_pos, WIDTH, HEIGHT);
+
+    let mut runner = Runner {
+        state,
+        console,
+        input,
+        map_viewer: MapViewer::default(),
+        mouse: (0, 0),
+        frames: 0,
+        shots: 0,
+        expects: 0,
+        failures: Vec::new(),
+        golden: args.golden.as_ref().map(|dir| Golden {
+            dir: PathBuf::from(dir),
+            tolerance: args.tolerance.unwrap_or(0),
+            update: args.update_golden,
+        }),
+        watch: None,
+    };
+    runner.map_viewer.focused = args.editor;
+    Ok(runner)
+}
+
 /// The `.eggtext` file stems under `<root>/script/`, excluding the base
 /// language (`en`, loaded separately by [`load_script_file`]) — each is a
 /// language overlay `--check` lints against the base script's skeleton (see
@@ -556,11 +714,14 @@ fn load_scene_file(root: &Path) -> Result<egg_core::data::scene::SceneFile, Stri
 /// Run [`egg_core::data::validate::check`] over the assets under `root`, then
 /// [`egg_core::data::validate::check_overlay`] over every language overlay
 /// under `script/` (see [`script_overlay_stems`]) against the base script,
-/// and print the combined report. `state` must already be booted (for its
-/// map store) — this additionally runs `load_data` on it to pick up the
-/// *live* `data/data.toml` (presets/portraits), rather than the binary's
-/// compiled-in defaults, so `--check` always reports on the tree it read
-/// maps/script/scenes from, `--assets` override included. Returns the
+/// [`egg_core::ui::fit::check_fit`] over the base and every overlay in both
+/// font settings (see [`check_fit_both`]), and print the flag dataflow
+/// ([`egg_core::data::validate::FlagFlow`]) and the combined report. `state`
+/// must already be booted (for its map store) — this additionally runs
+/// `load_data` on it to pick up the *live* `data/data.toml`
+/// (presets/portraits), rather than the binary's compiled-in defaults, so
+/// `--check` always reports on the tree it read maps/script/scenes from,
+/// `--assets` override included. Returns the
 /// process exit code: 0 if the report has no errors (warnings don't fail the
 /// run), 1 otherwise.
 fn run_check(state: &mut EggState, console: &mut HeadlessConsole, root: &Path) -> i32 {
@@ -594,9 +755,15 @@ fn run_check(state: &mut EggState, console: &mut HeadlessConsole, root: &Path) -
         &maps,
         &state.portraits,
         &state.presets,
+        &state.schedules,
+        &state.items,
         egg_core::data::validate::ENGINE_DIALOGUE_ROOTS,
     );
 
+    // Lay out every page in the box, base language first (see `check_fit`);
+    // each overlay gets the same pass below.
+    check_fit_both(&mut report, state, &script_file, "en", &state.script);
+
     // Lint every language overlay under `script/` (besides the base `en`)
     // against the base script's skeleton — see `script_overlay_stems`.
     for lang in script_overlay_stems(root) {
@@ -621,8 +788,32 @@ fn run_check(state: &mut EggState, console: &mut HeadlessConsole, root: &Path) -
         let overlay_report = egg_core::data::validate::check_overlay(&script_file, &overlay_file, &lang);
         report.errors.extend(overlay_report.errors);
         report.warnings.extend(overlay_report.warnings);
+
+        let mut script = state.script.clone();
+        script.set_language(overlay_file.clone(), &state.portraits);
+        check_fit_both(&mut report, state, &overlay_file, &lang, &script);
     }
 
+    // Walk the world: can a new game's player get to every warp and
+    // interactable, and every map? See `check_connectivity`.
+    let fresh = egg_core::gamestate::walkaround::WalkaroundState::new();
+    let walk_report = egg_core::data::validate::check_connectivity(
+        &state.maps,
+        &state.draw_state.indexed_sprites,
+        &scene_file,
+        DEFAULT_MAP,
+        fresh.player_ref(),
+    );
+    report.errors.extend(walk_report.errors);
+    report.warnings.extend(walk_report.warnings);
+
+    // A warp `transition` the map load read as a cut. See `check_transitions`.
+    let transition_report = egg_core::data::validate::check_transitions(&state.maps);
+    report.warnings.extend(transition_report.warnings);
+
+    // The flag dataflow the dead-flag/impossible-condition warnings were
+    // judged from, so a warning's sites are one scroll up.
+    print!("{}", report.flags);
     print!("{report}");
     if report.is_clean() {
         println!("clean: 0 error(s), {} warning(s)", report.warnings.len());
@@ -633,6 +824,203 @@ fn run_check(state: &mut EggState, console: &mut HeadlessConsole, root: &Path) -
     }
 }
 
+/// Run [`fit::check_fit`] for `file` in both font settings, full-size then
+/// small, into `report` — a finding both settings make is reported once.
+fn check_fit_both(
+    report: &mut egg_core::data::validate::Report,
+    state: &EggState,
+    file: &egg_core::data::script::ScriptFile,
+    lang: &str,
+    script: &egg_core::data::script::Script,
+) {
+    for small_text in [false, true] {
+        let dialogue = &state.walkaround.dialogue;
+        let fit_report = fit::check_fit(file, lang, script, &state.font, dialogue, small_text);
+        for finding in fit_report.errors {
+            if !report.errors.contains(&finding) {
+                report.errors.push(finding);
+            }
+        }
+        for finding in fit_report.warnings {
+            if !report.warnings.contains(&finding) {
+                report.warnings.push(finding);
+            }
+        }
+    }
+}
+
+/// `--coverage`/`--coverage-csv`: print
+/// [`egg_core::data::script::coverage::coverage`] for every language overlay
+/// under `script/` (see [`script_overlay_stems`]), and write them all to
+/// `csv` as one sheet when given. Returns the process exit code: 0 unless a
+/// file couldn't be read or written — a gap in a translation is news, not a
+/// failure.
+fn run_coverage(root: &Path, csv: Option<&str>) -> i32 {
+    use egg_core::data::script::coverage::{self, CSV_HEADER};
+    let base = match load_script_file(root) {
+        Ok(f) => f,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            return 1;
+        }
+    };
+    let mut sheet = format!("{CSV_HEADER}\n");
+    let langs = script_overlay_stems(root);
+    if langs.is_empty() {
+        println!("no language overlays under script/");
+    }
+    for lang in langs {
+        let overlay = load_overlay_file(root, &lang)
+            .and_then(|f| f.ok_or_else(|| format!("script/{lang}.eggtext: unreadable")));
+        let overlay = match overlay {
+            Ok(f) => f,
+            Err(e) => {
+                eprintln!("fatal: {e}");
+                return 1;
+            }
+        };
+        let report = coverage::coverage(&base, &overlay, &lang);
+        print!("{report}");
+        report.write_csv_rows(&mut sheet);
+    }
+    if let Some(path) = csv {
+        if let Err(e) = std::fs::write(path, sheet) {
+            eprintln!("fatal: --coverage-csv {path}: {e}");
+            return 1;
+        }
+        println!("wrote {path}");
+    }
+    0
+}
+
+/// Read + parse the `script/{lang}.eggtext` overlay under `root`: `Ok(None)`
+/// if there's no such file.
+fn load_overlay_file(
+    root: &Path,
+    lang: &str,
+) -> Result<Option<egg_core::data::script::ScriptFile>, String> {
+    let path = format!("script/{lang}.eggtext");
+    let Some(bytes) = read_asset(root, &path) else {
+        return Ok(None);
+    };
+    let text = String::from_utf8(bytes).map_err(|e| format!("{path} utf8: {e}"))?;
+    egg_core::data::script::eggtext::parse(&text)
+        .map(Some)
+        .map_err(|e| format!("{path} parse: {e}"))
+}
+
+/// `--po-export FILE --lang LANG`: write
+/// [`egg_core::data::script::po::export`] of the base script to `path`,
+/// prefilled from the `lang` overlay if there is one. Returns the process
+/// exit code.
+fn run_po_export(root: &Path, lang: &str, path: &str) -> i32 {
+    let catalogue = load_script_file(root).and_then(|base| {
+        let overlay = load_overlay_file(root, lang)?;
+        Ok(egg_core::data::script::po::export(&base, overlay.as_ref(), lang))
+    });
+    let catalogue = match catalogue {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            return 1;
+        }
+    };
+    if let Err(e) = std::fs::write(path, catalogue) {
+        eprintln!("fatal: --po-export {path}: {e}");
+        return 1;
+    }
+    println!("wrote {path}");
+    0
+}
+
+/// `--po-import FILE --lang LANG`: rebuild the `lang` overlay from the PO
+/// catalogue at `path` ([`egg_core::data::script::po::import`]), lint it as it
+/// would land on disk with [`egg_core::data::validate::check_overlay`], and
+/// write it to `script/LANG.eggtext` only if that finds no error. Entries
+/// naming nothing in the base are warned about and dropped. Returns the
+/// process exit code: 1 on an I/O or parse failure or a lint error.
+fn run_po_import(root: &Path, lang: &str, path: &str) -> i32 {
+    use egg_core::data::script::{eggtext, po};
+    let rel = format!("script/{lang}.eggtext");
+    let Some(dest) = asset_path(root, &rel) else {
+        eprintln!("fatal: --lang {lang}: not a plain language name");
+        return 1;
+    };
+    let imported = load_script_file(root).and_then(|base| {
+        let text = std::fs::read_to_string(path).map_err(|e| format!("--po-import {path}: {e}"))?;
+        let imported = po::import(&base, &text).map_err(|e| format!("{path}: {e}"))?;
+        // Lint what actually lands on disk: the emitted text, parsed back.
+        let emitted = eggtext::emit(&imported.overlay);
+        let overlay = eggtext::parse(&emitted).map_err(|e| format!("emitted {rel}: {e}"))?;
+        Ok((base, imported.unmatched, overlay, emitted))
+    });
+    let (base, unmatched, overlay, emitted) = match imported {
+        Ok(i) => i,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            return 1;
+        }
+    };
+    for context in &unmatched {
+        println!("warning: `{context}` names nothing in the base script; dropped");
+    }
+    // Lint before writing: a catalogue that fails leaves the working overlay
+    // as it was.
+    let report = egg_core::data::validate::check_overlay(&base, &overlay, lang);
+    if report.errors.is_empty() {
+        if let Err(e) = std::fs::write(&dest, emitted) {
+            eprintln!("fatal: {}: {e}", dest.display());
+            return 1;
+        }
+        println!("wrote {}", dest.display());
+    } else {
+        println!("not writing {}: the import fails the lint", dest.display());
+    }
+    print!("{report}");
+    if report.errors.is_empty() { 0 } else { 1 }
+}
+
+/// `--smoke`: play every registered cutscene to its end on a snapshot of the
+/// started world (see [`egg_core::gamestate::smoke`]) and print one line per
+/// scene. The dog joins the party first when it isn't already in it, so the
+/// dog's own scenes have the companion their triggers guarantee. Returns the
+/// process exit code: 0 if every scene ran clean, 1 otherwise.
+fn run_smoke(state: EggState, root: &Path, args: &Args) -> i32 {
+    let mut runner = match start(state, HeadlessConsole::with_root(root.to_path_buf()), args) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            return 1;
+        }
+    };
+    let state = &mut runner.state;
+    let dog = egg_core::world::player::PresetId::dog();
+    if !state.walkaround.player_ref().companions.iter().any(|c| c.preset == dog) {
+        state.walkaround.give_dog(&state.presets);
+    }
+
+    let cap = args.frames.map_or(SMOKE_FRAME_CAP, |n| n as usize);
+    let reports = state.smoke_cutscenes(cap);
+    let mut failed = 0;
+    for report in &reports {
+        if report.is_clean() {
+            println!("ok    {} ({} frames)", report.name, report.frames);
+            continue;
+        }
+        failed += 1;
+        for problem in &report.problems {
+            println!("FAIL  {}: {problem}", report.name);
+        }
+    }
+    if failed == 0 {
+        println!("clean: {} cutscene(s) played to their end", reports.len());
+        0
+    } else {
+        eprintln!("FAILED: {failed} of {} cutscene(s)", reports.len());
+        1
+    }
+}
+
 /// The live harness: the booted engine, the disk-isolated console, one reused
 /// per-window input, the headless map editor, and the counters the run summary
 /// reports. Holds a sticky cursor position so a cursor move persists across
@@ -645,6 +1033,24 @@ struct Runner {
     mouse: (i16, i16),
     frames: u64,
     shots: u64,
+    /// `expect` commands checked so far, passed or not.
+    expects: u64,
+    /// One readable line per failed `expect`. A failure doesn't stop the
+    /// script — the rest still runs, so one report shows every regression —
+    /// but any entry here fails the run.
+    failures: Vec<String>,
+    /// Where shots are checked against references, if `--golden` asked.
+    golden: Option<Golden>,
+    /// The `--fuzz` invariant checker, run after every frame while set.
+    watch: Option<fuzz::Watch>,
+}
+
+/// The `--golden` settings: the reference directory, the per-channel
+/// tolerance, and whether this run re-blesses the references instead.
+struct Golden {
+    dir: PathBuf,
+    tolerance: u8,
+    update: bool,
 }
 
 impl Runner {
@@ -666,6 +1072,9 @@ impl Runner {
             false,
         );
         self.frames += 1;
+        if let Some(watch) = &mut self.watch {
+            watch.observe(&self.state, &self.console, self.frames);
+        }
     }
 
     /// Write the current framebuffer to `path` as a PNG (harness output — it goes
@@ -675,8 +1084,69 @@ impl Runner {
         std::fs::write(path, self.console.output.encode_png())
     }
 
+    /// Write the shot `name` to `path`, then hold it against its `--golden`
+    /// reference (if any): re-bless it under `--update-golden`, else compare,
+    /// writing `NAME.diff.png` beside the shot and recording a failure on a
+    /// mismatch. An `Err` is an IO error, fatal to the run.
+    fn take_shot(&mut self, name: &str, path: &Path) -> Result<(), String> {
+        self.shot(path).map_err(|e| format!("failed to write {}: {e}", path.display()))?;
+        println!("shot: {}", path.display());
+        self.shots += 1;
+        let Some(golden) = &self.golden else {
+            return Ok(());
+        };
+        let reference = golden.dir.join(format!("{name}.png"));
+        if golden.update {
+            std::fs::create_dir_all(&golden.dir)
+                .and_then(|()| std::fs::write(&reference, self.console.output.encode_png()))
+                .map_err(|e| format!("failed to write {}: {e}", reference.display()))?;
+            println!("golden: {}", reference.display());
+            return Ok(());
+        }
+        self.expects += 1;
+        let frame = &self.console.output;
+        let failure = match std::fs::read(&reference) {
+            Err(e) => Some(format!("can't read {} ({e})", reference.display())),
+            Ok(bytes) => match decode_png(&bytes) {
+                Err(e) => Some(format!("can't decode {} ({e})", reference.display())),
+                Ok(expected) => match golden_diff(frame, &expected, golden.tolerance) {
+                    Err(sizes) => Some(sizes),
+                    Ok((0, _)) => None,
+                    Ok((changed, diff)) => {
+                        let diff_path = path.with_file_name(format!("{name}.diff.png"));
+                        std::fs::write(&diff_path, diff.encode_png()).map_err(|e| {
+                            format!("failed to write {}: {e}", diff_path.display())
+                        })?;
+                        Some(format!(
+                            "{changed} pixel(s) differ beyond tolerance {} (diff: {})",
+                            golden.tolerance,
+                            diff_path.display()
+                        ))
+                    }
+                },
+            },
+        };
+        if let Some(failure) = failure {
+            self.failures.push(format!(
+                "frame {}: shot `{name}` doesn't match its golden: {failure}",
+                self.frames
+            ));
+        }
+        Ok(())
+    }
+
+    /// Execute a whole parsed script in order. An `Err` is a fatal harness
+    /// error (an unwritable shot); failed `expect`s land in
+    /// [`failures`](Self::failures) instead.
+    fn run_script(&mut self, commands: &[Command], out_dir: &Path) -> Result<(), String> {
+        for cmd in commands {
+            self.exec(cmd, out_dir)?;
+        }
+        Ok(())
+    }
+
     /// Execute one script command, advancing the frame loop as the command
-    /// dictates (see [`USAGE`]). `mouse`/`editor`/`shot` set state without a
+    /// dictates (see [`USAGE`]). `mouse`/`editor`/`shot`/`expect` act without a
     /// frame; the rest each run one or more frames.
     fn exec(&mut self, cmd: &Command, out_dir: &Path) -> Result<(), String> {
         match cmd {
@@ -685,10 +1155,16 @@ impl Runner {
                     self.frame(|_| {});
                 }
             }
-            Command::Hold(btn, n) => {
-                let btn = *btn;
+            Command::Hold(held, n) => {
                 for _ in 0..*n {
-                    self.frame(|i| press_button(i, btn));
+                    self.frame(|i| {
+                        for &input in held {
+                            match input {
+                                Held::Button(btn) => press_button(i, btn),
+                                Held::Key(sc) => i.press_key(sc),
+                            }
+                        }
+                    });
                 }
             }
             Command::Press(btn) => {
@@ -715,20 +1191,71 @@ impl Runner {
                 self.frame(|i| i.mouse.left[0] = true);
             }
             Command::Editor(on) => self.map_viewer.focused = *on,
-            Command::Shot(name) => {
-                let path = out_dir.join(format!("{name}.png"));
-                self.shot(&path)
-                    .map_err(|e| format!("failed to write {}: {e}", path.display()))?;
-                println!("shot: {}", path.display());
-                self.shots += 1;
+            Command::Shot(name) => self.take_shot(name, &out_dir.join(format!("{name}.png")))?,
+            Command::Expect(lineno, expect) => {
+                self.expects += 1;
+                if let Err(got) = self.check(expect) {
+                    self.failures.push(format!(
+                        "line {lineno}, frame {}: expected {expect}, but {got}",
+                        self.frames
+                    ));
+                }
             }
         }
         Ok(())
     }
+
+    /// Test one [`Expect`] against the game as it stands after the last frame:
+    /// `Err` describes what was found instead.
+    fn check(&self, expect: &Expect) -> Result<(), String> {
+        let state = &self.state;
+        let walk = &state.walkaround;
+        match expect {
+            Expect::Flag(condition) => match state.save.test(condition) {
+                true => Ok(()),
+                false => Err("it doesn't hold".to_string()),
+            },
+            Expect::Map(name) => match &walk.current_map.source {
+                source if source == name => Ok(()),
+                source => Err(format!("the map is `{source}`")),
+            },
+            Expect::Pos { x, y, tolerance } => {
+                let pos = walk.player_ref().pos;
+                let off = (i32::from(pos.x) - i32::from(*x)).abs()
+                    .max((i32::from(pos.y) - i32::from(*y)).abs());
+                match off <= i32::from(*tolerance) {
+                    true => Ok(()),
+                    false => Err(format!("the player is at {} {}", pos.x, pos.y)),
+                }
+            }
+            Expect::Dialogue(key) => {
+                let open = walk.dialogue.key.as_deref().filter(|_| walk.dialogue.is_active());
+                match open {
+                    Some(open) if open == key => Ok(()),
+                    Some(open) => Err(format!("dialogue `{open}` is open")),
+                    None if walk.dialogue.is_active() => {
+                        Err("an unnamed dialogue is open".to_string())
+                    }
+                    None => Err("no dialogue is open".to_string()),
+                }
+            }
+            Expect::Pixel { x, y, rgb } => {
+                let out = &self.console.output;
+                if *x >= out.width() || *y >= out.height() {
+                    return Err(format!("the frame is only {}x{}", out.width(), out.height()));
+                }
+                let [r, g, b, _] = out.get_pixel(*x, *y).0;
+                match [r, g, b] == *rgb {
+                    true => Ok(()),
+                    false => Err(format!("the pixel is #{r:02x}{g:02x}{b:02x}")),
+                }
+            }
+        }
+    }
 }
 
 /// A controller face/direction button, the target of `hold`/`press`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Button {
     Up,
     Down,
@@ -740,6 +1267,13 @@ enum Button {
     Y,
 }
 
+/// One input a `hold` keeps down: a controller button or a keyboard key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Held {
+    Button(Button),
+    Key(ScanCode),
+}
+
 /// Assert `btn` held on controller 0 for the current frame.
 fn press_button(input: &mut EggInput, btn: Button) {
     let c = &mut input.controllers[0];
@@ -755,11 +1289,43 @@ fn press_button(input: &mut EggInput, btn: Button) {
     }
 }
 
+/// What an `expect` command asserts about the game (see [`USAGE`]).
+#[derive(Debug, PartialEq)]
+enum Expect {
+    /// A save condition holds — a flag name, or a counter comparison like
+    /// `eggs >= 3` (see `SaveData::test`).
+    Flag(SaveTest),
+    /// The walkaround is on this map.
+    Map(String),
+    /// The player stands within `tolerance` map pixels of (`x`, `y`) on both
+    /// axes.
+    Pos { x: i16, y: i16, tolerance: u16 },
+    /// This script key's conversation is open in the dialogue box.
+    Dialogue(String),
+    /// The composited frame's pixel at (`x`, `y`) has this colour.
+    Pixel { x: u32, y: u32, rgb: [u8; 3] },
+}
+
+impl std::fmt::Display for Expect {
+    /// The command as the script spelled it, for the failure report.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expect::Flag(condition) => write!(f, "flag `{condition}`"),
+            Expect::Map(name) => write!(f, "map `{name}`"),
+            Expect::Pos { x, y, tolerance } => write!(f, "pos {x} {y} ±{tolerance}"),
+            Expect::Dialogue(key) => write!(f, "dialogue `{key}`"),
+            Expect::Pixel { x, y, rgb: [r, g, b] } => {
+                write!(f, "pixel {x} {y} #{r:02x}{g:02x}{b:02x}")
+            }
+        }
+    }
+}
+
 /// One parsed script command (see [`USAGE`] for the surface syntax).
 #[derive(Debug)]
 enum Command {
     Wait(u32),
-    Hold(Button, u32),
+    Hold(Vec<Held>, u32),
     Press(Button),
     Key(ScanCode),
     Type(String),
@@ -767,6 +1333,8 @@ enum Command {
     Click(i16, i16),
     Editor(bool),
     Shot(String),
+    /// An assertion, with the script line it came from for the report.
+    Expect(usize, Expect),
 }
 
 /// Parse a whole input script, skipping blank lines and `#` comments. Any error
@@ -805,11 +1373,21 @@ fn parse_command(cmd: &str, rest: &str, line: &str, lineno: usize) -> Result<Com
             let (Some(btn), Some(n)) = (it.next(), it.next()) else {
                 return Err(err("hold expects <button> <frames>".into()));
             };
-            let btn = button_from_name(btn).ok_or_else(|| err(format!("unknown button `{btn}`")))?;
+            let held = btn
+                .split('+')
+                .map(|name| match name.strip_prefix("key:") {
+                    Some(key) => scancode_from_name(key)
+                        .map(Held::Key)
+                        .ok_or_else(|| err(format!("unknown key `{key}`"))),
+                    None => button_from_name(name)
+                        .map(Held::Button)
+                        .ok_or_else(|| err(format!("unknown button `{name}`"))),
+                })
+                .collect::<Result<_, _>>()?;
             let n = n
                 .parse()
                 .map_err(|_| err("hold frame count not a number".into()))?;
-            Ok(Command::Hold(btn, n))
+            Ok(Command::Hold(held, n))
         }
         "press" => {
             let name = rest.trim();
@@ -849,10 +1427,62 @@ fn parse_command(cmd: &str, rest: &str, line: &str, lineno: usize) -> Result<Com
             }
             Ok(Command::Shot(name.to_string()))
         }
+        "expect" => parse_expect(rest).map(|e| Command::Expect(lineno, e)).map_err(err),
         other => Err(err(format!("unknown command `{other}`"))),
     }
 }
 
+/// Parse an `expect` command's arguments: its kind word, then that kind's
+/// operands (see [`USAGE`]).
+fn parse_expect(rest: &str) -> Result<Expect, String> {
+    let (kind, args) = match rest.split_once(char::is_whitespace) {
+        Some((kind, args)) => (kind, args.trim()),
+        None => (rest, ""),
+    };
+    let word = |what: &str| match args {
+        "" => Err(format!("expect {kind} expects {what}")),
+        _ => Ok(args.to_string()),
+    };
+    match kind {
+        "flag" => word("a flag or comparison").map(|c| Expect::Flag(SaveTest::from(c))),
+        "map" => word("a map name").map(Expect::Map),
+        "dialogue" => word("a dialogue key").map(Expect::Dialogue),
+        "pos" => {
+            let (x, y) = parse_xy(args)?;
+            let tolerance = match args.split_whitespace().nth(2) {
+                None => 0,
+                Some(t) => {
+                    let t = t.strip_prefix('±').or_else(|| t.strip_prefix("+-")).unwrap_or(t);
+                    t.parse().map_err(|_| format!("tolerance not a number: {t:?}"))?
+                }
+            };
+            Ok(Expect::Pos { x, y, tolerance })
+        }
+        "pixel" => {
+            let (x, y) = parse_xy(args)?;
+            let (x, y) = (
+                u32::try_from(x).map_err(|_| "X is negative".to_string())?,
+                u32::try_from(y).map_err(|_| "Y is negative".to_string())?,
+            );
+            let colour = args.split_whitespace().nth(2).unwrap_or_default();
+            let rgb = parse_rgb(colour).ok_or_else(|| format!("not a #RRGGBB colour: {colour:?}"))?;
+            Ok(Expect::Pixel { x, y, rgb })
+        }
+        "" => Err("expect needs a kind: flag, map, pos, dialogue or pixel".to_string()),
+        other => Err(format!("unknown expectation `{other}`")),
+    }
+}
+
+/// A `#RRGGBB` colour.
+fn parse_rgb(s: &str) -> Option<[u8; 3]> {
+    let hex = s.strip_prefix('#')?;
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
 /// Two whitespace-separated integers (`mouse`/`click` coordinates).
 fn parse_xy(s: &str) -> Result<(i16, i16), String> {
     let mut it = s.split_whitespace();
@@ -879,94 +1509,13 @@ fn button_from_name(name: &str) -> Option<Button> {
     })
 }
 
-/// Map a `key` command name to a [`ScanCode`]. Covers the letters, digits, arrow
-/// keys, and the named editing/function/punctuation keys the in-game text fields
-/// and hotkeys read (see [`ScanCode`]).
+
+/// Map a `key` command name to a [`ScanCode`] (see [`KEY_NAMES`]).
 fn scancode_from_name(name: &str) -> Option<ScanCode> {
-    use ScanCode::*;
-    Some(match name {
-        "a" => A,
-        "b" => B,
-        "c" => C,
-        "d" => D,
-        "e" => E,
-        "f" => F,
-        "g" => G,
-        "h" => H,
-        "i" => I,
-        "j" => J,
-        "k" => K,
-        "l" => L,
-        "m" => M,
-        "n" => N,
-        "o" => O,
-        "p" => P,
-        "q" => Q,
-        "r" => R,
-        "s" => S,
-        "t" => T,
-        "u" => U,
-        "v" => V,
-        "w" => W,
-        "x" => X,
-        "y" => Y,
-        "z" => Z,
-        "0" => Digit0,
-        "1" => Digit1,
-        "2" => Digit2,
-        "3" => Digit3,
-        "4" => Digit4,
-        "5" => Digit5,
-        "6" => Digit6,
-        "7" => Digit7,
-        "8" => Digit8,
-        "9" => Digit9,
-        "minus" => Minus,
-        "equals" => Equals,
-        "leftbracket" => LeftBracket,
-        "rightbracket" => RightBracket,
-        "backslash" => Backslash,
-        "semicolon" => Semicolon,
-        "apostrophe" => Apostrophe,
-        "grave" => Grave,
-        "comma" => Comma,
-        "period" => Period,
-        "slash" => Slash,
-        "space" => Space,
-        "tab" => Tab,
-        "return" | "enter" => Return,
-        "backspace" => Backspace,
-        "delete" | "del" => Delete,
-        "insert" => Insert,
-        "pageup" => PageUp,
-        "pagedown" => PageDown,
-        "home" => Home,
-        "end" => End,
-        "up" => Up,
-        "down" => Down,
-        "left" => Left,
-        "right" => Right,
-        "capslock" => CapsLock,
-        "ctrl" | "control" => Ctrl,
-        "shift" => Shift,
-        "alt" => Alt,
-        "escape" | "esc" => Escape,
-        "f1" => F1,
-        "f2" => F2,
-        "f3" => F3,
-        "f4" => F4,
-        "f5" => F5,
-        "f6" => F6,
-        "f7" => F7,
-        "f8" => F8,
-        "f9" => F9,
-        "f10" => F10,
-        "f11" => F11,
-        "f12" => F12,
-        _ => return None,
-    })
+    KEY_NAMES.iter().find(|(n, _)| *n == name).map(|&(_, sc)| sc)
 }
 
+
 /// Parsed command line. Every field defaults to "unset"; [`run`] applies the
 /// defaults (map = bedroom, frames = 60, out = headless_shot.png, out-dir = ".").
 #[derive(Default)]
@@ -978,6 +1527,8 @@ struct Args {
     map: Option<String>,
     pos: Option<(i16, i16)>,
     flags: Vec<String>,
+    /// Save counters to set after boot (`--var NAME=N`).
+    vars: Vec<(String, i32)>,
     seed: Option<u64>,
     save: Option<String>,
     frames: Option<u64>,
@@ -986,9 +1537,58 @@ struct Args {
     out_dir: Option<String>,
     /// Override the auto-detected asset root (`--assets DIR`).
     assets: Option<String>,
+    /// Compare every shot against the references in this dir (`--golden DIR`).
+    golden: Option<String>,
+    /// Per-channel difference a golden compare lets through (`--tolerance N`).
+    tolerance: Option<u8>,
+    /// Write shots as the new goldens instead of comparing (`--update-golden`).
+    update_golden: bool,
+    /// Play this many frames of random input, checking invariants (`--fuzz N`).
+    fuzz: Option<u64>,
+    /// Play every cutscene to its end and report the failures (`--smoke`).
+    smoke: bool,
+    /// Report each language overlay's translation coverage (`--coverage`).
+    coverage: bool,
+    /// Also write every overlay key's status as CSV here (`--coverage-csv FILE`).
+    coverage_csv: Option<String>,
+    /// Export `lang`'s translation as a PO catalogue here (`--po-export FILE`).
+    po_export: Option<String>,
+    /// Import a translated PO catalogue for `lang` from here (`--po-import FILE`).
+    po_import: Option<String>,
+    /// The language the PO export/import works on (`--lang LANG`).
+    lang: Option<String>,
 }
 
 impl Args {
+    /// The args that put [`start`] in this run's state — the assets, RNG
+    /// seed, pre-seeded save, map, position, flags and counters, and the
+    /// editor overlay — spelled back as a command line, for a run that
+    /// replays this one (see `--fuzz`).
+    fn start_args(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        if let Some(dir) = &self.assets {
+            out.push(format!("--assets {dir}"));
+        }
+        if let Some(seed) = self.seed {
+            out.push(format!("--seed {seed}"));
+        }
+        if let Some(path) = &self.save {
+            out.push(format!("--save {path}"));
+        }
+        if let Some(map) = &self.map {
+            out.push(format!("--map {map}"));
+        }
+        if let Some((x, y)) = self.pos {
+            out.push(format!("--pos {x},{y}"));
+        }
+        out.extend(self.flags.iter().map(|flag| format!("--flag {flag}")));
+        out.extend(self.vars.iter().map(|(name, value)| format!("--var {name}={value}")));
+        if self.editor {
+            out.push("--editor".to_string());
+        }
+        out
+    }
+
     /// Parse the harness args. Hand-rolled (no clap — no new deps): value-taking
     /// flags consume the next arg, an unknown flag or a missing value is an error
     /// the caller turns into a usage message + exit 2.
@@ -1005,6 +1605,7 @@ impl Args {
                 "--map" => out.map = Some(take(args, &mut i, arg)?),
                 "--pos" => out.pos = Some(parse_pos(&take(args, &mut i, arg)?)?),
                 "--flag" => out.flags.push(take(args, &mut i, arg)?),
+                "--var" => out.vars.push(parse_var(&take(args, &mut i, arg)?)?),
                 "--seed" => {
                     let v = take(args, &mut i, arg)?;
                     out.seed = Some(v.parse().map_err(|_| format!("--seed: not a number: {v}"))?);
@@ -1019,10 +1620,36 @@ impl Args {
                 "--script" => out.script = Some(take(args, &mut i, arg)?),
                 "--out-dir" => out.out_dir = Some(take(args, &mut i, arg)?),
                 "--assets" => out.assets = Some(take(args, &mut i, arg)?),
+                "--golden" => out.golden = Some(take(args, &mut i, arg)?),
+                "--tolerance" => {
+                    let v = take(args, &mut i, arg)?;
+                    out.tolerance =
+                        Some(v.parse().map_err(|_| format!("--tolerance: not 0-255: {v}"))?);
+                }
+                "--update-golden" => out.update_golden = true,
+                "--fuzz" => {
+                    let v = take(args, &mut i, arg)?;
+                    out.fuzz = Some(v.parse().map_err(|_| format!("--fuzz: not a number: {v}"))?);
+                }
+                "--smoke" => out.smoke = true,
+                "--coverage" => out.coverage = true,
+                "--coverage-csv" => out.coverage_csv = Some(take(args, &mut i, arg)?),
+                "--po-export" => out.po_export = Some(take(args, &mut i, arg)?),
+                "--po-import" => out.po_import = Some(take(args, &mut i, arg)?),
+                "--lang" => out.lang = Some(take(args, &mut i, arg)?),
                 other => return Err(format!("unknown argument: {other}")),
             }
             i += 1;
         }
+        if out.update_golden && out.golden.is_none() {
+            return Err("--update-golden needs --golden DIR".to_string());
+        }
+        if out.tolerance.is_some() && out.golden.is_none() {
+            return Err("--tolerance needs --golden DIR".to_string());
+        }
+        if (out.po_export.is_some() || out.po_import.is_some()) && out.lang.is_none() {
+            return Err("--po-export/--po-import need --lang LANG".to_string());
+        }
         Ok(out)
     }
 }
@@ -1035,6 +1662,18 @@ fn take(args: &[String], i: &mut usize, flag: &str) -> Result<String, String> {
         .ok_or_else(|| format!("{flag} needs a value"))
 }
 
+/// Parse a `--var NAME=N` string into a counter name and value.
+fn parse_var(s: &str) -> Result<(String, i32), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("--var must be NAME=N (got {s:?})"))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("--var value not an integer: {:?}", value.trim()))?;
+    Ok((name.trim().to_string(), value))
+}
+
 /// Parse a `--pos X,Y` string into map-pixel coordinates.
 fn parse_pos(s: &str) -> Result<(i16, i16), String> {
     let (x, y) = s
@@ -1054,7 +1693,6 @@ fn parse_pos(s: &str) -> Result<(i16, i16), String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use egg_core::render::image::Rgba;
 
     /// A PNG the engine's [`RgbaImage::encode_png`] produces decodes back —
     /// through the `image` crate ([`decode_png`], an independent decoder) — to
@@ -1122,38 +1760,8 @@ mod tests {
         let mut state = EggState::default();
         boot(&mut state, &root).expect("assets boot from the resolved root");
 
-        let mut console = HeadlessConsole::new();
-        state.load_data(&mut console);
-        state.enter(GameMode::Walkaround);
-        let input = EggInput::new();
-        {
-            let mut ctx = egg_core::Ctx {
-                draw: &mut state.draw_state,
-                system: &mut console,
-                input: &input,
-                maps: &mut state.maps,
-                rng: &mut state.rng,
-                script: &state.script,
-                scenes: &state.scenes,
-                save: &mut state.save,
-                items: &state.items,
-                presets: &state.presets,
-                font: &state.font,
-            };
-            state.walkaround.load_map_by_name(&mut ctx, DEFAULT_MAP);
-        }
-        let player_pos = state.walkaround.player_ref().pos;
-        state.walkaround.center_camera_on(player_pos, WIDTH, HEIGHT);
-
-        let mut runner = Runner {
-            state,
-            console,
-            input,
-            map_viewer: MapViewer::default(),
-            mouse: (0, 0),
-            frames: 0,
-            shots: 0,
-        };
+        let mut runner =
+            start(state, HeadlessConsole::new(), &Args::default()).expect("the default map starts");
         for _ in 0..60 {
             runner.frame(|_| {});
         }
@@ -1192,7 +1800,9 @@ shot frame_one";
         let commands = parse_script(script).expect("parses");
         assert_eq!(commands.len(), 10, "comment + blank line skipped");
         assert!(matches!(commands[0], Command::Wait(5)));
-        assert!(matches!(commands[1], Command::Hold(Button::Up, 10)));
+        assert!(
+            matches!(&commands[1], Command::Hold(held, 10) if held == &[Held::Button(Button::Up)])
+        );
         assert!(matches!(commands[2], Command::Press(Button::A)));
         assert!(matches!(commands[3], Command::Key(ScanCode::Escape)));
         // `type` keeps the rest of the line verbatim, internal spaces and all.
@@ -1227,18 +1837,20 @@ shot frame_one";
         assert!(parse_script("wait soon").unwrap_err().contains("line 1"));
     }
 
-    /// The CLI parser reads values, collects repeated `--flag`s, and rejects
-    /// unknown flags / missing values.
+    /// The CLI parser reads values, collects repeated `--flag`s and `--var`s,
+    /// and rejects unknown flags / missing values.
     #[test]
     fn args_parse_values_and_reject_unknowns() {
         let ok = Args::parse(&strs(&[
             "--map", "town", "--pos", "10,20", "--flag", "is_night", "--flag", "met_dog",
-            "--seed", "7", "--frames", "3", "--editor", "--assets", "../../assets",
+            "--var", "eggs=3", "--seed", "7", "--frames", "3", "--editor", "--assets",
+            "../../assets",
         ]))
         .expect("parses");
         assert_eq!(ok.map.as_deref(), Some("town"));
         assert_eq!(ok.pos, Some((10, 20)));
         assert_eq!(ok.flags, vec!["is_night", "met_dog"]);
+        assert_eq!(ok.vars, vec![("eggs".to_string(), 3)]);
         assert_eq!(ok.seed, Some(7));
         assert_eq!(ok.frames, Some(3));
         assert!(ok.editor);
@@ -1248,6 +1860,272 @@ shot frame_one";
         assert!(Args::parse(&strs(&["--map"])).is_err(), "missing value");
         assert!(Args::parse(&strs(&["--pos", "nope"])).is_err(), "bad pos");
         assert!(Args::parse(&strs(&["--seed", "x"])).is_err(), "bad seed");
+        assert!(Args::parse(&strs(&["--var", "eggs"])).is_err(), "var without a value");
+        assert!(Args::parse(&strs(&["--var", "eggs=lots"])).is_err(), "bad var value");
+        let golden = Args::parse(&strs(&["--golden", "g", "--tolerance", "4", "--update-golden"]))
+            .expect("parses");
+        assert_eq!(golden.golden.as_deref(), Some("g"));
+        assert_eq!((golden.tolerance, golden.update_golden), (Some(4), true));
+        assert!(Args::parse(&strs(&["--update-golden"])).is_err(), "nothing to update");
+        assert!(Args::parse(&strs(&["--tolerance", "4"])).is_err(), "nothing to compare");
+        let past_u8 = strs(&["--golden", "g", "--tolerance", "300"]);
+        assert!(Args::parse(&past_u8).is_err(), "tolerance past u8");
+    }
+
+    /// A run's start args parse back to the same start state, and leave out
+    /// what doesn't shape it.
+    #[test]
+    fn start_args_replay_the_start_state() {
+        let args = Args::parse(&strs(&[
+            "--assets", "a", "--seed", "7", "--save", "s.json", "--map", "town", "--pos", "1,-2",
+            "--flag", "is_night", "--var", "eggs=3", "--editor", "--fuzz", "100",
+        ]))
+        .expect("parses");
+        let line = args.start_args().join(" ");
+        assert!(!line.contains("--fuzz"), "got: {line}");
+        let words: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+        let replayed = Args::parse(&words).expect("replay parses");
+        assert_eq!(replayed.assets.as_deref(), Some("a"));
+        assert_eq!((replayed.seed, replayed.save.as_deref()), (Some(7), Some("s.json")));
+        assert_eq!((replayed.map.as_deref(), replayed.pos), (Some("town"), Some((1, -2))));
+        assert_eq!(replayed.flags, ["is_night"]);
+        assert_eq!(replayed.vars, [("eggs".to_string(), 3)]);
+        assert!(replayed.editor);
+    }
+
+    /// Every `expect` kind parses to its operands (both tolerance spellings
+    /// included), and a malformed one is a line-numbered parse error.
+    #[test]
+    fn expect_parses_every_kind() {
+        let script = "\
+expect flag eggs >= 3
+expect map town
+expect pos 10 -4
+expect pos 10 20 ±3
+expect pos 10 20 +-2
+expect dialogue bedroom_mattress
+expect pixel 1 2 #1a1C2c";
+        let expects: Vec<_> = parse_script(script)
+            .expect("parses")
+            .into_iter()
+            .map(|cmd| match cmd {
+                Command::Expect(_, expect) => expect,
+                other => panic!("expected Expect, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            expects,
+            [
+                Expect::Flag("eggs >= 3".into()),
+                Expect::Map("town".into()),
+                Expect::Pos { x: 10, y: -4, tolerance: 0 },
+                Expect::Pos { x: 10, y: 20, tolerance: 3 },
+                Expect::Pos { x: 10, y: 20, tolerance: 2 },
+                Expect::Dialogue("bedroom_mattress".into()),
+                Expect::Pixel { x: 1, y: 2, rgb: [0x1a, 0x1c, 0x2c] },
+            ]
+        );
+        for bad in ["expect", "expect colour red", "expect map", "expect pixel 1 2 red"] {
+            let err = parse_script(&format!("wait 1\n{bad}")).unwrap_err();
+            assert!(err.contains("line 2"), "{bad}: {err}");
+        }
+    }
+
+    /// A fresh bedroom start, booted the way [`run`] boots it.
+    pub(super) fn bedroom_runner(args: &Args) -> Runner {
+        let mut state = EggState::default();
+        boot(&mut state, &resolve_asset_root(None)).expect("assets boot");
+        start(state, HeadlessConsole::new(), args).expect("the map starts")
+    }
+
+    /// Passing expectations leave no failures; failing ones are all reported,
+    /// each with its line, frame and what was actually there, and the script
+    /// runs on past them.
+    #[test]
+    fn expect_failures_are_collected_with_line_and_frame() {
+        let args = Args {
+            flags: vec!["met_dog".into()],
+            ..Args::default()
+        };
+        let mut runner = bedroom_runner(&args);
+        let script = "\
+wait 3
+expect flag met_dog
+expect map bedroom
+expect map town
+expect flag !met_dog
+wait 1
+expect dialogue bedroom_mattress";
+        let commands = parse_script(script).expect("parses");
+        runner.run_script(&commands, Path::new(".")).expect("runs");
+        assert_eq!((runner.expects, runner.frames), (5, 4));
+        assert_eq!(runner.failures.len(), 3, "{:#?}", runner.failures);
+        assert!(runner.failures[0].starts_with("line 4, frame 3: expected map `town`"));
+        assert!(runner.failures[0].ends_with("the map is `bedroom`"));
+        assert!(runner.failures[1].starts_with("line 5, frame 3:"));
+        assert!(runner.failures[2].starts_with("line 7, frame 4:"));
+        assert!(runner.failures[2].ends_with("no dialogue is open"));
+    }
+
+    /// Every checked-in script under `scripts/` plays from a fresh start with
+    /// all of its expectations met — regression tests written as harness
+    /// scripts, run by `cargo test`.
+    #[test]
+    fn checked_in_scripts_pass() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("scripts");
+        let mut paths: Vec<_> = std::fs::read_dir(&dir)
+            .expect("scripts dir")
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|e| e == "script"))
+            .collect();
+        paths.sort();
+        assert!(!paths.is_empty(), "no scripts in {}", dir.display());
+        for path in paths {
+            let text = std::fs::read_to_string(&path).expect("readable script");
+            let commands = parse_script(&text).expect("parses");
+            let mut runner = bedroom_runner(&Args::default());
+            runner.run_script(&commands, &std::env::temp_dir()).expect("runs");
+            assert!(runner.expects > 0, "{} checks nothing", path.display());
+            assert!(runner.failures.is_empty(), "{}: {:#?}", path.display(), runner.failures);
+        }
+    }
+
+    /// A pixel only counts as changed past the tolerance, the diff marks
+    /// exactly the changed pixels red, and differently sized frames don't
+    /// compare at all.
+    #[test]
+    fn golden_diff_counts_pixels_past_the_tolerance() {
+        let mut golden = RgbaImage::new(4, 2);
+        golden.fill(Rgba::new(100, 100, 100, 255));
+        let mut frame = golden.clone();
+        frame.set_pixel(0, 0, Rgba::new(103, 100, 100, 255));
+        frame.set_pixel(3, 1, Rgba::new(100, 100, 90, 255));
+
+        let (changed, _) = golden_diff(&frame, &golden, 10).expect("same size");
+        assert_eq!(changed, 0);
+        let (changed, diff) = golden_diff(&frame, &golden, 3).expect("same size");
+        assert_eq!(changed, 1);
+        let red = Rgba::new(255, 0, 0, 255);
+        assert_eq!(diff.get_pixel(3, 1), red);
+        assert_ne!(diff.get_pixel(0, 0), red);
+        assert_eq!(golden_diff(&frame, &golden, 0).expect("same size").0, 2);
+
+        let err = golden_diff(&RgbaImage::new(2, 2), &golden, 0).unwrap_err();
+        assert!(err.contains("2x2") && err.contains("4x2"), "got: {err}");
+    }
+
+    /// `--update-golden` blesses a shot; the same frame then matches it, and a
+    /// tampered reference fails the run with a diff written beside the shot.
+    #[test]
+    fn golden_shots_bless_then_compare() {
+        let dir = std::env::temp_dir().join(format!("egg_golden_{}", std::process::id()));
+        let (golden_dir, out_dir) = (dir.join("golden"), dir.join("out"));
+        std::fs::create_dir_all(&out_dir).expect("temp dir");
+        let golden = |update| Args {
+            golden: Some(golden_dir.to_string_lossy().into_owned()),
+            update_golden: update,
+            ..Args::default()
+        };
+        let commands = parse_script("wait 2\nshot start").expect("parses");
+
+        let mut runner = bedroom_runner(&golden(true));
+        runner.run_script(&commands, &out_dir).expect("runs");
+        assert!(golden_dir.join("start.png").exists());
+        assert_eq!(runner.expects, 0, "blessing checks nothing");
+
+        let mut runner = bedroom_runner(&golden(false));
+        runner.run_script(&commands, &out_dir).expect("runs");
+        assert_eq!((runner.expects, runner.failures.len()), (1, 0), "{:?}", runner.failures);
+
+        let mut tampered = decode_png(&std::fs::read(golden_dir.join("start.png")).unwrap())
+            .expect("decodes");
+        tampered.set_pixel(0, 0, Rgba::new(1, 2, 3, 255));
+        std::fs::write(golden_dir.join("start.png"), tampered.encode_png()).unwrap();
+        let mut runner = bedroom_runner(&golden(false));
+        runner.run_script(&commands, &out_dir).expect("runs");
+        assert_eq!(runner.failures.len(), 1);
+        assert!(runner.failures[0].contains("1 pixel(s) differ"), "{}", runner.failures[0]);
+        assert!(out_dir.join("start.diff.png").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// Held input is run-length encoded into `hold`/`wait` lines, editor
+    /// toggles land between them, and replaying the script from the recorded
+    /// arguments (the save's counters included) ends where the live play did.
+    #[test]
+    fn recording_replays_to_the_same_place() {
+        let mut live = bedroom_runner(&Args::default());
+        live.state.save.set_var("eggs", 2);
+        let mut rec = egg_core::recorder::Recorder::start(&mut live.state, 99, false);
+        let plan: [(&[&str], u64); 3] = [(&["left"], 5), (&[], 2), (&["down", "shift"], 3)];
+        for (held, frames) in plan {
+            for _ in 0..frames {
+                live.frame(|input| {
+                    let pad = &mut input.controllers[0];
+                    pad.left[0] = held.contains(&"left");
+                    pad.down[0] = held.contains(&"down");
+                    if held.contains(&"shift") {
+                        input.press_key(ScanCode::Shift);
+                    }
+                    rec.record(input, false);
+                });
+            }
+        }
+        rec.record(&EggInput::new(), true);
+
+        assert_eq!(rec.frames(), 11);
+        let script = rec.script("bug.script");
+        let body: Vec<&str> = script.lines().filter(|l| !l.starts_with('#')).collect();
+        assert_eq!(
+            body,
+            ["hold left 5", "wait 2", "hold down+key:shift 3", "editor on", "wait 1"]
+        );
+        assert!(
+            script.contains("--map bedroom --pos 62,33 --seed 99 --var eggs=2 --script bug.script")
+        );
+
+        let args: Vec<String> = rec.args();
+        let mut replay = bedroom_runner(&Args::parse(&args).expect("recorded args parse"));
+        let commands = parse_script(&script).expect("recorded script parses");
+        let lines = commands.len() - 2; // up to the trailing `editor on` + `wait 1`
+        replay.run_script(&commands[..lines], Path::new(".")).expect("replays");
+        assert_eq!(replay.frames, live.frames);
+        let pos = |runner: &Runner| runner.state.walkaround.player_ref().pos;
+        assert_eq!(pos(&replay), pos(&live));
+        assert_eq!(replay.state.save.var("eggs"), 2);
+        assert_ne!(pos(&live).x, 62, "the live play moved");
+    }
+
+    /// A warp through a transition saves on the frame the screen is fully
+    /// covered, but the slot's thumbnail waits until the destination shows:
+    /// it comes out a picture of the new map, not a flat black cover.
+    #[test]
+    fn thumbnail_waits_out_the_warp_transition() {
+        use egg_core::data::save::slot_thumb_path;
+        use egg_core::world::map::ObjectEffect;
+        use egg_core::world::transition::{TRANSITION_FRAMES, Transition};
+
+        let mut runner = bedroom_runner(&Args { pos: Some((108, 48)), ..Args::default() });
+        for object in &mut runner.state.walkaround.current_map.objects {
+            if let ObjectEffect::Warp(warp) = &mut object.effect {
+                warp.transition = Transition::Iris;
+            }
+        }
+        let saves = runner.state.save.save_count;
+        for _ in 0..3 * TRANSITION_FRAMES {
+            runner.frame(|input| input.controllers[0].right[0] = true);
+        }
+        assert_eq!(runner.state.walkaround.current_map.source, "house_stairwell");
+        assert_eq!(runner.state.save.save_count, saves + 1, "the warp saved");
+
+        let png = runner.console.files.get(&slot_thumb_path(0)).expect("thumbnail written");
+        let thumb = RgbaImage::decode_png(png).expect("our own PNG");
+        let first = thumb.get_pixel(0, 0);
+        let flat = (0..thumb.height())
+            .all(|y| (0..thumb.width()).all(|x| thumb.get_pixel(x, y) == first));
+        assert!(!flat, "the thumbnail is a single flat colour");
     }
 
     fn strs(args: &[&str]) -> Vec<String> {
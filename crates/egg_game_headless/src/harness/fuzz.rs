@@ -0,0 +1,356 @@
+//! `--fuzz N`: drive the game with N frames of random — but seeded, so
+//! reproducible — button, keyboard and mouse input, checking invariants after
+//! every frame. The input is generated as ordinary script lines, so a failure
+//! is reported as a script: the failing prefix is cut down to a small input
+//! that still breaks the same invariant and written out for `--script` replay.
+//!
+//! The invariants (see [`Watch`]): nothing panics; in walkaround, outside a
+//! cutscene, the player stays inside the map's layers and no shell walks into
+//! collision; no cutscene stack lingers past [`CUTSCENE_LIMIT`] frames; and the
+//! save the frame flushed reads back through `SaveData::from_json` as the live
+//! save (give or take the clock, which is flushed by the hour).
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::Path;
+
+use egg_core::EggState;
+use egg_core::data::save::{SaveData, slot_path};
+use egg_core::gamestate::GameMode;
+use egg_core::platform::{HEIGHT, WIDTH};
+use egg_core::rand::Lcg64Xsh32;
+use egg_core::recorder::KEY_NAMES;
+use egg_core::world::pathfind::body_collides;
+
+use super::{Args, HeadlessConsole, Runner, boot, parse_script, start};
+
+/// The input generator's PCG stream, distinct from the game's
+/// ([`DEFAULT_STREAM`](egg_core::recorder::DEFAULT_STREAM)) so the same `--seed` gives the
+/// game and the fuzzer unrelated rolls.
+const FUZZ_STREAM: u64 = 0x5851_f42d_4c95_7f2d;
+
+/// Consecutive frames a cutscene stack may stay non-empty before it counts as
+/// stuck — a minute at 60 fps, far past any authored scene.
+const CUTSCENE_LIMIT: u64 = 60 * 60;
+
+/// Replays the shrinker may spend cutting a failing input down.
+const SHRINK_BUDGET: usize = 200;
+
+/// The controller buttons the generator holds and presses.
+const BUTTONS: [&str; 8] = ["up", "down", "left", "right", "a", "b", "x", "y"];
+
+/// A broken invariant, by kind — the shrinker keeps an input only while it
+/// breaks the same kind — with the detail for the report.
+#[derive(Clone, Debug)]
+pub(super) struct Violation {
+    kind: &'static str,
+    detail: String,
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.detail)
+    }
+}
+
+/// The per-frame invariant checker a fuzzing [`Runner`] carries (see
+/// [`Runner::frame`]). Keeps what the checks compare across frames, and the
+/// first violation with the frame it happened on.
+#[derive(Default)]
+pub(super) struct Watch {
+    /// Which shells were embedded in collision last frame (by
+    /// [`all_shells`](egg_core::gamestate::walkaround::WalkaroundState::all_shells)
+    /// order), with the map they were on; `None` when last frame didn't count.
+    embedded: Option<(String, Vec<bool>)>,
+    /// Consecutive frames the cutscene stack has been non-empty.
+    cutscene_frames: u64,
+    pub(super) violation: Option<(u64, Violation)>,
+}
+
+impl Watch {
+    /// Check the invariants after frame `frame`, recording the first failure.
+    pub(super) fn observe(&mut self, state: &EggState, console: &HeadlessConsole, frame: u64) {
+        if self.violation.is_none()
+            && let Err(violation) = self.check(state, console)
+        {
+            self.violation = Some((frame, violation));
+        }
+    }
+
+    fn check(&mut self, state: &EggState, console: &HeadlessConsole) -> Result<(), Violation> {
+        let fail = |kind, detail| Err(Violation { kind, detail });
+        let walk = &state.walkaround;
+        let walking = matches!(state.gamestate, GameMode::Walkaround);
+
+        self.cutscene_frames = if walking && !walk.cutscene.is_empty() {
+            self.cutscene_frames + 1
+        } else {
+            0
+        };
+        if self.cutscene_frames > CUTSCENE_LIMIT {
+            let detail = format!("a cutscene has run for {CUTSCENE_LIMIT} frames");
+            return fail("lingering cutscene", detail);
+        }
+
+        let map = &walk.current_map;
+        let embedded = if walking && walk.cutscene.is_empty() {
+            let now: Vec<bool> =
+                walk.all_shells().map(|s| body_collides(map, s.hitbox())).collect();
+            if let Some((source, before)) = &self.embedded
+                && *source == map.source
+                && before.len() == now.len()
+                && let Some(i) = (0..now.len()).find(|&i| now[i] && !before[i])
+            {
+                let shell = walk.all_shells().nth(i).expect("indexed above");
+                let (x, y) = (shell.pos.x, shell.pos.y);
+                let detail = format!("shell {i} walked into collision at {x} {y}");
+                return fail("embedded in collision", detail);
+            }
+            Some((map.source.clone(), now))
+        } else {
+            None
+        };
+        self.embedded = embedded;
+
+        if walking && walk.cutscene.is_empty() {
+            let layers = map.layers.iter().map(|l| l.hitbox()).filter(|h| h.area() > 0);
+            let bounds = layers.fold(None, |acc: Option<(i16, i16, i16, i16)>, h| {
+                let (x, y, ex, ey) = acc.unwrap_or((h.x, h.y, h.ex(), h.ey()));
+                Some((x.min(h.x), y.min(h.y), ex.max(h.ex()), ey.max(h.ey())))
+            });
+            let (px, py) = (walk.player_ref().pos.x, walk.player_ref().pos.y);
+            if let Some((x, y, ex, ey)) = bounds
+                && !(x..=ex).contains(&px) | !(y..=ey).contains(&py)
+            {
+                let detail = format!("the player is at {px} {py}, outside ({x}, {y})-({ex}, {ey})");
+                return fail("out of bounds", detail);
+            }
+        }
+
+        if let Some(bytes) = console.files.get(&slot_path(state.active_slot())) {
+            match SaveData::from_json(bytes) {
+                Err(e) => {
+                    return fail("save round-trip", format!("the flushed save won't load: {e}"));
+                }
+                // The clock is flushed only as the hour turns (see
+                // `EggState::flush_save`), so the file may trail it.
+                Ok(mut save) => {
+                    save.clock = state.save.clock;
+                    if save != state.save {
+                        return fail("save round-trip", "the flushed save reads back changed".into());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `below(rng, n)`: a roll in `0..n`.
+fn below(rng: &mut Lcg64Xsh32, n: u32) -> u32 {
+    rng.next_u32() % n
+}
+
+/// Script lines of random input adding up to exactly `frames` frames: mostly
+/// held buttons (walking about, mashing A), with waits, single presses, key
+/// taps, cursor moves and clicks mixed in.
+pub(super) fn generate(seed: u64, frames: u64) -> Vec<String> {
+    let mut rng = Lcg64Xsh32::new(seed, FUZZ_STREAM);
+    let keys: Vec<&str> = KEY_NAMES.iter().map(|(name, _)| *name).collect();
+    let mut lines = Vec::new();
+    let mut left = frames;
+    while left > 0 {
+        let xy = |rng: &mut Lcg64Xsh32| (below(rng, WIDTH as u32), below(rng, HEIGHT as u32));
+        let (line, used) = match below(&mut rng, 20) {
+            0..=9 => {
+                let mut held = vec![BUTTONS[below(&mut rng, 4) as usize]];
+                if below(&mut rng, 3) == 0 {
+                    held.push(BUTTONS[4 + below(&mut rng, 4) as usize]);
+                }
+                let n = u64::from(1 + below(&mut rng, 30)).min(left);
+                (format!("hold {} {n}", held.join("+")), n)
+            }
+            10..=12 => {
+                let n = u64::from(1 + below(&mut rng, 20)).min(left);
+                (format!("wait {n}"), n)
+            }
+            13..=15 => (format!("press {}", BUTTONS[below(&mut rng, 8) as usize]), 1),
+            16..=17 => {
+                let key = keys[below(&mut rng, keys.len() as u32) as usize];
+                (format!("key {key}"), 1)
+            }
+            18 => {
+                let (x, y) = xy(&mut rng);
+                (format!("mouse {x} {y}"), 1)
+            }
+            _ => {
+                let (x, y) = xy(&mut rng);
+                (format!("click {x} {y}"), 1)
+            }
+        };
+        lines.push(line);
+        left -= used;
+    }
+    lines
+}
+
+/// Play `lines` on `runner` under a [`Watch`], stopping at the first broken
+/// invariant (a panic included). Returns the violation and the index of the
+/// line it happened during.
+pub(super) fn play(runner: &mut Runner, lines: &[String]) -> Option<(usize, Violation)> {
+    runner.watch = Some(Watch::default());
+    let commands = parse_script(&lines.join("\n")).expect("generated lines parse");
+    for (i, cmd) in commands.iter().enumerate() {
+        let ran = catch_unwind(AssertUnwindSafe(|| runner.exec(cmd, Path::new("."))));
+        let violation = match ran {
+            Err(payload) => Some(Violation {
+                kind: "panic",
+                detail: payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "(non-string payload)".to_string()),
+            }),
+            Ok(_) => runner.watch.as_mut().and_then(|w| w.violation.take()).map(|(_, v)| v),
+        };
+        if let Some(violation) = violation {
+            return Some((i, violation));
+        }
+    }
+    None
+}
+
+/// Cut `lines` down while `fails` still holds for what's left: drop ever
+/// smaller runs of lines (halves, quarters, … single lines), keeping each cut
+/// that still fails, for at most `budget` tries.
+pub(super) fn shrink(
+    mut lines: Vec<String>,
+    budget: usize,
+    mut fails: impl FnMut(&[String]) -> bool,
+) -> Vec<String> {
+    let mut tries = 0;
+    let mut chunk = lines.len().div_ceil(2);
+    while chunk > 0 && tries < budget {
+        let mut i = 0;
+        while i < lines.len() && tries < budget {
+            let mut cut = lines.clone();
+            cut.drain(i..(i + chunk).min(lines.len()));
+            tries += 1;
+            if !cut.is_empty() && fails(&cut) {
+                lines = cut;
+            } else {
+                i += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+    lines
+}
+
+/// The `--fuzz N` entry: fuzz `state` (already booted from `root`) for
+/// `frames` frames. On a violation, shrink the input (re-booting for every
+/// replay), write `<out-dir>/fuzz_repro.script` and report it. Returns the
+/// process exit code: 0 clean, 1 on a violation or a harness error.
+pub(super) fn run_fuzz(state: EggState, root: &Path, args: &Args, frames: u64) -> i32 {
+    // Without `--seed` the game keeps its default RNG and the input rolls
+    // from seed 0.
+    let seed = args.seed.unwrap_or(0);
+    let fresh = || -> Result<Runner, String> {
+        let mut state = EggState::default();
+        boot(&mut state, root)?;
+        start(state, HeadlessConsole::with_root(root.to_path_buf()), args)
+    };
+    let mut runner = match start(state, HeadlessConsole::with_root(root.to_path_buf()), args) {
+        Ok(runner) => runner,
+        Err(e) => {
+            eprintln!("fatal: {e}");
+            return 1;
+        }
+    };
+    let lines = generate(seed, frames);
+    let Some((at, violation)) = play(&mut runner, &lines) else {
+        println!("fuzz: {frames} frame(s) with input seed {seed}, every invariant held");
+        return 0;
+    };
+    eprintln!("fuzz: frame {}: {violation}", runner.frames);
+
+    // Replays panic on purpose; keep the hook quiet while shrinking.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let repro = shrink(lines[..=at].to_vec(), SHRINK_BUDGET, |cut| {
+        let replayed = fresh().ok().and_then(|mut runner| play(&mut runner, cut));
+        replayed.is_some_and(|(_, v)| v.kind == violation.kind)
+    });
+    std::panic::set_hook(hook);
+
+    let out_dir = Path::new(args.out_dir.as_deref().unwrap_or("."));
+    let path = out_dir.join("fuzz_repro.script");
+    let replay = args.start_args();
+    let script = format!(
+        "# --fuzz found {violation}\n# Replay with: egg_game_headless {} --script {}\n{}\n",
+        replay.join(" "),
+        path.display(),
+        repro.join("\n")
+    );
+    if let Err(e) = std::fs::write(&path, script) {
+        eprintln!("fatal: failed to write {}: {e}", path.display());
+        return 1;
+    }
+    eprintln!(
+        "fuzz: cut the input to {} of {} line(s): {}",
+        repro.len(),
+        at + 1,
+        path.display()
+    );
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::harness::tests::bedroom_runner;
+
+    /// The same seed always generates the same input, of exactly the asked
+    /// frame count, in lines the script parser accepts.
+    #[test]
+    fn generated_input_is_seeded_and_exact() {
+        let lines = generate(7, 500);
+        assert_eq!(lines, generate(7, 500));
+        assert_ne!(lines, generate(8, 500));
+        let mut runner = bedroom_runner(&Args::default());
+        runner.run_script(&parse_script(&lines.join("\n")).expect("parses"), Path::new("."))
+            .expect("runs");
+        assert_eq!(runner.frames, 500);
+    }
+
+    /// A short fuzz of the real game breaks no invariant.
+    #[test]
+    fn a_short_fuzz_keeps_every_invariant() {
+        let mut runner = bedroom_runner(&Args::default());
+        let found = play(&mut runner, &generate(0, 1500));
+        assert!(found.is_none(), "{found:?}");
+    }
+
+    /// A broken save invariant is caught on the frame it breaks.
+    #[test]
+    fn a_save_that_reads_back_changed_is_caught() {
+        let mut runner = bedroom_runner(&Args::default());
+        runner.watch = Some(Watch::default());
+        runner.state.save.egg_count = 3;
+        runner.frame(|_| {});
+        let path = slot_path(runner.state.active_slot());
+        runner.console.files.insert(path, b"{\"egg_count\": 4}".to_vec());
+        runner.frame(|_| {});
+        let (frame, violation) = runner.watch.unwrap().violation.expect("caught");
+        assert_eq!((frame, violation.kind), (2, "save round-trip"));
+    }
+
+    /// The shrinker keeps only what the failure needs.
+    #[test]
+    fn shrink_cuts_to_the_lines_that_matter() {
+        let lines: Vec<String> = (0..40).map(|i| format!("wait {i}")).collect();
+        let needs = |cut: &[String]| {
+            cut.iter().any(|l| l == "wait 7") && cut.iter().any(|l| l == "wait 31")
+        };
+        assert_eq!(shrink(lines, 500, needs), ["wait 7", "wait 31"]);
+    }
+}
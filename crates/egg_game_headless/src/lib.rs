@@ -96,6 +96,7 @@ pub fn run_frame(
                         save: &mut state.save,
                         items: &state.items,
                         presets: &state.presets,
+                        schedules: &state.schedules,
                         font: &state.font,
                     };
                     state.walkaround.load_map_by_name(&mut ctx, &name);
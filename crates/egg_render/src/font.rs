@@ -5,6 +5,9 @@ use super::Canvas;
 use super::image::RgbaImage;
 use crate::PrintOptions;
 
+/// How far down each `\n` moves the pen, in pixels.
+pub const LINE_HEIGHT: i32 = 6;
+
 /// An 8×8 bitmap font: an [`RgbaImage`] laid out as a 16×16 grid of glyphs
 /// (indexed by `char as u8`) plus the precomputed visual width of every
 /// glyph. Caching the widths lets [`text_width`] measure a string without
@@ -156,7 +159,7 @@ fn layout(
         match char as u8 {
             10 => {
                 dx = x;
-                dy += 6;
+                dy += LINE_HEIGHT;
             }
             32 => {
                 dx += if opts.small_text { 3 } else { 4 };
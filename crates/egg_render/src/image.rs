@@ -144,6 +144,64 @@ impl RgbaImage {
         out
     }
 
+    /// Read back a PNG written by [`encode_png`](Self::encode_png) — 8-bit RGBA,
+    /// no interlace, filter-0 rows in *stored* DEFLATE blocks — so the engine
+    /// can show its own thumbnails (the save-slot screen) without an image
+    /// dependency. Anything else (a compressed or palette PNG from a paint
+    /// program) is `None`; use a real decoder for authored art. Chunk CRCs and
+    /// the Adler-32 trailer aren't checked: the bytes come from our own writes.
+    pub fn decode_png(bytes: &[u8]) -> Option<RgbaImage> {
+        let mut rest = bytes.strip_prefix(&PNG_SIGNATURE)?;
+        let mut size = None;
+        let mut zlib = Vec::new();
+        while rest.len() >= 12 {
+            let len = u32::from_be_bytes(rest[..4].try_into().ok()?) as usize;
+            let data = rest.get(8..8 + len)?;
+            match &rest[4..8] {
+                b"IHDR" => {
+                    if data.len() != 13 || data[8..] != [8, 6, 0, 0, 0] {
+                        return None;
+                    }
+                    let width = u32::from_be_bytes(data[..4].try_into().ok()?);
+                    let height = u32::from_be_bytes(data[4..8].try_into().ok()?);
+                    size = Some((width, height));
+                }
+                b"IDAT" => zlib.extend_from_slice(data),
+                b"IEND" => break,
+                _ => {}
+            }
+            rest = rest.get(12 + len..)?;
+        }
+        let (width, height) = size?;
+        // Skip the two-byte zlib header, then concatenate the stored blocks.
+        let mut raw = Vec::new();
+        let mut pos = 2;
+        loop {
+            let header = *zlib.get(pos)?;
+            if header & 0b110 != 0 {
+                return None; // a compressed block: not one of ours
+            }
+            let len = u16::from_le_bytes(zlib.get(pos + 1..pos + 3)?.try_into().ok()?) as usize;
+            raw.extend_from_slice(zlib.get(pos + 5..pos + 5 + len)?);
+            pos += 5 + len;
+            if header & 1 == 1 {
+                break;
+            }
+        }
+        let row_bytes = width as usize * 4;
+        if raw.len() != height as usize * (1 + row_bytes) {
+            return None;
+        }
+        let mut data = Vec::with_capacity(height as usize * row_bytes);
+        for row in raw.chunks_exact(1 + row_bytes) {
+            if row[0] != 0 {
+                return None;
+            }
+            data.extend_from_slice(&row[1..]);
+        }
+        Some(RgbaImage::from_vec(data, width, height))
+    }
+
     /// Convert to indexed form by matching each pixel's RGB against `palette`:
     /// the first entry whose R/G/B equal the pixel's becomes that pixel's index,
     /// and a pixel matching none becomes index 0. Alpha is ignored — only the
@@ -337,6 +395,23 @@ mod png_tests {
         assert_eq!(png[25], 6, "colour type RGBA");
     }
 
+    /// `decode_png` reads back exactly what `encode_png` wrote — including a
+    /// multi-block stream (over 64K of rows) and an empty image — and refuses
+    /// bytes that aren't a PNG at all.
+    #[test]
+    fn decode_png_round_trips_encode_png() {
+        for (w, h) in [(3, 2), (130, 130), (0, 0)] {
+            let mut img = RgbaImage::new(w, h);
+            for i in 0..(w * h) as usize {
+                img.set_pixel_index(i, Rgba::new(i as u8, (i / 7) as u8, 3, (i * 5) as u8));
+            }
+            let decoded = RgbaImage::decode_png(&img.encode_png()).expect("decodes");
+            assert_eq!((decoded.width(), decoded.height()), (w, h));
+            assert_eq!(decoded.data(), img.data());
+        }
+        assert!(RgbaImage::decode_png(b"not a png").is_none());
+    }
+
     /// The sheet-indexing policy: an exact RGB match takes that palette index, a
     /// non-match falls to 0, and alpha is ignored (a pixel whose RGB matches but
     /// whose alpha differs still indexes by colour).
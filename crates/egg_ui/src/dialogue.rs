@@ -21,12 +21,24 @@ use egg_render::geometry::Vec2;
 use egg_world::data::save::SaveData;
 use egg_world::draw_state::{DrawState, LayerId};
 
-use egg_render::{Flip, Font, PrintOptions, SpriteOptions, print_to_with_font, text_width};
+use egg_render::{
+    Flip, Font, LINE_HEIGHT, PrintOptions, SpriteOptions, print_to_with_font, text_width,
+};
 
 use egg_world::data::portraits::Portrait;
-use egg_world::data::script::message::{ChoiceOption, Message, PortraitState, TextContent};
+use egg_world::data::script::Script;
+use egg_world::data::script::message::{
+    ChoiceOption, Message, Placeholder, PortraitState, TextContent, interpolate, placeholders,
+};
 use egg_world::data::sound;
 
+/// The dialogue box's height, before a portrait stretches it.
+const BOX_HEIGHT: i32 = 24;
+/// How far a portrait stretches the box (so its frame fits alongside).
+const PORTRAIT_GROWTH: i32 = 4;
+/// The text's inset from the box's top-left corner.
+const TEXT_INSET: i32 = 3;
+
 /// The dialogue [`PrintOptions`]: defaults plus the caller's small-text setting
 /// (the save flag `small_text_on`, passed in now that it's game state).
 pub fn print_options(small_text: bool) -> PrintOptions {
@@ -91,6 +103,40 @@ fn lower_messages(messages: &[Message]) -> Vec<TextContent> {
     queue
 }
 
+/// Look up every `{item:KEY}`/`{label:KEY}` placeholder in `messages` — text,
+/// choice options, and both sides of every `#if` — against `script`, onto
+/// `lookups` (see [`Dialogue::lookups`]).
+fn collect_lookups(messages: &[Message], script: &Script, lookups: &mut Vec<(String, String)>) {
+    fn add(text: &str, script: &Script, lookups: &mut Vec<(String, String)>) {
+        for (range, placeholder) in placeholders(text) {
+            let value = match placeholder {
+                Placeholder::Item(key) => script.item_name(key),
+                Placeholder::Label(key) => script.label(key),
+                Placeholder::EggCount | Placeholder::Flag(_) => continue,
+            };
+            let key = &text[range.start + 1..range.end - 1];
+            if !lookups.iter().any(|(k, _)| k == key) {
+                lookups.push((key.to_string(), value));
+            }
+        }
+    }
+    for message in messages {
+        for content in &message.content {
+            match content {
+                TextContent::Text { text, .. } => add(text, script, lookups),
+                TextContent::Choice(options) => {
+                    options.iter().for_each(|o| add(&o.text, script, lookups))
+                }
+                TextContent::If { then, otherwise, .. } => {
+                    collect_lookups(then, script, lookups);
+                    collect_lookups(otherwise, script, lookups);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 /// The portrait/side actually shown for each of `messages`, folding every
 /// message's `Keep`/`Clear`/`Set` (and flip's `None`/`Some`) against whatever
 /// came before it — the same carry-over rule [`lower_messages`] bakes into
@@ -166,6 +212,16 @@ pub struct Dialogue {
     /// `on NAME` handler. State-flavoured: banked even under a manual
     /// fast-forward (see [`TextContent::Cue`]).
     pub pending_cues: Vec<String>,
+    /// This conversation's `{item:KEY}`/`{label:KEY}` values, looked up once
+    /// when [`set_messages`](Self::set_messages) queues it (keyed by the text
+    /// between the braces) — they only change with the language, unlike the
+    /// save-backed placeholders [`interpolate`](Self::interpolate) reads live.
+    pub lookups: Vec<(String, String)>,
+    /// The script key of the conversation playing, when it was started by
+    /// key through [`open`](Self::open) — what tooling (the headless harness's
+    /// `expect dialogue`) reads to tell which conversation is on screen.
+    /// `None` for messages queued directly.
+    pub key: Option<String>,
 }
 impl Dialogue {
     pub const fn default() -> Self {
@@ -184,6 +240,8 @@ impl Dialogue {
             choice: None,
             pending_shake: None,
             pending_cues: Vec::new(),
+            lookups: Vec::new(),
+            key: None,
         }
     }
     pub fn with_width(self, width: usize) -> Self {
@@ -214,20 +272,42 @@ impl Dialogue {
     /// playback reaches it, so passing `&mut save` is what lets dialogue both
     /// mutate progress as it plays *and* branch on progress set earlier in the
     /// very same conversation.
+    ///
+    /// `script` fills in the conversation's `{item:KEY}`/`{label:KEY}`
+    /// placeholders (see [`lookups`](Self::lookups)); the rest are read from
+    /// `save` as each line plays.
     pub fn set_messages(
         &mut self,
         system: &mut impl ConsoleApi,
         font: &Font,
         save: &mut SaveData,
+        script: &Script,
         messages: &[Message],
     ) {
         // A host may start a new conversation without calling `close()`
         // first — stale cues from whatever played before must not leak into
         // this one.
         self.pending_cues.clear();
+        self.lookups.clear();
+        self.key = None;
+        collect_lookups(messages, script, &mut self.lookups);
         self.next_text = lower_messages(messages).into_iter().rev().collect();
         self.next_text(system, font, save, false);
     }
+    /// Look conversation `key` up in `script` and [queue](Self::set_messages)
+    /// it, remembering the [`key`](Self::key).
+    pub fn open(
+        &mut self,
+        system: &mut impl ConsoleApi,
+        font: &Font,
+        save: &mut SaveData,
+        script: &Script,
+        key: &str,
+    ) {
+        let convo = script.get_dialogue(key);
+        self.set_messages(system, font, save, script, &convo);
+        self.key = Some(key.to_string());
+    }
     pub fn next_text(
         &mut self,
         system: &mut impl ConsoleApi,
@@ -263,6 +343,9 @@ impl Dialogue {
             // first either way; a manual skip drops the hold, same as it
             // always has for an append.
             TextContent::Text { text, delay, .. } => {
+                // Substitute first, so the wrap (and the typewriter's length)
+                // is measured on what is actually shown.
+                let text = self.interpolate(&text, save);
                 let wrap_width = self.wrap_width();
                 if let Some(string) = &mut self.current_text {
                     string.push_str(&text);
@@ -323,6 +406,14 @@ impl Dialogue {
                 save.set_flag(&name, value);
                 true
             }
+            TextContent::SetVar(name, value) => {
+                save.set_var(&name, value);
+                true
+            }
+            TextContent::AddVar(name, delta) => {
+                save.add_var(&name, delta);
+                true
+            }
             // Bank the cue for the cutscene engine to drain — state-flavoured
             // like `SetFlag` above, unlike `Shake` below: no `manual_skip`
             // guard, so a fast-forwarded cue is still banked. Skipping
@@ -343,7 +434,10 @@ impl Dialogue {
             // Open the menu and stop: playback blocks here until the driver calls
             // [`confirm_choice`](Self::confirm_choice). `Choice` is neither auto
             // nor skip, so `next_text` returns after consuming it.
-            TextContent::Choice(options) => {
+            TextContent::Choice(mut options) => {
+                for option in &mut options {
+                    option.text = self.interpolate(&option.text, save);
+                }
                 self.choice = Some(ChoiceState {
                     options,
                     selected: 0,
@@ -361,7 +455,7 @@ impl Dialogue {
                 then,
                 otherwise,
             } => {
-                let branch = if save.flag(&flag) { &then } else { &otherwise };
+                let branch = if save.test(&flag) { &then } else { &otherwise };
                 let mut spliced = lower_messages(branch);
                 // The pause separating the branch's last page from whatever
                 // follows the `#end` — only when both sides actually exist
@@ -384,12 +478,40 @@ impl Dialogue {
             }
         }
     }
+    /// `text` with its `{…}` placeholders filled in: `{egg_count}` and
+    /// `{flag:NAME}` from the live `save` (so a `#set` earlier in the same
+    /// conversation shows), `{item:KEY}`/`{label:KEY}` from
+    /// [`lookups`](Self::lookups).
+    pub fn interpolate(&self, text: &str, save: &SaveData) -> String {
+        interpolate(text, |placeholder| match placeholder {
+            Placeholder::EggCount => save.egg_count.to_string(),
+            Placeholder::Flag(name) => save.flag(name).to_string(),
+            Placeholder::Item(key) => self.lookup("item", key),
+            Placeholder::Label(key) => self.lookup("label", key),
+        })
+    }
+    fn lookup(&self, kind: &str, key: &str) -> String {
+        let wanted = format!("{kind}:{key}");
+        self.lookups
+            .iter()
+            .find(|(k, _)| *k == wanted)
+            .map(|(_, value)| value.clone())
+            .unwrap_or_default()
+    }
     pub fn fit_text(&self, font: &Font, small_text: bool, string: &str) -> String {
         fit_default_paragraph(font, string, self.wrap_width(), small_text)
     }
     pub fn wrap_width(&self) -> usize {
         self.width - 3
     }
+    /// Text rows the box shows: the rows of [`LINE_HEIGHT`] that fit below
+    /// the text inset, in the box as
+    /// [`draw_dialogue_box_with_offset`](Self::draw_dialogue_box_with_offset)
+    /// sizes it — three, or four in the box a portrait stretches.
+    pub const fn lines(portrait: bool) -> usize {
+        let height = if portrait { BOX_HEIGHT + PORTRAIT_GROWTH } else { BOX_HEIGHT };
+        ((height - TEXT_INSET) / LINE_HEIGHT) as usize
+    }
     pub fn close(&mut self) {
         *self = Self {
             width: self.width,
@@ -544,9 +666,9 @@ impl Dialogue {
         let (screen_w, screen_h) = draw_state.size();
 
         let w = self.width as i32;
-        let h = 24;
+        let h = BOX_HEIGHT;
         self.draw_dialogue_box_with_offset(
-            draw_state, layer, font, small_text, string, timer, 14, -2, 4,
+            draw_state, layer, font, small_text, string, timer, 14, -2, PORTRAIT_GROWTH,
         );
         let rect_fill = draw_state.colour(0);
         let rect_outline = draw_state.colour(3);
@@ -601,7 +723,7 @@ impl Dialogue {
 
         let print_timer = self.characters;
         let w = self.width as i32;
-        let h = 24;
+        let h = BOX_HEIGHT;
 
         let outline_colour = draw_state.colour(if self.dark_theme { 1u8 } else { 3 });
         let bg_colour = draw_state.colour(if self.dark_theme { 1u8 } else { 2 });
@@ -627,7 +749,7 @@ impl Dialogue {
                 dark,
                 outline_colour,
             );
-            height += 4;
+            height += PORTRAIT_GROWTH;
             crate::portrait::draw_offset(
                 portrait,
                 draw_state,
@@ -676,8 +798,8 @@ impl Dialogue {
             font,
             draw_state.rgba(layer),
             text,
-            (screen_w - w) / 2 + 3 + x,
-            (screen_h - h) - 4 + 3 + y,
+            (screen_w - w) / 2 + TEXT_INSET + x,
+            (screen_h - h) - 4 + TEXT_INSET + y,
             bright,
             PrintOptions {
                 color: 12,
@@ -727,7 +849,7 @@ impl Dialogue {
         let options = print_options(small_text);
         let (w, wrapped) =
             wrap_choice_options(font, &choice.options, small_text, self.width as i32);
-        let box_h = 24;
+        let box_h = BOX_HEIGHT;
         let row_h = 8;
         let pad = 3;
         let prefix_w = choice_marker_width(font, options.clone());
@@ -1030,7 +1152,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(d.current_text.as_deref(), Some("Hi."));
         advance(&mut d, &mut console, &font, &mut save);
         assert_eq!(d.current_text.as_deref(), Some("Bye."));
@@ -1051,7 +1173,7 @@ mod tests {
         d.pending_cues = vec!["stale".to_string()];
 
         let messages = dialogue_from("#dialogue d\n    Hi.", "d");
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert!(
             d.pending_cues.is_empty(),
             "stale cues from before must not leak into the new conversation"
@@ -1139,7 +1261,7 @@ mod tests {
             TextContent::text("What'll it be?"),
             TextContent::Choice(options),
         ])];
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
 
         // The prompt page shows first; the box is active but not yet choosing.
         assert!(d.is_active());
@@ -1232,6 +1354,13 @@ mod tests {
         }
     }
 
+    /// The row count the fit check enforces is the one the box draws: three
+    /// rows, or four in a portrait's stretched box.
+    #[test]
+    fn lines_follow_the_box_layout() {
+        assert_eq!((Dialogue::lines(false), Dialogue::lines(true)), (3, 4));
+    }
+
     #[test]
     fn fit_paragraph_terminates_on_an_over_wide_word() {
         // A fully-opaque atlas makes every glyph 8px wide, so even a single
@@ -1307,7 +1436,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(d.current_text.as_deref(), Some("Hi."));
         assert!(d.portrait.is_some(), "the first message's #pic shows a portrait");
         let first_portrait = d.portrait.clone();
@@ -1332,7 +1461,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert!(d.portrait.is_some());
 
         advance(&mut d, &mut console, &font, &mut save);
@@ -1354,7 +1483,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert!(d.flip_portrait, "the first message's #flip true takes effect");
 
         advance(&mut d, &mut console, &font, &mut save);
@@ -1380,7 +1509,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert!(d.flip_portrait);
         let first_portrait = d.portrait.clone();
 
@@ -1407,7 +1536,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(
             (d.speed_chars, d.speed_frames),
             (1, 5),
@@ -1437,7 +1566,7 @@ mod tests {
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
 
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!((d.speed_chars, d.speed_frames), (3, 1));
         assert_eq!(d.characters, 0);
 
@@ -1489,7 +1618,7 @@ mod tests {
             let mut save = SaveData::default();
             let mut d = Dialogue::default();
 
-            d.set_messages(&mut console, &font, &mut save, &messages);
+            d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
             assert_eq!(d.current_text.as_deref(), Some("Hi!"));
 
             advance(&mut d, &mut console, &font, &mut save);
@@ -1529,7 +1658,7 @@ mod tests {
         let font = Font::blank();
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(d.current_text.as_deref(), Some("First."));
 
         // `finish_line` before each call mirrors the real driver only calling
@@ -1568,7 +1697,7 @@ mod tests {
         let mut save = SaveData::default();
         save.set_flag("set_flag", true);
         let mut d = Dialogue::default();
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(d.current_text.as_deref(), Some("Branch page."));
 
         // The one pause after the branch's last page — no visible change.
@@ -1608,7 +1737,7 @@ mod tests {
             save.set_flag("a", a);
             save.set_flag("b", b);
             let mut d = Dialogue::default();
-            d.set_messages(&mut console, &font, &mut save, &messages);
+            d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
             d.current_text.clone().unwrap_or_default()
         };
 
@@ -1640,11 +1769,70 @@ mod tests {
         let font = Font::blank();
         let mut save = SaveData::default();
         let mut d = Dialogue::default();
-        d.set_messages(&mut console, &font, &mut save, &messages);
+        d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
         assert_eq!(d.current_text.as_deref(), Some("Intro."));
         assert!(save.flag("flag_x"), "the #set fired immediately, during its own message");
 
         advance(&mut d, &mut console, &font, &mut save);
         assert_eq!(d.current_text.as_deref(), Some("Yes."));
     }
+
+    /// `#add` fires in place like `#set`, so a later `#if` comparing the same
+    /// counter in the same conversation already sees the new value.
+    #[test]
+    fn an_add_earlier_in_a_conversation_drives_a_later_comparison() {
+        let src = "#var visits\n\
+                    #dialogue conv\n\
+                    \x20   #add visits 2\n\
+                    \x20   Hello.\n\
+                    \n\
+                    \x20   #if visits >= 3\n\
+                    \x20       Again?\n\
+                    \x20   #else\n\
+                    \x20       Welcome.";
+        let messages = dialogue_from(src, "conv");
+
+        let play = |start: i32| -> (String, i32) {
+            let mut console = NullConsole::new();
+            let font = Font::blank();
+            let mut save = SaveData::default();
+            save.set_var("visits", start);
+            let mut d = Dialogue::default();
+            d.set_messages(&mut console, &font, &mut save, &Script::new(), &messages);
+            advance(&mut d, &mut console, &font, &mut save);
+            (d.current_text.clone().unwrap_or_default(), save.var("visits"))
+        };
+        assert_eq!(play(0), ("Welcome.".to_string(), 2));
+        assert_eq!(play(1), ("Again?".to_string(), 3));
+    }
+
+    /// Placeholders are filled in as each page opens: from the live save for
+    /// `{egg_count}`/`{flag:}` (so a `#set` earlier in the same message
+    /// already shows), from the script for `{item:}`/`{label:}`.
+    #[test]
+    fn placeholders_are_filled_in_when_a_page_opens() {
+        use egg_world::data::portraits::Portraits;
+        use egg_world::data::script::eggtext;
+        let src = "#flag met\n\
+                   ok = \"Okay\"\n\
+                   #list item_chegg\n\
+                   \x20   Chegg\n\
+                   #dialogue conv\n\
+                   \x20   #set met true\n\
+                   \x20   {egg_count} eggs, a {item:chegg}, {flag:met}. {label:ok} {nope}";
+        let mut script = Script::new();
+        script.set_base(eggtext::parse(src).expect("parse eggtext"), &Portraits::builtin());
+        let messages = script.get_dialogue("conv");
+
+        let mut console = NullConsole::new();
+        let font = Font::blank();
+        let mut save = SaveData { egg_count: 7, ..SaveData::default() };
+        let mut d = Dialogue::default();
+        d.set_messages(&mut console, &font, &mut save, &script, &messages);
+        assert_eq!(
+            d.current_text.as_deref(),
+            Some("7 eggs, a Chegg, true. Okay {nope}"),
+            "unknown placeholders are left as written"
+        );
+    }
 }
@@ -0,0 +1,280 @@
+// Copyright (c) 2023 Adam Godwin <evilspamalt/at/gmail.com>
+//
+// This file is part of Egg Game - https://github.com/Madadog/Egg-Game/
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Does every dialogue page fit the box? [`check_fit`] lays each page out the
+//! way [`Dialogue`] will at playback — same font, same wrap, same line count —
+//! down every `#if` branch, and reports the pages that don't as
+//! [`validate`](egg_world::data::validate) [`Finding`]s. It lives here rather
+//! than in `validate` because the wrap it has to match is the widget's own.
+
+use egg_render::{Font, text_width};
+use egg_world::data::script::message::{Placeholder, interpolate};
+use egg_world::data::script::{
+    ContentDef, DialogueDef, Entry, MessageDef, PortraitChange, Script, ScriptFile, SegmentDef,
+};
+use egg_world::data::validate::{Finding, Report};
+
+use crate::dialogue::{Dialogue, print_options};
+
+/// Lay out every page of `file`'s own dialogue (language `lang`) in a box
+/// shaped like `dialogue`, and report each page that wraps to more lines
+/// than [`Dialogue::lines`] allows ([`Finding::DialogueOverflow`]) or holds a
+/// word the wrap can't break ([`Finding::DialogueOverwide`]). The portrait is
+/// followed through each conversation as the box would carry it, since one
+/// showing makes room for another line; across an `#if`, each branch starts
+/// from the portrait before it and the text after `#end` carries on from
+/// there too.
+///
+/// `script` — with `lang` active — fills `{item:KEY}`/`{label:KEY}` as the
+/// box would. The save-backed placeholders take their widest values: the
+/// largest egg count a save holds, and `false` for a flag. `small_text` is
+/// the player's font setting; the full-size font (`false`) is the one that
+/// overflows first.
+pub fn check_fit(
+    file: &ScriptFile,
+    lang: &str,
+    script: &Script,
+    font: &Font,
+    dialogue: &Dialogue,
+    small_text: bool,
+) -> Report {
+    let mut keys: Vec<&String> = file.dialogue.keys().collect();
+    keys.sort();
+    let mut layout = Layout {
+        lang,
+        key: "",
+        script,
+        font,
+        dialogue,
+        small_text,
+        portrait: false,
+        report: Report::default(),
+    };
+    for key in keys {
+        layout.key = key;
+        layout.portrait = false;
+        layout.def(&file.dialogue[key], &mut Vec::new());
+    }
+    layout.report
+}
+
+/// The walk's fixed inputs, and the report it fills.
+struct Layout<'a> {
+    lang: &'a str,
+    key: &'a str,
+    script: &'a Script,
+    font: &'a Font,
+    dialogue: &'a Dialogue,
+    small_text: bool,
+    /// Whether a portrait is showing at this point in the conversation.
+    portrait: bool,
+    report: Report,
+}
+
+impl Layout<'_> {
+    /// One dialogue body at `branch`. Pages are numbered across its plain
+    /// segments, so an `#if` between two runs doesn't restart the count.
+    fn def(&mut self, def: &DialogueDef, branch: &mut Vec<String>) {
+        let mut page = 0;
+        match def {
+            DialogueDef::Plain(entry) => self.entry(entry, branch, &mut page),
+            DialogueDef::Segments { segments } => {
+                for segment in segments {
+                    match segment {
+                        SegmentDef::Plain(entry) => self.entry(entry, branch, &mut page),
+                        SegmentDef::If { flag, negated, then, otherwise, elifs } => {
+                            let not = if *negated { "not " } else { "" };
+                            self.branch(format!("if {not}{flag}"), then, branch);
+                            for elif in elifs {
+                                let not = if elif.negated { "not " } else { "" };
+                                self.branch(format!("elif {not}{}", elif.flag), &elif.then, branch);
+                            }
+                            if let Some(otherwise) = otherwise {
+                                self.branch("else".to_string(), otherwise, branch);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn branch(&mut self, step: String, def: &DialogueDef, branch: &mut Vec<String>) {
+        let portrait = self.portrait;
+        branch.push(step);
+        self.def(def, branch);
+        branch.pop();
+        self.portrait = portrait;
+    }
+
+    fn entry(&mut self, entry: &Entry, branch: &[String], page: &mut usize) {
+        match entry {
+            Entry::Line(text) => self.page([text.as_str()], branch, page),
+            Entry::Pages(pages) => {
+                for text in pages {
+                    self.page([text.as_str()], branch, page);
+                }
+            }
+            Entry::Conversation { messages } => {
+                for message in messages {
+                    match &message.portrait {
+                        PortraitChange::Keep => {}
+                        PortraitChange::Clear => self.portrait = false,
+                        PortraitChange::Set(_) => self.portrait = true,
+                    }
+                    // The box is drawn at the height of whatever portrait the
+                    // page finishes under.
+                    for content in &message.content {
+                        if let ContentDef::Portrait(name) = content {
+                            self.portrait = name.is_some();
+                        }
+                    }
+                    self.page(texts(message), branch, page);
+                }
+            }
+        }
+    }
+
+    /// Build one page from its text runs exactly as
+    /// `Dialogue::consume_text_content` does — each run interpolated, appended
+    /// to what's open, and the whole page re-wrapped — then measure it.
+    fn page<'t>(&mut self, runs: impl IntoIterator<Item = &'t str>, branch: &[String], page: &mut usize) {
+        let index = *page;
+        *page += 1;
+        let mut text: Option<String> = None;
+        for run in runs {
+            let run = self.interpolate(run);
+            text = Some(match text {
+                Some(mut open) => {
+                    open.push_str(&run);
+                    self.dialogue.fit_text(self.font, self.small_text, &open)
+                }
+                None => self.dialogue.fit_text(self.font, self.small_text, &run),
+            });
+        }
+        let Some(text) = text else {
+            return;
+        };
+
+        let branch = branch.join(" > ");
+        let lines = text.lines().count();
+        let max = Dialogue::lines(self.portrait);
+        if lines > max {
+            self.report.push(Finding::DialogueOverflow {
+                lang: self.lang.to_string(),
+                key: self.key.to_string(),
+                branch: branch.clone(),
+                page: index,
+                lines,
+                max,
+            });
+        }
+        // A line only runs wide when the wrap had to take a lone word that
+        // didn't fit (see `fit_string`), so the line *is* the word.
+        let options = print_options(self.small_text);
+        for line in text.lines().map(str::trim) {
+            if text_width(self.font, line, options.clone()) as usize > self.dialogue.wrap_width() {
+                self.report.push(Finding::DialogueOverwide {
+                    lang: self.lang.to_string(),
+                    key: self.key.to_string(),
+                    branch: branch.clone(),
+                    page: index,
+                    word: line.to_string(),
+                });
+            }
+        }
+    }
+
+    fn interpolate(&self, text: &str) -> String {
+        interpolate(text, |placeholder| match placeholder {
+            Placeholder::EggCount => u16::MAX.to_string(),
+            Placeholder::Flag(_) => false.to_string(),
+            Placeholder::Item(key) => self.script.item_name(key),
+            Placeholder::Label(key) => self.script.label(key),
+        })
+    }
+}
+
+/// A message's text runs, in order — everything that lands on its page.
+fn texts(message: &MessageDef) -> impl Iterator<Item = &str> {
+    message.content.iter().filter_map(|content| match content {
+        ContentDef::Text(text) | ContentDef::Auto(text) | ContentDef::Delayed(text, _) => Some(text.as_str()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egg_world::data::script::eggtext;
+
+    fn check(src: &str, width: usize) -> Report {
+        let file = eggtext::parse(src).expect("parse eggtext");
+        // `Font::blank` has zero-width glyphs, so every letter advances 1px
+        // and every space 4px — enough to pin the wrap without the atlas.
+        let dialogue = Dialogue::default().with_width(width);
+        check_fit(&file, "en", &Script::new(), &Font::blank(), &dialogue, false)
+    }
+
+    /// A page that fits raises nothing; one that wraps past the box's three
+    /// rows is an overflow, named down its `#if` path. A portrait makes room
+    /// for a fourth row, and carries into the branch.
+    #[test]
+    fn overflow_is_reported_with_its_branch() {
+        let src = "#flag tea\n\
+                   #dialogue menu\n\
+                   \x20   aaaa bbbb\n\
+                   \x20   #if not tea\n\
+                   \x20   aaaa bbbb cccc dddd\n\
+                   \x20   #end\n\
+                   #dialogue portrait\n\
+                   \x20   #pic y_normal\n\
+                   \x20   aaaa\n\
+                   \x20   #if tea\n\
+                   \x20   aaaa bbbb cccc dddd\n\
+                   \x20   #end";
+        let report = check(src, 13);
+        assert!(report.errors.is_empty());
+        assert_eq!(
+            report.warnings,
+            vec![Finding::DialogueOverflow {
+                lang: "en".into(),
+                key: "menu".into(),
+                branch: "if not tea".into(),
+                page: 0,
+                lines: 4,
+                max: Dialogue::lines(false),
+            }]
+        );
+    }
+
+    /// A word longer than the wrap width can't be broken: it's reported as
+    /// jutting past the box.
+    #[test]
+    fn unbreakable_word_is_reported() {
+        let report = check("#dialogue sign\n    Supercalifragilistic!", 13);
+        assert_eq!(
+            report.warnings,
+            vec![Finding::DialogueOverwide {
+                lang: "en".into(),
+                key: "sign".into(),
+                branch: String::new(),
+                page: 0,
+                word: "Supercalifragilistic!".into(),
+            }]
+        );
+    }
+}
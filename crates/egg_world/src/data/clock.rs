@@ -0,0 +1,221 @@
+// Copyright (c) 2023 Adam Godwin <evilspamalt/at/gmail.com>
+//
+// This file is part of Egg Game - https://github.com/Madadog/Egg-Game/
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! The world's time of day. A [`Clock`] rides in the save
+//! ([`SaveData::clock`](crate::data::save::SaveData::clock)) and the walkaround
+//! ticks it once per simulated frame, one game minute every
+//! [`FRAMES_PER_MINUTE`] frames — so a whole day passes in 24 real minutes.
+//!
+//! The day runs through four [`Phase`]s. Day and night are steady; dawn and
+//! dusk are the two-hour blends between them, which
+//! [`night_amount`](Clock::night_amount) measures for the walkaround to fade
+//! the palette by. The [`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG)
+//! story flag follows the clock, turning at the middle of each blend (and
+//! `#set is_night` winds the clock to match), and the
+//! hour reads as the [`HOUR_VAR`] counter — so `#if hour >= 18`, a gate's
+//! `if = "hour < 6"` and a scene's `if hour >= 20` all work like any other
+//! counter test, and `#setv hour 7` winds the clock.
+
+use serde::{Deserialize, Serialize};
+
+/// Simulated frames per game minute.
+pub const FRAMES_PER_MINUTE: u32 = 60;
+
+pub const MINUTES_PER_DAY: u32 = 24 * 60;
+
+const FRAMES_PER_DAY: u32 = MINUTES_PER_DAY * FRAMES_PER_MINUTE;
+
+/// The counter name the hour of day reads and writes as, through
+/// [`SaveData::var`](crate::data::save::SaveData::var) /
+/// [`set_var`](crate::data::save::SaveData::set_var): `0`-`23`. Never stored
+/// in [`vars`](crate::data::save::SaveData::vars) — the clock is the value.
+pub const HOUR_VAR: &str = "hour";
+
+/// Dawn: night blends into day from 05:00.
+const DAWN: u32 = 5 * 60;
+/// Full day from 07:00.
+const DAY: u32 = 7 * 60;
+/// Dusk: day blends into night from 18:00.
+const DUSK: u32 = 18 * 60;
+/// Full night from 20:00.
+const NIGHT: u32 = 20 * 60;
+
+/// Where in the day a [`Clock`] stands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    /// 05:00-07:00, night brightening to day.
+    Dawn,
+    /// 07:00-18:00.
+    Day,
+    /// 18:00-20:00, day darkening to night.
+    Dusk,
+    /// 20:00-05:00.
+    Night,
+}
+
+/// A time of day, to the frame. Serialised as the bare frame count since
+/// midnight.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Clock {
+    frame: u32,
+}
+
+impl Default for Clock {
+    /// 08:00 — a new game starts in the morning.
+    fn default() -> Self {
+        Self::at(8, 0)
+    }
+}
+
+impl Clock {
+    /// The clock at `hour:minute` (wrapping past a day).
+    pub const fn at(hour: u32, minute: u32) -> Self {
+        Self {
+            frame: ((hour * 60 + minute) % MINUTES_PER_DAY) * FRAMES_PER_MINUTE,
+        }
+    }
+
+    /// Advance one simulated frame, wrapping at midnight.
+    pub fn tick(&mut self) {
+        self.frame = (self.frame + 1) % FRAMES_PER_DAY;
+    }
+
+    /// Move by `minutes` (either way), wrapping around the day. The debug
+    /// scrub.
+    pub fn advance_minutes(&mut self, minutes: i32) {
+        let frames = i64::from(self.frame) + i64::from(minutes) * i64::from(FRAMES_PER_MINUTE);
+        self.frame = frames.rem_euclid(i64::from(FRAMES_PER_DAY)) as u32;
+    }
+
+    /// Minutes since midnight, `0..1440`.
+    pub fn minute_of_day(&self) -> u32 {
+        self.frame / FRAMES_PER_MINUTE
+    }
+
+    /// The hour, `0..24`.
+    pub fn hour(&self) -> u32 {
+        self.minute_of_day() / 60
+    }
+
+    /// Jump to the top of `hour`, taken modulo 24 so a counter `#add hour 3`
+    /// past midnight wraps rather than failing.
+    pub fn set_hour(&mut self, hour: i32) {
+        *self = Self::at(hour.rem_euclid(24) as u32, 0);
+    }
+
+    pub fn phase(&self) -> Phase {
+        match self.minute_of_day() {
+            m if m < DAWN => Phase::Night,
+            m if m < DAY => Phase::Dawn,
+            m if m < DUSK => Phase::Day,
+            m if m < NIGHT => Phase::Dusk,
+            _ => Phase::Night,
+        }
+    }
+
+    /// How far toward the night palette the world is, in
+    /// [`fade_palette_into`](crate::draw_state::fade_palette_into)'s fixed
+    /// point: `0` all day, `256` all night, a linear ramp through dawn and
+    /// dusk.
+    pub fn night_amount(&self) -> u16 {
+        let minute = self.minute_of_day();
+        let ramp = |from: u32, to: u32| ((minute - from) * 256 / (to - from)) as u16;
+        match self.phase() {
+            Phase::Day => 0,
+            Phase::Night => 256,
+            Phase::Dusk => ramp(DUSK, NIGHT),
+            Phase::Dawn => 256 - ramp(DAWN, DAY),
+        }
+    }
+
+    /// Whether it counts as night: past the middle of dusk and before the
+    /// middle of dawn — what the [`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG)
+    /// flag mirrors.
+    pub fn is_night(&self) -> bool {
+        self.night_amount() >= 128
+    }
+
+    /// Make it night (20:00) or day (07:00) — unless it already is, in which
+    /// case the clock is left alone. How a write to the `is_night` flag winds
+    /// the clock to agree with it.
+    pub fn set_night(&mut self, night: bool) {
+        if self.is_night() != night {
+            *self = if night { Self::at(NIGHT / 60, 0) } else { Self::at(DAY / 60, 0) };
+        }
+    }
+}
+
+impl std::fmt::Display for Clock {
+    /// `HH:MM`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let minute = self.minute_of_day();
+        write!(f, "{:02}:{:02}", minute / 60, minute % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The blend is flat through day and night and ramps through dawn and
+    /// dusk, with `is_night` turning at the middle of each ramp.
+    #[test]
+    fn night_amount_ramps_through_dawn_and_dusk() {
+        let amount = |hour, minute| Clock::at(hour, minute).night_amount();
+        assert_eq!(amount(12, 0), 0);
+        assert_eq!(amount(0, 0), 256);
+        assert_eq!(amount(18, 0), 0);
+        assert_eq!(amount(19, 0), 128);
+        assert_eq!(amount(20, 0), 256);
+        assert_eq!(amount(6, 0), 128);
+        assert_eq!(amount(7, 0), 0);
+        assert!(!Clock::at(18, 59).is_night());
+        assert!(Clock::at(19, 0).is_night());
+        assert!(Clock::at(5, 59).is_night());
+        assert!(!Clock::at(6, 1).is_night());
+    }
+
+    /// Ticking a day's worth of frames comes back round to the start; the
+    /// scrub and `set_hour` wrap both ways.
+    #[test]
+    fn clock_wraps_at_midnight() {
+        let mut clock = Clock::at(23, 59);
+        for _ in 0..FRAMES_PER_MINUTE {
+            clock.tick();
+        }
+        assert_eq!(clock, Clock::at(0, 0));
+        clock.advance_minutes(-90);
+        assert_eq!(clock.to_string(), "22:30");
+        clock.set_hour(-1);
+        assert_eq!(clock.hour(), 23);
+        clock.set_hour(26);
+        assert_eq!(clock.hour(), 2);
+    }
+
+    /// `set_night` only winds a clock that disagrees.
+    #[test]
+    fn set_night_winds_only_when_needed() {
+        let mut clock = Clock::at(12, 0);
+        clock.set_night(false);
+        assert_eq!(clock, Clock::at(12, 0));
+        clock.set_night(true);
+        assert_eq!(clock, Clock::at(20, 0));
+        let mut late = Clock::at(2, 0);
+        late.set_night(true);
+        assert_eq!(late, Clock::at(2, 0));
+    }
+}
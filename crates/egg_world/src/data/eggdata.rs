@@ -25,8 +25,11 @@ use std::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use crate::data::portraits::Portrait;
+use crate::data::schedule::ScheduleDef;
 use crate::data::sound::SfxDef;
-use egg_render::geometry::Hitbox;
+use egg_render::geometry::{Hitbox, Vec2};
+use crate::world::interact::Talk;
+use crate::world::map::PlacedCreature;
 use crate::world::player::{
     CreatureState, MoveMode, PresetId, Shell, ShellSprites, SpriteAnimation, Timer, WalkSprites,
 };
@@ -142,6 +145,11 @@ pub struct DataFile {
     /// [`Portraits`](crate::data::portraits::Portraits) store.
     #[serde(default)]
     pub portraits: BTreeMap<String, Portrait>,
+    /// NPC daily schedules, keyed by the map creature's
+    /// [`Shell::id`](crate::world::player::Shell::id). Built into the
+    /// [`Schedules`](crate::data::schedule::Schedules) store.
+    #[serde(default)]
+    pub schedules: BTreeMap<String, ScheduleDef>,
 }
 
 /// Parse a `data.toml` document. A malformed file is the caller's to tolerate
@@ -199,6 +207,25 @@ impl Presets {
     pub fn spawn(&self, id: &PresetId) -> Option<Shell> {
         self.get(id).map(|def| def.build_shell(id))
     }
+    /// Spawn a map-placed creature (a Tiled `creature` object) standing at
+    /// `at`: its preset's shell with the placement's id, move mode, facing and
+    /// talk laid over the top. `None` for an unknown preset.
+    pub fn place(&self, placed: &PlacedCreature, at: Vec2) -> Option<Shell> {
+        let def = self.get(&placed.preset)?;
+        let mut shell = def.build_shell(&placed.preset);
+        shell.id.clone_from(&placed.id);
+        shell.pos = at;
+        if let Some(mode) = &placed.move_mode {
+            shell.move_mode = mode.build(def.radius.unwrap_or(DEFAULT_RADIUS));
+        }
+        if let Some(dir) = placed.facing {
+            shell.face(dir);
+        }
+        if placed.talk.is_some() {
+            shell.talk.clone_from(&placed.talk);
+        }
+        Some(shell)
+    }
     /// Every preset as `(name, def)`, name-sorted — the walk-sprite editor's
     /// listing + edit snapshot (pushed into the editor each frame, the way the
     /// cutscene names are).
@@ -305,16 +332,26 @@ pub fn splice_preset(src: &str, name: &str, emitted: &str) -> String {
 /// constructor in [`crate::world::player`].
 ///
 /// Field order matters for TOML serialisation: the scalar/array values
-/// (`hitbox`, `move_mode`) come before the sub-table fields (`others`, `walk`,
-/// `poses`), since TOML forbids a bare key after a table within the same table.
+/// (`hitbox`, `radius`, `move_mode`, `talk`) come before the sub-table fields
+/// (`others`, `walk`, `poses`), since TOML forbids a bare key after a table
+/// within the same table.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PresetDef {
     /// `[x, y, w, h]` for [`Hitbox::new`] — the shell's local (un-offset) box.
     pub hitbox: [i16; 4],
+    /// How near, in pixels, the player comes before a `flee` or `approach`
+    /// preset reacts. Absent ⇒ [`DEFAULT_RADIUS`]; the other behaviours
+    /// ignore it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<u16>,
     /// The wander behaviour the preset spawns with (most are `wander`; the
     /// critter `amble`s). Absent ⇒ [`PresetMove::Wander`].
     #[serde(default, skip_serializing_if = "PresetMove::is_default")]
     pub move_mode: PresetMove,
+    /// What interacting with a creature of this preset opens — a dialogue
+    /// key or a cutscene name. Absent ⇒ it has nothing to say.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub talk: Option<Talk>,
     /// Non-walk animations (today just the petting sprite), as a single sprite
     /// strip — the `other_ids` of [`ShellSprites`](crate::world::player::ShellSprites).
     pub others: SpriteSet,
@@ -349,12 +386,15 @@ impl PresetDef {
     }
     /// The [`MoveMode`] this preset spawns with.
     pub fn move_mode(&self) -> MoveMode {
-        self.move_mode.build()
+        self.move_mode.build(self.radius.unwrap_or(DEFAULT_RADIUS))
     }
     /// Spawn a [`Shell`] of this preset, stamped with `id`. The store's
     /// [`Presets::spawn`] funnel.
     pub fn build_shell(&self, id: &PresetId) -> Shell {
-        Shell::from_parts(id.clone(), self.hitbox(), self.build_sprites(), self.move_mode())
+        let mut shell =
+            Shell::from_parts(id.clone(), self.hitbox(), self.build_sprites(), self.move_mode());
+        shell.talk = self.talk.clone();
+        shell
     }
 }
 
@@ -384,14 +424,18 @@ impl SpriteSet {
         } else {
             &self.ids[..]
         };
-        SpriteAnimation::from_sprite_ids(ids, self.w, self.h).with_flip(self.flip.clone())
+        SpriteAnimation::from_sprite_ids(ids, self.w, self.h).with_flip(self.flip)
     }
 }
 
+/// [`PresetDef::radius`] when a `flee`/`approach` preset doesn't set one: four
+/// tiles.
+pub const DEFAULT_RADIUS: u16 = 32;
+
 /// The wander behaviour a preset spawns with — the data form of the relevant
-/// [`MoveMode`](crate::world::player::MoveMode) variants. (`Egg`/`Player` aren't preset
-/// spawn states, so they're not here.)
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+/// [`MoveMode`](crate::world::player::MoveMode) variants. (`Egg`/`Player`/
+/// `Companion` aren't preset spawn states, so they're not here.)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PresetMove {
     /// Memoryless NPC wander ([`MoveMode::Wander`]).
@@ -399,15 +443,50 @@ pub enum PresetMove {
     Wander,
     /// Dwell-then-walk critter gait ([`MoveMode::Amble`]).
     Amble,
+    /// Walk a `#path` loop from the scene registry ([`MoveMode::Patrol`]).
+    /// `move_mode = { patrol = "NAME" }`
+    Patrol(String),
+    /// Keep away from a player within [`PresetDef::radius`]
+    /// ([`MoveMode::Flee`]).
+    Flee,
+    /// Come up to a player within [`PresetDef::radius`]
+    /// ([`MoveMode::Approach`]).
+    Approach,
 }
 impl PresetMove {
     fn is_default(&self) -> bool {
         matches!(self, PresetMove::Wander)
     }
-    fn build(&self) -> MoveMode {
+    /// The behaviour's name as a Tiled `move_mode` property spells it (a
+    /// patrol's path rides separately, as `path`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            PresetMove::Wander => "wander",
+            PresetMove::Amble => "amble",
+            PresetMove::Patrol(_) => "patrol",
+            PresetMove::Flee => "flee",
+            PresetMove::Approach => "approach",
+        }
+    }
+    /// Inverse of [`name`](Self::name): `path` names a `patrol`'s loop, which
+    /// it can't go without. `None` for an unknown name or a pathless patrol.
+    pub fn from_name(name: &str, path: Option<&str>) -> Option<Self> {
+        Some(match name {
+            "wander" => PresetMove::Wander,
+            "amble" => PresetMove::Amble,
+            "patrol" => PresetMove::Patrol(path.filter(|p| !p.is_empty())?.to_string()),
+            "flee" => PresetMove::Flee,
+            "approach" => PresetMove::Approach,
+            _ => return None,
+        })
+    }
+    fn build(&self, radius: u16) -> MoveMode {
         match self {
             PresetMove::Wander => MoveMode::Wander,
             PresetMove::Amble => MoveMode::Amble(CreatureState::Idle(Timer(0))),
+            PresetMove::Patrol(path) => MoveMode::Patrol { path: path.clone(), frame: 0 },
+            PresetMove::Flee => MoveMode::Flee { radius },
+            PresetMove::Approach => MoveMode::Approach { radius },
         }
     }
 }
@@ -456,6 +535,60 @@ mod tests {
         assert!(presets.spawn(&PresetId::new("nope")).is_none());
     }
 
+    /// `patrol`/`flee`/`approach` parse from `move_mode` (the patrol as an
+    /// inline `{ patrol = "NAME" }`), build their [`MoveMode`] with the
+    /// preset's `radius` (or [`DEFAULT_RADIUS`]), and round-trip through TOML.
+    #[test]
+    fn preset_move_modes_build_and_round_trip() {
+        let src = include_str!("../../../../assets/data/data.toml");
+        let file = parse(src).unwrap();
+        let critter = emit_preset("critter", &file.presets["critter"]).unwrap();
+        let with = |line: &str| {
+            let edited = critter.replacen("move_mode = \"amble\"", line, 1);
+            assert_ne!(edited, critter, "critter ships `move_mode = \"amble\"`");
+            let out = splice_preset(src, "critter", &edited);
+            let def = parse(&out).unwrap().presets["critter"].clone();
+            let reparsed = parse(&splice_preset(src, "critter", &emit_preset("critter", &def).unwrap()))
+                .unwrap()
+                .presets["critter"]
+                .clone();
+            assert_eq!(reparsed, def, "{line} round-trips");
+            def.move_mode()
+        };
+        assert_eq!(
+            with("move_mode = { patrol = \"beat\" }"),
+            MoveMode::Patrol { path: "beat".into(), frame: 0 }
+        );
+        assert_eq!(with("move_mode = \"flee\""), MoveMode::Flee { radius: DEFAULT_RADIUS });
+        assert_eq!(
+            with("radius = 48\nmove_mode = \"approach\""),
+            MoveMode::Approach { radius: 48 }
+        );
+    }
+
+    /// A preset's `talk` parses from its inline-table spelling, survives the
+    /// walk editor's emit/splice, and is stamped on every shell it spawns.
+    #[test]
+    fn preset_talk_round_trips_and_spawns() {
+        let src = include_str!("../../../../assets/data/data.toml");
+        let file = parse(src).unwrap();
+        let critter = emit_preset("critter", &file.presets["critter"]).unwrap();
+        let edited = critter.replacen(
+            "move_mode = \"amble\"",
+            "move_mode = \"amble\"\ntalk = { dialogue = \"chirp\" }",
+            1,
+        );
+        let def = parse(&splice_preset(src, "critter", &edited)).unwrap().presets["critter"].clone();
+        assert_eq!(def.talk, Some(Talk::Dialogue("chirp".into())));
+        let emitted = emit_preset("critter", &def).unwrap();
+        assert_eq!(parse(&splice_preset(src, "critter", &emitted)).unwrap().presets["critter"], def);
+
+        let mut file = DataFile::default();
+        file.presets.insert("critter".into(), def);
+        let shell = Presets::from_data(&file).spawn(&PresetId::critter()).unwrap();
+        assert_eq!(shell.talk, Some(Talk::Dialogue("chirp".into())));
+    }
+
     /// A preset's `poses` table builds into `ShellSprites.poses`, keyed by
     /// name — what a `pose NAME` motion (see
     /// [`crate::data::scene::Motion::Pose`]) resolves against at draw time.
@@ -1,8 +1,10 @@
+pub mod clock;
 pub mod eggdata;
 pub mod metasprite;
 pub mod portraits;
 pub mod save;
 pub mod scene;
+pub mod schedule;
 pub mod script;
 pub mod sound;
 pub mod tiled;
@@ -1,27 +1,173 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use egg_render::geometry::Vec2;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-use crate::world::player::Shell;
+use crate::data::clock::{Clock, HOUR_VAR};
+use crate::world::player::{MoveMode, Shell};
 
 /// The path the engine persists progress under. The engine names the file; a
 /// host routes it to whatever user-data backend it has (a file on native, a
 /// `localStorage` entry on web) — see `ConsoleApi::write_file`/`read_file`.
+///
+/// Since save slots arrived this is the *legacy* single save: slot 0 falls back
+/// to it until its own [`slot_path`] has been written (see
+/// `EggState::load_save`), so a player's pre-slot progress carries over.
 pub const SAVE_PATH: &str = "save.json";
 
-/// The [`flags`](SaveData::flags) name the day/night palette rides on. A plain
+/// How many save slots the title's slot screen offers.
+pub const SLOT_COUNT: usize = 3;
+
+/// Where save slot `slot` (0-based) persists its [`SaveData`] as JSON. Under
+/// `save/`, which every host routes to user data like [`SAVE_PATH`].
+pub fn slot_path(slot: usize) -> String {
+    format!("save/slot{slot}.json")
+}
+
+/// Where save slot `slot` keeps its thumbnail: a small PNG of the screen at
+/// the slot's last in-game save, shown on the slot screen.
+pub fn slot_thumb_path(slot: usize) -> String {
+    format!("save/slot{slot}.png")
+}
+
+/// What the slot screen shows for a used slot, read off its stored save.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlotSummary {
+    /// The slot's [`SaveData::save_count`].
+    pub save_count: u32,
+    /// The map the slot was saved on ([`SaveData::current_map_name`]).
+    pub map_name: Option<String>,
+}
+
+impl SlotSummary {
+    /// Summarise a live save.
+    pub fn of(save: &SaveData) -> Self {
+        Self {
+            save_count: save.save_count,
+            map_name: save.current_map_name.clone(),
+        }
+    }
+
+    /// Summarise a slot's stored JSON; `None` if it doesn't parse.
+    pub fn from_json(bytes: &[u8]) -> Option<Self> {
+        SaveData::from_json(bytes).ok().map(|save| Self::of(&save))
+    }
+}
+
+/// The [`flags`](SaveData::flags) name that mirrors whether the
+/// [`clock`](SaveData::clock) reads night (see [`Clock::is_night`]). A plain
 /// story flag like any other, so dialogue (`#set is_night true` / `#if is_night`),
 /// object gates (`if`/`unless is_night`) and cutscene `set` steps can all read and
-/// write the world's day/night state with no dedicated machinery — the walkaround
-/// paints [`NIGHT_16`](egg_platform::NIGHT_16) when it is set and
-/// [`SWEETIE_16`](egg_platform::SWEETIE_16) otherwise. Named here (not spelled
-/// as a bare literal at each site) because the engine reads it from several files.
+/// write the world's day/night state with no dedicated machinery: setting it
+/// winds the clock to nightfall or morning ([`SaveData::set_flag`]), and the
+/// walkaround keeps it in step as the clock runs. Named here (not spelled as a bare literal at
+/// each site) because the engine reads it from several files.
 pub const IS_NIGHT_FLAG: &str = "is_night";
 
+/// The save format version this build writes — one past the last step of
+/// the [migration chain](MIGRATIONS). [`SaveData::from_json`] lifts an older
+/// save through the remaining steps and refuses a newer one.
+pub const SAVE_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// One step of the save format's migration chain: rewrites a stored save's raw
+/// JSON object from one [`version`](SaveData::version) to the next, before
+/// `serde` sees it (and drops whatever keys it no longer knows).
+type Migration = fn(&mut Map<String, Value>);
+
+/// The migration chain, oldest first: entry `n` lifts a version-`n` save to
+/// `n + 1`. A shipped step is never edited or reordered — a format change
+/// appends a new one, which bumps [`SAVE_VERSION`] with it.
+const MIGRATIONS: [Migration; 3] = [migrate_night_flag, migrate_player_entity, migrate_clock];
+
+/// v0 → v1: the old dedicated `is_night` bool becomes the [`IS_NIGHT_FLAG`]
+/// story flag, so the world's day/night state survives its promotion.
+fn migrate_night_flag(save: &mut Map<String, Value>) {
+    if save.remove("is_night").and_then(|night| night.as_bool()) != Some(true) {
+        return;
+    }
+    let flags = save.entry("flags").or_insert_with(|| Value::Array(Vec::new()));
+    if let Some(flags) = flags.as_array_mut()
+        && !flags.iter().any(|flag| flag == IS_NIGHT_FLAG)
+    {
+        flags.push(IS_NIGHT_FLAG.into());
+    }
+}
+
+/// v1 → v2: a save from before the whole player entity was persisted kept
+/// only a position, in `player_x`/`player_y`; it becomes the default player
+/// [`Shell`] (as `WalkaroundState::new` builds it) standing there.
+fn migrate_player_entity(save: &mut Map<String, Value>) {
+    let mut coord = |key| save.remove(key).and_then(|v| v.as_i64()).unwrap_or(0) as i16;
+    let pos = Vec2::new(coord("player_x"), coord("player_y"));
+    if save.get("player").is_none_or(Value::is_null) {
+        let player = Shell {
+            move_mode: MoveMode::Player,
+            pos,
+            ..Default::default()
+        };
+        if let Ok(player) = serde_json::to_value(player) {
+            save.insert("player".to_string(), player);
+        }
+    }
+}
+
+/// v2 → v3: a save from before the [`Clock`] had only the [`IS_NIGHT_FLAG`]
+/// flag for the time of day; it wakes at the start of night if that was set,
+/// else at the default morning hour.
+fn migrate_clock(save: &mut Map<String, Value>) {
+    let night = save
+        .get("flags")
+        .and_then(Value::as_array)
+        .is_some_and(|flags| flags.iter().any(|flag| flag == IS_NIGHT_FLAG));
+    let mut clock = Clock::default();
+    clock.set_night(night);
+    if let Ok(clock) = serde_json::to_value(clock) {
+        save.entry("clock").or_insert(clock);
+    }
+}
+
+/// Why a stored save couldn't be loaded.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The bytes aren't a save: malformed JSON, or a field of the wrong shape.
+    Json(serde_json::Error),
+    /// The save was written by a newer build, whose fields this one would
+    /// silently drop — refused rather than loaded lossily.
+    TooNew { version: u64 },
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Json(e) => write!(f, "{e}"),
+            SaveError::TooNew { version } => write!(
+                f,
+                "save format version {version} is newer than this build reads \
+                 (up to {SAVE_VERSION}); update the game to load it"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
 /// Misc. progression flags and numbers. Persisted to the player's storage
 /// device and restored across runs.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SaveData {
+    /// The save format version (see [`SAVE_VERSION`]). Absent — so `0` — in
+    /// every save written before versioning; [`from_json`](Self::from_json)
+    /// migrates forward from whatever it reads.
+    #[serde(default)]
+    pub version: u32,
+
     // UI / general flags
     pub intro_anim_seen: bool,
     pub small_text_on: bool,
@@ -38,6 +184,20 @@ pub struct SaveData {
     #[serde(default)]
     pub flags: BTreeSet<String>,
 
+    /// Named story counters, the numeric sibling of [`flags`](Self::flags):
+    /// dialogue bumps them with `#add`/`#setv` and compares them with
+    /// `#if NAME >= 3` (a [`VarTest`]), against the `#var NAME` vocabulary the
+    /// script declares. Only non-zero values are stored, so an absent name
+    /// reads as `0` and old saves simply lack the key.
+    #[serde(default)]
+    pub vars: BTreeMap<String, i32>,
+
+    /// The time of day, ticked by the walkaround every simulated frame. Read
+    /// by scripts as the [`HOUR_VAR`] counter and mirrored into the
+    /// [`IS_NIGHT_FLAG`] flag; see [`crate::data::clock`].
+    #[serde(default)]
+    pub clock: Clock,
+
     /// Stable ids of removable interactables the player has consumed (pickups),
     /// keyed `"<map>#<object id>"` — see
     /// [`MapObject::removable`](crate::world::map::MapObject::removable). Mirrors
@@ -77,15 +237,6 @@ pub struct SaveData {
     #[serde(default)]
     pub current_map_name: Option<String>,
 
-    /// Legacy player position, kept only to load saves written before the whole
-    /// player entity was persisted (see [`player`](Self::player)): when `player`
-    /// is absent these place the restored default player. New saves carry the
-    /// position inside `player` instead and leave these at `0`.
-    #[serde(default)]
-    pub player_x: i16,
-    #[serde(default)]
-    pub player_y: i16,
-
     /// Number of times the game has saved
     pub save_count: u32,
 
@@ -95,8 +246,8 @@ pub struct SaveData {
     /// but travels across maps, so it gets its own slot here rather than living in
     /// [`map_entities`](Self::map_entities) (which is per-map). Every field
     /// round-trips except the derived `sprites`/`trail`/`interaction`, rebuilt on
-    /// load. `None` only in older saves, which fall back to
-    /// [`player_x`](Self::player_x)/[`player_y`](Self::player_y).
+    /// load. `None` until the first in-game save; a save from before the player
+    /// was persisted gets one from its old position in migration.
     #[serde(default)]
     pub player: Option<Shell>,
 
@@ -133,6 +284,7 @@ fn default_inventory() -> [Option<String>; 8] {
 impl Default for SaveData {
     fn default() -> Self {
         Self {
+            version: SAVE_VERSION,
             inventory: default_inventory(),
             // Every other field is its own type's default; only `inventory`
             // departs from a derived `Default` (it seeds the starting items).
@@ -141,6 +293,8 @@ impl Default for SaveData {
             instructions_read: false,
             manual_doors: false,
             flags: BTreeSet::new(),
+            vars: BTreeMap::new(),
+            clock: Clock::default(),
             taken: BTreeSet::new(),
             egg_count: 0,
             egg_pop_count: 0,
@@ -149,8 +303,6 @@ impl Default for SaveData {
             shell_matryoshka: false,
             shell_monster: false,
             current_map_name: None,
-            player_x: 0,
-            player_y: 0,
             save_count: 0,
             player: None,
             map_entities: BTreeMap::new(),
@@ -159,29 +311,38 @@ impl Default for SaveData {
 }
 
 impl SaveData {
-    /// Parse a save from its stored JSON, folding legacy fields forward. The
-    /// derive tolerates unknown keys (no `deny_unknown_fields`), so a stale key
-    /// is normally dropped silently — but a field that has since *become a flag*
-    /// must be carried over, not lost. This reads those keys off the raw JSON
-    /// before [`from_value`](serde_json::from_value) discards them and
-    /// re-expresses them as flags. Today that is the old `is_night` bool: a save
-    /// written when it was a dedicated field loads with the [`IS_NIGHT_FLAG`]
-    /// flag set to match, so the world's day/night state survives the promotion.
-    pub fn from_json(bytes: &[u8]) -> serde_json::Result<Self> {
-        let value: serde_json::Value = serde_json::from_slice(bytes)?;
-        // Read the legacy bool before `from_value` drops the now-unknown key.
-        let legacy_night = value.get("is_night").and_then(|v| v.as_bool()) == Some(true);
-        let mut save: Self = serde_json::from_value(value)?;
-        if legacy_night {
-            save.set_flag(IS_NIGHT_FLAG, true);
+    /// Parse a save from its stored JSON, migrating it forward. The derive
+    /// tolerates unknown keys (no `deny_unknown_fields`), so a stale key is
+    /// normally dropped silently — but a field whose *meaning* moved (the old
+    /// `is_night` bool, now a flag) must be carried over, not lost. So the raw
+    /// JSON runs through every [migration](MIGRATIONS) past its
+    /// [`version`](Self::version) before [`from_value`](serde_json::from_value)
+    /// sees it. A save from a newer build is refused ([`SaveError::TooNew`]):
+    /// loading it would drop the fields this build doesn't know, and the next
+    /// flush would write the loss back.
+    pub fn from_json(bytes: &[u8]) -> Result<Self, SaveError> {
+        let mut value: Value = serde_json::from_slice(bytes)?;
+        if let Some(save) = value.as_object_mut() {
+            let version = save.get("version").and_then(Value::as_u64).unwrap_or(0);
+            if version > u64::from(SAVE_VERSION) {
+                return Err(SaveError::TooNew { version });
+            }
+            for migrate in &MIGRATIONS[version as usize..] {
+                migrate(save);
+            }
+            save.insert("version".to_string(), SAVE_VERSION.into());
         }
-        Ok(save)
+        Ok(serde_json::from_value(value)?)
     }
 
     /// Set (or clear) a named story [`flag`](Self::flag). Setting inserts the
     /// name; clearing removes it, so the stored set only ever holds the flags
-    /// that are currently true.
+    /// that are currently true. [`IS_NIGHT_FLAG`] also winds the
+    /// [`clock`](Self::clock) to agree, if it doesn't ([`Clock::set_night`]).
     pub fn set_flag(&mut self, name: &str, value: bool) {
+        if name == IS_NIGHT_FLAG {
+            self.clock.set_night(value);
+        }
         if value {
             // Avoid allocating when the flag is already present.
             if !self.flags.contains(name) {
@@ -197,6 +358,44 @@ impl SaveData {
         self.flags.contains(name)
     }
 
+    /// Read a named story counter. An undeclared/unset name reads as `0`;
+    /// [`HOUR_VAR`] reads the [`clock`](Self::clock)'s hour.
+    pub fn var(&self, name: &str) -> i32 {
+        if name == HOUR_VAR {
+            return self.clock.hour() as i32;
+        }
+        self.vars.get(name).copied().unwrap_or(0)
+    }
+
+    /// Set a named story counter. Setting `0` removes the entry, so the stored
+    /// map only ever holds the counters that differ from the default.
+    /// [`HOUR_VAR`] winds the [`clock`](Self::clock) to the top of that hour.
+    pub fn set_var(&mut self, name: &str, value: i32) {
+        if name == HOUR_VAR {
+            self.clock.set_hour(value);
+        } else if value == 0 {
+            self.vars.remove(name);
+        } else {
+            self.vars.insert(name.to_string(), value);
+        }
+    }
+
+    /// Add `delta` (which may be negative) to a named story counter,
+    /// saturating rather than wrapping at the `i32` bounds.
+    pub fn add_var(&mut self, name: &str, delta: i32) {
+        self.set_var(name, self.var(name).saturating_add(delta));
+    }
+
+    /// Evaluate a parsed `#if`, object gate or schedule slot condition: a
+    /// flag reads [`flag`](Self::flag), a counter comparison reads
+    /// [`var`](Self::var).
+    pub fn test(&self, condition: &SaveTest) -> bool {
+        match condition {
+            SaveTest::Flag(flag) => self.flag(flag),
+            SaveTest::Var(test) => test.holds(self),
+        }
+    }
+
     /// The four shell story flags in their canonical (declaration) order — the
     /// unlock state of the four Eggs-page shell slots (index `0..4`). Each gates
     /// the emblem (sprite `10 + index`) drawn centred on that egg once its shell
@@ -241,12 +440,170 @@ impl SaveData {
     }
 }
 
+/// The comparison in a [`VarTest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    /// Every operator, two-character spellings first so a parse tries `>=`
+    /// before `>`.
+    const ALL: [CompareOp; 6] = [
+        CompareOp::Eq,
+        CompareOp::Ne,
+        CompareOp::Le,
+        CompareOp::Ge,
+        CompareOp::Lt,
+        CompareOp::Gt,
+    ];
+
+    /// The operator as written: `==`, `!=`, `<`, `<=`, `>`, `>=`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    /// Apply the operator, `lhs OP rhs`.
+    pub fn compare(self, lhs: i32, rhs: i32) -> bool {
+        match self {
+            CompareOp::Eq => lhs == rhs,
+            CompareOp::Ne => lhs != rhs,
+            CompareOp::Lt => lhs < rhs,
+            CompareOp::Le => lhs <= rhs,
+            CompareOp::Gt => lhs > rhs,
+            CompareOp::Ge => lhs >= rhs,
+        }
+    }
+}
+
+/// A numeric condition on a story counter — `NAME OP N`, e.g. `visits >= 3`
+/// (spaces around the operator optional). Written wherever a flag name can go
+/// (`#if`, a gate's `if`/`unless`, a schedule slot's), and told apart from a
+/// flag by [`parse`](Self::parse) succeeding — a flag name never contains an
+/// operator character. See [`SaveTest`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VarTest {
+    pub name: String,
+    pub op: CompareOp,
+    pub value: i32,
+}
+
+impl VarTest {
+    /// Parse `NAME OP N`. `None` unless there is exactly one word before the
+    /// operator and an integer after it.
+    pub fn parse(s: &str) -> Option<Self> {
+        let at = s.find(['=', '!', '<', '>'])?;
+        let name = s[..at].trim();
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return None;
+        }
+        let rest = &s[at..];
+        let op = CompareOp::ALL.into_iter().find(|op| rest.starts_with(op.symbol()))?;
+        let value = rest[op.symbol().len()..].trim().parse().ok()?;
+        Some(Self {
+            name: name.to_string(),
+            op,
+            value,
+        })
+    }
+
+    /// Whether the test holds against `save`.
+    pub fn holds(&self, save: &SaveData) -> bool {
+        self.op.compare(save.var(&self.name), self.value)
+    }
+}
+
+impl std::fmt::Display for VarTest {
+    /// The canonical spelling, `NAME OP N` with single spaces.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.name, self.op.symbol(), self.value)
+    }
+}
+
+/// A condition on the save as `#if`/`#elif`, a map object's `if`/`unless` gate
+/// and a schedule slot's `if`/`unless` spell it: a flag name, or a
+/// [`VarTest`] on a counter. Parsed once by the loaders
+/// ([`parse`](Self::parse)) and read through [`SaveData::test`]; (de)serialises
+/// as its source spelling, so `data.toml` and JSON scripts keep writing a
+/// plain string.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SaveTest {
+    Flag(String),
+    Var(VarTest),
+}
+
+impl SaveTest {
+    /// Read `NAME OP N` as a [`VarTest`], anything else as a flag name.
+    pub fn parse(s: &str) -> Self {
+        match VarTest::parse(s) {
+            Some(test) => Self::Var(test),
+            None => Self::Flag(s.to_string()),
+        }
+    }
+
+    /// The flag this condition reads, or `None` for a counter comparison.
+    pub fn flag(&self) -> Option<&str> {
+        match self {
+            Self::Flag(flag) => Some(flag),
+            Self::Var(_) => None,
+        }
+    }
+}
+
+impl From<String> for SaveTest {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<&str> for SaveTest {
+    fn from(s: &str) -> Self {
+        Self::parse(s)
+    }
+}
+
+impl From<SaveTest> for String {
+    fn from(test: SaveTest) -> Self {
+        test.to_string()
+    }
+}
+
+impl std::fmt::Display for SaveTest {
+    /// The flag name, or the comparison's canonical spelling.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Flag(flag) => f.write_str(flag),
+            Self::Var(test) => test.fmt(f),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::data::eggdata::Presets;
     use crate::world::player::PresetId;
-    use egg_render::geometry::Vec2;
+
+    /// A save as written before versioning (version 0).
+    const V0_FIXTURE: &str = include_str!("save_fixtures/v0.json");
+    /// A version-1 save: `is_night` already a flag, the player still a bare
+    /// position.
+    const V1_FIXTURE: &str = include_str!("save_fixtures/v1.json");
+    /// A version-2 save: the whole player entity, no clock yet.
+    const V2_FIXTURE: &str = include_str!("save_fixtures/v2.json");
 
     /// A pre-name save carries the long-removed numeric `current_map` field and
     /// no `current_map_name` key at all; it must still deserialise (the unknown
@@ -289,6 +646,27 @@ mod tests {
         assert_eq!(save, SaveData::default());
     }
 
+    /// Each slot gets its own file pair under `save/`, and a summary reads the
+    /// count and map back off the stored JSON (garbage reads as no summary).
+    #[test]
+    fn slots_have_distinct_paths_and_summaries() {
+        let paths: BTreeSet<_> = (0..SLOT_COUNT).map(slot_path).collect();
+        assert_eq!(paths.len(), SLOT_COUNT);
+        assert!(paths.iter().all(|p| p.starts_with("save/") && p.ends_with(".json")));
+        assert_eq!(slot_thumb_path(1), "save/slot1.png");
+
+        let save = SaveData {
+            save_count: 12,
+            current_map_name: Some("town".to_string()),
+            ..SaveData::default()
+        };
+        let json = serde_json::to_vec(&save).unwrap();
+        let summary = SlotSummary::from_json(&json).expect("parses");
+        assert_eq!(summary.save_count, 12);
+        assert_eq!(summary.map_name.as_deref(), Some("town"));
+        assert_eq!(SlotSummary::from_json(b"not json"), None);
+    }
+
     /// A populated save survives a pretty-print/parse round trip unchanged —
     /// the format the engine autosaves through (see [`SAVE_PATH`]).
     #[test]
@@ -308,8 +686,6 @@ mod tests {
                 None,
             ],
             current_map_name: Some("town".to_string()),
-            player_x: -42,
-            player_y: 300,
             ..SaveData::default()
         };
         data.set_flag("house_stairwell_window_interacted", true);
@@ -377,39 +753,105 @@ mod tests {
         assert!(!save.flag("anything"));
     }
 
-    /// A save written when `is_night` was a dedicated bool (since promoted to the
-    /// [`IS_NIGHT_FLAG`] flag) migrates on load: a legacy night save comes back
-    /// with the flag set, so day/night survives the field's removal. A legacy day
-    /// save (the bool `false`, or the key absent entirely) leaves the flag clear.
+    /// A version-0 fixture (no `version` key: written before versioning, when
+    /// `is_night` was a dedicated bool) gains the [`IS_NIGHT_FLAG`] flag from
+    /// the first migration, and a day save (the bool `false`) leaves it clear.
     #[test]
-    fn legacy_is_night_bool_migrates_to_flag() {
-        // Night: the legacy bool was `true` -> the flag is set on load.
-        let mut value = serde_json::to_value(SaveData::default()).unwrap();
-        value
-            .as_object_mut()
-            .unwrap()
-            .insert("is_night".to_string(), serde_json::json!(true));
-        let bytes = serde_json::to_vec(&value).unwrap();
-        let night = SaveData::from_json(&bytes).expect("legacy night save loads");
-        assert!(
-            night.flag(IS_NIGHT_FLAG),
-            "the legacy is_night bool migrates to the flag"
-        );
+    fn migration_0_moves_is_night_onto_the_flag() {
+        let fixture = |night: bool| {
+            let mut value: Value = serde_json::from_str(V0_FIXTURE).unwrap();
+            value["is_night"] = night.into();
+            value.as_object().unwrap().clone()
+        };
+        let mut night = fixture(true);
+        MIGRATIONS[0](&mut night);
+        assert_eq!(night.get("is_night"), None);
+        assert_eq!(night["flags"], serde_json::json!([IS_NIGHT_FLAG]));
+
+        let mut day = fixture(false);
+        MIGRATIONS[0](&mut day);
+        assert_eq!(day.get("is_night"), None);
+        assert_eq!(day.get("flags"), None);
+    }
 
-        // Day: the legacy bool was `false` -> the flag stays clear.
-        let mut value = serde_json::to_value(SaveData::default()).unwrap();
-        value
-            .as_object_mut()
-            .unwrap()
-            .insert("is_night".to_string(), serde_json::json!(false));
-        let bytes = serde_json::to_vec(&value).unwrap();
-        let day = SaveData::from_json(&bytes).expect("legacy day save loads");
-        assert!(!day.flag(IS_NIGHT_FLAG));
+    /// A version-1 fixture (a bare `player_x`/`player_y`, `player` null) gains a
+    /// default player standing at that position from the second migration.
+    #[test]
+    fn migration_1_builds_the_player_from_its_old_position() {
+        let mut save = serde_json::from_str::<Value>(V1_FIXTURE).unwrap();
+        let save = save.as_object_mut().unwrap();
+        MIGRATIONS[1](save);
+        assert_eq!(save.get("player_x"), None);
+        let player: Shell = serde_json::from_value(save["player"].clone()).unwrap();
+        assert_eq!(player.pos, Vec2::new(-8, 40));
+        assert_eq!(player.move_mode, MoveMode::Player);
+    }
+
+    /// A version-2 save has no clock: the third migration starts one at night
+    /// if the `is_night` flag was set, and in the morning otherwise.
+    #[test]
+    fn migration_2_starts_the_clock_from_the_night_flag() {
+        let mut night = serde_json::from_str::<Value>(V2_FIXTURE).unwrap();
+        let night = night.as_object_mut().unwrap();
+        assert_eq!(night.get("clock"), None);
+        MIGRATIONS[2](night);
+        let clock: Clock = serde_json::from_value(night["clock"].clone()).unwrap();
+        assert!(clock.is_night());
+
+        let mut day = serde_json::from_str::<Value>(V2_FIXTURE).unwrap();
+        let day = day.as_object_mut().unwrap();
+        day.insert("flags".to_string(), serde_json::json!(["met_the_dog"]));
+        MIGRATIONS[2](day);
+        assert_eq!(day["clock"], serde_json::to_value(Clock::default()).unwrap());
+    }
+
+    /// `from_json` runs only the steps past a save's version: the version-0
+    /// fixture goes through all three, the version-1 fixture from the second
+    /// (its flags already hold `is_night`), the version-2 fixture through the
+    /// third alone (its player already whole), and each comes back at
+    /// [`SAVE_VERSION`] with the rest of its progress intact. A current save is
+    /// left as it is.
+    #[test]
+    fn from_json_migrates_each_fixture_to_the_current_version() {
+        let v0 = SaveData::from_json(V0_FIXTURE.as_bytes()).expect("v0 loads");
+        assert_eq!(v0.version, SAVE_VERSION);
+        assert!(v0.flag(IS_NIGHT_FLAG));
+        assert!(v0.clock.is_night(), "a night save wakes at night");
+        assert_eq!(v0.player.as_ref().map(|p| p.pos), Some(Vec2::new(120, 64)));
+        assert_eq!((v0.egg_count, v0.save_count), (3, 9));
+        assert_eq!(v0.current_map_name.as_deref(), Some("town"));
+
+        let v1 = SaveData::from_json(V1_FIXTURE.as_bytes()).expect("v1 loads");
+        assert_eq!(v1.version, SAVE_VERSION);
+        assert_eq!(v1.flags.len(), 2, "is_night not duplicated, met_the_dog kept");
+        assert_eq!(v1.player.as_ref().map(|p| p.pos), Some(Vec2::new(-8, 40)));
+
+        let v2 = SaveData::from_json(V2_FIXTURE.as_bytes()).expect("v2 loads");
+        assert_eq!(v2.version, SAVE_VERSION);
+        assert!(v2.clock.is_night(), "a night save wakes at night");
+        assert_eq!(v2.player.as_ref().map(|p| p.pos), Some(Vec2::new(24, 56)));
+        assert_eq!((v2.egg_count, v2.save_count), (7, 4));
+        assert_eq!(v2.current_map_name.as_deref(), Some("town"));
+
+        let mut current = SaveData::default();
+        current.set_flag("met_the_dog", true);
+        let bytes = serde_json::to_vec(&current).unwrap();
+        assert_eq!(SaveData::from_json(&bytes).expect("current loads"), current);
+    }
 
-        // A modern save carries no `is_night` key at all and also reads as day.
-        let bytes = serde_json::to_vec(&SaveData::default()).unwrap();
-        let modern = SaveData::from_json(&bytes).expect("modern save loads");
-        assert!(!modern.flag(IS_NIGHT_FLAG));
+    /// A save stamped with a version past [`SAVE_VERSION`] is refused with an
+    /// error naming both versions, rather than loaded minus its unknown fields.
+    #[test]
+    fn a_save_from_a_newer_build_is_refused() {
+        let mut value = serde_json::to_value(SaveData::default()).unwrap();
+        value["version"] = (SAVE_VERSION + 1).into();
+        let err = SaveData::from_json(&serde_json::to_vec(&value).unwrap()).unwrap_err();
+        let newer = u64::from(SAVE_VERSION) + 1;
+        assert!(matches!(err, SaveError::TooNew { version } if version == newer));
+        let message = err.to_string();
+        assert!(message.contains(&format!("{}", SAVE_VERSION + 1)), "{message}");
+        assert!(message.contains("newer"), "{message}");
+        assert!(matches!(SaveData::from_json(b"not json"), Err(SaveError::Json(_))));
     }
 
     /// `mark_taken`/`is_taken` record and read consumed removable pickups by map
@@ -427,6 +869,61 @@ mod tests {
         assert!(!save.is_taken("town", 6));
     }
 
+    /// `is_night` is the clock's too: writing it winds the clock to agree.
+    #[test]
+    fn night_flag_winds_the_clock() {
+        let mut save = SaveData::default();
+        save.set_flag(IS_NIGHT_FLAG, true);
+        assert!(save.clock.is_night());
+        save.set_flag(IS_NIGHT_FLAG, false);
+        assert_eq!(save.clock, Clock::at(7, 0));
+    }
+
+    /// `hour` is the clock, not a stored counter: it reads the hour, and
+    /// setting or adding to it winds the clock (wrapping past midnight).
+    #[test]
+    fn hour_var_reads_and_winds_the_clock() {
+        let mut save = SaveData::default();
+        assert_eq!(save.var(HOUR_VAR), 8);
+        assert!(save.test(&SaveTest::parse("hour < 12")));
+        save.add_var(HOUR_VAR, 17);
+        assert_eq!(save.clock, Clock::at(1, 0));
+        save.set_var(HOUR_VAR, 21);
+        assert!(save.test(&SaveTest::parse("hour >= 20")) && save.clock.is_night());
+        assert!(save.vars.is_empty());
+    }
+
+    /// Counters read `0` until set, `add_var` accumulates (and setting back to
+    /// `0` drops the entry), and a [`SaveTest`] parses `NAME OP N` as a
+    /// comparison and anything else as a flag.
+    #[test]
+    fn vars_count_and_compare() {
+        let mut save = SaveData::default();
+        assert_eq!(save.var("visits"), 0);
+        save.add_var("visits", 2);
+        save.add_var("visits", 1);
+        assert_eq!(save.var("visits"), 3);
+        assert!(save.test(&SaveTest::parse("visits >= 3")));
+        assert!(save.test(&SaveTest::parse("visits==3")));
+        assert!(!save.test(&SaveTest::parse("visits < 3")));
+        assert!(save.test(&SaveTest::parse("other != 1")), "an unset counter reads 0");
+        save.set_flag("met", true);
+        assert!(save.test(&SaveTest::parse("met")));
+        save.set_var("visits", 0);
+        assert!(save.vars.is_empty());
+
+        let test = VarTest::parse("  visits>=-2 ").unwrap();
+        assert_eq!(test.op, CompareOp::Ge);
+        assert_eq!(test.value, -2);
+        assert_eq!(test.to_string(), "visits >= -2");
+        for bad in ["visits", ">= 3", "visits >=", "two words > 1", "visits => 3"] {
+            assert_eq!(VarTest::parse(bad), None, "{bad:?}");
+        }
+        assert_eq!(SaveTest::parse("met"), SaveTest::Flag("met".into()));
+        assert_eq!(SaveTest::parse("visits>=-2"), SaveTest::Var(test));
+        assert_eq!(String::from(SaveTest::parse("visits>=-2")), "visits >= -2");
+    }
+
     /// A save written before `taken` existed has no `taken` key; it must still
     /// load, with nothing taken (every removable pickup intact).
     #[test]
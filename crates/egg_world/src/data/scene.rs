@@ -84,11 +84,68 @@
 //! | `dialogue KEY` (+ `on` handlers) | play a dialogue block, done when its box closes and every fired handler has finished — see below |
 //! | `interact ACTOR TARGET`  | fire TARGET's intrinsic interaction |
 //! | `load NAME`              | push a sub-cutscene (popped on its finish) |
+//! | `warp MAP X Y [fade N]`  | move the player and every bound actor to MAP, the player landing at X Y — see below |
 //! | `wait N`                 | hold for N frames |
 //! | `camera ACTOR` / `camera X Y` | point the scene camera at an actor / a fixed map point |
 //! | `camera … over N`        | same, but glide there over N frames (non-blocking — pair with `wait`) |
 //! | `shake N [AMP]`          | shake the camera for N frames, ±AMP px (default 2; non-blocking) |
 //! | `sound NAME` / `music [NAME]` / `set FLAG BOOL` | effects (carried over) |
+//! | `add VAR N` / `setv VAR N` | bump / set a story counter, like `.eggtext`'s `#add`/`#setv` |
+//! | `give ITEM` / `take ITEM` | add one of an item to the bag / remove one (a full bag, or a missing item, is a no-op) |
+//! | `if [not] FLAG` / `if [not] has ITEM` / `if [not] VAR OP N` + indented steps (`elif`/`else`) | branch on a save flag, the bag or a counter — see below |
+//!
+//! ### Branching: `if` / `elif` / `else`
+//!
+//! `if FLAG` (or `if not FLAG`) opens a branch whose indented body is ordinary
+//! content steps; `elif [not] FLAG` and a trailing `else`, at the `if`'s own
+//! indent, extend the chain. The first branch whose condition holds runs; with
+//! none holding, the `else` body (or nothing) does. The chain is decided
+//! *live*, against the actual save, at the moment the scene reaches it — not
+//! at launch — so a `set` earlier in the same scene (or a `#choice` in an
+//! earlier `dialogue`) steers a later `if`, the same way a `.eggtext` `#if`
+//! resolves at playback. Chains nest, and are allowed inside an `on` handler
+//! (where their bodies obey the handler's own restrictions). `has ITEM` in
+//! place of the flag tests the player's bag instead of the save, so an
+//! earlier `give`/`take` steers it the same way; `VAR OP N` (`visits >= 3`,
+//! with `==`/`!=`/`<`/`<=`/`>`/`>=`) compares a declared `#var` counter.
+//!
+//! ```text
+//! #cutscene greet_dog
+//!     if not met_dog
+//!         dialogue dog_first_meeting
+//!         set met_dog true
+//!     elif has chegg
+//!         take chegg
+//!         dialogue dog_happy
+//!     else
+//!         dialogue dog_hungry
+//! ```
+//!
+//! ### Changing maps mid-scene: `warp`
+//!
+//! `map NAME` only picks the map a scene *starts* on; `warp MAP X Y` moves the
+//! scene to another map partway through, so one cutscene can stage a whole
+//! walk between rooms. The player lands at `X Y` on MAP, and every actor the
+//! scene has bound by id (`spawn`/`bind`/`find`) comes along, keeping its
+//! offset from the player — a group walking out of one room arrives in the
+//! same formation in the next. Companions travel with the player as always;
+//! every other creature stays parked on the map it was on. A bare `warp` cuts
+//! in one frame; `fade N` fades the screen to black over N frames, swaps maps
+//! on the black frame, then fades back in over another N — the step holds for
+//! the whole 2N. An unknown MAP logs and leaves everyone where they are. Like
+//! `load`, `warp` can't appear inside an `on` handler (a map change under an
+//! open dialogue box).
+//!
+//! ```text
+//! #cutscene walk_to_town
+//!     bind fido dog 20 40
+//!     move
+//!         player: walk 0 40 in 30
+//!         fido:   walk 0 56 in 30
+//!     warp town 120 8 fade 20
+//!     move
+//!         player: walk 120 40 in 30
+//! ```
 //!
 //! ### `dialogue` handlers: `on NAME [wait]`
 //!
@@ -96,7 +153,8 @@
 //! holding its own indented step-list — ordinary content steps, `move`
 //! included, except `dialogue`, `load`, and a nested `on` are parse errors
 //! (a handler can't open another dialogue, push a sub-cutscene, or nest
-//! further; it also can't spawn — init verbs are scene-level only). While
+//! further; it also can't spawn — init verbs are scene-level only — or
+//! `warp`). While
 //! the dialogue plays, the cutscene engine drains the box's `#cue NAME`
 //! beats ([`.eggtext`](crate::data::script::eggtext)'s directive) and runs
 //! the matching handler's steps concurrently with the box: a handler fires
@@ -127,19 +185,30 @@
 //! A chain is `ACTOR: motion args [in N]; motion args [in N]; …` — a sequence of
 //! timed motions. `in N` is the frame budget (the motion takes exactly N frames,
 //! speed inflated to suit); omitted ⇒ natural speed until done. Motions:
-//! `walk X Y`, `noclip X Y`, `to NAME`, `beside NAME [gap]`, `face NAME`,
-//! `face DX DY`, `teleport X Y`, `record [noclip] DX DY N …`,
-//! `path [noclip] NAME` (a `#path` block by name — see above), `pose NAME` /
-//! `pose none`. `pose`, like `face`, is instant (`in N` just holds the chain
-//! there for N frames) — but unlike every other motion, its effect *persists*
-//! on the actor past the instruction, past the whole step, even past a scene
-//! that's playing something else on top: it's standing choreography (a guy
-//! slumped against a wall) rather than a one-off action. It stays until
-//! another `pose` overwrites it, `pose none` clears it, or the owning scene's
-//! cleanup clears every pose it applied, same as it does spawned actors — so
-//! a pose can't outlive the scene that set it. A posed actor draws a named
-//! sprite strip off its preset instead of its walk sprite; a name the preset
-//! doesn't have logs a warning and falls back to the walk sprite.
+//! `walk X Y`, `noclip X Y`, `to NAME`, `walk! X Y`, `to! NAME`,
+//! `beside NAME [gap]`, `face NAME`, `face DX DY`, `teleport X Y`,
+//! `record [noclip] DX DY N …`, `path [noclip] NAME` (a `#path` block by
+//! name — see above), `pose NAME` / `pose none`. `walk` and `to` head in a
+//! straight line and stop against the first wall; `walk!` and `to!` plan a
+//! route around the map's collision first
+//! ([`find_path`](crate::world::pathfind::find_path)) and follow it, the
+//! `in N` budget covering the whole route. A target nothing can reach (inside
+//! a wall, in a sealed room) falls back to the straight line — so a required
+//! `walk! X Y?` still cancels the scene when it sticks. `to!` re-plans only
+//! once its target has strayed from the route's end. On `record`/`path`,
+//! `in N` time-scales the recorded path so it
+//! lands exactly on frame N: stretched slower or compressed faster, same
+//! route, same end point (see [`rescaled_step`]). `pose`, like `face`, is
+//! instant (`in N` just holds the chain there for N frames) — but unlike
+//! every other motion, its effect *persists* on the actor past the
+//! instruction, past the whole step, even past a scene that's playing
+//! something else on top: it's standing choreography (a guy slumped against a
+//! wall) rather than a one-off action. It stays until another `pose`
+//! overwrites it, `pose none` clears it, or the owning scene's cleanup clears
+//! every pose it applied, same as it does spawned actors — so a pose can't
+//! outlive the scene that set it. A posed actor draws a named sprite strip off
+//! its preset instead of its walk sprite; a name the preset doesn't have logs
+//! a warning and falls back to the walk sprite.
 //!
 //! # What belongs where
 //!
@@ -168,6 +237,7 @@
 use std::collections::HashMap;
 
 use egg_render::geometry::Vec2;
+use crate::data::save::VarTest;
 use crate::world::player::PresetId;
 
 pub use super::script::eggtext::ParseError;
@@ -239,6 +309,15 @@ pub enum CutsceneContent {
     Interact { actor: String, target: String },
     /// Push a sub-cutscene by name onto the stack (popped on its finish).
     Load(String),
+    /// `warp MAP X Y [fade N]` — move the player to `pos` on `map`, carrying
+    /// the scene's bound actors along (see the module doc's `warp` section).
+    /// `Some(n)` fades to black over `n` frames, swaps, then fades back in over
+    /// `n` more; `None` cuts.
+    Warp {
+        map: String,
+        pos: Vec2,
+        fade: Option<u32>,
+    },
     /// Hold for `N` frames.
     Wait(u32),
     /// Play a sound effect by name (resolved at build time).
@@ -247,6 +326,16 @@ pub enum CutsceneContent {
     Music(Option<String>),
     /// Set a named save flag.
     SetFlag(String, bool),
+    /// `setv VAR N` — set a named save counter.
+    SetVar(String, i32),
+    /// `add VAR N` — add to a named save counter (`N` may be negative).
+    AddVar(String, i32),
+    /// `give ITEM` — put an item (by its `GameItems` key) in the player's bag,
+    /// exactly as a `give_item` map interaction does: a full bag is a no-op.
+    GiveItem(String),
+    /// `take ITEM` — remove one of an item from the player's bag; a no-op when
+    /// the bag holds none.
+    TakeItem(String),
     /// Retarget the scene camera (`camera ACTOR` / `camera X Y`); it follows the
     /// target until retargeted again, resetting to the player when the scene ends.
     /// `Some(n)` (`… over N`) glides there over `n` frames instead of cutting;
@@ -256,6 +345,65 @@ pub enum CutsceneContent {
     /// up to ±`amplitude` px in a fixed pattern that tapers out. Non-blocking and
     /// transient: the camera is back on its focus when the frames run out.
     Shake { frames: u32, amplitude: i16 },
+    /// `if`/`elif`/`else` — a branch chain, resolved live against the save when
+    /// the scene reaches it (see the module doc's branching section).
+    If(IfChain),
+}
+
+/// An `if [not] FLAG` / `elif …` / `else` chain: the branches in authored
+/// order, then the `else` body (empty when there's no `else`). Evaluated at
+/// play time — [`pick`](Self::pick) takes the condition test from the caller,
+/// since what a condition reads (the live save) is the runtime's business,
+/// not the parser's.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IfChain {
+    /// The `if` branch first, then each `elif`, in order. Never empty.
+    pub branches: Vec<Branch>,
+    /// The `else` body; empty when the chain has no `else`.
+    pub otherwise: Vec<CutsceneContent>,
+}
+
+/// One guarded branch of an [`IfChain`]: `if`/`elif [not] CONDITION`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Branch {
+    pub condition: Condition,
+    /// The `not` prefix — the branch runs when the condition does *not* hold.
+    pub negated: bool,
+    pub content: Vec<CutsceneContent>,
+}
+
+/// What an `if`/`elif` tests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Condition {
+    /// A named save flag (`if FLAG`), read like `.eggtext`'s `#if`.
+    Flag(String),
+    /// `if has ITEM` — whether the player's bag holds at least one of the
+    /// item, by its `GameItems` key.
+    Has(String),
+    /// `if VAR OP N` — a comparison on a named save counter.
+    Var(VarTest),
+}
+
+impl IfChain {
+    /// The steps this chain resolves to: the first branch whose condition
+    /// (`holds`, flipped by `not`) is true, else the `else` body. `holds` is
+    /// called at most once per branch, in order, stopping at the first match.
+    pub fn pick(&self, mut holds: impl FnMut(&Condition) -> bool) -> &[CutsceneContent] {
+        self.branches
+            .iter()
+            .find(|branch| holds(&branch.condition) != branch.negated)
+            .map_or(&self.otherwise[..], |branch| &branch.content[..])
+    }
+
+    /// Every step list in the chain (each branch, then `else`) — for walkers
+    /// (path inlining, the checker) that must visit all sides regardless of
+    /// which one a given save would take.
+    pub fn bodies(&self) -> impl Iterator<Item = &Vec<CutsceneContent>> {
+        self.branches
+            .iter()
+            .map(|branch| &branch.content)
+            .chain(std::iter::once(&self.otherwise))
+    }
 }
 
 /// The `shake` amplitude used when the author gives only a duration, in pixels.
@@ -348,6 +496,50 @@ impl Instruction {
 /// [`parse_path_body`]).
 pub type PathRuns = Vec<((i8, i8), u16)>;
 
+/// The recorded length of `runs`, in frames.
+pub fn runs_frames(runs: &[((i8, i8), u16)]) -> u32 {
+    runs.iter().map(|&(_, n)| u32::from(n)).sum()
+}
+
+/// Net displacement after the first `frames` recorded frames of `runs`.
+fn runs_offset(runs: &[((i8, i8), u16)], frames: u32) -> (i32, i32) {
+    let (mut x, mut y, mut left) = (0i32, 0i32, frames);
+    for &((dx, dy), n) in runs {
+        let held = left.min(u32::from(n)) as i32;
+        x += i32::from(dx) * held;
+        y += i32::from(dy) * held;
+        left -= held as u32;
+        if left == 0 {
+            break;
+        }
+    }
+    (x, y)
+}
+
+/// The displacement on frame `frame` (0-based) of `runs` replayed over
+/// `budget` frames. Frame `k` of the budget stands at recorded frame
+/// `round(k × recorded / budget)`, so a stretched path holds still on some
+/// frames and a compressed one covers several recorded pixels per frame —
+/// the route is the recorded one either way, and the last frame lands
+/// exactly on the recorded end point. A `budget` equal to the recorded
+/// length (or zero, meaning "unbudgeted") is the plain run heading. The
+/// single source every consumer (playback, the skip snap, the scrubber's
+/// re-sim) reads budgeted timing from.
+pub fn rescaled_step(runs: &[((i8, i8), u16)], budget: u32, frame: u32) -> (i16, i16) {
+    let recorded = runs_frames(runs);
+    let budget = if budget == 0 { recorded } else { budget };
+    if budget == 0 {
+        return (0, 0);
+    }
+    let at = |k: u32| {
+        let k = u64::from(k.min(budget));
+        let t = (k * u64::from(recorded) + u64::from(budget) / 2) / u64::from(budget);
+        runs_offset(runs, t as u32)
+    };
+    let ((x0, y0), (x1, y1)) = (at(frame), at(frame + 1));
+    ((x1 - x0) as i16, (y1 - y0) as i16)
+}
+
 /// A single motion an actor performs. Entity-relative motions name another actor
 /// (re-read live each frame at play time).
 #[derive(Clone, Debug, PartialEq)]
@@ -358,6 +550,11 @@ pub enum Motion {
     MoveToPointNoclip(Vec2),
     /// `to NAME` — toward another actor's live position.
     MoveToEntity(String),
+    /// `walk! X Y` — to a point along a route planned around collision.
+    PathToPoint(Vec2),
+    /// `to! NAME` — to another actor along a route planned around collision,
+    /// re-planned when the actor wanders off its end.
+    PathToEntity(String),
     /// `beside NAME [gap]` — to NAME's head-side, `gap` px away, facing it.
     MoveBesideHorizontal { target: String, gap: i16 },
     /// `face NAME` — turn to face another actor.
@@ -368,17 +565,15 @@ pub enum Motion {
     Teleport(Vec2),
     /// `record [noclip] DX DY N …` — an RLE path (direction held for N frames),
     /// authored by the path recorder. Replayed step-for-step at its recorded
-    /// frame counts, so it takes no `in N` budget: one is a parse-time error
-    /// (rescaling playback to a budget is future work), and the emitter never
-    /// writes one, so a recorded path round-trips.
+    /// frame counts, or — given an `in N` budget — resampled to land exactly
+    /// on frame N (see [`rescaled_step`]).
     Record { runs: PathRuns, noclip: bool },
     /// `path [noclip] NAME` — a named RLE path, authored separately as a
     /// `#path` block (see the module doc) instead of inline. Resolved against
     /// the path registry at the runtime boundary
     /// ([`SceneFile::inline_paths`]) into a [`Motion::Record`] before
-    /// execution or drawing code ever sees it, so — like `record` — it takes
-    /// no `in N` budget (rejected at parse time) and the emitter never writes
-    /// one.
+    /// execution or drawing code ever sees it; an `in N` budget stays on the
+    /// instruction and rescales the resolved record the same way.
     Path { name: String, noclip: bool },
     /// `pose NAME` / `pose none` — put (or clear) a named standing pose on
     /// the actor: instant like [`FaceDir`](Self::FaceDir) (an `in N` budget
@@ -391,6 +586,20 @@ pub enum Motion {
     Pose(Option<String>),
 }
 
+impl Motion {
+    /// The other actor this motion names (`to`/`to!`/`beside`/`face NAME`),
+    /// or `None` for a point, direction, path, or pose motion.
+    pub fn target_actor(&self) -> Option<&str> {
+        match self {
+            Motion::MoveToEntity(name)
+            | Motion::PathToEntity(name)
+            | Motion::MoveBesideHorizontal { target: name, .. }
+            | Motion::FaceEntity(name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
 /// The hand-owned `.eggscene` source — cutscenes and paths an author writes
 /// directly (see the module doc's "What belongs where"). Named here so the
 /// handful of load/merge/install sites across the host and editor can't drift
@@ -449,7 +658,7 @@ impl SceneFile {
     }
 
     /// One content step's half of [`Self::inline_paths`], recursive over
-    /// `Dialogue`'s `on` handlers.
+    /// `Dialogue`'s `on` handlers and every side of an `if` chain.
     fn inline_paths_step(&self, step: &mut CutsceneContent) {
         match step {
             CutsceneContent::Move(chains) => {
@@ -473,12 +682,27 @@ impl SceneFile {
                     }
                 }
             }
+            CutsceneContent::If(chain) => {
+                for branch in &mut chain.branches {
+                    for sub in &mut branch.content {
+                        self.inline_paths_step(sub);
+                    }
+                }
+                for sub in &mut chain.otherwise {
+                    self.inline_paths_step(sub);
+                }
+            }
             CutsceneContent::Interact { .. }
             | CutsceneContent::Load(_)
+            | CutsceneContent::Warp { .. }
             | CutsceneContent::Wait(_)
             | CutsceneContent::Sound(_)
             | CutsceneContent::Music(_)
             | CutsceneContent::SetFlag(..)
+            | CutsceneContent::SetVar(..)
+            | CutsceneContent::AddVar(..)
+            | CutsceneContent::GiveItem(_)
+            | CutsceneContent::TakeItem(_)
             | CutsceneContent::Camera(..)
             | CutsceneContent::Shake { .. } => {}
         }
@@ -659,8 +883,9 @@ fn parse_cutscene(body: &[(usize, &str)]) -> Result<CutsceneDef, ParseError> {
                 }
                 i += 1;
             }
-            "move" | "dialogue" | "interact" | "load" | "wait" | "sound" | "music" | "set"
-            | "camera" | "shake" => {
+            "move" | "dialogue" | "interact" | "load" | "warp" | "wait" | "sound" | "music"
+            | "set" | "add" | "setv" | "give" | "take" | "camera" | "shake" | "if" | "elif"
+            | "else" => {
                 seen_content = true;
                 let (step, next_i) = parse_content_step(body, i, verb, args, line_no, false)?;
                 def.content.push(step);
@@ -684,10 +909,12 @@ fn parse_cutscene(body: &[(usize, &str)]) -> Result<CutsceneDef, ParseError> {
 /// [`parse_handlers`]); everything else is a single line, delegated to
 /// [`parse_content`]. Returns the step and the index of the first line after
 /// it — the caller (the top-level loop, or [`parse_content_block`] for a
-/// handler body) resumes from there. `in_handler` is true while parsing an
-/// `on` handler's own body: `dialogue` and `load` are parse errors there (a
-/// handler can't open another dialogue or push a sub-cutscene — see the
-/// module doc).
+/// handler or branch body) resumes from there. `if` consumes its whole chain
+/// ([`parse_if_chain`]); an `elif`/`else` reaching here has no `if` to
+/// belong to. `in_handler` is true while parsing an `on` handler's own body
+/// (and any branch nested in it): `dialogue` and `load` are parse errors
+/// there (a handler can't open another dialogue or push a sub-cutscene — see
+/// the module doc).
 fn parse_content_step(
     body: &[(usize, &str)],
     i: usize,
@@ -731,10 +958,130 @@ fn parse_content_step(
         "load" if in_handler => {
             Err(ParseError::new(line_no, "`load` cannot nest inside an `on` handler"))
         }
+        "warp" if in_handler => {
+            Err(ParseError::new(line_no, "`warp` cannot nest inside an `on` handler"))
+        }
+        "if" => parse_if_chain(body, i, args, line_no, in_handler),
+        "elif" | "else" => Err(ParseError::new(
+            line_no,
+            format!("`{verb}` without a matching `if`"),
+        )),
         _ => Ok((parse_content(verb, args, line_no)?, i + 1)),
     }
 }
 
+/// Parse an `if` chain opened at `body[i]`: the `if`'s own deeper-indented
+/// body, then any `elif`/`else` lines sitting at the `if`'s exact indent, each
+/// with its own body (see the module doc's branching section). A chain ends
+/// at the first line at that indent that isn't `elif`/`else`, at any
+/// shallower line, or at the end of `body`. Bodies are parsed with
+/// [`parse_content_block`], so they nest and inherit `in_handler`. Returns the
+/// chain and the index of the first line not consumed.
+fn parse_if_chain(
+    body: &[(usize, &str)],
+    i: usize,
+    args: &str,
+    line_no: usize,
+    in_handler: bool,
+) -> Result<(CutsceneContent, usize), ParseError> {
+    let if_indent = indent(body[i].1);
+    let (condition, negated) = parse_condition(args, line_no, "if")?;
+    let (content, mut next) = parse_content_block(body, i + 1, if_indent, in_handler)?;
+    if content.is_empty() {
+        return Err(ParseError::new(line_no, "`if` needs at least one step"));
+    }
+    let mut chain = IfChain {
+        branches: vec![Branch {
+            condition,
+            negated,
+            content,
+        }],
+        otherwise: Vec::new(),
+    };
+    let mut seen_else = false;
+    let mut j = next;
+    while j < body.len() {
+        let (l_no, raw) = body[j];
+        let logical = raw.trim_start();
+        if logical.is_empty() || is_comment(logical) {
+            j += 1;
+            continue;
+        }
+        if indent(raw) != if_indent {
+            break;
+        }
+        let (verb, rest) = split_first_word(logical);
+        if verb != "elif" && verb != "else" {
+            break;
+        }
+        if seen_else {
+            return Err(ParseError::new(l_no, format!("`{verb}` after `else`")));
+        }
+        let (content, after) = parse_content_block(body, j + 1, if_indent, in_handler)?;
+        if content.is_empty() {
+            return Err(ParseError::new(
+                l_no,
+                format!("`{verb}` needs at least one step"),
+            ));
+        }
+        if verb == "elif" {
+            let (condition, negated) = parse_condition(rest, l_no, "elif")?;
+            chain.branches.push(Branch {
+                condition,
+                negated,
+                content,
+            });
+        } else {
+            if !rest.trim().is_empty() {
+                return Err(ParseError::new(
+                    l_no,
+                    "`else` takes no condition (use `elif`)",
+                ));
+            }
+            chain.otherwise = content;
+            seen_else = true;
+        }
+        next = after;
+        j = after;
+    }
+    Ok((CutsceneContent::If(chain), next))
+}
+
+/// Parse an `if`/`elif` condition: `[not] FLAG`, `[not] has ITEM` or
+/// `[not] VAR OP N` — one name after the optional `not` (and `has`), matching
+/// `.eggtext`'s `#if` (so `not` is only ever the negation, and `has` only ever
+/// the item test, never a flag name).
+fn parse_condition(
+    args: &str,
+    line_no: usize,
+    verb: &str,
+) -> Result<(Condition, bool), ParseError> {
+    let (first, rest) = split_first_word(args);
+    let (args, negated) = if first == "not" { (rest, true) } else { (args, false) };
+    if let Some(test) = VarTest::parse(args) {
+        return Ok((Condition::Var(test), negated));
+    }
+    let (first, rest) = split_first_word(args);
+    let (args, has) = if first == "has" { (rest, true) } else { (args, false) };
+    let (name, extra) = split_first_word(args);
+    if name.is_empty() {
+        let what = if has { "an item key" } else { "a flag name" };
+        return Err(ParseError::new(line_no, format!("`{verb}` needs {what}")));
+    }
+    if !extra.trim().is_empty() {
+        return Err(ParseError::new(
+            line_no,
+            format!("`{verb}` takes `[not] FLAG`, `[not] has ITEM` or `[not] VAR OP N`"),
+        ));
+    }
+    let condition = if has {
+        Condition::Has(name.to_string())
+    } else {
+        Condition::Flag(name.to_string())
+    };
+    Ok((condition, negated))
+}
+
 /// Parse the `on NAME [wait]` handler blocks under a `dialogue` step,
 /// starting at `body[i]`, each more deeply indented than `dialogue_indent`
 /// (the `dialogue` line's own indent). Stops at the first line indented at or
@@ -801,7 +1148,8 @@ fn parse_handlers(
 
 /// Parse a sequence of content steps (see [`parse_content_step`]) starting at
 /// `body[i]`, for as long as each is indented deeper than `min_indent` — an
-/// `on` handler's body. `in_handler` is threaded to each step.
+/// `on` handler's body, or an `if`/`elif`/`else` branch's. `in_handler` is
+/// threaded to each step.
 fn parse_content_block(
     body: &[(usize, &str)],
     mut i: usize,
@@ -822,8 +1170,9 @@ fn parse_content_block(
         let (verb, args) = split_first_word(logical);
         let known = matches!(
             verb,
-            "move" | "dialogue" | "interact" | "load" | "wait" | "sound" | "music" | "set"
-                | "camera" | "shake"
+            "move" | "dialogue" | "interact" | "load" | "warp" | "wait" | "sound" | "music"
+                | "set" | "add" | "setv" | "give" | "take" | "camera" | "shake" | "if"
+                | "elif" | "else"
         );
         if !known {
             return Err(ParseError::new(line_no, format!("unknown verb `{verb}`")));
@@ -897,6 +1246,24 @@ fn parse_content(verb: &str, args: &str, line_no: usize) -> Result<CutsceneConte
             }
             CutsceneContent::SetFlag(name.to_string(), parse_bool(value, line_no)?)
         }
+        "add" | "setv" => {
+            let (name, value) = split_first_word(args);
+            let value = value.trim().parse().ok().filter(|_| !name.is_empty());
+            let Some(value) = value else {
+                return Err(ParseError::new(line_no, format!("`{verb}` needs `VAR N`")));
+            };
+            if verb == "add" {
+                CutsceneContent::AddVar(name.to_string(), value)
+            } else {
+                CutsceneContent::SetVar(name.to_string(), value)
+            }
+        }
+        "give" => {
+            CutsceneContent::GiveItem(require_name(args, line_no, "`give` needs an item key")?)
+        }
+        "take" => {
+            CutsceneContent::TakeItem(require_name(args, line_no, "`take` needs an item key")?)
+        }
         "interact" => {
             let (actor, target) = split_first_word(args);
             let target = target.trim();
@@ -912,6 +1279,7 @@ fn parse_content(verb: &str, args: &str, line_no: usize) -> Result<CutsceneConte
             let (target, over) = parse_camera(args, line_no)?;
             CutsceneContent::Camera(target, over)
         }
+        "warp" => parse_warp(args, line_no)?,
         "shake" => {
             let mut parts = args.split_whitespace();
             let frames = parse_u32(
@@ -971,6 +1339,33 @@ fn parse_camera(args: &str, line_no: usize) -> Result<(CameraTarget, Option<u32>
     Ok((target, over))
 }
 
+/// Parse a `warp` argument: `MAP X Y`, optionally followed by `fade N` (the
+/// frames each half of the fade takes — same trailing-suffix shape as
+/// `camera … over N`).
+fn parse_warp(args: &str, line_no: usize) -> Result<CutsceneContent, ParseError> {
+    let mut tokens: Vec<&str> = args.split_whitespace().collect();
+    let fade = if tokens.len() >= 2 && tokens[tokens.len() - 2] == "fade" {
+        let frames = parse_u32(tokens[tokens.len() - 1], line_no, "`fade` needs a frame count")?;
+        if frames == 0 {
+            return Err(ParseError::new(line_no, "`fade 0` — fade needs ≥1 frame"));
+        }
+        tokens.truncate(tokens.len() - 2);
+        Some(frames)
+    } else if tokens.last() == Some(&"fade") {
+        return Err(ParseError::new(line_no, "`fade` needs a frame count"));
+    } else {
+        None
+    };
+    let [map, x, y] = tokens[..] else {
+        return Err(ParseError::new(line_no, "`warp` takes `MAP X Y [fade N]`"));
+    };
+    Ok(CutsceneContent::Warp {
+        map: map.to_string(),
+        pos: args_vec2(&[x, y], line_no, "warp")?,
+        fade,
+    })
+}
+
 /// Parse one `actor: motion; motion; …` chain line.
 fn parse_chain(logical: &str, line_no: usize) -> Result<Chain, ParseError> {
     let (actor, rest) = logical
@@ -1029,6 +1424,8 @@ fn parse_motion(segment: &str, line_no: usize) -> Result<Instruction, ParseError
         "walk" => Motion::MoveToPoint(args_vec2(args, line_no, "walk")?),
         "noclip" => Motion::MoveToPointNoclip(args_vec2(args, line_no, "noclip")?),
         "to" => Motion::MoveToEntity(args_name(args, line_no, "to")?),
+        "walk!" => Motion::PathToPoint(args_vec2(args, line_no, "walk!")?),
+        "to!" => Motion::PathToEntity(args_name(args, line_no, "to!")?),
         "beside" => {
             let target = args_name(&args[..args.len().min(1)], line_no, "beside")?;
             let gap = match args.get(1) {
@@ -1057,22 +1454,6 @@ fn parse_motion(segment: &str, line_no: usize) -> Result<Instruction, ParseError
         }
         other => return Err(ParseError::new(line_no, format!("unknown motion `{other}`"))),
     };
-    // `record`/`path` both replay at their own recorded frame counts; an `in N`
-    // budget on either would be a silent no-op, so reject it at parse time
-    // (fail loud beats a dropped budget).
-    let recorded_verb = match &motion {
-        Motion::Record { .. } => Some("record"),
-        Motion::Path { .. } => Some("path"),
-        _ => None,
-    };
-    if time != 0
-        && let Some(verb) = recorded_verb
-    {
-        return Err(ParseError::new(
-            line_no,
-            format!("`{verb}` takes no `in N` budget (it replays at its recorded frame counts)"),
-        ));
-    }
     Ok(Instruction {
         motion,
         time,
@@ -1282,8 +1663,9 @@ fn emit_init(entity: &GetEntity) -> String {
 /// Emit one content step, including its trailing newline(s), indented
 /// `depth` levels deep (4 spaces per level — 1 at the top of a `#cutscene`
 /// body, one more for each nesting: a `move`'s chains, an `on` handler
-/// header, and that handler's own steps, recursively through this same
-/// function). The inverse of [`parse_content_step`].
+/// header, that handler's own steps, and an `if`/`elif`/`else` body,
+/// recursively through this same function). The inverse of
+/// [`parse_content_step`].
 fn emit_content(step: &CutsceneContent, depth: usize) -> String {
     let ind = "    ".repeat(depth);
     match step {
@@ -1318,11 +1700,19 @@ fn emit_content(step: &CutsceneContent, depth: usize) -> String {
             format!("{ind}interact {actor} {target}\n")
         }
         CutsceneContent::Load(name) => format!("{ind}load {name}\n"),
+        CutsceneContent::Warp { map, pos, fade } => match fade {
+            Some(frames) => format!("{ind}warp {map} {} {} fade {frames}\n", pos.x, pos.y),
+            None => format!("{ind}warp {map} {} {}\n", pos.x, pos.y),
+        },
         CutsceneContent::Wait(frames) => format!("{ind}wait {frames}\n"),
         CutsceneContent::Sound(name) => format!("{ind}sound {name}\n"),
         CutsceneContent::Music(Some(track)) => format!("{ind}music {track}\n"),
         CutsceneContent::Music(None) => format!("{ind}music\n"),
         CutsceneContent::SetFlag(name, value) => format!("{ind}set {name} {value}\n"),
+        CutsceneContent::SetVar(name, value) => format!("{ind}setv {name} {value}\n"),
+        CutsceneContent::AddVar(name, delta) => format!("{ind}add {name} {delta}\n"),
+        CutsceneContent::GiveItem(item) => format!("{ind}give {item}\n"),
+        CutsceneContent::TakeItem(item) => format!("{ind}take {item}\n"),
         CutsceneContent::Camera(target, over) => {
             let target = match target {
                 CameraTarget::Actor(name) => name.clone(),
@@ -1340,6 +1730,29 @@ fn emit_content(step: &CutsceneContent, depth: usize) -> String {
                 format!("{ind}shake {frames} {amplitude}\n")
             }
         }
+        CutsceneContent::If(chain) => {
+            let mut out = String::new();
+            for (n, branch) in chain.branches.iter().enumerate() {
+                let verb = if n == 0 { "if" } else { "elif" };
+                let not = if branch.negated { "not " } else { "" };
+                let condition = match &branch.condition {
+                    Condition::Flag(flag) => flag.clone(),
+                    Condition::Has(item) => format!("has {item}"),
+                    Condition::Var(test) => test.to_string(),
+                };
+                out.push_str(&format!("{ind}{verb} {not}{condition}\n"));
+                for sub in &branch.content {
+                    out.push_str(&emit_content(sub, depth + 1));
+                }
+            }
+            if !chain.otherwise.is_empty() {
+                out.push_str(&format!("{ind}else\n"));
+                for sub in &chain.otherwise {
+                    out.push_str(&emit_content(sub, depth + 1));
+                }
+            }
+            out
+        }
     }
 }
 
@@ -1352,9 +1765,7 @@ fn emit_chain(chain: &Chain) -> String {
             if ins.required {
                 m.push('?');
             }
-            // `record`/`path` never carry an `in N` budget (parse rejects one), so
-            // never emit one either — otherwise a re-parse of the output would fail.
-            if ins.time != 0 && !matches!(ins.motion, Motion::Record { .. } | Motion::Path { .. }) {
+            if ins.time != 0 {
                 m.push_str(&format!(" in {}", ins.time));
             }
             m
@@ -1368,6 +1779,8 @@ fn emit_motion(motion: &Motion) -> String {
         Motion::MoveToPoint(p) => format!("walk {} {}", p.x, p.y),
         Motion::MoveToPointNoclip(p) => format!("noclip {} {}", p.x, p.y),
         Motion::MoveToEntity(name) => format!("to {name}"),
+        Motion::PathToPoint(p) => format!("walk! {} {}", p.x, p.y),
+        Motion::PathToEntity(name) => format!("to! {name}"),
         Motion::MoveBesideHorizontal { target, gap } => {
             if *gap == 0 {
                 format!("beside {target}")
@@ -1490,7 +1903,7 @@ mod tests {
         let def = one(
             "#cutscene c\n\
              \x20   move\n\
-             \x20       a: walk 1 2; noclip 3 4; to b; beside b 5; face b; face 1 -1; teleport 7 8; record noclip 1 0 10; pose slump; pose none",
+             \x20       a: walk 1 2; noclip 3 4; to b; walk! 5 6; to! b; beside b 5; face b; face 1 -1; teleport 7 8; record noclip 1 0 10; pose slump; pose none",
         );
         let CutsceneContent::Move(chains) = &def.content[0] else {
             panic!("move");
@@ -1501,6 +1914,8 @@ mod tests {
                 Instruction::new(Motion::MoveToPoint(Vec2::new(1, 2)), 0),
                 Instruction::new(Motion::MoveToPointNoclip(Vec2::new(3, 4)), 0),
                 Instruction::new(Motion::MoveToEntity("b".into()), 0),
+                Instruction::new(Motion::PathToPoint(Vec2::new(5, 6)), 0),
+                Instruction::new(Motion::PathToEntity("b".into()), 0),
                 Instruction::new(Motion::MoveBesideHorizontal { target: "b".into(), gap: 5 }, 0),
                 Instruction::new(Motion::FaceEntity("b".into()), 0),
                 Instruction::new(Motion::FaceDir(1, -1), 0),
@@ -1540,7 +1955,7 @@ mod tests {
         let def = one(
             "#cutscene c interruptible\n\
              \x20   move\n\
-             \x20       a: beside b? ; walk 4 5? in 12",
+             \x20       a: beside b? ; walk 4 5? in 12; to! dog?",
         );
         assert!(def.interruptible);
         let CutsceneContent::Move(chains) = &def.content[0] else {
@@ -1552,9 +1967,12 @@ mod tests {
                 Instruction::new(Motion::MoveBesideHorizontal { target: "b".into(), gap: 0 }, 0)
                     .required(),
                 Instruction::new(Motion::MoveToPoint(Vec2::new(4, 5)), 12).required(),
+                Instruction::new(Motion::PathToEntity("dog".into()), 0).required(),
             ]
         );
-        let (file, reparsed) = round_trip("#cutscene c interruptible\n    move\n        a: beside b? in 3");
+        let (file, reparsed) = round_trip(
+            "#cutscene c interruptible\n    move\n        a: beside b? in 3; walk! 1 2? in 9",
+        );
         assert_eq!(file, reparsed);
     }
 
@@ -1665,18 +2083,44 @@ mod tests {
         assert_eq!(parse("#wat name").unwrap_err().line, 1);
     }
 
-    /// An `in N` budget on a `record` motion is a parse-time error (it would
-    /// otherwise be a silent no-op), pointed at its line. A budget-free `record`
-    /// still parses.
+    /// `record` and `path` take an `in N` budget like any motion, and it
+    /// round-trips (a budget-free one still emits none).
     #[test]
-    fn record_rejects_an_in_budget() {
-        let err = parse("#cutscene c\n    move\n        a: record 1 0 10 in 5")
-            .unwrap_err();
-        assert_eq!(err.line, 3);
-        assert!(
-            parse("#cutscene c\n    move\n        a: record 1 0 10").is_ok(),
-            "a budget-free record still parses",
-        );
+    fn record_and_path_take_an_in_budget() {
+        let src = "#cutscene c\n    move\n        a: record 1 0 10 in 5; path noclip p in 90";
+        let def = one(src);
+        let CutsceneContent::Move(chains) = &def.content[0] else {
+            panic!("expected a move step");
+        };
+        assert_eq!(chains[0].instructions[0].time, 5);
+        assert_eq!(chains[0].instructions[1].time, 90);
+        for src in [src, "#cutscene c\n    move\n        a: record 1 0 10; path p"] {
+            let (file, reparsed) = round_trip(src);
+            assert_eq!(file.cutscenes, reparsed.cutscenes);
+        }
+    }
+
+    /// A budget resamples the recorded route: compressed or stretched, every
+    /// frame's steps sum to the recorded end point, and an unbudgeted (or
+    /// exact) budget is the plain per-run heading.
+    #[test]
+    fn rescaled_steps_land_on_the_recorded_end_point() {
+        let runs: PathRuns = vec![((1, 0), 6), ((0, 0), 2), ((0, -1), 4)];
+        let steps = |budget| -> Vec<(i16, i16)> {
+            let frames = if budget == 0 { runs_frames(&runs) } else { budget };
+            (0..frames).map(|k| rescaled_step(&runs, budget, k)).collect()
+        };
+        let plain = steps(0);
+        assert_eq!(plain, steps(12));
+        assert_eq!(plain[..7], [(1, 0), (1, 0), (1, 0), (1, 0), (1, 0), (1, 0), (0, 0)]);
+        for budget in [1, 3, 5, 12, 30, 97] {
+            let total = steps(budget)
+                .iter()
+                .fold((0, 0), |(x, y), (dx, dy)| (x + dx, y + dy));
+            assert_eq!(total, (6, -4), "budget {budget}");
+        }
+        assert_eq!(steps(3), [(4, 0), (2, 0), (0, -4)]);
+        assert_eq!(rescaled_step(&[], 5, 0), (0, 0));
     }
 
     // --- #path blocks ---
@@ -1772,18 +2216,6 @@ mod tests {
         );
     }
 
-    /// A `path` motion replays at its named path's recorded frame counts, so
-    /// (like `record`) an `in N` budget on it is a parse-time error.
-    #[test]
-    fn path_motion_rejects_an_in_budget() {
-        let err = parse("#cutscene c\n    move\n        a: path p in 5").unwrap_err();
-        assert_eq!(err.line, 3);
-        assert!(
-            parse("#cutscene c\n    move\n        a: path p").is_ok(),
-            "a budget-free path still parses",
-        );
-    }
-
     // --- merging multiple sources ---
 
     #[test]
@@ -2062,6 +2494,284 @@ mod tests {
         assert_eq!(handlers[0].content[1], CutsceneContent::Sound("pop".into()));
     }
 
+    /// An `if`/`elif`/`else` chain parses into one [`IfChain`] step: each
+    /// branch's body is its deeper-indented steps, and the step after the
+    /// chain (back at the `if`'s indent) is a sibling, not part of `else`.
+    #[test]
+    fn if_elif_else_chain_parses() {
+        let def = one("#cutscene c\n\
+             \x20   if not met_dog\n\
+             \x20       dialogue first\n\
+             \x20       set met_dog true\n\
+             \x20   elif fed_dog\n\
+             \x20       dialogue happy\n\
+             \x20   // a comment between branches\n\
+             \x20   else\n\
+             \x20       wait 3\n\
+             \x20   sound pop");
+        assert_eq!(def.content.len(), 2, "{:?}", def.content);
+        let CutsceneContent::If(chain) = &def.content[0] else {
+            panic!("if step");
+        };
+        assert_eq!(chain.branches.len(), 2);
+        assert_eq!(
+            chain.branches[0].condition,
+            Condition::Flag("met_dog".into())
+        );
+        assert!(chain.branches[0].negated);
+        assert_eq!(chain.branches[0].content.len(), 2);
+        assert_eq!(
+            chain.branches[1].condition,
+            Condition::Flag("fed_dog".into())
+        );
+        assert!(!chain.branches[1].negated);
+        assert_eq!(chain.otherwise, vec![CutsceneContent::Wait(3)]);
+        assert_eq!(def.content[1], CutsceneContent::Sound("pop".into()));
+    }
+
+    /// `pick` takes the first branch whose (possibly negated) condition
+    /// holds, falls back to `else`, and resolves to nothing when there's no
+    /// `else` and no branch matches.
+    #[test]
+    fn if_chain_picks_the_first_holding_branch() {
+        let def = one(
+            "#cutscene c\n    if a\n        wait 1\n    elif not b\n        wait 2\n    else\n        wait 3",
+        );
+        let CutsceneContent::If(chain) = &def.content[0] else {
+            panic!("if step");
+        };
+        let with = |set: &[&str]| {
+            let set: Vec<String> = set.iter().map(|s| s.to_string()).collect();
+            chain.pick(|c| matches!(c, Condition::Flag(f) if set.contains(f))).to_vec()
+        };
+        assert_eq!(with(&["a", "b"]), vec![CutsceneContent::Wait(1)]);
+        assert_eq!(with(&[]), vec![CutsceneContent::Wait(2)]);
+        assert_eq!(with(&["b"]), vec![CutsceneContent::Wait(3)]);
+
+        let bare = one("#cutscene c\n    if a\n        wait 1");
+        let CutsceneContent::If(chain) = &bare.content[0] else {
+            panic!("if step");
+        };
+        assert!(chain.pick(|_| false).is_empty());
+    }
+
+    /// Malformed chains are parse errors pointed at the offending line: a
+    /// stray `elif`/`else`, a branch after `else`, an empty body, a missing or
+    /// doubled flag name, and an `else` carrying a condition.
+    #[test]
+    fn if_chain_errors_point_at_the_line() {
+        assert_eq!(
+            parse("#cutscene c\n    elif a\n        wait 1")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    else\n        wait 1")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if a\n        wait 1\n    else\n        wait 1\n    elif b\n        wait 1")
+                .unwrap_err()
+                .line,
+            6
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if a\n    wait 1").unwrap_err().line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if\n        wait 1")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if not\n        wait 1")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if a b\n        wait 1")
+                .unwrap_err()
+                .line,
+            2
+        );
+        assert_eq!(
+            parse("#cutscene c\n    if a\n        wait 1\n    else b\n        wait 1")
+                .unwrap_err()
+                .line,
+            4
+        );
+    }
+
+    /// An `if` inside an `on` handler inherits the handler's restrictions —
+    /// its branches can't open a `dialogue` either.
+    #[test]
+    fn if_chain_inside_a_handler_keeps_handler_rules() {
+        let err = parse(
+            "#cutscene c\n\
+             \x20   dialogue d\n\
+             \x20       on beat\n\
+             \x20           if a\n\
+             \x20               dialogue nested",
+        )
+        .unwrap_err();
+        assert_eq!(err.line, 5);
+    }
+
+    /// Nested chains, chains inside handlers, and bare `if`s all round-trip
+    /// through the emitter.
+    #[test]
+    fn emit_round_trips_if_chains() {
+        let (file, reparsed) = round_trip(
+            "#cutscene c\n\
+             \x20   if a\n\
+             \x20       if not b\n\
+             \x20           set b true\n\
+             \x20       else\n\
+             \x20           wait 2\n\
+             \x20   elif not c\n\
+             \x20       dialogue d\n\
+             \x20           on beat\n\
+             \x20               if e\n\
+             \x20                   shake 4\n\
+             \x20   if f\n\
+             \x20       load g",
+        );
+        assert_eq!(file, reparsed);
+    }
+
+    /// `give`/`take ITEM` parse to their steps and `[not] has ITEM` to an item
+    /// condition (so `has` is never a flag name); both emit back unchanged. A
+    /// step or condition missing its item key is a parse error on its line.
+    #[test]
+    fn inventory_verbs_and_has_conditions_round_trip() {
+        let src = "#cutscene c\n\
+             \x20   give chegg\n\
+             \x20   if not has ff\n\
+             \x20       take chegg\n\
+             \x20   elif has lm\n\
+             \x20       wait 1";
+        let def = one(src);
+        assert_eq!(def.content[0], CutsceneContent::GiveItem("chegg".into()));
+        let CutsceneContent::If(chain) = &def.content[1] else {
+            panic!("if step");
+        };
+        assert_eq!(chain.branches[0].condition, Condition::Has("ff".into()));
+        assert!(chain.branches[0].negated);
+        assert_eq!(chain.branches[0].content, vec![CutsceneContent::TakeItem("chegg".into())]);
+        assert_eq!(chain.branches[1].condition, Condition::Has("lm".into()));
+        let (file, reparsed) = round_trip(src);
+        assert_eq!(file, reparsed);
+
+        for bad in [
+            "#cutscene c\n    give",
+            "#cutscene c\n    take",
+            "#cutscene c\n    if has\n        wait 1",
+            "#cutscene c\n    if not has\n        wait 1",
+            "#cutscene c\n    if has a b\n        wait 1",
+        ] {
+            assert_eq!(parse(bad).unwrap_err().line, 2, "{bad}");
+        }
+    }
+
+    /// `add`/`setv VAR N` parse to counter steps and `[not] VAR OP N` to a
+    /// comparison condition; all emit back in canonical spelling. A missing or
+    /// non-integer amount is a parse error on its line.
+    #[test]
+    fn counter_steps_and_comparisons_round_trip() {
+        let src = "#cutscene c\n\
+             \x20   add visits 1\n\
+             \x20   setv visits -3\n\
+             \x20   if not visits<0\n\
+             \x20       wait 1";
+        let def = one(src);
+        assert_eq!(def.content[0], CutsceneContent::AddVar("visits".into(), 1));
+        assert_eq!(def.content[1], CutsceneContent::SetVar("visits".into(), -3));
+        let CutsceneContent::If(chain) = &def.content[2] else {
+            panic!("if step");
+        };
+        let test = VarTest::parse("visits < 0").unwrap();
+        assert_eq!(chain.branches[0].condition, Condition::Var(test));
+        assert!(chain.branches[0].negated);
+        assert!(emit_cutscene("c", &def).contains("if not visits < 0\n"));
+        let (file, reparsed) = round_trip(src);
+        assert_eq!(file, reparsed);
+
+        for bad in ["#cutscene c\n    add visits", "#cutscene c\n    setv visits x"] {
+            assert_eq!(parse(bad).unwrap_err().line, 2, "{bad}");
+        }
+    }
+
+    /// `warp MAP X Y [fade N]` parses with and without its fade and emits back
+    /// unchanged; a malformed one — or one inside an `on` handler — is a parse
+    /// error on its line.
+    #[test]
+    fn warp_step_parses_and_round_trips() {
+        let src = "#cutscene c\n    warp town 120 8 fade 20\n    warp bedroom -4 16";
+        let def = one(src);
+        assert_eq!(
+            def.content,
+            vec![
+                CutsceneContent::Warp {
+                    map: "town".into(),
+                    pos: Vec2::new(120, 8),
+                    fade: Some(20),
+                },
+                CutsceneContent::Warp {
+                    map: "bedroom".into(),
+                    pos: Vec2::new(-4, 16),
+                    fade: None,
+                },
+            ]
+        );
+        let (file, reparsed) = round_trip(src);
+        assert_eq!(file, reparsed);
+
+        for bad in [
+            "#cutscene c\n    warp",
+            "#cutscene c\n    warp town 1",
+            "#cutscene c\n    warp town 1 x",
+            "#cutscene c\n    warp town 1 2 fade",
+            "#cutscene c\n    warp town 1 2 fade 0",
+            "#cutscene c\n    warp town 1 2 3",
+        ] {
+            assert_eq!(parse(bad).unwrap_err().line, 2, "{bad}");
+        }
+        let err = parse("#cutscene c\n    dialogue d\n        on go\n            warp town 1 2")
+            .unwrap_err();
+        assert_eq!(err.line, 4);
+    }
+
+    /// Path inlining reaches `move` steps inside every side of a chain.
+    #[test]
+    fn inline_paths_recurses_into_if_branches() {
+        let file = parse(
+            "#cutscene c\n    if a\n        move\n            x: path p\n    else\n        move\n            x: path p\n#path p\n    1 0 2",
+        )
+        .unwrap();
+        let resolved = file.get_cutscene_resolved("c").unwrap();
+        let CutsceneContent::If(chain) = &resolved.content[0] else {
+            panic!("if step");
+        };
+        for body in chain.bodies() {
+            let CutsceneContent::Move(chains) = &body[0] else {
+                panic!("move");
+            };
+            assert_eq!(
+                chains[0].instructions[0].motion,
+                Motion::Record {
+                    runs: vec![((1, 0), 2)],
+                    noclip: false
+                }
+            );
+        }
+    }
+
     /// The strongest guarantee: the shipped `.eggscene` round-trips.
     #[test]
     fn emit_round_trips_every_shipped_cutscene() {
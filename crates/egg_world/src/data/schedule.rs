@@ -0,0 +1,208 @@
+//! NPC daily schedules — `data.toml`'s `[schedules.<id>]` tables, one per
+//! named map creature (the [`Shell::id`](crate::world::player::Shell::id) a
+//! cutscene addresses it by). A schedule is an ordered list of [`SlotDef`]s,
+//! each "between these hours / while this holds, be on map M at point P (in
+//! pose Q)":
+//!
+//! ```toml
+//! [schedules.may]
+//! preset = "may"
+//!
+//! [[schedules.may.slots]]
+//! hours = [8, 18]
+//! unless = "may_left_town"
+//! map = "town"
+//! at = [96, 64]
+//!
+//! [[schedules.may.slots]]
+//! map = "may_house"
+//! at = [40, 32]
+//! pose = "slump"
+//! ```
+//!
+//! The first slot whose conditions hold wins ([`ScheduleDef::slot`]); a
+//! creature no slot claims stays wherever it was. Nothing simulates the
+//! creature between rooms: whenever the player changes map, every scheduled
+//! creature that is off screen is lifted out of wherever it was parked (or
+//! spawned from its `preset`, the first time) and set down at its slot — so
+//! it is simply *there* when the player walks in. One the player can see is
+//! left alone until they leave, and so is one whose slot is the room the
+//! player is standing in (it can't pop into view); both catch up at the next
+//! map change. See `WalkaroundState::follow_schedules`.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::eggdata::DataFile;
+use crate::data::save::{SaveData, SaveTest};
+use crate::world::player::PresetId;
+use egg_render::geometry::Vec2;
+
+/// One creature's day: the preset it spawns as, and its slots in priority
+/// order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleDef {
+    /// What the creature is spawned as the first time its schedule places
+    /// it, if nothing else (a cutscene `bind`, an earlier visit) made it
+    /// already.
+    pub preset: PresetId,
+    #[serde(default)]
+    pub slots: Vec<SlotDef>,
+}
+
+/// Where a scheduled creature belongs while its conditions hold. Every
+/// condition is optional; a slot with none always holds, which makes it the
+/// natural last, fallback slot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlotDef {
+    /// `[from, to]`: holds from hour `from` (inclusive) up to `to`
+    /// (exclusive) on the [`Clock`](crate::data::clock::Clock), wrapping past
+    /// midnight when `to <= from` — `[20, 6]` is overnight.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<[u32; 2]>,
+    /// Holds only when this flag is set (or this comparison holds), read
+    /// through [`SaveData::test`] like a map object's gate.
+    #[serde(default, rename = "if", skip_serializing_if = "Option::is_none")]
+    pub if_flag: Option<SaveTest>,
+    /// Holds only when this flag is clear (or this comparison fails).
+    #[serde(default, rename = "unless", skip_serializing_if = "Option::is_none")]
+    pub unless_flag: Option<SaveTest>,
+    /// The map the creature is on.
+    pub map: String,
+    /// Map-pixel `[x, y]` of the creature's hitbox top-left, as a warp's
+    /// landing point is spelled.
+    pub at: [i16; 2],
+    /// A named standing pose from the preset's `poses` to hold, standing
+    /// still — set as the creature's saved
+    /// [`Shell::slot_pose`](crate::world::player::Shell::slot_pose). `None`
+    /// leaves the creature to its preset's wander.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pose: Option<String>,
+}
+
+impl SlotDef {
+    /// Whether this slot claims the creature against the live `save` — its
+    /// hours, `if` and `unless` all agreeing.
+    pub fn holds(&self, save: &SaveData) -> bool {
+        let in_hours = self.hours.is_none_or(|[from, to]| {
+            let hour = save.clock.hour();
+            if from < to { (from..to).contains(&hour) } else { hour >= from || hour < to }
+        });
+        in_hours
+            && self.if_flag.as_ref().is_none_or(|c| save.test(c))
+            && self.unless_flag.as_ref().is_none_or(|c| !save.test(c))
+    }
+
+    /// The slot's point as a map position.
+    pub fn pos(&self) -> Vec2 {
+        let [x, y] = self.at;
+        Vec2::new(x, y)
+    }
+
+    /// Every condition this slot reads, `if` then `unless` — for the
+    /// validator's flag dataflow.
+    pub fn conditions(&self) -> impl Iterator<Item = &SaveTest> {
+        [&self.if_flag, &self.unless_flag].into_iter().flatten()
+    }
+}
+
+impl ScheduleDef {
+    /// The slot that holds right now: the first whose conditions do, or
+    /// `None` if none does.
+    pub fn slot(&self, save: &SaveData) -> Option<&SlotDef> {
+        self.slots.iter().find(|slot| slot.holds(save))
+    }
+}
+
+/// The runtime schedule registry, keyed by creature id. Built from the
+/// loaded `data.toml` ([`from_data`](Self::from_data)) and threaded through
+/// gameplay as `Ctx::schedules`. Empty by default — the shipped game
+/// schedules no one yet, and a headless test opts in by building its own.
+#[derive(Debug, Clone, Default)]
+pub struct Schedules {
+    defs: BTreeMap<String, ScheduleDef>,
+}
+
+impl Schedules {
+    /// Build from a parsed [`DataFile`]'s `[schedules]`.
+    pub fn from_data(file: &DataFile) -> Self {
+        Self {
+            defs: file.schedules.clone(),
+        }
+    }
+
+    /// The schedule for creature `id`, if it has one.
+    pub fn get(&self, id: &str) -> Option<&ScheduleDef> {
+        self.defs.get(id)
+    }
+
+    /// Every schedule as `(id, def)`, id-sorted — so placing them is
+    /// deterministic.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ScheduleDef)> {
+        self.defs.iter().map(|(id, def)| (id.as_str(), def))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.defs.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::clock::Clock;
+    use crate::data::eggdata;
+
+    const SRC: &str = "\
+[schedules.may]
+preset = \"may\"
+
+[[schedules.may.slots]]
+hours = [8, 18]
+unless = \"gone\"
+map = \"town\"
+at = [96, 64]
+
+[[schedules.may.slots]]
+hours = [20, 6]
+map = \"house\"
+at = [40, 32]
+pose = \"slump\"
+";
+
+    /// The TOML form parses into slots, and the first slot that holds wins:
+    /// hours (wrapping overnight) and the flag conditions all count, and a
+    /// gap between slots places the creature nowhere.
+    #[test]
+    fn first_holding_slot_wins() {
+        let file = eggdata::parse(SRC).expect("parse");
+        let schedules = Schedules::from_data(&file);
+        let may = schedules.get("may").expect("may is scheduled");
+        assert_eq!(may.preset, PresetId::may());
+        let mut save = SaveData::default();
+        let map_at = |save: &SaveData| may.slot(save).map(|slot| slot.map.as_str());
+
+        save.clock = Clock::at(12, 0);
+        assert_eq!(map_at(&save), Some("town"));
+        assert_eq!(may.slot(&save).unwrap().pos(), Vec2::new(96, 64));
+        save.set_flag("gone", true);
+        assert_eq!(map_at(&save), None, "the unless bars the only day slot");
+        save.clock = Clock::at(23, 0);
+        assert_eq!(map_at(&save), Some("house"));
+        save.clock = Clock::at(5, 59);
+        assert_eq!(map_at(&save), Some("house"), "the overnight slot wraps");
+        assert_eq!(may.slot(&save).unwrap().pose.as_deref(), Some("slump"));
+        save.clock = Clock::at(6, 0);
+        assert_eq!(map_at(&save), None);
+    }
+
+    /// A schedule survives a TOML round trip, with unset conditions elided.
+    #[test]
+    fn schedules_round_trip_through_toml() {
+        let file = eggdata::parse(SRC).expect("parse");
+        let toml = eggdata::to_toml(&file).expect("serialise");
+        assert!(!toml.contains("if ="), "no `if` ⇒ no key: {toml}");
+        assert_eq!(eggdata::parse(&toml).expect("reparse"), file);
+    }
+}
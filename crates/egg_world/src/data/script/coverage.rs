@@ -0,0 +1,382 @@
+// Copyright (c) 2023 Adam Godwin <evilspamalt/at/gmail.com>
+//
+// This file is part of Egg Game - https://github.com/Madadog/Egg-Game/
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! How much of the base script a language overlay translates, and how much
+//! of that is out of date. [`check_overlay`](crate::data::validate::check_overlay)
+//! lints what an overlay *has* against the base; [`coverage`] is the
+//! translator's view — every label, list and dialogue key, marked translated,
+//! missing (falls back to the base), orphaned (the base dropped it), or stale.
+//!
+//! Staleness needs a memory of what was translated: an overlay stamps each key
+//! with the [`source_hash`] of the base text it worked from (`#source KEY
+//! HASH`, see [`ScriptFile::sources`]). When the base text changes, its hash
+//! does, and the stamp no longer matches. A translated key with no stamp is
+//! [`Status::Unstamped`] — it may be current, there's just no telling.
+//!
+//! A [`Coverage`] prints as a summary plus one line per key needing work, and
+//! [`Coverage::to_csv`] gives every key as a spreadsheet row for translators.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use super::{ContentDef, DialogueDef, Entry, ScriptFile, SegmentDef};
+
+/// Which section of the script a key lives in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    Label,
+    List,
+    Dialogue,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Kind::Label => "label",
+            Kind::List => "list",
+            Kind::Dialogue => "dialogue",
+        })
+    }
+}
+
+/// Where one key stands in a translation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Translated from the base text as it is now.
+    Translated,
+    /// Translated, but with no `#source` stamp to check it against.
+    Unstamped,
+    /// Translated from base text that has since changed.
+    Stale,
+    /// In the base, not the overlay: players see the base language.
+    Missing,
+    /// In the overlay, not the base: nothing can reach it.
+    Orphan,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Status::Translated => "translated",
+            Status::Unstamped => "unstamped",
+            Status::Stale => "stale",
+            Status::Missing => "missing",
+            Status::Orphan => "orphan",
+        })
+    }
+}
+
+/// One key's line in a [`Coverage`] report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub kind: Kind,
+    pub key: String,
+    pub status: Status,
+    /// The key's current [`source_hash`] — what a fresh translation stamps.
+    /// `None` for an [`Status::Orphan`].
+    pub hash: Option<String>,
+    /// The base language's text for the key, one line per text run (empty
+    /// for an orphan), so a translator has the English beside the status.
+    pub base_text: Vec<String>,
+}
+
+/// A per-language translation report; see the module doc.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    pub lang: String,
+    /// Every key in the base or the overlay, sorted by kind then key.
+    pub rows: Vec<Row>,
+}
+
+impl Coverage {
+    /// How many rows have `status`.
+    pub fn count(&self, status: Status) -> usize {
+        self.rows.iter().filter(|row| row.status == status).count()
+    }
+
+    /// Every row as CSV — `lang,kind,key,status,source_hash,base_text`, with a
+    /// header line — for a translator's spreadsheet. `base_text` joins the
+    /// English runs with newlines, quoted.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        self.write_csv_rows(&mut out);
+        out
+    }
+
+    /// [`to_csv`](Self::to_csv)'s rows without the header, for stacking
+    /// several languages into one sheet.
+    pub fn write_csv_rows(&self, out: &mut String) {
+        for row in &self.rows {
+            let fields = [
+                self.lang.clone(),
+                row.kind.to_string(),
+                row.key.clone(),
+                row.status.to_string(),
+                row.hash.clone().unwrap_or_default(),
+                row.base_text.join("\n"),
+            ];
+            let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+            out.push_str(&fields.join(","));
+            out.push('\n');
+        }
+    }
+}
+
+/// The header line of [`Coverage::to_csv`].
+pub const CSV_HEADER: &str = "lang,kind,key,status,source_hash,base_text";
+
+impl fmt::Display for Coverage {
+    /// A summary line, then each key that needs work as `status kind `key``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let in_base = self.rows.len() - self.count(Status::Orphan);
+        let translated = in_base - self.count(Status::Missing);
+        writeln!(
+            f,
+            "`{}`: {translated} of {in_base} key(s) translated ({} stale, {} unstamped), {} missing, {} orphan(s)",
+            self.lang,
+            self.count(Status::Stale),
+            self.count(Status::Unstamped),
+            self.count(Status::Missing),
+            self.count(Status::Orphan),
+        )?;
+        for row in self.rows.iter().filter(|row| row.status != Status::Translated) {
+            writeln!(f, "  {} {} `{}`", row.status, row.kind, row.key)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare `overlay` (language `lang`) against `base`, key by key, across
+/// labels, lists and dialogue.
+pub fn coverage(base: &ScriptFile, overlay: &ScriptFile, lang: &str) -> Coverage {
+    let mut rows = Vec::new();
+    for kind in [Kind::Label, Kind::List, Kind::Dialogue] {
+        let keys: BTreeSet<&String> = keys(base, kind).chain(keys(overlay, kind)).collect();
+        for key in keys {
+            let (in_base, in_overlay) = (has(base, kind, key), has(overlay, kind, key));
+            let hash = in_base.then(|| source_hash(base, key)).flatten();
+            let status = match (in_base, in_overlay) {
+                (true, false) => Status::Missing,
+                (false, _) => Status::Orphan,
+                (true, true) => match overlay.sources.get(key) {
+                    None => Status::Unstamped,
+                    Some(stamp) if Some(stamp) == hash.as_ref() => Status::Translated,
+                    Some(_) => Status::Stale,
+                },
+            };
+            let base_text = if in_base { text_of(base, kind, key) } else { Vec::new() };
+            rows.push(Row { kind, key: key.clone(), status, hash, base_text });
+        }
+    }
+    Coverage { lang: lang.to_string(), rows }
+}
+
+/// The stamp for `key`'s base text: a 64-bit FNV-1a over its label, list and
+/// dialogue text (whichever it has), as 16 hex digits. `None` if `base` has
+/// no such key. Only text counts — a moved `#pic` or `#delay` doesn't need
+/// retranslating (the skeleton lint catches structural drift instead).
+pub fn source_hash(base: &ScriptFile, key: &str) -> Option<String> {
+    let mut hash = FNV_OFFSET;
+    let mut found = false;
+    for kind in [Kind::Label, Kind::List, Kind::Dialogue] {
+        if !has(base, kind, key) {
+            continue;
+        }
+        found = true;
+        hash = fnv1a(hash, kind.to_string().as_bytes());
+        for text in text_of(base, kind, key) {
+            // A separator byte no text contains, so `ab`+`c` and `a`+`bc`
+            // hash apart.
+            hash = fnv1a(hash, &[0]);
+            hash = fnv1a(hash, text.as_bytes());
+        }
+    }
+    found.then(|| format!("{hash:016x}"))
+}
+
+/// Every translatable string of a dialogue entry, in authored order: its
+/// text runs and `#choice` options, down every `#if` branch (`then`, each
+/// `#elif`, `#else`).
+pub fn dialogue_text(def: &DialogueDef) -> Vec<&str> {
+    let mut out = Vec::new();
+    walk_dialogue(def, &mut out);
+    out
+}
+
+fn walk_dialogue<'a>(def: &'a DialogueDef, out: &mut Vec<&'a str>) {
+    match def {
+        DialogueDef::Plain(entry) => walk_entry(entry, out),
+        DialogueDef::Segments { segments } => {
+            for segment in segments {
+                match segment {
+                    SegmentDef::Plain(entry) => walk_entry(entry, out),
+                    SegmentDef::If { then, otherwise, elifs, .. } => {
+                        walk_dialogue(then, out);
+                        for elif in elifs {
+                            walk_dialogue(&elif.then, out);
+                        }
+                        if let Some(otherwise) = otherwise {
+                            walk_dialogue(otherwise, out);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn walk_entry<'a>(entry: &'a Entry, out: &mut Vec<&'a str>) {
+    match entry {
+        Entry::Line(text) => out.push(text),
+        Entry::Pages(pages) => out.extend(pages.iter().map(String::as_str)),
+        Entry::Conversation { messages } => {
+            for content in messages.iter().flat_map(|m| &m.content) {
+                match content {
+                    ContentDef::Text(text) | ContentDef::Auto(text) | ContentDef::Delayed(text, _) => out.push(text),
+                    ContentDef::Choice(options) => out.extend(options.iter().map(|o| o.text.as_str())),
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+fn keys(file: &ScriptFile, kind: Kind) -> Box<dyn Iterator<Item = &String> + '_> {
+    match kind {
+        Kind::Label => Box::new(file.labels.keys()),
+        Kind::List => Box::new(file.lists.keys()),
+        Kind::Dialogue => Box::new(file.dialogue.keys()),
+    }
+}
+
+fn has(file: &ScriptFile, kind: Kind, key: &str) -> bool {
+    match kind {
+        Kind::Label => file.labels.contains_key(key),
+        Kind::List => file.lists.contains_key(key),
+        Kind::Dialogue => file.dialogue.contains_key(key),
+    }
+}
+
+fn text_of(file: &ScriptFile, kind: Kind, key: &str) -> Vec<String> {
+    match kind {
+        Kind::Label => file.labels.get(key).cloned().into_iter().collect(),
+        Kind::List => file.lists.get(key).cloned().unwrap_or_default(),
+        Kind::Dialogue => file
+            .dialogue
+            .get(key)
+            .map(|def| dialogue_text(def).into_iter().map(str::to_string).collect())
+            .unwrap_or_default(),
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Fold `bytes` into an FNV-1a `hash`. Hand-rolled rather than std's
+/// `DefaultHasher`, whose output may change between Rust releases — a stamp
+/// written today has to match next year.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// One CSV field, quoted (with `"` doubled) when it holds a comma, quote or
+/// line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::script::eggtext;
+
+    const BASE: &str = "title = \"Egg Game\"\n\
+                        quit = \"Quit\"\n\
+                        #list days\n\
+                        \x20   Monday\n\
+                        #dialogue sink\n\
+                        \x20   Found something, down the drain.\n\
+                        #dialogue door\n\
+                        \x20   It's locked.";
+
+    fn status(report: &Coverage, kind: Kind, key: &str) -> Status {
+        report.rows.iter().find(|r| r.kind == kind && r.key == key).expect("row").status
+    }
+
+    /// Each way a key can stand: stamped and current, stamped against older
+    /// English, never stamped, absent, and overlay-only.
+    #[test]
+    fn every_status_is_reported() {
+        let base = eggtext::parse(BASE).unwrap();
+        let sink = source_hash(&base, "sink").unwrap();
+        let overlay = eggtext::parse(&format!(
+            "#source sink {sink}\n\
+             #source door 0123456789abcdef\n\
+             title = \"Eierspiel\"\n\
+             #dialogue sink\n\
+             \x20   Etwas im Abfluss.\n\
+             #dialogue door\n\
+             \x20   Abgeschlossen.\n\
+             #dialogue extra\n\
+             \x20   Nur hier."
+        ))
+        .unwrap();
+
+        let report = coverage(&base, &overlay, "de");
+        assert_eq!(status(&report, Kind::Dialogue, "sink"), Status::Translated);
+        assert_eq!(status(&report, Kind::Dialogue, "door"), Status::Stale);
+        assert_eq!(status(&report, Kind::Label, "title"), Status::Unstamped);
+        assert_eq!(status(&report, Kind::Label, "quit"), Status::Missing);
+        assert_eq!(status(&report, Kind::List, "days"), Status::Missing);
+        assert_eq!(status(&report, Kind::Dialogue, "extra"), Status::Orphan);
+        assert_eq!(
+            report.to_string().lines().next(),
+            Some("`de`: 3 of 5 key(s) translated (1 stale, 1 unstamped), 2 missing, 1 orphan(s)")
+        );
+    }
+
+    /// The stamp follows the text, not the staging: rewording a line changes
+    /// it, adding a `#pic` doesn't.
+    #[test]
+    fn source_hash_tracks_text_only() {
+        let hash = |src: &str| source_hash(&eggtext::parse(src).unwrap(), "door");
+        let before = hash("#dialogue door\n    It's locked.");
+        assert_eq!(before, hash("#dialogue door\n    #pic y_normal\n    It's locked."));
+        assert_ne!(before, hash("#dialogue door\n    It's open."));
+        assert_eq!(hash("#dialogue sink\n    Hm."), None);
+    }
+
+    /// CSV quotes the English where it needs to, one row per key.
+    #[test]
+    fn csv_quotes_base_text() {
+        let base = eggtext::parse(BASE).unwrap();
+        let csv = coverage(&base, &ScriptFile::default(), "fr").to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        let sink = source_hash(&base, "sink").unwrap();
+        assert!(csv.contains(&format!("fr,dialogue,sink,missing,{sink},\"Found something, down the drain.\"")));
+        assert_eq!(csv.lines().count(), 1 + 5);
+    }
+}
@@ -62,6 +62,9 @@
 //!   `#delay` at all) appends immediately. This now applies to a message's
 //!   very first text line too — a `#delay` there holds before the page's
 //!   first character appears, instead of being silently dropped.
+//!   Text (and `#choice` options) may carry `{egg_count}`, `{item:KEY}`,
+//!   `{flag:NAME}` and `{label:KEY}` placeholders, filled in when the page
+//!   opens (see [`crate::data::script::message::interpolate`]).
 //! * **Directives** — one or more `#word [arg]` on a line:
 //!   * `#pic NAME` sets the speaker portrait; a later `#pic` switches it
 //!     mid-message. `#pic none` explicitly clears it back to narration. A
@@ -108,6 +111,12 @@
 //! reserved for this — `#flag not` is a parse error — so `#if not NAME` can
 //! never be mistaken for testing a flag literally named `not`.
 //!
+//! A condition may instead compare a counter: `#if visits >= 3` (also `==`,
+//! `!=`, `<`, `<=`, `>`). Counters are declared like flags, `#var NAME` at
+//! the top (a name is a flag or a var, never both), and written by
+//! `#add NAME N` / `#setv NAME N` the moment playback passes them; an unset
+//! counter reads `0`.
+//!
 //! The whole chain resolves to a single carrier message: the branch is
 //! chosen live, at *playback* time, against the actual save, the moment the
 //! dialogue box reaches that point — not once, up front, when the
@@ -207,6 +216,23 @@
 //!
 //! Escapes understood in text and labels: `\n` `\t` `\r` `\\` `\"` `\#`.
 //!
+//! ## Translations
+//!
+//! A language overlay may stamp each key it translates with the base text it
+//! was translated from, so a later change to the English shows up as stale
+//! (see [`crate::data::script::coverage`]). The stamp is a top-level line,
+//! usually just above the entry it's for:
+//!
+//! ```text
+//! #source house_kitchen_sink 6c1f0a93e2d4b857
+//! #dialogue house_kitchen_sink
+//!     ...
+//! ```
+//!
+//! Translators needn't write `.eggtext` at all: [`crate::data::script::po`]
+//! round-trips the text through a gettext PO catalogue, and [`emit`] writes
+//! the overlay it rebuilds back out in this format, stamps included.
+//!
 //! # What belongs where
 //!
 //! `.eggtext` owns *presentation*, and the save flags that presentation
@@ -226,12 +252,11 @@
 //! of its own; if a cutscene needs to say something, that something belongs
 //! in a `#dialogue` block here, referenced by key.
 
-use std::collections::BTreeSet;
-
 use super::{
     ChoiceOptionDef, ContentDef, DialogueDef, ElifDef, Entry, MessageDef, PortraitChange,
     ScriptFile, SegmentDef,
 };
+use crate::data::save::{SaveTest, VarTest};
 
 /// A parse failure, carrying the 1-based source line it occurred on.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -281,25 +306,60 @@ pub fn parse(src: &str) -> Result<ScriptFile, ParseError> {
 
         if let Some(header) = logical.strip_prefix('#') {
             let (kind, name) = split_first_word(header);
-            // `#flag NAME` is a declaration, not a block: it takes no indented
-            // body, just registers the name.
-            if kind == "flag" {
+            // `#flag NAME` / `#var NAME` are declarations, not blocks: they
+            // take no indented body, just register the name.
+            if kind == "flag" || kind == "var" {
                 if name.is_empty() {
-                    return Err(ParseError::new(line_no, "`#flag` needs a name"));
+                    return Err(ParseError::new(line_no, format!("`#{kind}` needs a name")));
                 }
                 if name == "not" {
                     return Err(ParseError::new(
                         line_no,
-                        "`not` is reserved and can't be a flag name (needed for `#if not NAME`)",
+                        format!(
+                            "`not` is reserved and can't be a {kind} name \
+                             (needed for `#if not NAME`)"
+                        ),
+                    ));
+                }
+                if name.contains(['=', '!', '<', '>']) {
+                    return Err(ParseError::new(
+                        line_no,
+                        format!("`#{kind} {name}`: a name can't contain `=`, `!`, `<` or `>`"),
                     ));
                 }
                 if seen_entry {
                     return Err(ParseError::new(
                         line_no,
-                        format!("`#flag {name}` must be declared before the first entry"),
+                        format!("`#{kind} {name}` must be declared before the first entry"),
                     ));
                 }
-                file.flags.insert(name.to_string());
+                // One namespace for both, so a `#if NAME` can never mean
+                // either.
+                let (names, others, other) = if kind == "flag" {
+                    (&mut file.flags, &file.vars, "var")
+                } else {
+                    (&mut file.vars, &file.flags, "flag")
+                };
+                if others.contains(name) {
+                    return Err(ParseError::new(
+                        line_no,
+                        format!("`{name}` is already declared as a {other}"),
+                    ));
+                }
+                names.insert(name.to_string());
+                continue;
+            }
+            // `#source KEY HASH` stamps a translated key with the base text
+            // it was translated from (see `coverage`); like `#flag`, no body.
+            if kind == "source" {
+                let (key, hash) = split_first_word(name);
+                if key.is_empty() || hash.is_empty() {
+                    return Err(ParseError::new(line_no, "`#source` needs a key and a hash"));
+                }
+                if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(ParseError::new(line_no, format!("`#source {key}`: hash {hash:?} isn't hex")));
+                }
+                file.sources.insert(key.to_string(), hash.to_ascii_lowercase());
                 continue;
             }
             if name.is_empty() {
@@ -309,7 +369,7 @@ pub fn parse(src: &str) -> Result<ScriptFile, ParseError> {
             let body = collect_block(&mut lines);
             match kind {
                 "dialogue" => {
-                    let dialogue = parse_dialogue(&body, &file.flags)?;
+                    let dialogue = parse_dialogue(&body, &file)?;
                     file.dialogue.insert(name.to_string(), dialogue);
                 }
                 "list" => {
@@ -319,7 +379,7 @@ pub fn parse(src: &str) -> Result<ScriptFile, ParseError> {
                     return Err(ParseError::new(
                         line_no,
                         format!(
-                            "unknown block `#{other}` (expected `#dialogue`, `#list` or `#flag`)"
+                            "unknown block `#{other}` (expected `#dialogue`, `#list`, `#flag` or `#source`)"
                         ),
                     ));
                 }
@@ -391,8 +451,8 @@ fn parse_list(body: &[(usize, &str)]) -> Result<Vec<String>, ParseError> {
 /// `#else`/`#end` conditional delimiters, which split the body at message
 /// boundaries.
 enum Marker {
-    If { flag: String, negated: bool },
-    Elif { flag: String, negated: bool },
+    If { flag: SaveTest, negated: bool },
+    Elif { flag: SaveTest, negated: bool },
     Else,
     End,
 }
@@ -419,13 +479,14 @@ enum BodyItem<'a> {
 /// indentation rules that decide it). Each `#if` chain resolves (see
 /// [`SegmentDef::resolve`] in `crate::data::script`) to a single carrier
 /// message the dialogue box picks a branch from live, at *playback* time —
-/// not once, up front, at [`get_dialogue`]. `flags` is the declared
-/// vocabulary, against which every `#set`/`#if`/`#elif` name is checked.
+/// not once, up front, at [`get_dialogue`]. `declared` holds the `#flag` and
+/// `#var` vocabulary, against which every `#set`/`#add`/`#setv`/`#if`/`#elif`
+/// name is checked.
 ///
 /// [`get_dialogue`]: crate::data::script::Script::get_dialogue
 fn parse_dialogue(
     body: &[(usize, &str)],
-    flags: &BTreeSet<String>,
+    declared: &ScriptFile,
 ) -> Result<DialogueDef, ParseError> {
     // Scan the body into message groups and conditional markers. A blank line OR
     // a marker line ends the current message group.
@@ -473,11 +534,11 @@ fn parse_dialogue(
     for item in &items {
         match item {
             BodyItem::Group(depth, group) => {
-                let def = resolver.resolve(parse_message(group, flags)?);
-                builder.route(*depth, group[0].0, Item::Message(def), flags)?;
+                let def = resolver.resolve(parse_message(group, declared)?);
+                builder.route(*depth, group[0].0, Item::Message(def), declared)?;
             }
             BodyItem::Marker(line_no, depth, marker) => {
-                builder.route(*depth, *line_no, Item::Marker(marker), flags)?;
+                builder.route(*depth, *line_no, Item::Marker(marker), declared)?;
             }
         }
     }
@@ -508,8 +569,9 @@ fn parse_marker(logical: &str, line_no: usize) -> Result<Option<Marker>, ParseEr
     }))
 }
 
-/// Parse the `[not] NAME` argument shared by `#if`/`#elif`: a bare flag name,
-/// or `not` followed by one. `not` is only recognised as a whole word (via
+/// Parse the `[not] NAME` argument shared by `#if`/`#elif`: a bare flag name
+/// or a counter comparison (`visits >= 3`, a [`VarTest`]), parsed into a
+/// [`SaveTest`], optionally after `not`. `not` is only recognised as a whole word (via
 /// [`split_first_word`]), so a flag whose name merely *starts with* `not`
 /// (e.g. `nothing`) is never mistaken for negation — and `not` itself can
 /// never be a declared flag name (see the `#flag` check in [`parse`]), so
@@ -519,7 +581,7 @@ fn parse_condition(
     arg: &str,
     line_no: usize,
     keyword: &str,
-) -> Result<(String, bool), ParseError> {
+) -> Result<(SaveTest, bool), ParseError> {
     if arg.is_empty() {
         return Err(ParseError::new(line_no, format!("`#{keyword}` needs a flag name")));
     }
@@ -535,6 +597,9 @@ fn parse_condition(
     } else {
         (arg, false)
     };
+    if let Some(test) = VarTest::parse(name_part) {
+        return Ok((SaveTest::Var(test), negated));
+    }
     let (flag, extra) = split_first_word(name_part);
     if !extra.is_empty() {
         return Err(ParseError::new(
@@ -542,7 +607,7 @@ fn parse_condition(
             format!("`#{keyword}` has an unexpected extra word {extra:?}"),
         ));
     }
-    Ok((flag.to_string(), negated))
+    Ok((SaveTest::Flag(flag.to_string()), negated))
 }
 
 /// Tracks portrait-side state (`#autoflip` + explicit `#flip`) across a whole
@@ -671,7 +736,7 @@ impl ScopeBuilder {
 /// messages/segments gathered for it since (reduced to a [`DialogueDef`]
 /// only once the whole chain closes).
 struct OpenElif {
-    flag: String,
+    flag: SaveTest,
     negated: bool,
     then: ScopeBuilder,
 }
@@ -681,7 +746,7 @@ struct OpenElif {
 /// at `if_depth` or shallower that isn't itself a matching
 /// `#elif`/`#else`/`#end`; see [`SegmentBuilder::route`]).
 struct OpenIf {
-    flag: String,
+    flag: SaveTest,
     negated: bool,
     /// The `#if` line's own depth. `#elif`/`#else`/`#end` operate on this
     /// chain only when they land at exactly this depth, regardless of mode.
@@ -703,7 +768,7 @@ struct OpenIf {
 }
 
 impl OpenIf {
-    fn new(flag: String, negated: bool, if_depth: usize, open_line: usize) -> Self {
+    fn new(flag: SaveTest, negated: bool, if_depth: usize, open_line: usize) -> Self {
         Self {
             flag,
             negated,
@@ -774,13 +839,13 @@ impl SegmentBuilder {
         depth: usize,
         line_no: usize,
         item: Item,
-        flags: &BTreeSet<String>,
+        declared: &ScriptFile,
     ) -> Result<(), ParseError> {
         loop {
             // Peek the innermost open chain's depth/mode as plain values, so
             // nothing borrows `self.stack` across the `pop`/`push` calls below.
             let Some(top) = self.stack.last() else {
-                return self.route_top_level(depth, line_no, item, flags);
+                return self.route_top_level(depth, line_no, item, declared);
             };
             let if_depth = top.if_depth;
             let mode = top.mode;
@@ -791,7 +856,7 @@ impl SegmentBuilder {
                 && let Item::Marker(marker) = &item
                 && !matches!(marker, Marker::If { .. })
             {
-                return self.apply_marker_to_top(line_no, marker, flags);
+                return self.apply_marker_to_top(line_no, marker, declared);
             }
 
             if depth > if_depth {
@@ -810,7 +875,7 @@ impl SegmentBuilder {
                         Ok(())
                     }
                     Item::Marker(Marker::If { flag, negated }) => {
-                        check_flag(flag, line_no, flags)?;
+                        check_condition(flag, line_no, declared)?;
                         self.stack
                             .last_mut()
                             .unwrap()
@@ -877,7 +942,7 @@ impl SegmentBuilder {
         depth: usize,
         line_no: usize,
         item: Item,
-        flags: &BTreeSet<String>,
+        declared: &ScriptFile,
     ) -> Result<(), ParseError> {
         match item {
             Item::Message(def) => {
@@ -885,7 +950,7 @@ impl SegmentBuilder {
                 Ok(())
             }
             Item::Marker(Marker::If { flag, negated }) => {
-                check_flag(flag, line_no, flags)?;
+                check_condition(flag, line_no, declared)?;
                 self.top.flush_plain();
                 self.stack.push(OpenIf::new(flag.clone(), *negated, depth, line_no));
                 Ok(())
@@ -900,7 +965,7 @@ impl SegmentBuilder {
         &mut self,
         line_no: usize,
         marker: &Marker,
-        flags: &BTreeSet<String>,
+        declared: &ScriptFile,
     ) -> Result<(), ParseError> {
         let top = self.stack.last_mut().unwrap();
         match marker {
@@ -908,7 +973,7 @@ impl SegmentBuilder {
                 if top.in_else {
                     return Err(ParseError::new(line_no, "`#elif` can't follow `#else`"));
                 }
-                check_flag(flag, line_no, flags)?;
+                check_condition(flag, line_no, declared)?;
                 top.elifs.push(OpenElif {
                     flag: flag.clone(),
                     negated: *negated,
@@ -977,8 +1042,8 @@ fn unmatched_marker_error(marker: &Marker, line_no: usize) -> ParseError {
 
 /// A `#set`/`#if` may only name a declared `#flag`; otherwise it is a
 /// line-pointed parse error.
-fn check_flag(name: &str, line_no: usize, flags: &BTreeSet<String>) -> Result<(), ParseError> {
-    if flags.contains(name) {
+fn check_flag(name: &str, line_no: usize, declared: &ScriptFile) -> Result<(), ParseError> {
+    if declared.flags.contains(name) {
         Ok(())
     } else {
         Err(ParseError::new(
@@ -988,6 +1053,31 @@ fn check_flag(name: &str, line_no: usize, flags: &BTreeSet<String>) -> Result<()
     }
 }
 
+/// An `#add`/`#setv` (or a numeric `#if`) may only name a declared `#var`.
+fn check_var(name: &str, line_no: usize, declared: &ScriptFile) -> Result<(), ParseError> {
+    if declared.vars.contains(name) {
+        Ok(())
+    } else {
+        Err(ParseError::new(
+            line_no,
+            format!("undeclared var {name:?} (add `#var {name}` at the top)"),
+        ))
+    }
+}
+
+/// An `#if`/`#elif` condition: a [`VarTest`] checks its counter, a flag its
+/// flag.
+fn check_condition(
+    condition: &SaveTest,
+    line_no: usize,
+    declared: &ScriptFile,
+) -> Result<(), ParseError> {
+    match condition {
+        SaveTest::Var(test) => check_var(&test.name, line_no, declared),
+        SaveTest::Flag(flag) => check_flag(flag, line_no, declared),
+    }
+}
+
 /// One message mid-parse: its [`MessageDef`] plus directives whose effect needs
 /// resolving across the whole conversation (`#flip` side, `#autoflip` toggle).
 struct ParsedMessage {
@@ -1001,7 +1091,7 @@ struct ParsedMessage {
 
 fn parse_message(
     lines: &[(usize, &str)],
-    flags: &BTreeSet<String>,
+    declared: &ScriptFile,
 ) -> Result<ParsedMessage, ParseError> {
     let mut def = MessageDef {
         portrait: PortraitChange::Keep,
@@ -1025,7 +1115,7 @@ fn parse_message(
                     "`#choice` takes no arguments (put the prompt on the line above)",
                 ));
             }
-            let options = parse_choice(&lines[idx + 1..], line_no, flags)?;
+            let options = parse_choice(&lines[idx + 1..], line_no, declared)?;
             def.content.push(ContentDef::Choice(options));
             break;
         }
@@ -1040,7 +1130,7 @@ fn parse_message(
             if name.is_empty() {
                 return Err(ParseError::new(line_no, "`#set` needs `NAME BOOL`"));
             }
-            check_flag(name, line_no, flags)?;
+            check_flag(name, line_no, declared)?;
             let value = parse_bool(Some(bool_arg.trim()), line_no).map_err(|_| {
                 ParseError::new(line_no, "`#set` needs `NAME true` or `NAME false`")
             })?;
@@ -1048,6 +1138,27 @@ fn parse_message(
                 .push(ContentDef::SetFlag(name.to_string(), value));
             continue;
         }
+        // `#add NAME N` / `#setv NAME N` take two arguments too, parsed the
+        // same way as `#set`.
+        let var_directive = strip_directive(logical, "add")
+            .map(|rest| ("add", rest))
+            .or_else(|| strip_directive(logical, "setv").map(|rest| ("setv", rest)));
+        if let Some((directive, rest)) = var_directive {
+            let (name, value) = split_first_word(rest);
+            if name.is_empty() {
+                return Err(ParseError::new(line_no, format!("`#{directive}` needs `NAME N`")));
+            }
+            check_var(name, line_no, declared)?;
+            let value: i32 = value.trim().parse().map_err(|_| {
+                ParseError::new(line_no, format!("`#{directive}` needs `NAME N` (an integer)"))
+            })?;
+            def.content.push(if directive == "add" {
+                ContentDef::AddVar(name.to_string(), value)
+            } else {
+                ContentDef::SetVar(name.to_string(), value)
+            });
+            continue;
+        }
         // `#shake FRAMES [AMP]` can take two arguments, so like `#set` it is
         // parsed as a whole line rather than through `directive_segments`
         // (same reason: two arguments, one-arg-per-directive splitter).
@@ -1159,7 +1270,7 @@ fn parse_message(
 fn parse_choice(
     lines: &[(usize, &str)],
     header_line: usize,
-    flags: &BTreeSet<String>,
+    declared: &ScriptFile,
 ) -> Result<Vec<ChoiceOptionDef>, ParseError> {
     let mut options: Vec<ChoiceOptionDef> = Vec::new();
     for &(line_no, logical) in lines {
@@ -1180,7 +1291,7 @@ fn parse_choice(
             if name.is_empty() {
                 return Err(ParseError::new(line_no, "`#set` needs `NAME BOOL`"));
             }
-            check_flag(name, line_no, flags)?;
+            check_flag(name, line_no, declared)?;
             let value = parse_bool(Some(bool_arg.trim()), line_no).map_err(|_| {
                 ParseError::new(line_no, "`#set` needs `NAME true` or `NAME false`")
             })?;
@@ -1519,6 +1630,196 @@ pub(crate) fn unescape(s: &str) -> String {
     out
 }
 
+// --- emitting: the inverse of `parse` ---
+
+/// Emit a whole [`ScriptFile`] back to `.eggtext` that re-parses to the same
+/// file. Declarations come first (`#flag`, `#var`, then `#source` stamps),
+/// then labels, lists and dialogue, each name-sorted — so emitting is
+/// deterministic even though the file's maps aren't ordered. The inverse of
+/// [`parse`] for everything `parse` can produce; a JSON-only shape with no
+/// `.eggtext` spelling (an `auto` run, a bare `pause` item) is written as its
+/// nearest equivalent (plain text; dropped).
+pub fn emit(file: &ScriptFile) -> String {
+    let mut out = String::new();
+    for flag in &file.flags {
+        out.push_str(&format!("#flag {flag}\n"));
+    }
+    for var in &file.vars {
+        out.push_str(&format!("#var {var}\n"));
+    }
+    let mut sources: Vec<(&String, &String)> = file.sources.iter().collect();
+    sources.sort();
+    for (key, hash) in sources {
+        out.push_str(&format!("#source {key} {hash}\n"));
+    }
+
+    let mut labels: Vec<(&String, &String)> = file.labels.iter().collect();
+    labels.sort();
+    if !out.is_empty() && !labels.is_empty() {
+        out.push('\n');
+    }
+    for (key, value) in labels {
+        out.push_str(&format!("{key} = \"{}\"\n", escape(value)));
+    }
+
+    let mut lists: Vec<&String> = file.lists.keys().collect();
+    lists.sort();
+    for key in lists {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("#list {key}\n"));
+        for item in &file.lists[key] {
+            out.push_str(&format!("    {}\n", emit_value(item)));
+        }
+    }
+
+    let mut keys: Vec<&String> = file.dialogue.keys().collect();
+    keys.sort();
+    for key in keys {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("#dialogue {key}\n"));
+        let mut body = Vec::new();
+        emit_dialogue(&file.dialogue[key], 1, &mut body);
+        for line in body {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// One dialogue body as lines, indented `depth` levels (4 spaces each).
+/// Messages are separated by a blank line; an `#if` chain is written scoped
+/// — its branches one level deeper — and closed with an explicit `#end`, so
+/// whatever follows it can't be read as part of its last branch.
+fn emit_dialogue(def: &DialogueDef, depth: usize, out: &mut Vec<String>) {
+    match def {
+        DialogueDef::Plain(entry) => emit_entry(entry, depth, out),
+        DialogueDef::Segments { segments } => {
+            let ind = "    ".repeat(depth);
+            for segment in segments {
+                match segment {
+                    SegmentDef::Plain(entry) => emit_entry(entry, depth, out),
+                    SegmentDef::If { flag, negated, then, otherwise, elifs } => {
+                        let not = if *negated { "not " } else { "" };
+                        out.push(format!("{ind}#if {not}{flag}"));
+                        emit_dialogue(then, depth + 1, out);
+                        for elif in elifs {
+                            let not = if elif.negated { "not " } else { "" };
+                            out.push(format!("{ind}#elif {not}{}", elif.flag));
+                            emit_dialogue(&elif.then, depth + 1, out);
+                        }
+                        if let Some(otherwise) = otherwise {
+                            out.push(format!("{ind}#else"));
+                            emit_dialogue(otherwise, depth + 1, out);
+                        }
+                        out.push(format!("{ind}#end"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn emit_entry(entry: &Entry, depth: usize, out: &mut Vec<String>) {
+    let ind = "    ".repeat(depth);
+    let mut messages: Vec<Vec<String>> = Vec::new();
+    match entry {
+        Entry::Line(text) => messages.push(vec![format!("{ind}{}", emit_value(text))]),
+        Entry::Pages(pages) => {
+            messages.extend(pages.iter().map(|text| vec![format!("{ind}{}", emit_value(text))]));
+        }
+        Entry::Conversation { messages: defs } => {
+            messages.extend(defs.iter().map(|def| emit_message(def, &ind)));
+        }
+    }
+    for (i, lines) in messages.into_iter().enumerate() {
+        // A blank line splits messages — but a marker line already ends the
+        // one before it, so the first message of a run needs none.
+        if i > 0 {
+            out.push(String::new());
+        }
+        out.extend(lines);
+    }
+}
+
+/// One message's lines. Its own `#pic`/`#flip`/`#nopause` lead, before any
+/// text — which is what makes the parser read them as message-level rather
+/// than mid-message switches.
+fn emit_message(def: &MessageDef, ind: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    match &def.portrait {
+        PortraitChange::Keep => {}
+        PortraitChange::Clear => lines.push(format!("{ind}#pic none")),
+        PortraitChange::Set(name) => lines.push(format!("{ind}#pic {name}")),
+    }
+    if let Some(flip) = def.flip {
+        lines.push(format!("{ind}#flip {flip}"));
+    }
+    if !def.pause {
+        lines.push(format!("{ind}#nopause"));
+    }
+    for content in &def.content {
+        lines.push(match content {
+            ContentDef::Text(text) | ContentDef::Auto(text) => format!("{ind}{}", emit_value(text)),
+            ContentDef::Delayed(text, delay) => format!("{ind}{} #delay {delay}", emit_value(text)),
+            ContentDef::Delay(frames) => format!("{ind}#delay {frames}"),
+            ContentDef::Sound(name) => format!("{ind}#sound {name}"),
+            ContentDef::Portrait(Some(name)) => format!("{ind}#pic {name}"),
+            ContentDef::Portrait(None) => format!("{ind}#pic none"),
+            ContentDef::Pause => continue,
+            ContentDef::Flip(flip) => format!("{ind}#flip {flip}"),
+            ContentDef::SetFlag(name, value) => format!("{ind}#set {name} {value}"),
+            ContentDef::SetVar(name, value) => format!("{ind}#setv {name} {value}"),
+            ContentDef::AddVar(name, delta) => format!("{ind}#add {name} {delta}"),
+            ContentDef::Shake(frames, amplitude) => format!("{ind}#shake {frames} {amplitude}"),
+            ContentDef::Speed(chars, frames) => format!("{ind}#speed {chars}/{frames}"),
+            ContentDef::Cue(name) => format!("{ind}#cue {name}"),
+            ContentDef::Choice(options) => {
+                lines.push(format!("{ind}#choice"));
+                for option in options {
+                    lines.push(format!("{ind}    #option {}", emit_value(&option.text)));
+                    for (name, value) in &option.sets {
+                        lines.push(format!("{ind}        #set {name} {value}"));
+                    }
+                }
+                continue;
+            }
+        });
+    }
+    lines
+}
+
+/// A text line, `#option` text or list item: bare when [`parse_value`] /
+/// [`split_text`] would read it back unchanged, else quoted and escaped.
+fn emit_value(text: &str) -> String {
+    let bare = !text.is_empty()
+        && text.trim() == text
+        && !text.starts_with(['"', '#'])
+        && !is_comment(text)
+        && !text.contains(['\\', '#', '\n', '\t', '\r']);
+    if bare { text.to_string() } else { format!("\"{}\"", escape(text)) }
+}
+
+/// The inverse of [`unescape`], for text written between quotes.
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1850,6 +2151,54 @@ mod tests {
         assert_eq!(err.line, 3);
     }
 
+    /// `#var` declares a counter; `#add`/`#setv` write it and `#if` compares
+    /// it, the condition stored in its canonical `NAME OP N` spelling.
+    #[test]
+    fn vars_declare_write_and_compare() {
+        let def = dialogue_def(
+            "#var visits\n\
+             #dialogue d\n\
+             \x20   #add visits 1\n\
+             \x20   #setv visits -2\n\
+             \x20   Hi.\n\
+             \n\
+             \x20   #if not visits>=3\n\
+             \x20       Few.",
+        );
+        let DialogueDef::Segments { segments } = def else {
+            panic!("expected segments, got {def:?}");
+        };
+        let SegmentDef::Plain(Entry::Conversation { messages }) = &segments[0] else {
+            panic!("expected a plain conversation, got {:?}", segments[0]);
+        };
+        assert_eq!(
+            messages[0].content[..2],
+            [
+                ContentDef::AddVar("visits".into(), 1),
+                ContentDef::SetVar("visits".into(), -2),
+            ]
+        );
+        let SegmentDef::If { flag, negated, .. } = &segments[1] else {
+            panic!("expected an #if, got {:?}", segments[1]);
+        };
+        assert_eq!((flag.to_string().as_str(), *negated), ("visits >= 3", true));
+    }
+
+    /// Counters are declared like flags: an undeclared one, a non-integer
+    /// amount, or a name declared as both a flag and a var is a line error.
+    #[test]
+    fn var_errors_point_at_the_line() {
+        for (src, line) in [
+            ("#dialogue d\n    #add nope 1", 2),
+            ("#var n\n#dialogue d\n    #setv n lots", 3),
+            ("#dialogue d\n    #if nope > 1\n        Hi.", 2),
+            ("#flag n\n#var n", 2),
+            ("#var a<b", 1),
+        ] {
+            assert_eq!(parse(src).unwrap_err().line, line, "{src:?}");
+        }
+    }
+
     #[test]
     fn if_else_end_build_a_conditional_segment() {
         let def = dialogue_def(
@@ -1912,7 +2261,7 @@ mod tests {
         };
         assert_eq!(segments.len(), 3);
         assert!(matches!(&segments[0], SegmentDef::Plain(Entry::Line(s)) if s == "Intro."));
-        assert!(matches!(&segments[1], SegmentDef::If { flag, .. } if flag == "seen"));
+        assert!(matches!(&segments[1], SegmentDef::If { flag, .. } if flag.flag() == Some("seen")));
         assert!(matches!(&segments[2], SegmentDef::Plain(Entry::Line(s)) if s == "Outro."));
     }
 
@@ -2285,7 +2634,7 @@ mod tests {
         let SegmentDef::If { flag, then, otherwise, elifs, .. } = &segments[0] else {
             panic!("expected an If segment");
         };
-        assert_eq!(flag, "a");
+        assert_eq!(flag.to_string(), "a");
         assert!(elifs.is_empty());
         let DialogueDef::Segments { segments: then_segments } = then else {
             panic!("outer `then` should itself be segmented (plain run + nested if)");
@@ -2331,7 +2680,7 @@ mod tests {
             panic!("expected an If segment");
         };
         assert_eq!(elifs.len(), 1);
-        assert_eq!(elifs[0].flag, "b");
+        assert_eq!(elifs[0].flag.to_string(), "b");
         let DialogueDef::Segments { segments: elif_segments } = &elifs[0].then else {
             panic!("elif's `then` should itself be segmented (nested if)");
         };
@@ -2390,4 +2739,49 @@ mod tests {
         );
         assert_eq!(scoped[0].content, flat[0].content);
     }
+
+    /// `emit` is the inverse of `parse`: the shipped script survives the trip
+    /// unchanged.
+    #[test]
+    fn emit_round_trips_the_shipped_script() {
+        let file = parse(include_str!("../../../../../assets/script/en.eggtext")).expect("parse en");
+        let emitted = emit(&file);
+        assert_eq!(parse(&emitted).expect("re-parse"), file);
+    }
+
+    /// The shapes the shipped script might not exercise: a chain with
+    /// `#elif`/`#else` followed by more text, a counter condition, a choice,
+    /// text that has to be quoted, and `#source` stamps.
+    #[test]
+    fn emit_round_trips_branches_choices_and_quoting() {
+        let src = "#flag tea\n\
+                   #var visits\n\
+                   #source d 00ff00ff00ff00ff\n\
+                   title = \"  padded # \\\"quoted\\\"\"\n\
+                   #list l\n\
+                   \x20   \"// not a comment\"\n\
+                   #dialogue d\n\
+                   \x20   #pic y_normal\n\
+                   \x20   #nopause\n\
+                   \x20   Hi. #delay 4\n\
+                   \x20   \"line\\nbreak\"\n\
+                   \x20   #if not tea\n\
+                   \x20       #choice\n\
+                   \x20           #option Tea?\n\
+                   \x20               #set tea true\n\
+                   \x20           #option No\n\
+                   \x20   #elif visits >= 3\n\
+                   \x20       #add visits 1\n\
+                   \x20       Again.\n\
+                   \x20   #else\n\
+                   \x20       #shake 10 2\n\
+                   \x20       #speed 1/4\n\
+                   \x20       Bye.\n\
+                   \x20   #end\n\
+                   \x20   #pic none\n\
+                   \x20   #cue done\n\
+                   \x20   After.";
+        let file = parse(src).expect("parse");
+        assert_eq!(parse(&emit(&file)).expect("re-parse"), file);
+    }
 }
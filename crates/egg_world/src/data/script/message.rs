@@ -19,8 +19,13 @@
 //! script data — the registry built by [`crate::data::script`] stores dialogue
 //! as `Vec<Message>` — so it lives beside the script, not with the [`Dialogue`]
 //! box widget (in `ui::dialogue`) that plays it.
+//!
+//! Text may carry `{…}` [`Placeholder`]s — `{egg_count}`, `{item:KEY}`,
+//! `{flag:NAME}`, `{label:KEY}` — kept verbatim here and substituted by the
+//! box as it plays each line ([`interpolate`]), so the count or name shown is
+//! the one current when the line appears.
 
-use crate::data::{portraits::Portrait, sound::SfxData};
+use crate::data::{portraits::Portrait, save::SaveTest, sound::SfxData};
 
 #[derive(Debug, Clone)]
 pub enum TextContent {
@@ -51,6 +56,12 @@ pub enum TextContent {
     /// (it is a `is_skip` item, consumed in place), so the flag flips at the
     /// observable moment the dialogue plays past it. See [`crate::data::script::eggtext`].
     SetFlag(String, bool),
+    /// Set a named save counter (`#setv NAME N`) when playback reaches this
+    /// point. Fires like [`SetFlag`](Self::SetFlag).
+    SetVar(String, i32),
+    /// Add to a named save counter (`#add NAME N`) when playback reaches this
+    /// point. Fires like [`SetFlag`](Self::SetFlag).
+    AddVar(String, i32),
     /// Shake the screen for `frames` frames at up to ±`amplitude` px when
     /// playback reaches this point — the `#shake FRAMES [AMP]` directive.
     /// Fires like a [`Sound`](Self::Sound): the widget banks it as
@@ -77,7 +88,10 @@ pub enum TextContent {
     /// no `#else`. See [`crate::data::script::eggtext`] and
     /// `Dialogue::consume_text_content` (`egg_ui`) for the two ends of this.
     If {
-        flag: String,
+        /// A flag name, or a counter comparison (`visits >= 3`), parsed by the
+        /// loader and read through
+        /// [`SaveData::test`](crate::data::save::SaveData::test).
+        flag: SaveTest,
         then: Vec<Message>,
         otherwise: Vec<Message>,
     },
@@ -127,6 +141,8 @@ impl TextContent {
                 | Portrait(_)
                 | Flip(_)
                 | SetFlag(..)
+                | SetVar(..)
+                | AddVar(..)
                 | Shake { .. }
                 | If { .. }
                 | Clear
@@ -275,3 +291,100 @@ impl From<String> for Message {
         }
     }
 }
+
+/// A `{…}` placeholder in dialogue text, filled in by the `Dialogue` box at
+/// playback. Borrowed from the text it was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Placeholder<'a> {
+    /// `{egg_count}` — the save's egg count.
+    EggCount,
+    /// `{item:KEY}` — an inventory item's display name.
+    Item(&'a str),
+    /// `{flag:NAME}` — a save flag's value, `true` or `false`.
+    Flag(&'a str),
+    /// `{label:KEY}` — a UI label in the active language.
+    Label(&'a str),
+}
+
+impl<'a> Placeholder<'a> {
+    /// Parse the text between the braces. `None` for anything that isn't one
+    /// of the four forms (or names nothing), which then stays literal text.
+    pub fn parse(inner: &'a str) -> Option<Self> {
+        if inner == "egg_count" {
+            return Some(Self::EggCount);
+        }
+        let (kind, name) = inner.split_once(':')?;
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return None;
+        }
+        match kind {
+            "item" => Some(Self::Item(name)),
+            "flag" => Some(Self::Flag(name)),
+            "label" => Some(Self::Label(name)),
+            _ => None,
+        }
+    }
+}
+
+/// Every recognised [`Placeholder`] in `text`, in order, with its byte range
+/// (braces included). Unrecognised `{…}` runs are skipped.
+pub fn placeholders(text: &str) -> Vec<(std::ops::Range<usize>, Placeholder<'_>)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(open) = text[from..].find('{').map(|i| from + i) {
+        let Some(close) = text[open..].find('}').map(|i| open + i) else {
+            break;
+        };
+        match Placeholder::parse(&text[open + 1..close]) {
+            Some(placeholder) => {
+                found.push((open..close + 1, placeholder));
+                from = close + 1;
+            }
+            // A stray `{` before a real placeholder (`{{item:x}`) mustn't
+            // swallow it: resume just past the brace, not the close.
+            None => from = open + 1,
+        }
+    }
+    found
+}
+
+/// `text` with each recognised placeholder replaced by `resolve`'s value for
+/// it; everything else, unrecognised braces included, is kept as written.
+pub fn interpolate(text: &str, mut resolve: impl FnMut(Placeholder) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last = 0;
+    for (range, placeholder) in placeholders(text) {
+        out.push_str(&text[last..range.start]);
+        out.push_str(&resolve(placeholder));
+        last = range.end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The four forms are recognised and substituted in place; unknown kinds,
+    /// empty names, and unclosed or doubled braces are left as written.
+    #[test]
+    fn placeholders_parse_and_interpolate() {
+        let text = "{{item:chegg} x{egg_count} {flag:met} {label:ok} {hat} {item:} {oops";
+        let found: Vec<_> = placeholders(text).into_iter().map(|(_, p)| p).collect();
+        assert_eq!(
+            found,
+            [
+                Placeholder::Item("chegg"),
+                Placeholder::EggCount,
+                Placeholder::Flag("met"),
+                Placeholder::Label("ok"),
+            ]
+        );
+        let out = interpolate(text, |p| match p {
+            Placeholder::EggCount => "7".into(),
+            Placeholder::Item(k) | Placeholder::Flag(k) | Placeholder::Label(k) => k.to_uppercase(),
+        });
+        assert_eq!(out, "{CHEGG x7 MET OK {hat} {item:} {oops");
+    }
+}
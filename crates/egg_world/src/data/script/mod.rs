@@ -20,7 +20,7 @@
 //! into a [`ScriptFile`] and installs it into the [`Script`] registry it owns
 //! (via [`Script::set_base`] / [`Script::set_language`]); gameplay code
 //! reads it back through the shared context (`Ctx::label`,
-//! `Ctx::list`, `Ctx::get_dialogue`).
+//! `Ctx::list`) and the dialogue box (`Dialogue::open`).
 //!
 //! A *base* language is always kept as a fallback. A *language* can be swapped
 //! in at runtime; any key it doesn't define falls back to the base, so partial
@@ -36,9 +36,12 @@ use std::collections::{BTreeSet, HashMap};
 
 use serde::Deserialize;
 
+pub mod coverage;
 pub mod eggtext;
 pub mod message;
+pub mod po;
 
+use crate::data::save::SaveTest;
 use crate::data::portraits::Portraits;
 use crate::data::script::message::{ChoiceOption, Message, PortraitState, TextContent};
 use crate::data::sound;
@@ -64,6 +67,19 @@ pub struct ScriptFile {
     /// it via [`Script::flags`].
     #[serde(default)]
     pub flags: BTreeSet<String>,
+    /// The named story counters this script declares (`#var NAME`, or a
+    /// top-level `"vars": [...]` array in JSON) — the vocabulary
+    /// `#add`/`#setv` and a numeric `#if` may name. Re-exposed as
+    /// [`Script::vars`].
+    #[serde(default)]
+    pub vars: BTreeSet<String>,
+    /// In a translation: the [`coverage::source_hash`] of the base text each
+    /// key was translated from (`#source KEY HASH`, or a top-level
+    /// `"sources": {"key": "hash"}` object in JSON) — how
+    /// [`coverage::coverage`] tells a translation the English has since moved
+    /// on from. Empty in the base script.
+    #[serde(default)]
+    pub sources: HashMap<String, String>,
 }
 
 /// A dialogue map value: either a plain conversation (an [`Entry`]) or, for
@@ -117,7 +133,7 @@ pub enum SegmentDef {
     Plain(Entry),
     If {
         #[serde(rename = "if")]
-        flag: String,
+        flag: SaveTest,
         #[serde(default)]
         negated: bool,
         then: DialogueDef,
@@ -135,7 +151,7 @@ pub enum SegmentDef {
 /// "flag": "name", "negated": bool, "then": <entry-or-segments> }`.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ElifDef {
-    pub flag: String,
+    pub flag: SaveTest,
     #[serde(default)]
     pub negated: bool,
     pub then: DialogueDef,
@@ -166,18 +182,14 @@ pub enum Entry {
 /// defaults to), `"clear"`, or `{"set": "y_oof"}`.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[derive(Default)]
 pub enum PortraitChange {
+    #[default]
     Keep,
     Clear,
     Set(String),
 }
 
-impl Default for PortraitChange {
-    fn default() -> Self {
-        Self::Keep
-    }
-}
-
 impl PortraitChange {
     /// Resolve the authored name against `portraits`. An unresolvable name (a
     /// `#pic` referencing a portrait that doesn't exist) warns and resolves to
@@ -243,6 +255,12 @@ pub enum ContentDef {
     /// Set (or clear) a named save flag when playback reaches this point — the
     /// `#set NAME BOOL` directive. JSON: `{"set_flag": ["name", true]}`.
     SetFlag(String, bool),
+    /// Set a named story counter — the `#setv NAME N` directive. JSON:
+    /// `{"set_var": ["name", 3]}`.
+    SetVar(String, i32),
+    /// Add to a named story counter — the `#add NAME N` directive. JSON:
+    /// `{"add_var": ["name", 1]}`.
+    AddVar(String, i32),
     /// Shake the screen for N frames at ±AMP px — the `#shake FRAMES [AMP]`
     /// directive (the parser fills the default amplitude when omitted). JSON:
     /// `{"shake": [30, 2]}`.
@@ -299,6 +317,8 @@ impl ContentDef {
             ContentDef::Pause => TextContent::Pause,
             ContentDef::Flip(b) => TextContent::Flip(b),
             ContentDef::SetFlag(name, value) => TextContent::SetFlag(name, value),
+            ContentDef::SetVar(name, value) => TextContent::SetVar(name, value),
+            ContentDef::AddVar(name, delta) => TextContent::AddVar(name, delta),
             ContentDef::Shake(frames, amplitude) => TextContent::Shake { frames, amplitude },
             ContentDef::Choice(options) => TextContent::Choice(
                 options
@@ -387,7 +407,7 @@ impl SegmentDef {
 /// negated condition (`#if not`/`#elif not`) is just a matter of swapping
 /// which resolved branch plays which role — `TextContent::If` itself never
 /// needs to represent `not`.
-fn if_carrier(flag: String, negated: bool, then: Vec<Message>, otherwise: Vec<Message>) -> TextContent {
+fn if_carrier(flag: SaveTest, negated: bool, then: Vec<Message>, otherwise: Vec<Message>) -> TextContent {
     if negated {
         TextContent::If {
             flag,
@@ -452,6 +472,8 @@ struct Language {
     /// The flag vocabulary this language declared. Merged base+active is what
     /// [`Script::flags`] reports.
     flags: BTreeSet<String>,
+    /// The counter vocabulary, merged the same way for [`Script::vars`].
+    vars: BTreeSet<String>,
 }
 
 impl Language {
@@ -471,6 +493,7 @@ impl Language {
             entries,
             raw_dialogue,
             flags: file.flags,
+            vars: file.vars,
         }
     }
 }
@@ -542,6 +565,12 @@ impl Script {
             .unwrap_or_default()
     }
 
+    /// An inventory item's display name — element 0 of its `item_<key>` list,
+    /// or empty if undefined.
+    pub fn item_name(&self, key: &str) -> String {
+        self.list_get(&format!("item_{key}"), 0).unwrap_or_default()
+    }
+
     /// One entry of an ordered string list, or `None` if the key or index is
     /// undefined. Cheaper than [`Script::list`] when only one entry is wanted.
     pub fn list_get(&self, key: &str, index: usize) -> Option<String> {
@@ -598,6 +627,11 @@ impl Script {
             .collect()
     }
 
+    /// The merged counter vocabulary, like [`flags`](Self::flags) for `#var`.
+    pub fn vars(&self) -> BTreeSet<String> {
+        self.base.vars.iter().chain(self.active.vars.iter()).cloned().collect()
+    }
+
     /// A list entry's messages. Used by [`list`](Self::list)/
     /// [`list_get`](Self::list_get) to read them back as plain text; lists are
     /// authored unconditionally, so there's no `#if` carrier to worry about.
@@ -656,7 +690,7 @@ mod tests {
         assert_eq!(convo.len(), 1, "the whole #if resolves to one carrier message");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "seen");
+                assert_eq!(flag.to_string(), "seen");
                 assert_eq!(plain(then), "After.");
                 assert_eq!(plain(otherwise), "Before.");
             }
@@ -683,7 +717,7 @@ mod tests {
         let convo = script.get_dialogue("d");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, IS_NIGHT_FLAG);
+                assert_eq!(flag.to_string(), IS_NIGHT_FLAG);
                 assert_eq!(plain(then), "Good evening.");
                 assert_eq!(plain(otherwise), "Good morning.");
             }
@@ -708,7 +742,7 @@ mod tests {
         let convo = script.get_dialogue("d");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "seen");
+                assert_eq!(flag.to_string(), "seen");
                 assert_eq!(plain(then), "After.", "then fires when the flag IS true, i.e. `not seen` is false");
                 assert_eq!(
                     plain(otherwise),
@@ -739,12 +773,12 @@ mod tests {
         assert_eq!(convo.len(), 1, "the whole chain resolves to one carrier message");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "a");
+                assert_eq!(flag.to_string(), "a");
                 assert_eq!(plain(then), "A branch.");
                 assert_eq!(otherwise.len(), 1, "the #elif nests inside the #if's otherwise branch");
                 match &otherwise[0].content[..] {
                     [TextContent::If { flag, then, otherwise }] => {
-                        assert_eq!(flag, "b");
+                        assert_eq!(flag.to_string(), "b");
                         assert_eq!(plain(then), "B branch.");
                         assert_eq!(plain(otherwise), "Else branch.");
                     }
@@ -808,7 +842,7 @@ mod tests {
         let convo = script.get_dialogue("d");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "seen");
+                assert_eq!(flag.to_string(), "seen");
                 assert_eq!(plain(then), "After.");
                 assert_eq!(plain(otherwise), "Before.");
             }
@@ -832,7 +866,7 @@ mod tests {
         assert_eq!(convo[0].to_plain_string(), "Intro.");
         match &convo[1].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "seen");
+                assert_eq!(flag.to_string(), "seen");
                 assert_eq!(plain(then), "Extra.");
                 assert!(
                     otherwise.is_empty(),
@@ -948,7 +982,7 @@ mod tests {
         let convo = script.get_dialogue("react");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "chose_tea", "names the same flag the choice sets");
+                assert_eq!(flag.to_string(), "chose_tea", "names the same flag the choice sets");
                 assert_eq!(plain(then), "Enjoy your tea.");
                 assert_eq!(plain(otherwise), "Coffee it is.");
             }
@@ -1012,12 +1046,12 @@ mod tests {
         assert_eq!(convo.len(), 1, "the whole nested chain is one top-level carrier");
         match &convo[0].content[..] {
             [TextContent::If { flag, then, otherwise }] => {
-                assert_eq!(flag, "outer");
+                assert_eq!(flag.to_string(), "outer");
                 assert_eq!(plain(otherwise), "Shallow.");
                 assert_eq!(then.len(), 1, "outer's `then` holds exactly the inner carrier");
                 match &then[0].content[..] {
                     [TextContent::If { flag, then, otherwise }] => {
-                        assert_eq!(flag, "inner");
+                        assert_eq!(flag.to_string(), "inner");
                         // `#if not inner`: negation swaps which resolved
                         // branch plays which role.
                         assert_eq!(plain(then), "Deep else.");
@@ -0,0 +1,581 @@
+// Copyright (c) 2023 Adam Godwin <evilspamalt/at/gmail.com>
+//
+// This file is part of Egg Game - https://github.com/Madadog/Egg-Game/
+//
+// This program is free software: you can redistribute it and/or modify it under
+// the terms of the GNU General Public License as published by the Free Software
+// Foundation, either version 3 of the License, or (at your option) any later
+// version.
+//
+// This program is distributed in the hope that it will be useful, but WITHOUT
+// ANY WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS
+// FOR A PARTICULAR PURPOSE. See the GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License along with
+// this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! gettext PO catalogues, for translators who'd rather use Poedit or Weblate
+//! than learn `.eggtext`. [`export`] writes one entry per translatable string
+//! of the base script — a label, a list item, a dialogue text run or `#choice`
+//! option — and [`import`] reads a translated catalogue back into a language
+//! overlay, ready for [`eggtext::emit`](super::eggtext::emit).
+//!
+//! Only text crosses over. Every entry's `msgctxt` names where its string
+//! sits in the base script:
+//!
+//! ```text
+//! label game_title
+//! list menu_debug_controls item 2
+//! dialogue house_kitchen_sink message 1 text 0
+//! dialogue dog_greeting message 3 option 1 [if not fed_dog > else]
+//! ```
+//!
+//! A dialogue's messages are counted in document order down every `#if`
+//! branch, so each context is unique within its key; the trailing
+//! `[…]` is the branch path, there for the translator's benefit. Directives
+//! — `#pic`, `#set`, `#delay` and the rest — never appear in a `msgid`: the
+//! overlay [`import`] builds is the base's own skeleton with the translated
+//! text swapped in, so it keeps the structure
+//! [`check_overlay`](crate::data::validate::check_overlay) asks for by
+//! construction.
+//!
+//! `#, fuzzy` carries [`coverage`](super::coverage)'s staleness both ways: a
+//! prefilled translation whose key isn't stamped with the current
+//! [`source_hash`] exports fuzzy, and only a key whose every string comes back
+//! translated, unfuzzy, against the current English is stamped on import.
+
+use std::collections::HashMap;
+
+use super::coverage::source_hash;
+use super::eggtext::{ParseError, escape, unescape};
+use super::message::placeholders;
+use super::{ContentDef, DialogueDef, Entry, ScriptFile, SegmentDef};
+
+/// Write `base`'s strings as a PO catalogue for language `lang`. With an
+/// `overlay` — the language's current translation — each `msgstr` is
+/// prefilled from the same spot in it, and marked fuzzy where the overlay's
+/// `#source` stamp for that key isn't the current [`source_hash`] — except
+/// that a string still in the base's words, in a key not stamped current, is
+/// taken as untranslated. Empty strings are left out: there's nothing to
+/// translate.
+pub fn export(base: &ScriptFile, overlay: Option<&ScriptFile>, lang: &str) -> String {
+    let translated: HashMap<String, String> = overlay
+        .map(|overlay| strings(overlay).into_iter().map(|s| (s.context, s.text)).collect())
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    out.push_str(&format!("# Egg Game script text, translated into `{lang}`.\n"));
+    out.push_str("msgid \"\"\nmsgstr \"\"\n");
+    for field in [
+        format!("Language: {lang}"),
+        "MIME-Version: 1.0".to_string(),
+        "Content-Type: text/plain; charset=UTF-8".to_string(),
+        "Content-Transfer-Encoding: 8bit".to_string(),
+    ] {
+        out.push_str(&format!("\"{}\\n\"\n", escape(&field)));
+    }
+
+    for string in strings(base) {
+        if string.text.is_empty() {
+            continue;
+        }
+        out.push('\n');
+        let kept: Vec<&str> = placeholders(&string.text)
+            .into_iter()
+            .map(|(range, _)| &string.text[range])
+            .collect();
+        if !kept.is_empty() {
+            out.push_str(&format!("#. keep as written: {}\n", kept.join(" ")));
+        }
+        let stamped = overlay.and_then(|overlay| overlay.sources.get(&string.key));
+        let current = stamped.is_some() && stamped == source_hash(base, &string.key).as_ref();
+        // An overlay key carries its untranslated strings in the base language
+        // (see `import`); unless the key is vouched for, that's no translation.
+        let msgstr = match translated.get(&string.context) {
+            Some(text) if current || *text != string.text => text.as_str(),
+            _ => "",
+        };
+        if !msgstr.is_empty() && !current {
+            out.push_str("#, fuzzy\n");
+        }
+        push_field(&mut out, "msgctxt", &string.context);
+        push_field(&mut out, "msgid", &string.text);
+        push_field(&mut out, "msgstr", msgstr);
+    }
+    out
+}
+
+/// A translated catalogue read back by [`import`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Imported {
+    /// The language overlay: every key with at least one translated string,
+    /// its untranslated strings left in the base language.
+    pub overlay: ScriptFile,
+    /// Contexts of translated entries that name no string in the base — the
+    /// base has moved on since the catalogue was exported. Dropped.
+    pub unmatched: Vec<String>,
+}
+
+/// Rebuild a language overlay from `po`, a catalogue [`export`]ed from
+/// `base`. Each key the catalogue translates any of is copied from `base`
+/// with its translated strings swapped in; an empty `msgstr` keeps the base
+/// text. A fuzzy entry is still used — it's the best text there is — but its
+/// key isn't stamped, so [`coverage`](super::coverage::coverage) keeps
+/// flagging it for review. The overlay declares `base`'s flags and vars, as
+/// its `#set`s and `#if`s need them to parse.
+pub fn import(base: &ScriptFile, po: &str) -> Result<Imported, ParseError> {
+    let mut entries: HashMap<String, PoEntry> = HashMap::new();
+    let mut unmatched = Vec::new();
+    for entry in parse(po)? {
+        match &entry.context {
+            // The header.
+            None if entry.id.is_empty() => {}
+            None => {
+                if !entry.text.is_empty() {
+                    unmatched.push(format!("(no msgctxt) {:?}", entry.id));
+                }
+            }
+            Some(context) => {
+                if entries.contains_key(context) {
+                    return Err(ParseError::new(entry.line, format!("duplicate msgctxt {context:?}")));
+                }
+                entries.insert(context.clone(), entry);
+            }
+        }
+    }
+
+    // Per key: whether any string was translated, and whether all were,
+    // cleanly, against the current English.
+    let mut keys: HashMap<String, (bool, bool)> = HashMap::new();
+    let mut overlay = base.clone();
+    overlay.sources.clear();
+    visit(&mut overlay, &mut |key, context, text| {
+        let (any, all) = keys.entry(key.to_string()).or_insert((false, true));
+        if text.is_empty() {
+            return;
+        }
+        match entries.remove(&context) {
+            Some(entry) if !entry.text.is_empty() => {
+                *any = true;
+                *all &= !entry.fuzzy && entry.id == *text;
+                *text = entry.text;
+            }
+            _ => *all = false,
+        }
+    });
+
+    let translated = |key: &String| keys.get(key).is_some_and(|(any, _)| *any);
+    overlay.labels.retain(|key, _| translated(key));
+    overlay.lists.retain(|key, _| translated(key));
+    overlay.dialogue.retain(|key, _| translated(key));
+    for (key, (any, all)) in &keys {
+        if *any
+            && *all
+            && let Some(hash) = source_hash(base, key)
+        {
+            overlay.sources.insert(key.clone(), hash);
+        }
+    }
+
+    unmatched.extend(entries.into_iter().filter(|(_, e)| !e.text.is_empty()).map(|(context, _)| context));
+    unmatched.sort();
+    Ok(Imported { overlay, unmatched })
+}
+
+/// One translatable string of a script, and where it sits.
+struct Located {
+    key: String,
+    context: String,
+    text: String,
+}
+
+/// Every string of `file`, in catalogue order.
+fn strings(file: &ScriptFile) -> Vec<Located> {
+    let mut out = Vec::new();
+    visit(&mut file.clone(), &mut |key, context, text| {
+        out.push(Located { key: key.to_string(), context, text: text.clone() });
+    });
+    out
+}
+
+/// Hand `visit` each translatable string of `file` with its key and
+/// context: labels, then lists, then dialogue, each key-sorted. The one walk
+/// behind both [`export`] and [`import`], so the contexts one writes are the
+/// ones the other looks up.
+fn visit(file: &mut ScriptFile, visit: &mut dyn FnMut(&str, String, &mut String)) {
+    let mut labels: Vec<(&String, &mut String)> = file.labels.iter_mut().collect();
+    labels.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, text) in labels {
+        visit(key, format!("label {key}"), text);
+    }
+
+    let mut lists: Vec<(&String, &mut Vec<String>)> = file.lists.iter_mut().collect();
+    lists.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, items) in lists {
+        for (i, text) in items.iter_mut().enumerate() {
+            visit(key, format!("list {key} item {i}"), text);
+        }
+    }
+
+    let mut dialogue: Vec<(&String, &mut DialogueDef)> = file.dialogue.iter_mut().collect();
+    dialogue.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, def) in dialogue {
+        let mut walk = Walk { key, message: 0, branch: Vec::new(), visit };
+        walk.def(def);
+    }
+}
+
+/// A dialogue key's walk: the next message's index and the `#if` path down
+/// to it.
+struct Walk<'a, 'v> {
+    key: &'a str,
+    message: usize,
+    branch: Vec<String>,
+    visit: &'v mut dyn FnMut(&str, String, &mut String),
+}
+
+impl Walk<'_, '_> {
+    fn def(&mut self, def: &mut DialogueDef) {
+        match def {
+            DialogueDef::Plain(entry) => self.entry(entry),
+            DialogueDef::Segments { segments } => {
+                for segment in segments {
+                    match segment {
+                        SegmentDef::Plain(entry) => self.entry(entry),
+                        SegmentDef::If { flag, negated, then, otherwise, elifs } => {
+                            let not = if *negated { "not " } else { "" };
+                            self.branch(format!("if {not}{flag}"), then);
+                            for elif in elifs {
+                                let not = if elif.negated { "not " } else { "" };
+                                self.branch(format!("elif {not}{}", elif.flag), &mut elif.then);
+                            }
+                            if let Some(otherwise) = otherwise {
+                                self.branch("else".to_string(), otherwise);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn branch(&mut self, step: String, def: &mut DialogueDef) {
+        self.branch.push(step);
+        self.def(def);
+        self.branch.pop();
+    }
+
+    fn entry(&mut self, entry: &mut Entry) {
+        match entry {
+            Entry::Line(text) => self.message([text]),
+            Entry::Pages(pages) => {
+                for text in pages {
+                    self.message([text]);
+                }
+            }
+            Entry::Conversation { messages } => {
+                for message in messages {
+                    let mut runs = Vec::new();
+                    let mut options = Vec::new();
+                    for content in &mut message.content {
+                        match content {
+                            ContentDef::Text(text) | ContentDef::Auto(text) | ContentDef::Delayed(text, _) => {
+                                runs.push(text)
+                            }
+                            ContentDef::Choice(choice) => options.extend(choice.iter_mut().map(|o| &mut o.text)),
+                            _ => {}
+                        }
+                    }
+                    let (message, branch) = (self.message, self.at_branch());
+                    for (i, text) in runs.into_iter().enumerate() {
+                        let context = format!("dialogue {} message {message} text {i}{branch}", self.key);
+                        (self.visit)(self.key, context, text);
+                    }
+                    for (i, text) in options.into_iter().enumerate() {
+                        let context = format!("dialogue {} message {message} option {i}{branch}", self.key);
+                        (self.visit)(self.key, context, text);
+                    }
+                    self.message += 1;
+                }
+            }
+        }
+    }
+
+    /// A one-run message: a bare line or a page.
+    fn message<'t>(&mut self, runs: impl IntoIterator<Item = &'t mut String>) {
+        let branch = self.at_branch();
+        for (i, text) in runs.into_iter().enumerate() {
+            let context = format!("dialogue {} message {} text {i}{branch}", self.key, self.message);
+            (self.visit)(self.key, context, text);
+        }
+        self.message += 1;
+    }
+
+    fn at_branch(&self) -> String {
+        if self.branch.is_empty() { String::new() } else { format!(" [{}]", self.branch.join(" > ")) }
+    }
+}
+
+/// One `keyword "string"` field, split after each `\n` onto continuation
+/// lines when the string has several — how gettext tools lay them out.
+fn push_field(out: &mut String, keyword: &str, text: &str) {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    if lines.len() > 1 {
+        out.push_str(&format!("{keyword} \"\"\n"));
+        for line in lines {
+            out.push_str(&format!("\"{}\"\n", escape(line)));
+        }
+    } else {
+        out.push_str(&format!("{keyword} \"{}\"\n", escape(text)));
+    }
+}
+
+/// One parsed catalogue entry.
+#[derive(Debug, Default)]
+struct PoEntry {
+    /// The 1-based line its `msgid` is on.
+    line: usize,
+    context: Option<String>,
+    id: String,
+    text: String,
+    fuzzy: bool,
+}
+
+/// Which field a continuation string appends to.
+#[derive(Clone, Copy)]
+enum Field {
+    Context,
+    Id,
+    Text,
+}
+
+/// Read the entries of a PO catalogue. Comments are skipped — but for the
+/// `fuzzy` flag — as are obsolete (`#~`) entries. Plural forms are an error:
+/// the script has none, so a catalogue using them wasn't exported from it.
+fn parse(po: &str) -> Result<Vec<PoEntry>, ParseError> {
+    let mut entries = Vec::new();
+    let mut entry = PoEntry::default();
+    let mut field: Option<Field> = None;
+    let mut has_id = false;
+    let mut finish = |entry: &mut PoEntry, has_id: &mut bool, field: &mut Option<Field>| {
+        if std::mem::take(has_id) {
+            entries.push(std::mem::take(entry));
+        }
+        *field = None;
+    };
+
+    for (i, raw) in po.lines().enumerate() {
+        let number = i + 1;
+        let line = raw.trim();
+        if line.is_empty() {
+            finish(&mut entry, &mut has_id, &mut field);
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            if has_id {
+                finish(&mut entry, &mut has_id, &mut field);
+            }
+            if let Some(flags) = comment.strip_prefix(',') {
+                entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            }
+            continue;
+        }
+        if line.starts_with('"') {
+            let Some(field) = field else {
+                return Err(ParseError::new(number, "a string with no `msgctxt`/`msgid`/`msgstr` before it"));
+            };
+            let text = po_string(line, number)?;
+            match field {
+                Field::Context => entry.context.get_or_insert_default().push_str(&text),
+                Field::Id => entry.id.push_str(&text),
+                Field::Text => entry.text.push_str(&text),
+            }
+            continue;
+        }
+        let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let text = po_string(rest.trim(), number)?;
+        match keyword {
+            "msgctxt" => {
+                finish(&mut entry, &mut has_id, &mut field);
+                entry.context = Some(text);
+                field = Some(Field::Context);
+            }
+            "msgid" => {
+                if has_id {
+                    finish(&mut entry, &mut has_id, &mut field);
+                }
+                entry.line = number;
+                entry.id = text;
+                has_id = true;
+                field = Some(Field::Id);
+            }
+            "msgstr" if has_id => {
+                entry.text = text;
+                field = Some(Field::Text);
+            }
+            "msgstr" => return Err(ParseError::new(number, "`msgstr` with no `msgid` before it")),
+            "msgid_plural" => return Err(ParseError::new(number, "plural forms aren't used by the script")),
+            other if other.starts_with("msgstr[") => {
+                return Err(ParseError::new(number, "plural forms aren't used by the script"));
+            }
+            other => return Err(ParseError::new(number, format!("unknown PO keyword `{other}`"))),
+        }
+    }
+    finish(&mut entry, &mut has_id, &mut field);
+    Ok(entries)
+}
+
+/// The contents of one `"…"` PO string, escapes resolved.
+fn po_string(quoted: &str, line: usize) -> Result<String, ParseError> {
+    let inner = quoted
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .filter(|inner| !inner.ends_with('\\') || inner.ends_with("\\\\"))
+        .ok_or_else(|| ParseError::new(line, format!("expected a quoted string, found `{quoted}`")))?;
+    Ok(unescape(inner))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::script::eggtext;
+    use crate::data::validate::check_overlay;
+
+    const BASE: &str = "#flag tea\n\
+                        title = \"Egg Game\"\n\
+                        #list days\n\
+                        \x20   Monday\n\
+                        \x20   Tuesday\n\
+                        #dialogue kettle\n\
+                        \x20   #pic y_normal\n\
+                        \x20   You have {egg_count} eggs.\n\
+                        \x20   \"Line one\\nline two\" #delay 5\n\
+                        \n\
+                        \x20   #if not tea\n\
+                        \x20       Tea?\n\
+                        \x20       #choice\n\
+                        \x20           #option Yes\n\
+                        \x20               #set tea true\n\
+                        \x20           #option No\n\
+                        \x20   #else\n\
+                        \x20       All done.\n\
+                        \x20   #end";
+
+    /// Fill every `msgstr` of an exported catalogue with its `msgid` behind a
+    /// `FR ` prefix — a stand-in translator that leaves placeholders alone.
+    fn translate(po: &str) -> String {
+        let mut out = String::new();
+        let mut id = String::new();
+        for entry in parse(po).unwrap() {
+            if let Some(context) = &entry.context {
+                id = format!("FR {}", entry.id);
+                out.push_str(&format!("msgctxt \"{}\"\n", escape(context)));
+                out.push_str(&format!("msgid \"{}\"\n", escape(&entry.id)));
+                out.push_str(&format!("msgstr \"{}\"\n\n", escape(&id)));
+            }
+        }
+        assert!(!id.is_empty());
+        out
+    }
+
+    #[test]
+    fn export_names_each_string_by_key_message_and_branch() {
+        let base = eggtext::parse(BASE).unwrap();
+        let po = export(&base, None, "fr");
+        let contexts: Vec<String> = parse(&po).unwrap().into_iter().filter_map(|e| e.context).collect();
+        assert_eq!(
+            contexts,
+            [
+                "label title",
+                "list days item 0",
+                "list days item 1",
+                "dialogue kettle message 0 text 0",
+                "dialogue kettle message 0 text 1",
+                "dialogue kettle message 1 text 0 [if not tea]",
+                "dialogue kettle message 1 option 0 [if not tea]",
+                "dialogue kettle message 1 option 1 [if not tea]",
+                "dialogue kettle message 2 text 0 [else]",
+            ]
+        );
+        assert!(po.contains("#. keep as written: {egg_count}\n"));
+        assert!(po.contains("msgid \"\"\n\"Line one\\n\"\n\"line two\"\n"));
+        assert!(!po.contains("#pic") && !po.contains("#set"));
+    }
+
+    /// Export, translate, import, emit, re-parse: the overlay keeps the
+    /// base's skeleton, passes the overlay lint clean, and is stamped as
+    /// current.
+    #[test]
+    fn translated_catalogue_imports_to_a_clean_overlay() {
+        let base = eggtext::parse(BASE).unwrap();
+        let imported = import(&base, &translate(&export(&base, None, "fr"))).unwrap();
+        assert!(imported.unmatched.is_empty());
+
+        let overlay = eggtext::parse(&eggtext::emit(&imported.overlay)).unwrap();
+        assert_eq!(overlay, imported.overlay);
+        let report = check_overlay(&base, &overlay, "fr");
+        assert!(report.errors.is_empty() && report.warnings.is_empty(), "{report:?}");
+        assert_eq!(overlay.labels["title"], "FR Egg Game");
+        assert_eq!(overlay.lists["days"], ["FR Monday", "FR Tuesday"]);
+        assert_eq!(
+            super::super::coverage::dialogue_text(&overlay.dialogue["kettle"]),
+            [
+                "FR You have {egg_count} eggs.",
+                "FR Line one\nline two",
+                "FR Tea?",
+                "FR Yes",
+                "FR No",
+                "FR All done.",
+            ]
+        );
+        for key in ["title", "days", "kettle"] {
+            assert_eq!(overlay.sources.get(key), source_hash(&base, key).as_ref(), "{key}");
+        }
+
+        // Re-exporting against the overlay prefills every string, none fuzzy.
+        let again = export(&base, Some(&overlay), "fr");
+        assert!(again.contains("msgstr \"FR All done.\""));
+        assert!(!again.contains("fuzzy"));
+    }
+
+    /// Untranslated keys are left out; a partly translated or fuzzy key is
+    /// kept but not stamped; a context the base doesn't have is reported.
+    #[test]
+    fn partial_fuzzy_and_unknown_entries() {
+        let base = eggtext::parse(BASE).unwrap();
+        let po = "msgctxt \"list days item 1\"\n\
+                  msgid \"Tuesday\"\n\
+                  msgstr \"Mardi\"\n\
+                  \n\
+                  #, fuzzy\n\
+                  msgctxt \"label title\"\n\
+                  msgid \"Egg Game\"\n\
+                  msgstr \"Jeu d'\"\n\
+                  \"oeuf\"\n\
+                  \n\
+                  msgctxt \"label gone\"\n\
+                  msgid \"Gone\"\n\
+                  msgstr \"Parti\"\n";
+        let imported = import(&base, po).unwrap();
+        let overlay = &imported.overlay;
+        assert_eq!(overlay.lists["days"], ["Monday", "Mardi"]);
+        assert_eq!(overlay.labels["title"], "Jeu d'oeuf");
+        assert!(overlay.dialogue.is_empty());
+        assert!(overlay.sources.is_empty());
+        assert_eq!(imported.unmatched, ["label gone"]);
+    }
+
+    #[test]
+    fn malformed_catalogues_error_at_the_line() {
+        for (po, line) in [
+            ("msgid \"a\"\nmsgid_plural \"as\"", 2),
+            ("msgid \"a\"\nmsgstr[0] \"b\"", 2),
+            ("msgstr \"b\"", 1),
+            ("msgid a", 1),
+            ("msgctxt \"x\"\nmsgid \"a\"\nmsgstr \"b\"\n\nmsgctxt \"x\"\nmsgid \"a\"\nmsgstr \"c\"", 6),
+        ] {
+            let base = ScriptFile::default();
+            assert_eq!(import(&base, po).unwrap_err().line, line, "{po:?}");
+        }
+    }
+}
@@ -12,9 +12,13 @@
 use crate::world::animation::AnimFrame;
 use crate::data::sound::{self, SfxData};
 use crate::draw_state::BgColour;
-use crate::world::interact::{InteractFn, Interaction};
+use crate::data::eggdata::PresetMove;
+use crate::data::save::SaveTest;
+use crate::world::interact::{InteractFn, Interaction, Talk};
+use crate::world::player::PresetId;
+use crate::world::transition::Transition;
 use crate::world::map::{
-    Axis, Gate, LayerInfo, MapObject, ObjectEffect, Plane, Trigger, Warp, WarpMode,
+    Axis, Gate, LayerInfo, MapObject, ObjectEffect, PlacedCreature, Plane, Trigger, Warp, WarpMode,
 };
 use egg_render::geometry::{Hitbox, Vec2};
 use egg_render::SpriteOptions;
@@ -397,7 +401,8 @@ pub struct TiledObject {
     pub y: i32,
     pub width: usize,
     pub height: usize,
-    /// Tiled object "Type"/class. Used to mark warps (`type == "warp"`).
+    /// Tiled object "Type"/class. Used to mark warps (`type == "warp"`) and
+    /// placed creatures (`type == "creature"`).
     #[serde(rename = "type", default)]
     pub class: String,
     #[serde(default)]
@@ -424,6 +429,9 @@ impl TiledObject {
     /// Resolve this object into a runtime [`MapObject`] by a fixed property
     /// precedence, documented so the inverse [`object_to_tmj`] mirrors it:
     ///
+    /// 0. **creature** — `type == "creature"` ([`to_creature`](Self::to_creature));
+    ///    its `description` / `cutscene` are the creature's talk, so it's
+    ///    resolved before either is read as an interaction;
     /// 1. **warp** — `type == "warp"` or any warp property ([`to_warp`](Self::to_warp));
     /// 2. **func** — a `func` property names an [`InteractFn`](crate::world::interact::InteractFn)
     ///    ([`to_func`](Self::to_func));
@@ -436,6 +444,9 @@ impl TiledObject {
     /// 6. otherwise `None` (also for degenerate zero-size objects, via
     ///    [`hitbox`](Self::hitbox)) — the object is skipped.
     fn to_object(&self) -> Option<MapObject> {
+        if self.class.eq_ignore_ascii_case("creature") {
+            return self.to_creature();
+        }
         let hitbox = self.hitbox()?;
         let object = if let Some(warp) = self.to_warp() {
             MapObject::warp(hitbox, warp)
@@ -459,17 +470,54 @@ impl TiledObject {
             .with_gate(self.gate());
         Some(object)
     }
+    /// Build a placed-creature object (`type == "creature"`): a `preset`
+    /// (required — without one the object is skipped), and the optional
+    /// overrides `id` (the creature's cutscene/schedule name), `move_mode`
+    /// (`wander`/`amble`/`flee`/`approach`, or `patrol` with a `path`),
+    /// `facing` (`down`/`left`/`up`/`right`) and its talk — `cutscene`, else
+    /// `description`, as an interaction object spells them. Only the object's
+    /// top-left matters (the creature's size is its preset's), so a Tiled
+    /// point object works too: a zero-size one gets an 8×8 box to grab in the
+    /// editor. Unrecognised values are ignored, leaving the preset's own. Its
+    /// flag [`gate`](Self::gate) is read as any object's. Inverse of
+    /// [`creature_to_object`].
+    fn to_creature(&self) -> Option<MapObject> {
+        let preset = self.prop("preset").filter(|s| !s.is_empty())?;
+        let text = |name| self.prop(name).filter(|s| !s.is_empty());
+        let talk = match (text("cutscene"), text("description")) {
+            (Some(name), _) => Some(Talk::Cutscene(name.to_string())),
+            (None, Some(key)) => Some(Talk::Dialogue(key.to_string())),
+            (None, None) => None,
+        };
+        let creature = PlacedCreature {
+            preset: PresetId::new(preset),
+            id: text("id").map(str::to_string),
+            move_mode: text("move_mode").and_then(|m| PresetMove::from_name(m, self.prop("path"))),
+            facing: text("facing").and_then(|f| {
+                PlacedCreature::FACINGS.iter().find(|(name, _)| *name == f).map(|(_, dir)| *dir)
+            }),
+            talk,
+        };
+        let hitbox = self
+            .hitbox()
+            .unwrap_or(Hitbox::new(self.x as i16, self.y as i16, 8, 8));
+        let object = MapObject::creature(hitbox, creature)
+            .with_id((self.id != 0).then_some(self.id))
+            .with_gate(self.gate());
+        Some(object)
+    }
     /// Read this object's flag [`Gate`] from its `if` / `unless` / `sets`
-    /// properties (each naming a story flag; empty/absent ⇒ that condition is
+    /// properties (`if`/`unless` a flag or counter comparison, parsed here into
+    /// a [`SaveTest`]; `sets` a flag; empty/absent ⇒ that condition is
     /// unset). Parsed for any object kind, so a warp, dialogue, cutscene or func
     /// can all be flag-gated. Inverse of the gate emission in [`object_to_tmj`]
     /// (which uses [`Gate::properties`]).
     fn gate(&self) -> Gate {
-        let flag = |name| self.prop(name).filter(|s| !s.is_empty()).map(str::to_string);
+        let flag = |name| self.prop(name).filter(|s| !s.is_empty());
         Gate {
-            if_flag: flag("if"),
-            unless_flag: flag("unless"),
-            sets: flag("sets"),
+            if_flag: flag("if").map(SaveTest::parse),
+            unless_flag: flag("unless").map(SaveTest::parse),
+            sets: flag("sets").map(str::to_string),
         }
     }
     /// Whether a `removable` property marks this object as a consume-on-interact
@@ -551,8 +599,10 @@ impl TiledObject {
     /// warp properties): `to_map` (a map name, taken verbatim and resolved
     /// against the map store when the warp fires; absent = same map),
     /// `to_x`/`to_y` (destination pixels, default = the object's own
-    /// position), `flip`, `mode` (`auto`/`interact`), `sound`, and `narration`
-    /// (a pre-warp dialogue key; absent/empty = none). The trigger hitbox lives
+    /// position), `flip`, `mode` (`auto`/`interact`), `sound`, `narration`
+    /// (a pre-warp dialogue key; absent/empty = none), and `transition` (a
+    /// [`Transition`] name; absent/unknown = cut, an unknown one listed by
+    /// [`TiledMap::unknown_transitions`]). The trigger hitbox lives
     /// on the owning [`MapObject`], and the `trigger` axis is applied there too
     /// (see [`apply_trigger`](Self::apply_trigger)), so neither is built here.
     fn to_warp(&self) -> Option<Warp> {
@@ -588,6 +638,9 @@ impl TiledObject {
         if let Some(key) = self.prop("narration").filter(|s| !s.is_empty()) {
             warp = warp.with_narration(key);
         }
+        if let Some(transition) = self.prop("transition").and_then(Transition::parse) {
+            warp = warp.with_transition(transition);
+        }
         Some(warp)
     }
     /// Build a cutscene interaction object if this object carries a non-empty
@@ -683,7 +736,8 @@ fn sound_name(sfx: &SfxData) -> Option<&'static str> {
 
 /// Serialise one [`MapObject`] to a Tiled object by its effect, reusing the
 /// owning object's `hitbox` for the placed rectangle. The inverse of the
-/// parse-precedence in [`TiledObject::to_object`]: a warp serialises with
+/// parse-precedence in [`TiledObject::to_object`]: a placed creature
+/// serialises with `type: "creature"` ([`creature_to_object`]); a warp with
 /// `type: "warp"` + warp properties; a named `func` interaction with `func` +
 /// its scalar props; a dialogue interaction with `description`; a bare
 /// [`Interaction::None`] that carries a sprite as a `sprite`-only object — all
@@ -696,6 +750,7 @@ fn sound_name(sfx: &SfxData) -> Option<&'static str> {
 fn object_to_tmj(object: &MapObject, id: usize) -> Option<Value> {
     let mut value = match &object.effect {
         ObjectEffect::Warp(warp) => warp_to_object(object.hitbox, warp, id),
+        ObjectEffect::Creature(creature) => creature_to_object(object.hitbox, creature, id),
         ObjectEffect::Interact(interaction) => {
             interaction_to_object(object.hitbox, interaction, object.sprite.as_deref(), id)?
         }
@@ -720,7 +775,7 @@ fn object_to_tmj(object: &MapObject, id: usize) -> Option<Value> {
     // an ungated object emits nothing and its file stays byte-stable.
     if let Some(properties) = value.get_mut("properties").and_then(Value::as_array_mut) {
         for (name, flag) in object.gate.properties() {
-            properties.push(prop_str(name, flag));
+            properties.push(prop_str(name, &flag));
         }
     }
     Some(value)
@@ -747,6 +802,9 @@ fn warp_to_object(hitbox: Hitbox, warp: &Warp, id: usize) -> Value {
     if let Some(key) = &warp.narration {
         properties.push(prop_str("narration", key));
     }
+    if warp.transition != Transition::Cut {
+        properties.push(prop_str("transition", warp.transition.name()));
+    }
     json!({
         "id": id, "name": "", "type": "warp", "rotation": 0, "visible": true,
         "x": hitbox.x, "y": hitbox.y,
@@ -755,6 +813,35 @@ fn warp_to_object(hitbox: Hitbox, warp: &Warp, id: usize) -> Value {
     })
 }
 
+/// Serialise a placed creature as a `type: "creature"` Tiled object at
+/// `hitbox`: its `preset`, then only the overrides it carries. Inverse of
+/// [`TiledObject::to_creature`].
+fn creature_to_object(hitbox: Hitbox, creature: &PlacedCreature, id: usize) -> Value {
+    let mut properties = vec![prop_str("preset", creature.preset.as_str())];
+    if let Some(name) = &creature.id {
+        properties.push(prop_str("id", name));
+    }
+    if let Some(mode) = &creature.move_mode {
+        properties.push(prop_str("move_mode", mode.name()));
+        if let PresetMove::Patrol(path) = mode {
+            properties.push(prop_str("path", path));
+        }
+    }
+    if let Some(facing) = creature.facing_name() {
+        properties.push(prop_str("facing", facing));
+    }
+    match &creature.talk {
+        Some(Talk::Dialogue(key)) => properties.push(prop_str("description", key)),
+        Some(Talk::Cutscene(name)) => properties.push(prop_str("cutscene", name)),
+        None => {}
+    }
+    json!({
+        "id": id, "name": "", "type": "creature", "rotation": 0, "visible": true,
+        "x": hitbox.x, "y": hitbox.y, "width": hitbox.w, "height": hitbox.h,
+        "properties": properties,
+    })
+}
+
 /// Serialise an [`Interaction`] as a (non-warp) Tiled object placed at `hitbox`,
 /// carrying its optional sprite. Dialogue → `description`; a named `func` →
 /// `func` + its scalar props (`pitch`/`count`; piano/none need none); a cutscene
@@ -1278,8 +1365,9 @@ impl TiledMap {
 
     /// Parse this map's object layers into one ordered list of runtime
     /// [`MapObject`]s, in file order. Warps are objects with `type == "warp"` or
-    /// warp properties; interactions are objects carrying a `description`
-    /// (dialogue key). See [`TiledObject::to_object`].
+    /// warp properties; placed creatures have `type == "creature"`;
+    /// interactions are objects carrying a `description` (dialogue key). See
+    /// [`TiledObject::to_object`].
     pub fn parse_objects(&self) -> Vec<MapObject> {
         let mut objects = Vec::new();
         for layer in &self.layers {
@@ -1293,6 +1381,32 @@ impl TiledMap {
         }
         objects
     }
+    /// Every warp whose `transition` property names no [`Transition`], with
+    /// the value. The warp still parses — as a cut — so this is how the
+    /// validator finds the typo (see
+    /// [`check_transitions`](crate::data::validate::check_transitions)).
+    pub fn unknown_transitions(&self) -> Vec<(MapObject, String)> {
+        let mut found = Vec::new();
+        for layer in &self.layers {
+            let TiledMapLayer::ObjectLayer(group) = layer else {
+                continue;
+            };
+            for object in &group.objects {
+                let Some(name) = object.prop("transition") else {
+                    continue;
+                };
+                if Transition::parse(name).is_some() {
+                    continue;
+                }
+                if let Some(parsed) = object.to_object()
+                    && matches!(parsed.effect, ObjectEffect::Warp(_))
+                {
+                    found.push((parsed, name.to_string()));
+                }
+            }
+        }
+        found
+    }
     /// Re-serialise this map to Tiled JSON: `self` is both the structural
     /// template (dimensions, layer names, tilesets) and the live tile data
     /// (its tile layers hold flattened/sheet-local ids, which are re-gid'd on
@@ -1489,8 +1603,12 @@ fn is_scalar(v: &Value) -> bool {
 mod tests {
     use super::{TiledMap, TiledMapLayer, from_json};
     use crate::draw_state::BgColour;
-    use crate::world::interact::{InteractFn, Interaction};
-    use crate::world::map::{Gate, MapObject, ObjectEffect, Trigger, WarpMode};
+    use crate::data::eggdata::PresetMove;
+    use crate::data::save::SaveTest;
+    use crate::world::interact::{InteractFn, Interaction, Talk};
+    use crate::world::player::PresetId;
+    use crate::world::transition::Transition;
+    use crate::world::map::{Gate, MapObject, ObjectEffect, PlacedCreature, Trigger, WarpMode};
     use egg_render::image::RgbaImage;
 
     /// The single image layer of a parsed map (panics if it has none) — the
@@ -2138,6 +2256,58 @@ mod tests {
         assert_eq!((objects2[0].hitbox.x, objects2[0].hitbox.y), (8, 16));
     }
 
+    /// A `creature` object parses its preset and overrides — the talk from
+    /// the same `description` an interaction uses, not as a dialogue object —
+    /// and round-trips with its gate; a point object gets an 8×8 box, and one
+    /// without a preset is skipped.
+    #[test]
+    fn tmj_round_trips_creature_object() {
+        let map = one_object_map(
+            "creature",
+            r#"{"name":"preset","type":"string","value":"critter"},
+               {"name":"id","type":"string","value":"pip"},
+               {"name":"move_mode","type":"string","value":"patrol"},
+               {"name":"path","type":"string","value":"beat"},
+               {"name":"facing","type":"string","value":"up"},
+               {"name":"description","type":"string","value":"pip_hello"},
+               {"name":"if","type":"string","value":"met_pip"}"#,
+        );
+        let expected = PlacedCreature {
+            preset: PresetId::critter(),
+            id: Some("pip".into()),
+            move_mode: Some(PresetMove::Patrol("beat".into())),
+            facing: Some((0, -1)),
+            talk: Some(Talk::Dialogue("pip_hello".into())),
+        };
+        let objects = map.parse_objects();
+        assert!(matches!(&objects[0].effect, ObjectEffect::Creature(c) if *c == expected));
+
+        let reloaded = from_json(map.to_tmj(&objects).as_bytes()).unwrap();
+        let objects2 = reloaded.parse_objects();
+        assert!(matches!(&objects2[0].effect, ObjectEffect::Creature(c) if *c == expected));
+        assert_eq!((objects2[0].hitbox.x, objects2[0].hitbox.y), (8, 8));
+        assert_eq!(objects[0].gate.if_flag.as_ref().and_then(SaveTest::flag), Some("met_pip"));
+        assert_eq!(objects2[0].gate, objects[0].gate, "the gate round-trips");
+
+        let point = r#"{
+            "width": 2, "height": 2,
+            "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": [
+                {"x": 4, "y": 12, "width": 0, "height": 0, "type": "creature",
+                 "properties": [{"name": "preset", "type": "string", "value": "dog"}]},
+                {"x": 0, "y": 0, "width": 8, "height": 8, "type": "creature",
+                 "properties": [{"name": "description", "type": "string", "value": "k"}]}
+            ]}]
+        }"#;
+        let objects = from_json(point.as_bytes()).unwrap().parse_objects();
+        assert_eq!(objects.len(), 1, "a creature without a preset is skipped");
+        assert_eq!(objects[0].hitbox, egg_render::geometry::Hitbox::new(4, 12, 8, 8));
+        assert!(matches!(
+            &objects[0].effect,
+            ObjectEffect::Creature(c) if *c == PlacedCreature::new(PresetId::dog())
+        ));
+    }
+
     /// The pre-warp narration key of an object's warp effect, if it has one.
     fn warp_narration(object: &MapObject) -> Option<&str> {
         match &object.effect {
@@ -2269,6 +2439,34 @@ mod tests {
         assert_eq!(warp_narration(&empty.parse_objects()[0]), None);
     }
 
+    /// A warp `transition` round-trips by name; absent (or unknown) reads as
+    /// a cut, which serialises as nothing.
+    #[test]
+    fn tmj_round_trips_warp_transition() {
+        let transition = |object: &MapObject| match &object.effect {
+            ObjectEffect::Warp(w) => w.transition,
+            _ => panic!("not a warp"),
+        };
+        let map = one_object_map(
+            "warp",
+            r#"{"name":"to_map","type":"string","value":"a"},
+               {"name":"transition","type":"string","value":"iris"}"#,
+        );
+        let objects = map.parse_objects();
+        assert_eq!(transition(&objects[0]), Transition::Iris);
+        let reloaded = from_json(map.to_tmj(&objects).as_bytes()).unwrap();
+        assert_eq!(transition(&reloaded.parse_objects()[0]), Transition::Iris);
+
+        let odd = one_object_map(
+            "warp",
+            r#"{"name":"to_map","type":"string","value":"a"},
+               {"name":"transition","type":"string","value":"swirl"}"#,
+        );
+        let odd_objects = odd.parse_objects();
+        assert_eq!(transition(&odd_objects[0]), Transition::Cut);
+        assert!(!odd.to_tmj(&odd_objects).contains("transition"));
+    }
+
     /// The flag gate (`if` / `unless` / `sets`) parses on any object kind and
     /// round-trips all three flag names; an ungated object carries the default
     /// gate and emits none of the properties (byte-stable, like a default trigger).
@@ -2282,15 +2480,15 @@ mod tests {
                {"name":"sets","type":"string","value":"door_open"}"#,
         );
         let objects = map.parse_objects();
-        assert_eq!(objects[0].gate.if_flag.as_deref(), Some("has_key"));
-        assert_eq!(objects[0].gate.unless_flag.as_deref(), Some("door_open"));
+        assert_eq!(objects[0].gate.if_flag.as_ref().and_then(SaveTest::flag), Some("has_key"));
+        assert_eq!(objects[0].gate.unless_flag.as_ref().and_then(SaveTest::flag), Some("door_open"));
         assert_eq!(objects[0].gate.sets.as_deref(), Some("door_open"));
 
         let out = map.to_tmj(&objects);
         let reloaded = from_json(out.as_bytes()).unwrap();
         let gate = reloaded.parse_objects()[0].gate.clone();
-        assert_eq!(gate.if_flag.as_deref(), Some("has_key"));
-        assert_eq!(gate.unless_flag.as_deref(), Some("door_open"));
+        assert_eq!(gate.if_flag.as_ref().and_then(SaveTest::flag), Some("has_key"));
+        assert_eq!(gate.unless_flag.as_ref().and_then(SaveTest::flag), Some("door_open"));
         assert_eq!(gate.sets.as_deref(), Some("door_open"));
 
         // An ungated object: default gate, and none of the gate properties are
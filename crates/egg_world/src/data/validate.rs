@@ -14,35 +14,50 @@
 //! map's parsed objects, and the registries names resolve against
 //! ([`Portraits`], [`Presets`], and [`sound::by_name`]) — no I/O, no asset
 //! path knowledge; the caller (a test, or a CLI harness) owns loading.
+//!
+//! [`check_connectivity`] asks the physical version of the same question —
+//! not "does this warp name a map" but "can the player walk to it" — over the
+//! built maps' collision. [`check_transitions`] reads the raw map objects
+//! for a warp `transition` the parse quietly played as a cut. Dialogue
+//! layout (does each page fit the box?) needs the dialogue widget's wrap, so
+//! that pass lives beside it in `egg_ui::fit` and reports through the same
+//! [`Finding`]s.
 
 use std::collections::BTreeSet;
 use std::collections::BTreeMap;
 use std::fmt;
 
-use crate::data::eggdata::Presets;
+use crate::data::eggdata::{GameItems, PresetMove, Presets};
 use crate::data::portraits::Portraits;
-use crate::data::save::IS_NIGHT_FLAG;
-use crate::data::scene::{CutsceneContent, GetEntity, Motion, SceneFile};
+use crate::data::clock::HOUR_VAR;
+use crate::data::save::{IS_NIGHT_FLAG, SaveTest};
+use crate::data::scene::{Condition, CutsceneContent, GetEntity, Motion, SceneFile};
+use crate::data::schedule::Schedules;
 use crate::data::script::{
     ChoiceOptionDef, ContentDef, DialogueDef, ElifDef, Entry, MessageDef, PortraitChange, ScriptFile,
     SegmentDef,
 };
+use crate::data::script::message::placeholders;
 use crate::data::sound;
-use crate::world::interact::Interaction;
-use crate::world::map::{MapObject, ObjectEffect};
+use crate::world::interact::{Interaction, Talk};
+use crate::world::map::{MapInfo, MapObject, MapStore, ObjectEffect, Trigger, map_by_name};
+use crate::world::pathfind::{Reach, walk_flood};
+use crate::world::player::Shell;
+use egg_render::geometry::{Hitbox, Vec2};
+use egg_render::image::IndexedImage;
 
 /// Dialogue keys the engine reaches by a hardcoded Rust string literal rather
 /// than through script/scene/map content, so [`check`]'s dead-dialogue sweep
 /// doesn't flag them despite nothing in the data web naming them. Found by
-/// grepping the workspace for every `get_dialogue`/`Ctx::get_dialogue` call
+/// grepping the workspace for every `get_dialogue`/`Dialogue::open` call
 /// site with a literal key (2026-07-18):
 /// - `"default"` — [`Script::get_dialogue`](crate::data::script::Script::get_dialogue)'s
 ///   own fallback for an unresolvable key.
 /// - `"dog_obtained"` / `"dog_relinquished"` — returned as `Option<&'static
 ///   str>` by `WalkaroundState::execute_interact_fn`'s `ToggleDog` arm
 ///   (`egg_core/src/gamestate/walkaround/mod.rs`) and passed to
-///   `ctx.get_dialogue` one call site away, so they don't show up in a
-///   single-hop grep for `get_dialogue("...")`.
+///   `Dialogue::open` one call site away, so they don't show up in a
+///   single-hop grep for `open(..., "...")`.
 ///
 /// A future literal call site should extend this list rather than get
 /// silently reported as dead weight.
@@ -63,6 +78,13 @@ pub enum Finding {
     DanglingCutscene { map: String, object: ObjectLabel, name: String },
     /// A warp's `to_map` names no loaded map.
     DanglingWarpMap { map: String, object: ObjectLabel, name: String },
+    /// A warp's `transition` names no transition kind — it plays as a cut.
+    UnknownTransition { map: String, object: ObjectLabel, name: String },
+    /// A placed creature's `preset` names no preset — it would never spawn.
+    DanglingPreset { map: String, object: ObjectLabel, name: String },
+    /// A placed creature's `patrol` `path` names no `#path` block in the
+    /// scene registry — it would stand still.
+    DanglingPath { map: String, object: ObjectLabel, name: String },
     /// A map object's flag gate (`if`/`unless`/`sets`) names a flag the
     /// script never declares with `#flag`. Unlike `#set`/`#if` inside
     /// `.eggtext`, a `.tmj` gate's flag names are unchecked strings — this is
@@ -71,6 +93,8 @@ pub enum Finding {
 
     /// A `#cutscene`'s `map NAME` init step names no loaded map.
     SceneDanglingMap { cutscene: String, name: String },
+    /// A `#cutscene`'s `warp MAP X Y` step names no loaded map.
+    SceneDanglingWarp { cutscene: String, name: String },
     /// A `#cutscene`'s `spawn`/`bind NAME PRESET X Y` names no preset.
     SceneDanglingPreset { cutscene: String, name: String },
     /// A `#cutscene`'s `dialogue KEY` step names no entry in the script.
@@ -79,12 +103,15 @@ pub enum Finding {
     SceneDanglingLoad { cutscene: String, name: String },
     /// A `#cutscene`'s `sound NAME` step names no known sound effect.
     SceneDanglingSound { cutscene: String, name: String },
-    /// A `#cutscene`'s `set FLAG BOOL` step names a flag the script never
-    /// declares.
+    /// A `#cutscene`'s `set FLAG BOOL` step or `if`/`elif` condition names a
+    /// flag the script never declares.
     SceneDanglingFlag { cutscene: String, flag: String },
     /// A `#cutscene`'s `path [noclip] NAME` motion names no `#path` block in
     /// the registry.
     SceneDanglingPath { cutscene: String, name: String },
+    /// A `#cutscene`'s `give`/`take ITEM` step or `has ITEM` condition names
+    /// an item key the item registry doesn't know.
+    SceneDanglingItem { cutscene: String, item: String },
     /// A `#cutscene`'s `dialogue KEY` step has an `on NAME [wait]` handler
     /// whose cue that dialogue never reaches with a matching `#cue NAME` —
     /// dead choreography, the handler can never fire. The reverse — a
@@ -92,6 +119,25 @@ pub enum Finding {
     /// direction, or a beat for a different scene).
     SceneDanglingCue { cutscene: String, key: String, cue: String },
 
+    /// A preset's `move_mode = { patrol = "NAME" }` names no `#path` block in
+    /// the scene registry — its creatures would stand still.
+    PresetDanglingPath { preset: String, name: String },
+    /// A preset's `talk = { dialogue = "KEY" }` names no dialogue in the
+    /// script.
+    PresetDanglingDialogue { preset: String, key: String },
+    /// A preset's `talk = { cutscene = "NAME" }` names no cutscene.
+    PresetDanglingCutscene { preset: String, name: String },
+
+    /// A `[schedules.<id>]`'s `preset` names no preset.
+    ScheduleDanglingPreset { creature: String, name: String },
+    /// A schedule slot's `map` names no loaded map.
+    ScheduleDanglingMap { creature: String, slot: usize, name: String },
+    /// A schedule slot's `pose` names no entry in its preset's `poses` — the
+    /// creature would stand frozen in its plain walk sprite.
+    ScheduleDanglingPose { creature: String, slot: usize, name: String },
+    /// A schedule slot's `if`/`unless` names a flag the script never declares.
+    ScheduleDanglingFlag { creature: String, slot: usize, flag: String },
+
     /// A dialogue message's portrait switch (`#pic`, message-level or
     /// mid-message) names no portrait in the registry.
     DanglingPortrait { key: String, name: String },
@@ -103,11 +149,17 @@ pub enum Finding {
     /// `eggtext::check_flag`) — but a hand-authored or generated JSON script
     /// carries no such guarantee, which is what this covers.
     DanglingScriptFlag { key: String, flag: String },
+    /// A counter — an `#add`/`#setv` (or scene `add`/`setv`), or a `VAR OP N`
+    /// condition in dialogue, a scene or a map gate — the script never
+    /// declares with `#var`. `at` names the place, as the other findings
+    /// spell it (``dialogue `greet` ``, ``scene `intro` ``, …).
+    UndeclaredVar { at: String, var: String },
 
     /// A dialogue entry no map, scene, or [`ENGINE_DIALOGUE_ROOTS`] reaches.
     UnreferencedDialogue { key: String },
-    /// A `#path` block no `path` motion in any scene references — likely a
-    /// stale recording (see the `.eggscene` module doc).
+    /// A `#path` block no `path` motion in any scene (nor any preset's
+    /// `patrol`) references — likely a stale recording (see the `.eggscene`
+    /// module doc).
     UnreferencedPath { name: String },
     /// A declared `#flag` nothing ever sets (whether or not anything reads
     /// it — see [`Finding::FlagNeverSet`] for the "read but not set" case).
@@ -117,6 +169,9 @@ pub enum Finding {
     /// A declared flag some content reads (an `#if`/`#elif` or a map gate)
     /// but nothing ever sets — so the branch always goes the same way.
     FlagNeverSet { flag: String },
+    /// A declared `#var` that isn't both changed and read somewhere — dead,
+    /// or a comparison that always goes the same way.
+    UnusedVar { var: String },
 
     /// A language overlay's dialogue entry has drifted structurally from the
     /// base entry it translates — see [`check_overlay`]. `path` is a
@@ -132,10 +187,35 @@ pub enum Finding {
     /// base's same-named list — list entries are read back by index, so a
     /// length mismatch silently shifts every entry after the drift.
     OverlayListLength { lang: String, key: String, base_len: usize, overlay_len: usize },
-    /// A language overlay declares a `#flag` the base script never declares.
+    /// A language overlay declares a `#flag` (or `#var`) the base script never
+    /// declares.
     /// The base is the sole authority on the flag vocabulary — an overlay
     /// only ever reads/sets flags the base already named.
     OverlayUndeclaredFlag { lang: String, flag: String },
+
+    /// A map object (a warp, or an interactable) the player can't walk into
+    /// contact with from any of its map's entry points — see
+    /// [`check_connectivity`]. `kind` is `"warp"` or `"interactable"`. A
+    /// warning: content the player never meets is dead weight, not a break.
+    UnreachableObject { map: String, object: ObjectLabel, kind: &'static str },
+    /// A map no chain of reachable warps leads to from the starting map. A
+    /// warning, like an unreachable object (debug rooms live here).
+    UnreachableMap { map: String, start: String },
+    /// Arriving on `map` at (`x`, `y`) — from `from`, e.g. ``map `hall`
+    /// object[id=4]`` — leaves no warp within walking reach: once there, the
+    /// player can't leave. An error: it soft-locks the game.
+    OneWayTrap { map: String, x: i16, y: i16, from: String },
+
+    /// A dialogue page in language `lang` wraps to more lines than the
+    /// dialogue box shows, so its tail draws past the box's bottom edge —
+    /// found by laying the page out with the real font (see
+    /// `egg_ui::fit::check_fit`). `branch` is the `#if` path to the page
+    /// (`if tea > else`; empty at the top level) and `page` its index within
+    /// that branch.
+    DialogueOverflow { lang: String, key: String, branch: String, page: usize, lines: usize, max: usize },
+    /// A dialogue page has a word wider than the box's whole wrap width: the
+    /// wrap can't break it, so it juts past the right edge.
+    DialogueOverwide { lang: String, key: String, branch: String, page: usize, word: String },
 }
 
 impl Finding {
@@ -149,10 +229,22 @@ impl Finding {
                 | Finding::UnusedFlag { .. }
                 | Finding::FlagNeverRead { .. }
                 | Finding::FlagNeverSet { .. }
+                | Finding::UnusedVar { .. }
+                | Finding::UnreachableObject { .. }
+                | Finding::UnreachableMap { .. }
+                | Finding::UnknownTransition { .. }
+                | Finding::DialogueOverflow { .. }
+                | Finding::DialogueOverwide { .. }
         )
     }
 }
 
+/// A dialogue finding's `#if` path as a suffix: ` [if tea > else]`, or
+/// nothing at the top level.
+fn at_branch(branch: &str) -> String {
+    if branch.is_empty() { String::new() } else { format!(" [{branch}]") }
+}
+
 impl fmt::Display for Finding {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -165,12 +257,25 @@ impl fmt::Display for Finding {
             Finding::DanglingWarpMap { map, object, name } => {
                 write!(f, "map `{map}` object[{object}]: warp targets unknown map {name:?}")
             }
+            Finding::UnknownTransition { map, object, name } => {
+                let what = format!("unknown transition {name:?}, played as a cut");
+                write!(f, "map `{map}` object[{object}]: {what}")
+            }
+            Finding::DanglingPreset { map, object, name } => {
+                write!(f, "map `{map}` object[{object}]: creature preset {name:?} not found")
+            }
+            Finding::DanglingPath { map, object, name } => {
+                write!(f, "map `{map}` object[{object}]: creature `patrol` names an unknown path {name:?}")
+            }
             Finding::DanglingMapFlag { map, object, flag } => {
                 write!(f, "map `{map}` object[{object}]: flag {flag:?} is not declared with `#flag`")
             }
             Finding::SceneDanglingMap { cutscene, name } => {
                 write!(f, "scene `{cutscene}`: `map {name}` targets an unknown map")
             }
+            Finding::SceneDanglingWarp { cutscene, name } => {
+                write!(f, "scene `{cutscene}`: `warp {name}` targets an unknown map")
+            }
             Finding::SceneDanglingPreset { cutscene, name } => {
                 write!(f, "scene `{cutscene}`: preset {name:?} not found")
             }
@@ -189,12 +294,36 @@ impl fmt::Display for Finding {
             Finding::SceneDanglingPath { cutscene, name } => {
                 write!(f, "scene `{cutscene}`: `path {name}` names an unknown path")
             }
+            Finding::SceneDanglingItem { cutscene, item } => {
+                write!(f, "scene `{cutscene}`: item {item:?} not found")
+            }
             Finding::SceneDanglingCue { cutscene, key, cue } => {
                 write!(
                     f,
                     "scene `{cutscene}`: `on {cue}` on `dialogue {key}` names a cue that dialogue never reaches"
                 )
             }
+            Finding::PresetDanglingPath { preset, name } => {
+                write!(f, "preset `{preset}`: `patrol` names an unknown path {name:?}")
+            }
+            Finding::PresetDanglingDialogue { preset, key } => {
+                write!(f, "preset `{preset}`: `talk` dialogue key {key:?} not found in script")
+            }
+            Finding::PresetDanglingCutscene { preset, name } => {
+                write!(f, "preset `{preset}`: `talk` cutscene {name:?} not found in scene registry")
+            }
+            Finding::ScheduleDanglingPreset { creature, name } => {
+                write!(f, "schedule `{creature}`: preset {name:?} not found")
+            }
+            Finding::ScheduleDanglingMap { creature, slot, name } => {
+                write!(f, "schedule `{creature}` slot {slot}: map {name:?} not found")
+            }
+            Finding::ScheduleDanglingPose { creature, slot, name } => {
+                write!(f, "schedule `{creature}` slot {slot}: pose {name:?} is not one of its preset's poses")
+            }
+            Finding::ScheduleDanglingFlag { creature, slot, flag } => {
+                write!(f, "schedule `{creature}` slot {slot}: flag {flag:?} is not declared with `#flag`")
+            }
             Finding::DanglingPortrait { key, name } => {
                 write!(f, "dialogue `{key}`: unknown portrait {name:?}")
             }
@@ -204,11 +333,14 @@ impl fmt::Display for Finding {
             Finding::DanglingScriptFlag { key, flag } => {
                 write!(f, "dialogue `{key}`: flag {flag:?} is not declared with `#flag`")
             }
+            Finding::UndeclaredVar { at, var } => {
+                write!(f, "{at}: var {var:?} is not declared with `#var`")
+            }
             Finding::UnreferencedDialogue { key } => {
                 write!(f, "dialogue `{key}` is never referenced by any map, scene, or engine code path")
             }
             Finding::UnreferencedPath { name } => {
-                write!(f, "path `{name}` is never referenced by any scene")
+                write!(f, "path `{name}` is never referenced by any scene or preset")
             }
             Finding::UnusedFlag { flag } => {
                 write!(f, "flag `{flag}` is declared but never set")
@@ -219,6 +351,9 @@ impl fmt::Display for Finding {
             Finding::FlagNeverSet { flag } => {
                 write!(f, "flag `{flag}` is read but never set")
             }
+            Finding::UnusedVar { var } => {
+                write!(f, "var `{var}` is not both changed and read")
+            }
             Finding::OverlaySkeletonMismatch { lang, key, path } => {
                 write!(f, "overlay `{lang}` dialogue `{key}`: skeleton differs from base: {path}")
             }
@@ -231,6 +366,24 @@ impl fmt::Display for Finding {
             Finding::OverlayUndeclaredFlag { lang, flag } => {
                 write!(f, "overlay `{lang}`: flag `{flag}` is not declared in the base script")
             }
+            Finding::UnreachableObject { map, object, kind } => {
+                write!(f, "map `{map}` object[{object}]: {kind} can't be reached from any entry point")
+            }
+            Finding::UnreachableMap { map, start } => {
+                write!(f, "map `{map}` can't be reached through warps from `{start}`")
+            }
+            Finding::OneWayTrap { map, x, y, from } => {
+                write!(
+                    f,
+                    "map `{map}`: arriving at ({x}, {y}) from {from} leaves no warp in walking reach"
+                )
+            }
+            Finding::DialogueOverflow { lang, key, branch, page, lines, max } => {
+                write!(f, "`{lang}` dialogue `{key}`{}: page {page} wraps to {lines} lines, the box shows {max}", at_branch(branch))
+            }
+            Finding::DialogueOverwide { lang, key, branch, page, word } => {
+                write!(f, "`{lang}` dialogue `{key}`{}: page {page}: {word:?} is wider than the box", at_branch(branch))
+            }
         }
     }
 }
@@ -267,6 +420,74 @@ impl ObjectLabel {
 pub struct Report {
     pub errors: Vec<Finding>,
     pub warnings: Vec<Finding>,
+    /// Where every flag and counter is written and read — what the dead-flag
+    /// findings were judged from. Filled by [`check`] only; the other passes
+    /// leave it empty.
+    pub flags: FlagFlow,
+}
+
+/// The site [`FlagFlow`] credits for the engine's own clock: the `is_night`
+/// swap and the `hour` counter.
+const ENGINE_SITE: &str = "the engine";
+
+/// The flag dataflow [`check`] gathers on its walk: for every flag and
+/// counter name, the places that write it (`#set`, a `#choice` option, a map
+/// gate's `sets`, a scene `set`, `#add`/`#setv` and their scene forms) and the
+/// places that read it (`#if`/`#elif`, a map gate's `if`/`unless`, a scene
+/// `if`, a schedule slot's `if`/`unless`). Sites are spelled as the findings spell them (``dialogue `greet` ``,
+/// ``map `town` object[id=3]``, ``scene `intro` ``). A name with writes and no
+/// reads is dead ([`Finding::FlagNeverRead`]); reads and no writes, an
+/// impossible condition ([`Finding::FlagNeverSet`]).
+#[derive(Debug, Clone, Default)]
+pub struct FlagFlow {
+    /// Every name seen or declared, with its sites.
+    pub names: BTreeMap<String, FlagSites>,
+}
+
+/// Where one flag (or counter) is written and read, each site once.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlagSites {
+    pub writes: BTreeSet<String>,
+    pub reads: BTreeSet<String>,
+}
+
+impl FlagFlow {
+    fn write(&mut self, name: &str, at: &str) {
+        self.names.entry(name.to_string()).or_default().writes.insert(at.to_string());
+    }
+
+    fn read(&mut self, name: &str, at: &str) {
+        self.names.entry(name.to_string()).or_default().reads.insert(at.to_string());
+    }
+
+    /// Whether anything writes `name`.
+    pub fn is_written(&self, name: &str) -> bool {
+        self.names.get(name).is_some_and(|sites| !sites.writes.is_empty())
+    }
+
+    /// Whether anything reads `name`.
+    pub fn is_read(&self, name: &str) -> bool {
+        self.names.get(name).is_some_and(|sites| !sites.reads.is_empty())
+    }
+}
+
+impl fmt::Display for FlagFlow {
+    /// One line per name, sites comma-separated:
+    /// ``met_mayor: set in dialogue `mayor`; read in scene `intro` ``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |verb: &str, sites: &BTreeSet<String>| {
+            if sites.is_empty() {
+                format!("never {verb}")
+            } else {
+                let sites: Vec<&str> = sites.iter().map(String::as_str).collect();
+                format!("{verb} in {}", sites.join(", "))
+            }
+        };
+        for (name, sites) in &self.names {
+            writeln!(f, "{name}: {}; {}", list("set", &sites.writes), list("read", &sites.reads))?;
+        }
+        Ok(())
+    }
 }
 
 impl Report {
@@ -276,7 +497,10 @@ impl Report {
         self.errors.is_empty()
     }
 
-    fn push(&mut self, finding: Finding) {
+    /// File `finding` under [`Report::errors`] or [`Report::warnings`] by
+    /// [`Finding::is_error`] — for passes outside this module (which can't
+    /// see the data they check from here) to report through.
+    pub fn push(&mut self, finding: Finding) {
         if finding.is_error() {
             self.errors.push(finding);
         } else {
@@ -302,45 +526,84 @@ impl fmt::Display for Report {
 
 /// Cross-reference the game's whole data web and report what's dangling or
 /// unused. `maps` is every loaded map's parsed object list, keyed by map name
-/// (the warp/cutscene-reference target); `dialogue_roots` is
+/// (the warp/cutscene-reference target), and schedules are checked against
+/// the same names; `dialogue_roots` is
 /// [`ENGINE_DIALOGUE_ROOTS`] in production use, taken as a parameter so a
 /// test can extend or trim it without editing this function. Traverses maps,
-/// then scenes, then script — each name-sorted internally where its source
+/// then scenes, then schedules, then script — each name-sorted internally where its source
 /// collection isn't already ordered — so two runs over the same input always
 /// produce [`Finding`]s in the same order.
+// One borrowed registry per parameter, like `check_scenes` below.
+#[allow(clippy::too_many_arguments)]
 pub fn check(
     script: &ScriptFile,
     scenes: &SceneFile,
     maps: &BTreeMap<String, Vec<MapObject>>,
     portraits: &Portraits,
     presets: &Presets,
+    schedules: &Schedules,
+    items: &GameItems,
     dialogue_roots: &[&str],
 ) -> Report {
     let mut report = Report::default();
     let mut referenced_dialogue: BTreeSet<String> =
         dialogue_roots.iter().map(|s| s.to_string()).collect();
     let mut referenced_paths: BTreeSet<String> = BTreeSet::new();
-    let mut set_flags: BTreeSet<String> = BTreeSet::new();
-    let mut read_flags: BTreeSet<String> = BTreeSet::new();
-    // `is_night` is read and set directly by the engine (the day/night swap
-    // in `WalkaroundState`), never through script/scene/map content, so the
-    // dead-flag sweep must not flag it just because nothing *authored* sets
-    // or reads it.
-    set_flags.insert(IS_NIGHT_FLAG.to_string());
-    read_flags.insert(IS_NIGHT_FLAG.to_string());
-
-    check_maps(maps, script, scenes, &mut report, &mut referenced_dialogue, &mut set_flags, &mut read_flags);
+    let mut flow = FlagFlow::default();
+    // `is_night` and `hour` are read and set directly by the engine (the
+    // clock in `WalkaroundState`), never through script/scene/map content, so
+    // the dead-flag sweep must not flag them just because nothing *authored*
+    // sets or reads them.
+    for name in [IS_NIGHT_FLAG, HOUR_VAR] {
+        flow.write(name, ENGINE_SITE);
+        flow.read(name, ENGINE_SITE);
+    }
+
+    check_maps(
+        maps,
+        script,
+        scenes,
+        presets,
+        &mut report,
+        &mut referenced_dialogue,
+        &mut flow,
+        &mut referenced_paths,
+    );
     check_scenes(
         scenes,
         script,
         maps,
         presets,
+        items,
         &mut report,
         &mut referenced_dialogue,
-        &mut set_flags,
+        &mut flow,
         &mut referenced_paths,
     );
-    check_script(script, portraits, &mut report, &mut set_flags, &mut read_flags);
+    // A patrolling preset walks its `#path` outside any scene, and a talking
+    // one opens its dialogue or cutscene outside any map object.
+    for (name, def) in presets.named_defs() {
+        if let PresetMove::Patrol(path) = &def.move_mode {
+            referenced_paths.insert(path.clone());
+            if !scenes.paths.contains_key(path) {
+                report.push(Finding::PresetDanglingPath { preset: name.clone(), name: path.clone() });
+            }
+        }
+        match &def.talk {
+            Some(Talk::Dialogue(key)) => {
+                referenced_dialogue.insert(key.clone());
+                if !script.dialogue.contains_key(key) {
+                    report.push(Finding::PresetDanglingDialogue { preset: name, key: key.clone() });
+                }
+            }
+            Some(Talk::Cutscene(scene)) if scenes.get_cutscene(scene).is_none() => {
+                report.push(Finding::PresetDanglingCutscene { preset: name, name: scene.clone() });
+            }
+            _ => {}
+        }
+    }
+    check_schedules(schedules, script, maps, presets, &mut report, &mut flow);
+    check_script(script, portraits, &mut report, &mut flow);
 
     let mut dialogue_keys: Vec<&String> = script.dialogue.keys().collect();
     dialogue_keys.sort();
@@ -357,7 +620,7 @@ pub fn check(
         }
     }
     for flag in &script.flags {
-        let (is_set, is_read) = (set_flags.contains(flag), read_flags.contains(flag));
+        let (is_set, is_read) = (flow.is_written(flag), flow.is_read(flag));
         match (is_set, is_read) {
             (false, false) => report.push(Finding::UnusedFlag { flag: flag.clone() }),
             (false, true) => report.push(Finding::FlagNeverSet { flag: flag.clone() }),
@@ -365,20 +628,36 @@ pub fn check(
             (true, true) => {}
         }
     }
+    // Counters share the flags' set/read tracking: `.eggtext` refuses a name
+    // declared as both, so the two vocabularies never collide.
+    for var in &script.vars {
+        if !(flow.is_written(var) && flow.is_read(var)) {
+            report.push(Finding::UnusedVar { var: var.clone() });
+        }
+    }
+    // Every declared name gets a row, touched or not.
+    for name in script.flags.iter().chain(&script.vars) {
+        flow.names.entry(name.clone()).or_default();
+    }
 
+    report.flags = flow;
     report
 }
 
 /// The map half of [`check`]: every object's dialogue/cutscene/warp/gate
-/// references, name-sorted by map for determinism.
+/// references, and a placed creature's preset/path/talk, name-sorted by map
+/// for determinism.
+// The same accumulators `check_scenes` threads; see the note there.
+#[allow(clippy::too_many_arguments)]
 fn check_maps(
     maps: &BTreeMap<String, Vec<MapObject>>,
     script: &ScriptFile,
     scenes: &SceneFile,
+    presets: &Presets,
     report: &mut Report,
     referenced_dialogue: &mut BTreeSet<String>,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
+    referenced_paths: &mut BTreeSet<String>,
 ) {
     for (map, objects) in maps {
         for object in objects {
@@ -425,21 +704,72 @@ fn check_maps(
                     }
                 }
                 ObjectEffect::Interact(Interaction::Func(_) | Interaction::None) => {}
-            }
-            for flag in [&object.gate.if_flag, &object.gate.unless_flag] {
-                if let Some(flag) = flag {
-                    read_flags.insert(flag.clone());
-                    if !script.flags.contains(flag) {
-                        report.push(Finding::DanglingMapFlag {
+                ObjectEffect::Creature(creature) => {
+                    if presets.get(&creature.preset).is_none() {
+                        report.push(Finding::DanglingPreset {
                             map: map.clone(),
                             object: label,
-                            flag: flag.clone(),
+                            name: creature.preset.as_str().to_string(),
                         });
                     }
+                    if let Some(PresetMove::Patrol(path)) = &creature.move_mode {
+                        referenced_paths.insert(path.clone());
+                        if !scenes.paths.contains_key(path) {
+                            report.push(Finding::DanglingPath {
+                                map: map.clone(),
+                                object: label,
+                                name: path.clone(),
+                            });
+                        }
+                    }
+                    match &creature.talk {
+                        Some(Talk::Dialogue(key)) => {
+                            referenced_dialogue.insert(key.clone());
+                            if !script.dialogue.contains_key(key) {
+                                report.push(Finding::DanglingDialogue {
+                                    map: map.clone(),
+                                    object: label,
+                                    key: key.clone(),
+                                });
+                            }
+                        }
+                        Some(Talk::Cutscene(name)) if scenes.get_cutscene(name).is_none() => {
+                            report.push(Finding::DanglingCutscene {
+                                map: map.clone(),
+                                object: label,
+                                name: name.clone(),
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            for flag in [&object.gate.if_flag, &object.gate.unless_flag]
+                .into_iter()
+                .flatten()
+            {
+                let at = format!("map `{map}` object[{label}]");
+                let flag = match flag {
+                    SaveTest::Flag(flag) => flag,
+                    SaveTest::Var(test) => {
+                        flow.read(&test.name, &at);
+                        if !script.vars.contains(&test.name) {
+                            report.push(Finding::UndeclaredVar { at, var: test.name.clone() });
+                        }
+                        continue;
+                    }
+                };
+                flow.read(flag, &at);
+                if !script.flags.contains(flag) {
+                    report.push(Finding::DanglingMapFlag {
+                        map: map.clone(),
+                        object: label,
+                        flag: flag.clone(),
+                    });
                 }
             }
             if let Some(flag) = &object.gate.sets {
-                set_flags.insert(flag.clone());
+                flow.write(flag, &format!("map `{map}` object[{label}]"));
                 if !script.flags.contains(flag) {
                     report.push(Finding::DanglingMapFlag {
                         map: map.clone(),
@@ -452,10 +782,74 @@ fn check_maps(
     }
 }
 
+/// The schedule part of [`check`]: each schedule's preset, and every slot's
+/// map, pose and conditions. `Schedules::iter` is already id-sorted.
+fn check_schedules(
+    schedules: &Schedules,
+    script: &ScriptFile,
+    maps: &BTreeMap<String, Vec<MapObject>>,
+    presets: &Presets,
+    report: &mut Report,
+    flow: &mut FlagFlow,
+) {
+    for (id, schedule) in schedules.iter() {
+        let creature = id.to_string();
+        let preset = presets.get(&schedule.preset);
+        if preset.is_none() {
+            report.push(Finding::ScheduleDanglingPreset {
+                creature: creature.clone(),
+                name: schedule.preset.as_str().to_string(),
+            });
+        }
+        for (slot, def) in schedule.slots.iter().enumerate() {
+            if !maps.contains_key(&def.map) {
+                report.push(Finding::ScheduleDanglingMap {
+                    creature: creature.clone(),
+                    slot,
+                    name: def.map.clone(),
+                });
+            }
+            if let (Some(pose), Some(preset)) = (&def.pose, preset)
+                && !preset.poses.contains_key(pose)
+            {
+                report.push(Finding::ScheduleDanglingPose {
+                    creature: creature.clone(),
+                    slot,
+                    name: pose.clone(),
+                });
+            }
+            let at = format!("schedule `{id}` slot {slot}");
+            for condition in def.conditions() {
+                let flag = match condition {
+                    SaveTest::Flag(flag) => flag,
+                    SaveTest::Var(test) => {
+                        flow.read(&test.name, &at);
+                        if !script.vars.contains(&test.name) {
+                            report.push(Finding::UndeclaredVar {
+                                at: at.clone(),
+                                var: test.name.clone(),
+                            });
+                        }
+                        continue;
+                    }
+                };
+                flow.read(flag, &at);
+                if !script.flags.contains(flag) {
+                    report.push(Finding::ScheduleDanglingFlag {
+                        creature: creature.clone(),
+                        slot,
+                        flag: flag.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
+
 /// The scene half of [`check`]: every `#cutscene`'s init map/presets and
-/// content steps' dialogue/load/sound/flag/path references, name-sorted for
+/// content steps' dialogue/load/sound/flag/path/item references, name-sorted for
 /// determinism (`SceneFile::cutscenes` is a `HashMap`).
-// Eight loosely-coupled accumulator sets (`report` + four cross-referenced
+// Nine loosely-coupled accumulator sets (`report` + five cross-referenced
 // registries), each already threaded independently through `check`/
 // `check_maps`/`check_script` — bundling just this function's params into a
 // struct would obscure the sibling functions' shape rather than clarify it.
@@ -465,9 +859,10 @@ fn check_scenes(
     script: &ScriptFile,
     maps: &BTreeMap<String, Vec<MapObject>>,
     presets: &Presets,
+    items: &GameItems,
     report: &mut Report,
     referenced_dialogue: &mut BTreeSet<String>,
-    set_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
     referenced_paths: &mut BTreeSet<String>,
 ) {
     let mut names: Vec<&String> = scenes.cutscenes.keys().collect();
@@ -490,14 +885,26 @@ fn check_scenes(
             }
         }
         for step in &def.content {
-            check_content_step(step, name, script, scenes, report, referenced_dialogue, set_flags, referenced_paths);
+            check_content_step(
+                step,
+                name,
+                script,
+                scenes,
+                maps,
+                items,
+                report,
+                referenced_dialogue,
+                flow,
+                referenced_paths,
+            );
         }
     }
 }
 
 /// One cutscene content step's cross-references — the per-step body of
 /// [`check_scenes`]'s walk, factored out so it can also recurse into every
-/// `on` handler's own content steps: a handler body gets exactly the same
+/// `if` branch (all sides — which one runs depends on the live save) and
+/// every `on` handler's own content steps: a handler body gets exactly the same
 /// checks as a top-level step (flags/sounds/loads/paths), plus — on the
 /// `Dialogue` arm — a check of its own, that each handler's cue is one the
 /// dialogue can actually reach (`Finding::SceneDanglingCue`). `Load` can't
@@ -509,9 +916,11 @@ fn check_content_step(
     cutscene: &str,
     script: &ScriptFile,
     scenes: &SceneFile,
+    maps: &BTreeMap<String, Vec<MapObject>>,
+    items: &GameItems,
     report: &mut Report,
     referenced_dialogue: &mut BTreeSet<String>,
-    set_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
     referenced_paths: &mut BTreeSet<String>,
 ) {
     match step {
@@ -544,9 +953,59 @@ fn check_content_step(
                         cutscene,
                         script,
                         scenes,
+                        maps,
+                        items,
+                        report,
+                        referenced_dialogue,
+                        flow,
+                        referenced_paths,
+                    );
+                }
+            }
+        }
+        CutsceneContent::If(chain) => {
+            for branch in &chain.branches {
+                match &branch.condition {
+                    Condition::Flag(flag) => {
+                        flow.read(flag, &format!("scene `{cutscene}`"));
+                        if !script.flags.contains(flag) {
+                            report.push(Finding::SceneDanglingFlag {
+                                cutscene: cutscene.to_string(),
+                                flag: flag.clone(),
+                            });
+                        }
+                    }
+                    Condition::Has(item) => {
+                        if !items.contains(item) {
+                            report.push(Finding::SceneDanglingItem {
+                                cutscene: cutscene.to_string(),
+                                item: item.clone(),
+                            });
+                        }
+                    }
+                    Condition::Var(test) => {
+                        flow.read(&test.name, &format!("scene `{cutscene}`"));
+                        if !script.vars.contains(&test.name) {
+                            report.push(Finding::UndeclaredVar {
+                                at: format!("scene `{cutscene}`"),
+                                var: test.name.clone(),
+                            });
+                        }
+                    }
+                }
+            }
+            for body in chain.bodies() {
+                for sub in body {
+                    check_content_step(
+                        sub,
+                        cutscene,
+                        script,
+                        scenes,
+                        maps,
+                        items,
                         report,
                         referenced_dialogue,
-                        set_flags,
+                        flow,
                         referenced_paths,
                     );
                 }
@@ -563,11 +1022,28 @@ fn check_content_step(
             }
         }
         CutsceneContent::SetFlag(flag, _) => {
-            set_flags.insert(flag.clone());
+            flow.write(flag, &format!("scene `{cutscene}`"));
             if !script.flags.contains(flag) {
                 report.push(Finding::SceneDanglingFlag { cutscene: cutscene.to_string(), flag: flag.clone() });
             }
         }
+        CutsceneContent::SetVar(var, _) | CutsceneContent::AddVar(var, _) => {
+            let at = format!("scene `{cutscene}`");
+            flow.write(var, &at);
+            if !script.vars.contains(var) {
+                report.push(Finding::UndeclaredVar { at, var: var.clone() });
+            }
+        }
+        CutsceneContent::Warp { map, .. } => {
+            if !maps.contains_key(map) {
+                report.push(Finding::SceneDanglingWarp { cutscene: cutscene.to_string(), name: map.clone() });
+            }
+        }
+        CutsceneContent::GiveItem(item) | CutsceneContent::TakeItem(item) => {
+            if !items.contains(item) {
+                report.push(Finding::SceneDanglingItem { cutscene: cutscene.to_string(), item: item.clone() });
+            }
+        }
         CutsceneContent::Move(chains) => {
             for chain in chains {
                 for ins in &chain.instructions {
@@ -661,30 +1137,28 @@ fn check_script(
     script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     let mut keys: Vec<&String> = script.dialogue.keys().collect();
     keys.sort();
     for key in keys {
-        walk_dialogue(key, &script.dialogue[key], &script.flags, portraits, report, set_flags, read_flags);
+        walk_dialogue(key, &script.dialogue[key], script, portraits, report, flow);
     }
 }
 
 fn walk_dialogue(
     key: &str,
     def: &DialogueDef,
-    flags: &BTreeSet<String>,
+    script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     match def {
-        DialogueDef::Plain(entry) => walk_entry(key, entry, flags, portraits, report, set_flags, read_flags),
+        DialogueDef::Plain(entry) => walk_entry(key, entry, script, portraits, report, flow),
         DialogueDef::Segments { segments } => {
             for seg in segments {
-                walk_segment(key, seg, flags, portraits, report, set_flags, read_flags);
+                walk_segment(key, seg, script, portraits, report, flow);
             }
         }
     }
@@ -693,35 +1167,45 @@ fn walk_dialogue(
 fn walk_segment(
     key: &str,
     seg: &SegmentDef,
-    flags: &BTreeSet<String>,
+    script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     fn check_condition(
         key: &str,
-        flag: &str,
-        flags: &BTreeSet<String>,
+        condition: &SaveTest,
+        script: &ScriptFile,
         report: &mut Report,
-        read_flags: &mut BTreeSet<String>,
+        flow: &mut FlagFlow,
     ) {
-        read_flags.insert(flag.to_string());
-        if !flags.contains(flag) {
+        let at = format!("dialogue `{key}`");
+        let flag = match condition {
+            SaveTest::Flag(flag) => flag,
+            SaveTest::Var(test) => {
+                flow.read(&test.name, &at);
+                if !script.vars.contains(&test.name) {
+                    report.push(Finding::UndeclaredVar { at, var: test.name.clone() });
+                }
+                return;
+            }
+        };
+        flow.read(flag, &at);
+        if !script.flags.contains(flag) {
             report.push(Finding::DanglingScriptFlag { key: key.to_string(), flag: flag.to_string() });
         }
     }
     match seg {
-        SegmentDef::Plain(entry) => walk_entry(key, entry, flags, portraits, report, set_flags, read_flags),
+        SegmentDef::Plain(entry) => walk_entry(key, entry, script, portraits, report, flow),
         SegmentDef::If { flag, then, otherwise, elifs, .. } => {
-            check_condition(key, flag, flags, report, read_flags);
-            walk_dialogue(key, then, flags, portraits, report, set_flags, read_flags);
+            check_condition(key, flag, script, report, flow);
+            walk_dialogue(key, then, script, portraits, report, flow);
             if let Some(otherwise) = otherwise {
-                walk_dialogue(key, otherwise, flags, portraits, report, set_flags, read_flags);
+                walk_dialogue(key, otherwise, script, portraits, report, flow);
             }
             for elif in elifs {
-                check_condition(key, &elif.flag, flags, report, read_flags);
-                walk_dialogue(key, &elif.then, flags, portraits, report, set_flags, read_flags);
+                check_condition(key, &elif.flag, script, report, flow);
+                walk_dialogue(key, &elif.then, script, portraits, report, flow);
             }
         }
     }
@@ -730,15 +1214,14 @@ fn walk_segment(
 fn walk_entry(
     key: &str,
     entry: &Entry,
-    flags: &BTreeSet<String>,
+    script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     if let Entry::Conversation { messages } = entry {
         for message in messages {
-            walk_message(key, message, flags, portraits, report, set_flags, read_flags);
+            walk_message(key, message, script, portraits, report, flow);
         }
     }
     // `Entry::Line`/`Entry::Pages` are plain text — no directives to check.
@@ -747,11 +1230,10 @@ fn walk_entry(
 fn walk_message(
     key: &str,
     message: &MessageDef,
-    flags: &BTreeSet<String>,
+    script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     if let PortraitChange::Set(name) = &message.portrait
         && portraits.get(name).is_none()
@@ -759,18 +1241,17 @@ fn walk_message(
         report.push(Finding::DanglingPortrait { key: key.to_string(), name: name.clone() });
     }
     for content in &message.content {
-        walk_content(key, content, flags, portraits, report, set_flags, read_flags);
+        walk_content(key, content, script, portraits, report, flow);
     }
 }
 
 fn walk_content(
     key: &str,
     content: &ContentDef,
-    flags: &BTreeSet<String>,
+    script: &ScriptFile,
     portraits: &Portraits,
     report: &mut Report,
-    set_flags: &mut BTreeSet<String>,
-    _read_flags: &mut BTreeSet<String>,
+    flow: &mut FlagFlow,
 ) {
     match content {
         ContentDef::Sound(name) => {
@@ -784,16 +1265,23 @@ fn walk_content(
             }
         }
         ContentDef::SetFlag(name, _) => {
-            set_flags.insert(name.clone());
-            if !flags.contains(name) {
+            flow.write(name, &format!("dialogue `{key}`"));
+            if !script.flags.contains(name) {
                 report.push(Finding::DanglingScriptFlag { key: key.to_string(), flag: name.clone() });
             }
         }
+        ContentDef::SetVar(name, _) | ContentDef::AddVar(name, _) => {
+            let at = format!("dialogue `{key}`");
+            flow.write(name, &at);
+            if !script.vars.contains(name) {
+                report.push(Finding::UndeclaredVar { at, var: name.clone() });
+            }
+        }
         ContentDef::Choice(options) => {
             for option in options {
                 for (name, _) in &option.sets {
-                    set_flags.insert(name.clone());
-                    if !flags.contains(name) {
+                    flow.write(name, &format!("dialogue `{key}`"));
+                    if !script.flags.contains(name) {
                         report.push(Finding::DanglingScriptFlag { key: key.to_string(), flag: name.clone() });
                     }
                 }
@@ -824,7 +1312,8 @@ fn walk_content(
 // directives, branching, or choices around it, since those drive save flags
 // and pacing the *base* author already decided once. [`check_overlay`] pins
 // that: for every dialogue key an overlay shares with the base, the two
-// entries' *skeletons* (everything but text payloads) must match exactly.
+// entries' *skeletons* (everything but text payloads, save the `{…}`
+// placeholders inside them) must match exactly.
 
 /// Cross-reference one language overlay against the base script it
 /// translates: every dialogue key the overlay also defines in `base` must
@@ -871,8 +1360,8 @@ pub fn check_overlay(base: &ScriptFile, overlay: &ScriptFile, lang: &str) -> Rep
         }
     }
 
-    for flag in &overlay.flags {
-        if !base.flags.contains(flag) {
+    for flag in overlay.flags.iter().chain(&overlay.vars) {
+        if !base.flags.contains(flag) && !base.vars.contains(flag) {
             report.push(Finding::OverlayUndeclaredFlag { lang: lang.to_string(), flag: flag.clone() });
         }
     }
@@ -882,11 +1371,12 @@ pub fn check_overlay(base: &ScriptFile, overlay: &ScriptFile, lang: &str) -> Rep
 
 /// A [`SegmentDef::If`]/[`ElifDef`] condition's `#if`/`#elif [not] NAME`
 /// spelling, for a mismatch message.
-fn condition_label(flag: &str, negated: bool) -> String {
+fn condition_label(condition: &SaveTest, negated: bool) -> String {
+    let condition = condition.to_string();
     if negated {
-        format!("#if not {flag:?}")
+        format!("#if not {condition:?}")
     } else {
-        format!("#if {flag:?}")
+        format!("#if {condition:?}")
     }
 }
 
@@ -1060,9 +1550,11 @@ fn portrait_label(p: &Option<String>) -> String {
 /// values (text/label strings are exactly what's allowed to differ).
 fn skeleton_diff_content(base: &ContentDef, overlay: &ContentDef) -> Option<String> {
     match (base, overlay) {
-        (ContentDef::Text(_), ContentDef::Text(_)) | (ContentDef::Auto(_), ContentDef::Auto(_)) => None,
-        (ContentDef::Delayed(_, bd), ContentDef::Delayed(_, od)) => {
-            (bd != od).then(|| format!("#delay {bd} vs {od}"))
+        (ContentDef::Text(bt), ContentDef::Text(ot))
+        | (ContentDef::Auto(bt), ContentDef::Auto(ot)) => skeleton_diff_text(bt, ot),
+        (ContentDef::Delayed(bt, bd), ContentDef::Delayed(ot, od)) => {
+            let delay = (bd != od).then(|| format!("#delay {bd} vs {od}"));
+            delay.or_else(|| skeleton_diff_text(bt, ot))
         }
         (ContentDef::Delay(bd), ContentDef::Delay(od)) => (bd != od).then(|| format!("#delay {bd} vs {od}")),
         (ContentDef::Sound(bn), ContentDef::Sound(on)) => {
@@ -1075,6 +1567,12 @@ fn skeleton_diff_content(base: &ContentDef, overlay: &ContentDef) -> Option<Stri
         (ContentDef::SetFlag(bn, bv), ContentDef::SetFlag(on, ov)) => {
             (bn != on || bv != ov).then(|| format!("#set {bn:?} {bv} vs #set {on:?} {ov}"))
         }
+        (ContentDef::SetVar(bn, bv), ContentDef::SetVar(on, ov)) => {
+            (bn != on || bv != ov).then(|| format!("#setv {bn:?} {bv} vs #setv {on:?} {ov}"))
+        }
+        (ContentDef::AddVar(bn, bv), ContentDef::AddVar(on, ov)) => {
+            (bn != on || bv != ov).then(|| format!("#add {bn:?} {bv} vs #add {on:?} {ov}"))
+        }
         (ContentDef::Shake(bf, ba), ContentDef::Shake(of, oa)) => {
             (bf != of || ba != oa).then(|| format!("#shake {bf} {ba} vs #shake {of} {oa}"))
         }
@@ -1106,6 +1604,8 @@ fn content_kind(c: &ContentDef) -> &'static str {
         ContentDef::Pause => "pause",
         ContentDef::Flip(_) => "#flip",
         ContentDef::SetFlag(..) => "#set",
+        ContentDef::SetVar(..) => "#setv",
+        ContentDef::AddVar(..) => "#add",
         ContentDef::Shake(..) => "#shake",
         ContentDef::Choice(_) => "#choice",
         ContentDef::Speed(..) => "#speed",
@@ -1121,10 +1621,251 @@ fn skeleton_diff_choice(base: &[ChoiceOptionDef], overlay: &[ChoiceOptionDef]) -
     }
     base.iter().zip(overlay).enumerate().find_map(|(i, (b, o))| {
         (b.sets != o.sets)
-            .then(|| format!("#choice option {}: sets {:?} vs {:?}", i + 1, b.sets, o.sets))
+            .then(|| format!("sets {:?} vs {:?}", b.sets, o.sets))
+            .or_else(|| skeleton_diff_text(&b.text, &o.text))
+            .map(|sub| format!("#choice option {}: {sub}", i + 1))
     })
 }
 
+/// A text payload's skeleton: its `{…}` placeholders (see
+/// [`placeholders`]), compared as a sorted list — a translation may move one
+/// within the line, since word order differs between languages, but not drop,
+/// add, or retarget one (a line that loses `{item:KEY}` loses the name it
+/// exists to show).
+fn skeleton_diff_text(base: &str, overlay: &str) -> Option<String> {
+    fn spelled(text: &str) -> Vec<&str> {
+        let mut found: Vec<&str> =
+            placeholders(text).into_iter().map(|(range, _)| &text[range]).collect();
+        found.sort_unstable();
+        found
+    }
+    let (b, o) = (spelled(base), spelled(overlay));
+    (b != o).then(|| format!("placeholders differ ({} vs {})", b.join(" "), o.join(" ")))
+}
+
+/// One way onto a map for [`check_connectivity`]: where the player stands on
+/// arrival, and what put them there (for the report).
+struct Landing {
+    map: String,
+    pos: Vec2,
+    from: String,
+    /// The map and object index of the warp this landing is the far side of,
+    /// `None` for the game's start and for scene `warp`s.
+    warp: Option<(String, usize)>,
+}
+
+/// Check that the world hangs together for a player on foot: flood-fill the
+/// walkable space from every way onto each map — the new game's start (`start`
+/// map, at `player`'s position), every map warp's landing, and every scene
+/// `warp` step's — with `player`'s hitbox through the walk loop's own
+/// collision ([`walk_flood`]), then report
+/// - map objects no landing on their map walks into range of
+///   ([`Finding::UnreachableObject`]: in contact for a touch trigger, one
+///   step's facing probe away for a press),
+/// - maps the warps reachable from the start never lead to
+///   ([`Finding::UnreachableMap`]; scene warps don't count — a scene only
+///   plays once something reachable starts it),
+/// - landings with no warp in reach ([`Finding::OneWayTrap`]).
+///
+/// Optimistic about state: flag gates and `removable` pickups are taken as
+/// present, and only walls block the way (creatures don't). `maps` is the
+/// loaded store, built into collision against the indexed sprite `sheet`
+/// exactly as a map load builds it. Deterministic: maps and scenes are visited
+/// name-sorted.
+pub fn check_connectivity(
+    maps: &MapStore,
+    sheet: &IndexedImage,
+    scenes: &SceneFile,
+    start: &str,
+    player: &Shell,
+) -> Report {
+    let mut report = Report::default();
+    let built: BTreeMap<String, MapInfo> = maps
+        .names()
+        .into_iter()
+        .filter_map(|name| Some((name.to_string(), map_by_name(sheet, name, maps)?)))
+        .collect();
+
+    let mut landings = vec![Landing {
+        map: start.to_string(),
+        pos: player.pos,
+        from: "the new game".to_string(),
+        warp: None,
+    }];
+    for (name, info) in &built {
+        for (i, object) in info.objects.iter().enumerate() {
+            let ObjectEffect::Warp(warp) = &object.effect else {
+                continue;
+            };
+            landings.push(Landing {
+                map: warp.map.clone().unwrap_or_else(|| name.clone()),
+                pos: warp.to,
+                from: format!("map `{name}` object[{}]", ObjectLabel::of(object)),
+                warp: Some((name.clone(), i)),
+            });
+        }
+    }
+    for (name, def) in scenes.named_defs() {
+        let mut warps = Vec::new();
+        collect_scene_warps(&def.content, &mut warps);
+        for (map, pos) in warps {
+            landings.push(Landing { map, pos, from: format!("scene `{name}`"), warp: None });
+        }
+    }
+    // A landing on a map that isn't loaded is a dangling reference, which
+    // `check` already reports.
+    landings.retain(|landing| built.contains_key(&landing.map));
+
+    // Which of its map's objects each landing walks into range of. A map too
+    // big to flood is given the benefit of the doubt: everything in reach.
+    let body = player.local_hitbox;
+    let reached: Vec<BTreeSet<usize>> = landings
+        .iter()
+        .map(|landing| {
+            let info = &built[&landing.map];
+            let reach = walk_flood(info, maps.get(&landing.map), player, landing.pos);
+            let in_reach = |object| reach.as_ref().is_none_or(|r| object_in_reach(object, r, body));
+            (0..info.objects.len()).filter(|&i| in_reach(&info.objects[i])).collect()
+        })
+        .collect();
+
+    for (name, info) in &built {
+        let mut any_landing = false;
+        let mut touched = BTreeSet::new();
+        for (landing, objects) in landings.iter().zip(&reached) {
+            if landing.map == *name {
+                any_landing = true;
+                touched.extend(objects.iter().copied());
+            }
+        }
+        // A map nothing lands on is reported whole, as unreachable, below.
+        if !any_landing {
+            continue;
+        }
+        for (i, object) in info.objects.iter().enumerate() {
+            if !touched.contains(&i) {
+                let kind = match object.effect {
+                    ObjectEffect::Warp(_) => "warp",
+                    // A pure animation: nothing to set off, so nothing to reach.
+                    ObjectEffect::Interact(Interaction::None) => continue,
+                    // A creature walks about; where it starts isn't a place
+                    // the player has to reach.
+                    ObjectEffect::Creature(_) => continue,
+                    ObjectEffect::Interact(_) => "interactable",
+                };
+                report.push(Finding::UnreachableObject {
+                    map: name.clone(),
+                    object: ObjectLabel::of(object),
+                    kind,
+                });
+            }
+        }
+    }
+
+    let is_warp = |map: &str, i: usize| {
+        matches!(built[map].objects[i].effect, ObjectEffect::Warp(_))
+    };
+    for (landing, objects) in landings.iter().zip(&reached) {
+        if !objects.iter().any(|&i| is_warp(&landing.map, i)) {
+            report.push(Finding::OneWayTrap {
+                map: landing.map.clone(),
+                x: landing.pos.x,
+                y: landing.pos.y,
+                from: landing.from.clone(),
+            });
+        }
+    }
+
+    // The warp graph, walked from the start landing: a reached landing's
+    // reachable warps lead on to their own landings.
+    // The start landing survived the retain (as the first) only if the start
+    // map is loaded; if it isn't, nothing is reachable.
+    let mut visited = vec![false; landings.len()];
+    let mut queue = Vec::new();
+    if built.contains_key(start) {
+        visited[0] = true;
+        queue.push(0);
+    }
+    let mut maps_reached = BTreeSet::new();
+    while let Some(at) = queue.pop() {
+        maps_reached.insert(landings[at].map.as_str());
+        for (next, landing) in landings.iter().enumerate() {
+            let Some((map, i)) = &landing.warp else {
+                continue;
+            };
+            if !visited[next] && *map == landings[at].map && reached[at].contains(i) {
+                visited[next] = true;
+                queue.push(next);
+            }
+        }
+    }
+    for name in built.keys() {
+        if !maps_reached.contains(name.as_str()) {
+            report.push(Finding::UnreachableMap { map: name.clone(), start: start.to_string() });
+        }
+    }
+    report
+}
+
+/// Every `warp MAP X Y` in `content`, recursing into `on` handlers and every
+/// side of an `if` chain.
+fn collect_scene_warps(content: &[CutsceneContent], out: &mut Vec<(String, Vec2)>) {
+    for step in content {
+        match step {
+            CutsceneContent::Warp { map, pos, .. } => out.push((map.clone(), *pos)),
+            CutsceneContent::Dialogue { handlers, .. } => {
+                for handler in handlers {
+                    collect_scene_warps(&handler.content, out);
+                }
+            }
+            CutsceneContent::If(chain) => {
+                for body in chain.bodies() {
+                    collect_scene_warps(body, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Whether a body with hitbox `body` standing anywhere in `reach` can set
+/// `object` off: by touching it for a touch trigger, by its facing probe (one
+/// pixel out in any of the eight directions) for a press. A map-enter object
+/// needs no contact at all.
+fn object_in_reach(object: &MapObject, reach: &Reach, body: Hitbox) -> bool {
+    let contact = match object.trigger {
+        Trigger::Enter => return true,
+        Trigger::Touch => body,
+        Trigger::Press | Trigger::Any => body.offset_xy(-1, -1).grow(2, 2),
+    };
+    reach
+        .positions()
+        .iter()
+        .any(|&pos| contact.offset(pos).touches(object.hitbox))
+}
+
+/// Report every warp whose `transition` property names no transition kind
+/// ([`Finding::UnknownTransition`], a warning): the map load reads it as a
+/// cut, so a typo'd name would otherwise just lose its effect. Works on the
+/// loaded store's raw objects, since the parsed ones no longer carry the
+/// name. Deterministic: maps are visited name-sorted.
+pub fn check_transitions(maps: &MapStore) -> Report {
+    let mut report = Report::default();
+    for map in maps.names() {
+        let Some(tiled) = maps.get(map) else {
+            continue;
+        };
+        for (object, name) in tiled.unknown_transitions() {
+            report.warnings.push(Finding::UnknownTransition {
+                map: map.to_string(),
+                object: ObjectLabel::of(&object),
+                name,
+            });
+        }
+    }
+    report
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1147,6 +1888,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean());
@@ -1168,6 +1911,8 @@ mod tests {
             &maps(vec![("here", vec![bad]), ("elsewhere", vec![])]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 1);
@@ -1179,6 +1924,8 @@ mod tests {
             &maps(vec![("here", vec![good]), ("elsewhere", vec![])]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean());
@@ -1202,6 +1949,8 @@ mod tests {
             &maps(vec![("here", vec![live, dangling])]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 1);
@@ -1225,6 +1974,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean());
@@ -1245,6 +1996,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 2);
@@ -1272,7 +2025,7 @@ mod tests {
         use crate::world::map::{Gate, MapObject};
         use egg_render::geometry::Hitbox;
         let gated = MapObject::dialogue(Hitbox::new(0, 0, 8, 8), "d")
-            .with_gate(Gate { if_flag: Some("read_only".to_string()), unless_flag: None, sets: None });
+            .with_gate(Gate { if_flag: Some(SaveTest::parse("read_only")), unless_flag: None, sets: None });
 
         let report = check(
             &script,
@@ -1280,6 +2033,8 @@ mod tests {
             &maps(vec![("here", vec![gated])]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean(), "no dangling refs expected: {:?}", report.errors);
@@ -1289,6 +2044,69 @@ mod tests {
         assert!(!report.warnings.iter().any(|w| matches!(w, Finding::UnusedFlag { flag } | Finding::FlagNeverRead { flag } | Finding::FlagNeverSet { flag } if flag == "both")));
     }
 
+    /// The flag dataflow names every write and read site: `#choice` options,
+    /// map gates (`sets` writes, `if`/`unless` read), scene `set`/`if`, and
+    /// `#if` — each site once, with a row even for a flag nothing touches.
+    #[test]
+    fn flag_flow_records_every_site() {
+        use crate::data::scene;
+        use crate::world::map::{Gate, MapObject};
+        use egg_render::geometry::Hitbox;
+        let script = script(
+            "#flag tea\n#flag coffee\n#flag idle\n\
+             #dialogue menu\n\
+             \x20   What'll it be?\n\
+             \x20   #choice\n\
+             \x20   #option Tea\n\
+             \x20   #set tea true\n\
+             \x20   #option Coffee\n\
+             \x20   #set coffee true\n\
+             #dialogue after\n\
+             \x20   #if tea\n\
+             \x20   Tea it is.\n\
+             \x20   #end\n\
+             \x20   #if tea\n\
+             \x20   Still tea.\n\
+             \x20   #end",
+        );
+        let scenes = scene::parse("#cutscene brew\n    if coffee\n        set tea false").unwrap();
+        let gate = Gate { if_flag: None, unless_flag: Some(SaveTest::parse("tea")), sets: Some("coffee".into()) };
+        let door = MapObject::dialogue(Hitbox::new(0, 0, 8, 8), "menu").with_id(Some(3)).with_gate(gate);
+
+        let report = check(
+            &script,
+            &scenes,
+            &maps(vec![("cafe", vec![door])]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        assert!(report.is_clean(), "{:?}", report.errors);
+        let sites = |name: &str| report.flags.names[name].clone();
+        let set = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<BTreeSet<_>>();
+        assert_eq!(
+            sites("tea"),
+            FlagSites {
+                writes: set(&["dialogue `menu`", "scene `brew`"]),
+                reads: set(&["dialogue `after`", "map `cafe` object[id=3]"]),
+            }
+        );
+        assert_eq!(
+            sites("coffee"),
+            FlagSites {
+                writes: set(&["dialogue `menu`", "map `cafe` object[id=3]"]),
+                reads: set(&["scene `brew`"]),
+            }
+        );
+        assert_eq!(sites("idle"), FlagSites::default());
+        assert_eq!(
+            report.flags.to_string().lines().find(|l| l.starts_with("idle")),
+            Some("idle: never set; never read")
+        );
+    }
+
     /// A `.tmj` gate flag name outside the declared vocabulary is an error —
     /// the one place with no parse-time enforcement (`eggtext` only checks
     /// `#set`/`#if` inside `.eggtext` itself).
@@ -1297,7 +2115,7 @@ mod tests {
         use crate::world::map::{Gate, MapObject};
         use egg_render::geometry::Hitbox;
         let gated = MapObject::dialogue(Hitbox::new(0, 0, 8, 8), "d")
-            .with_gate(Gate { if_flag: Some("undeclared".to_string()), unless_flag: None, sets: None });
+            .with_gate(Gate { if_flag: Some(SaveTest::parse("undeclared")), unless_flag: None, sets: None });
         let script = script("#dialogue d\n    Hi.");
         let report = check(
             &script,
@@ -1305,12 +2123,59 @@ mod tests {
             &maps(vec![("here", vec![gated])]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 1);
         assert!(matches!(&report.errors[0], Finding::DanglingMapFlag { flag, .. } if flag == "undeclared"));
     }
 
+    /// Counters are cross-referenced like flags: a map gate comparison and a
+    /// scene `add` naming an undeclared `#var` are errors, and a declared var
+    /// that is only written (never compared) is a warning; one both written
+    /// and compared is clean.
+    #[test]
+    fn var_references_and_classification() {
+        use crate::data::scene;
+        use crate::world::map::{Gate, MapObject};
+        use egg_render::geometry::Hitbox;
+        let script = script(
+            "#var visits\n#var written_only\n\
+             #dialogue d\n\
+             \x20   #add visits 1\n\
+             \x20   #setv written_only 2\n\
+             \x20   #if visits >= 3\n\
+             \x20       Again!",
+        );
+        let gated = MapObject::dialogue(Hitbox::new(0, 0, 8, 8), "d").with_gate(Gate {
+            if_flag: Some(SaveTest::parse("laps > 1")),
+            ..Gate::default()
+        });
+        let scenes = scene::parse("#cutscene a\n    add steps 1").unwrap();
+        let report = check(
+            &script,
+            &scenes,
+            &maps(vec![("here", vec![gated])]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        let undeclared: Vec<&str> = report
+            .errors
+            .iter()
+            .filter_map(|e| match e {
+                Finding::UndeclaredVar { var, .. } => Some(var.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(undeclared, ["laps", "steps"], "{:?}", report.errors);
+        assert!(report.warnings.contains(&Finding::UnusedVar { var: "written_only".into() }));
+        assert!(!report.warnings.contains(&Finding::UnusedVar { var: "visits".into() }));
+    }
+
     /// A scene's `spawn`/`bind` preset, `dialogue`/`load`/`sound` targets,
     /// and `set` flag are each cross-referenced; a scene naming real targets
     /// is clean.
@@ -1335,6 +2200,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean(), "{:?}", report.errors);
@@ -1354,6 +2221,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 5, "{:?}", report.errors);
@@ -1381,6 +2250,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
@@ -1411,6 +2282,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 1, "{:?}", report.errors);
@@ -1450,6 +2323,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean(), "{:?}", report.errors);
@@ -1468,6 +2343,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean(), "{:?}", report.errors);
@@ -1498,6 +2375,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert_eq!(report.errors.len(), 2, "{:?}", report.errors);
@@ -1505,6 +2384,176 @@ mod tests {
         assert!(report.errors.iter().any(|e| matches!(e, Finding::SceneDanglingFlag { flag, .. } if flag == "undeclared")));
     }
 
+    /// A scene `if`/`elif` condition on an undeclared flag is an error, a
+    /// declared one counts as *read* for the dead-flag sweep, and every side
+    /// of the chain is walked — a bad reference in the untaken `else` is
+    /// still caught.
+    #[test]
+    fn scene_if_conditions_are_checked_and_count_as_reads() {
+        use crate::data::scene;
+        let script = script("#flag met\n#flag fed");
+        let scenes = scene::parse(
+            "#cutscene a\n\
+             \x20   if met\n\
+             \x20       set fed true\n\
+             \x20   elif not typo\n\
+             \x20       wait 1\n\
+             \x20   else\n\
+             \x20       sound nope",
+        )
+        .expect("parse scene");
+        let report = check(
+            &script,
+            &scenes,
+            &maps(vec![]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        assert_eq!(report.errors.len(), 2, "{:?}", report.errors);
+        assert!(
+            report
+                .errors
+                .iter()
+                .any(|e| matches!(e, Finding::SceneDanglingFlag { flag, .. } if flag == "typo"))
+        );
+        assert!(
+            report
+                .errors
+                .iter()
+                .any(|e| matches!(e, Finding::SceneDanglingSound { name, .. } if name == "nope"))
+        );
+        assert!(
+            report
+                .warnings
+                .contains(&Finding::FlagNeverSet { flag: "met".into() })
+        );
+        assert!(
+            report
+                .warnings
+                .contains(&Finding::FlagNeverRead { flag: "fed".into() })
+        );
+    }
+
+    /// A scene's `give`/`take ITEM` steps and `has ITEM` conditions are
+    /// checked against the item registry, inside branches too; known keys
+    /// are clean.
+    #[test]
+    fn scene_item_keys_are_checked_against_the_registry() {
+        use crate::data::scene;
+        let scenes = scene::parse(
+            "#cutscene a\n\
+             \x20   give chegg\n\
+             \x20   take nope\n\
+             \x20   if has ff\n\
+             \x20       give lm\n\
+             \x20   elif not has ghost\n\
+             \x20       wait 1",
+        )
+        .expect("parse scene");
+        let report = check(
+            &ScriptFile::default(),
+            &scenes,
+            &maps(vec![]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        let dangling: Vec<&str> = report
+            .errors
+            .iter()
+            .filter_map(|e| match e {
+                Finding::SceneDanglingItem { item, .. } => Some(item.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(dangling, ["nope", "ghost"], "{:?}", report.errors);
+        assert_eq!(report.errors.len(), 2);
+    }
+
+    /// A `warp` step's map — even one nested in an `if` branch — must be a
+    /// loaded map.
+    #[test]
+    fn scene_warp_targets_are_checked_against_the_maps() {
+        use crate::data::scene;
+        let scenes = scene::parse(
+            "#cutscene a\n\
+             \x20   warp town 8 8\n\
+             \x20   if has chegg\n\
+             \x20       warp nowhere 0 0 fade 10",
+        )
+        .expect("parse scene");
+        let mut items = GameItems::new();
+        items.add("chegg", 0);
+        let report = check(
+            &ScriptFile::default(),
+            &scenes,
+            &maps(vec![("town", vec![])]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &items,
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        assert!(
+            matches!(
+                report.errors.as_slice(),
+                [Finding::SceneDanglingWarp { name, .. }] if name == "nowhere"
+            ),
+            "{:?}",
+            report.errors
+        );
+    }
+
+    /// A schedule's preset, and each slot's map, pose and flags, resolve
+    /// against the data web; the slot's conditions count as reads in the
+    /// flag dataflow.
+    #[test]
+    fn schedules_are_checked() {
+        let file = crate::data::eggdata::parse(
+            "[schedules.may]\n\
+             preset = \"may\"\n\
+             [[schedules.may.slots]]\n\
+             if = \"met_may\"\n\
+             unless = \"visits > 2\"\n\
+             map = \"town\"\n\
+             at = [0, 0]\n\
+             [[schedules.may.slots]]\n\
+             if = \"typo\"\n\
+             map = \"nowhere\"\n\
+             at = [0, 0]\n\
+             pose = \"slump\"\n\
+             [schedules.ghost]\n\
+             preset = \"ghost\"\n",
+        )
+        .expect("parse schedules");
+        let report = check(
+            &script("#flag met_may\n#var visits\n"),
+            &SceneFile::default(),
+            &maps(vec![("town", vec![])]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::from_data(&file),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        assert_eq!(
+            report.errors,
+            [
+                Finding::ScheduleDanglingPreset { creature: "ghost".into(), name: "ghost".into() },
+                Finding::ScheduleDanglingMap { creature: "may".into(), slot: 1, name: "nowhere".into() },
+                Finding::ScheduleDanglingPose { creature: "may".into(), slot: 1, name: "slump".into() },
+                Finding::ScheduleDanglingFlag { creature: "may".into(), slot: 1, flag: "typo".into() },
+            ]
+        );
+        assert!(report.flags.is_read("met_may"));
+        assert!(report.flags.is_read("visits"));
+    }
+
     /// A `#path` block no scene references is dead weight — a warning, not an
     /// error.
     #[test]
@@ -1519,6 +2568,8 @@ mod tests {
             &maps(vec![]),
             &Portraits::builtin(),
             &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
             ENGINE_DIALOGUE_ROOTS,
         );
         assert!(report.is_clean(), "{:?}", report.errors);
@@ -1526,6 +2577,225 @@ mod tests {
         assert!(matches!(&report.warnings[0], Finding::UnreferencedPath { name } if name == "orphan"));
     }
 
+    /// A preset's `patrol` path must be a `#path` block, and counts as a
+    /// reference to it.
+    #[test]
+    fn patrol_paths_are_checked_and_referenced() {
+        use crate::data::eggdata::DataFile;
+        use crate::data::scene;
+        let shipped = crate::data::eggdata::parse(crate::data::eggdata::shipped_source()).unwrap();
+        let mut file = DataFile::default();
+        for (name, path) in [("guard", "beat"), ("lost", "nowhere")] {
+            let mut def = shipped.presets["critter"].clone();
+            def.move_mode = PresetMove::Patrol(path.to_string());
+            file.presets.insert(name.to_string(), def);
+        }
+        let mut scenes = scene::SceneFile::default();
+        scenes.paths.insert("beat".to_string(), vec![((1, 0), 4), ((-1, 0), 4)]);
+
+        let report = check(
+            &ScriptFile::default(),
+            &scenes,
+            &maps(vec![]),
+            &Portraits::builtin(),
+            &Presets::from_data(&file),
+            &Schedules::default(),
+            &GameItems::default(),
+            ENGINE_DIALOGUE_ROOTS,
+        );
+        assert_eq!(
+            report.errors,
+            [Finding::PresetDanglingPath { preset: "lost".into(), name: "nowhere".into() }]
+        );
+        assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+    }
+
+    /// A preset's `talk` must name real dialogue or a real cutscene, and
+    /// counts as a reference to its dialogue.
+    #[test]
+    fn preset_talk_is_checked_and_referenced() {
+        use crate::data::eggdata::DataFile;
+        let shipped = crate::data::eggdata::parse(crate::data::eggdata::shipped_source()).unwrap();
+        let mut file = DataFile::default();
+        let talks = [
+            ("chatty", Talk::Dialogue("hello".into())),
+            ("mumbly", Talk::Dialogue("nope".into())),
+            ("showy", Talk::Cutscene("missing".into())),
+        ];
+        for (name, talk) in talks {
+            let mut def = shipped.presets["critter"].clone();
+            def.talk = Some(talk);
+            file.presets.insert(name.to_string(), def);
+        }
+
+        let report = check(
+            &script("#dialogue hello\n    Hi!"),
+            &SceneFile::default(),
+            &maps(vec![]),
+            &Portraits::builtin(),
+            &Presets::from_data(&file),
+            &Schedules::default(),
+            &GameItems::default(),
+            &[],
+        );
+        assert_eq!(
+            report.errors,
+            [
+                Finding::PresetDanglingDialogue { preset: "mumbly".into(), key: "nope".into() },
+                Finding::PresetDanglingCutscene { preset: "showy".into(), name: "missing".into() },
+            ]
+        );
+        assert!(report.warnings.is_empty(), "`hello` is referenced: {:?}", report.warnings);
+    }
+
+    /// A placed creature's preset, patrol path and talk are all checked, and
+    /// its path and dialogue count as referenced.
+    #[test]
+    fn placed_creatures_are_checked_and_referenced() {
+        use crate::world::map::PlacedCreature;
+        use crate::world::player::PresetId;
+        let mut scenes = SceneFile::default();
+        scenes.paths.insert("beat".into(), vec![((1, 0), 8)]);
+        let placed = |x, preset: &str, move_mode, talk| {
+            let creature = PlacedCreature {
+                move_mode,
+                talk,
+                ..PlacedCreature::new(PresetId::new(preset))
+            };
+            MapObject::creature(Hitbox::new(x, 0, 8, 8), creature)
+        };
+        let objects = vec![
+            placed(0, "critter", Some(PresetMove::Patrol("beat".into())), Some(Talk::Dialogue("hello".into()))),
+            placed(8, "ghost", Some(PresetMove::Patrol("nowhere".into())), None),
+            placed(16, "dog", None, Some(Talk::Cutscene("missing".into()))),
+        ];
+
+        let report = check(
+            &script("#dialogue hello\n    Hi!"),
+            &scenes,
+            &maps(vec![("town", objects)]),
+            &Portraits::builtin(),
+            &Presets::builtin(),
+            &Schedules::default(),
+            &GameItems::default(),
+            &[],
+        );
+        assert_eq!(
+            report.errors,
+            [
+                Finding::DanglingPreset { map: "town".into(), object: ObjectLabel::Pos(8, 0), name: "ghost".into() },
+                Finding::DanglingPath { map: "town".into(), object: ObjectLabel::Pos(8, 0), name: "nowhere".into() },
+                Finding::DanglingCutscene { map: "town".into(), object: ObjectLabel::Pos(16, 0), name: "missing".into() },
+            ]
+        );
+        assert!(report.warnings.is_empty(), "`hello` and `beat` are referenced: {:?}", report.warnings);
+    }
+
+    /// A painted map `w`×`h` pixels: the `solid` pixels of its collision mask
+    /// are walls, and `objects` is its object layer's JSON.
+    fn painted_map(
+        w: u32,
+        h: u32,
+        solid: impl Fn(u32, u32) -> bool,
+        objects: &str,
+    ) -> crate::data::tiled::TiledMap {
+        use egg_render::image::{Rgba, RgbaImage};
+        let json = format!(
+            r#"{{
+                "width": {}, "height": {}, "tilesets": [],
+                "layers": [
+                    {{"type": "imagelayer", "name": "collision", "id": 1, "image": "m.png"}},
+                    {{"type": "objectgroup", "name": "objects", "id": 2, "objects": [{objects}]}}
+                ]
+            }}"#,
+            w / 8,
+            h / 8
+        );
+        let mut map = crate::data::tiled::from_json(json.as_bytes()).unwrap();
+        let mut mask = RgbaImage::new(w, h);
+        for y in 0..h {
+            for x in (0..w).filter(|&x| solid(x, y)) {
+                mask.set_pixel(x, y, Rgba::new(0, 0, 0, 255));
+            }
+        }
+        map.attach_image("m.png", mask);
+        map
+    }
+
+    /// The connectivity pass walks the world from the start: a sign behind a
+    /// wall, a map with no way back out, and a map no warp leads to are each
+    /// reported — the trap as an error, the dead weight as warnings.
+    #[test]
+    fn connectivity_reports_what_the_player_cant_walk_to() {
+        let mut maps = MapStore::default();
+        // `a`: a wall down x 32..40 parts a warp to `b` (left) from a sign (right).
+        maps.insert(
+            "a",
+            painted_map(
+                64,
+                32,
+                |x, _| (32..40).contains(&x),
+                r#"{"id": 1, "type": "warp", "x": 0, "y": 24, "width": 8, "height": 8,
+                    "properties": [
+                        {"name": "to_map", "type": "string", "value": "b"},
+                        {"name": "to_x", "type": "string", "value": "8"},
+                        {"name": "to_y", "type": "string", "value": "8"}
+                    ]},
+                   {"id": 2, "type": "", "x": 48, "y": 8, "width": 8, "height": 8,
+                    "properties": [{"name": "description", "type": "string", "value": "sign"}]}"#,
+            ),
+        );
+        // `b`: open, but nothing leads back out. `c`: nothing leads in.
+        maps.insert("b", painted_map(32, 32, |_, _| false, ""));
+        maps.insert("c", painted_map(32, 32, |_, _| false, ""));
+        let mut player = Shell { local_hitbox: Hitbox::new(0, 0, 8, 8), ..Shell::default() };
+        player.pos = Vec2::new(8, 8);
+
+        let sheet = IndexedImage::new(8, 8);
+        let report = check_connectivity(&maps, &sheet, &SceneFile::default(), "a", &player);
+        let shown = |found: &[Finding]| found.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            shown(&report.errors),
+            ["map `b`: arriving at (8, 8) from map `a` object[id=1] leaves no warp in walking reach"]
+        );
+        assert_eq!(
+            shown(&report.warnings),
+            [
+                "map `a` object[id=2]: interactable can't be reached from any entry point",
+                "map `c` can't be reached through warps from `a`",
+            ]
+        );
+    }
+
+    /// A warp's unknown `transition` is a warning naming the value; a known
+    /// one (any case) and a non-warp's property are left alone.
+    #[test]
+    fn transitions_report_an_unknown_name() {
+        let warp = |id: usize, transition: &str| {
+            format!(
+                r#"{{"id": {id}, "type": "warp", "x": 0, "y": 0, "width": 8, "height": 8,
+                    "properties": [
+                        {{"name": "to_x", "type": "string", "value": "8"}},
+                        {{"name": "to_y", "type": "string", "value": "8"}},
+                        {{"name": "transition", "type": "string", "value": "{transition}"}}
+                    ]}}"#
+            )
+        };
+        let sign = r#"{"id": 3, "type": "", "x": 16, "y": 0, "width": 8, "height": 8,
+            "properties": [
+                {"name": "description", "type": "string", "value": "sign"},
+                {"name": "transition", "type": "string", "value": "swirl"}
+            ]}"#;
+        let objects = format!("{}, {}, {sign}", warp(1, "Iris"), warp(2, "irsi"));
+        let mut maps = MapStore::default();
+        maps.insert("a", painted_map(32, 32, |_, _| false, &objects));
+
+        let report = check_transitions(&maps);
+        assert!(report.errors.is_empty());
+        let shown: Vec<String> = report.warnings.iter().map(|f| f.to_string()).collect();
+        assert_eq!(shown, ["map `a` object[id=2]: unknown transition \"irsi\", played as a cut"]);
+    }
+
     /// `Report`'s `Display` prints errors before warnings, one per line.
     #[test]
     fn report_display_groups_errors_before_warnings() {
@@ -1610,6 +2880,43 @@ mod tests {
         }
     }
 
+    /// Placeholders are skeleton too: a translation may move one within its
+    /// line, but dropping or retargeting one (in text or a choice option) is
+    /// a mismatch.
+    #[test]
+    fn overlay_placeholders_are_skeleton() {
+        let base = script(
+            "#dialogue d\n    You have {egg_count} eggs and a {item:chegg}.\n\
+             #dialogue c\n    Take it?\n    #choice\n    #option Take {item:chegg}\n    #option No",
+        );
+        let moved = script(
+            "#dialogue d\n    Un {item:chegg} y {egg_count} huevos.\n\
+             #dialogue c\n    ¿Lo tomas?\n    #choice\n\
+             \x20   #option Tomar {item:chegg}\n    #option No",
+        );
+        assert!(check_overlay(&base, &moved, "es").errors.is_empty());
+        let dropped = script(
+            "#dialogue d\n    Tienes {egg_count} huevos.\n\
+             #dialogue c\n    ¿Lo tomas?\n    #choice\n\
+             \x20   #option Tomar {item:egg}\n    #option No",
+        );
+        let report = check_overlay(&base, &dropped, "es");
+        let paths: Vec<&str> = report
+            .errors
+            .iter()
+            .map(|finding| match finding {
+                Finding::OverlaySkeletonMismatch { path, .. } => path.as_str(),
+                other => panic!("expected OverlaySkeletonMismatch, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(paths.len(), 2, "{paths:?}");
+        assert!(
+            paths.iter().any(|p| p.contains("{item:egg}") && p.contains("option 1")),
+            "{paths:?}"
+        );
+        assert!(paths.iter().any(|p| p.contains("placeholders differ")), "{paths:?}");
+    }
+
     /// A directive present in the base but dropped in the overlay is a
     /// mismatch — a translator can't silently drop a `#sound`.
     #[test]
@@ -1,4 +1,5 @@
 use crate::data::tiled::TileLayer;
+use crate::world::transition::Cover;
 use egg_platform::{HEIGHT, SWEETIE_16, WIDTH};
 use egg_render::image::{IndexedImage, Rgba, RgbaImage};
 use egg_render::{MapOptions, SpriteOptions};
@@ -11,6 +12,10 @@ pub struct DrawState {
     pub indexed_sprites: IndexedImage,
 
     pub palettes: Vec<Vec<[u8; 3]>>,
+
+    /// This frame's warp-transition cover, painted over the finished frame by
+    /// the walkaround's `composite_into`; `None` when no transition is running.
+    pub cover: Option<Cover>,
 }
 
 impl Default for DrawState {
@@ -21,6 +26,7 @@ impl Default for DrawState {
             indexed_canvas: vec![IndexedImage::new(WIDTH as usize, HEIGHT as usize); 2],
             indexed_sprites: IndexedImage::new(0, 0),
             palettes: vec![default_palette()],
+            cover: None,
         }
     }
 }
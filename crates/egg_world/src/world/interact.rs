@@ -15,6 +15,7 @@
 // this program. If not, see <https://www.gnu.org/licenses/>.
 
 use egg_render::geometry::{Hitbox, Vec2};
+use serde::{Deserialize, Serialize};
 
 /// The effect payload of an interaction [`MapObject`](crate::world::map::MapObject):
 /// what running it does. Resolved against the dialogue registry / dispatched
@@ -30,6 +31,39 @@ pub enum Interaction {
     None,
 }
 
+/// What talking to a creature opens — the serialisable slice of
+/// [`Interaction`] a [`Shell`](crate::world::player::Shell) carries as its
+/// `talk`. Authored on a preset in `data.toml` (`talk = { dialogue = "KEY" }`
+/// or `talk = { cutscene = "NAME" }`, spelled like an item's `use`) and
+/// overridden per creature from the map editor; persisted with the shell,
+/// unlike the dog's derived pet
+/// [`interaction`](crate::world::player::Shell::interaction).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Talk {
+    /// A dialogue-registry key.
+    Dialogue(String),
+    /// A cutscene-registry name.
+    Cutscene(String),
+}
+
+impl Talk {
+    /// The [`Interaction`] firing this talk runs.
+    pub fn interaction(&self) -> Interaction {
+        match self {
+            Talk::Dialogue(key) => Interaction::Dialogue(key.clone()),
+            Talk::Cutscene(name) => Interaction::Cutscene(name.clone()),
+        }
+    }
+
+    /// The dialogue key or cutscene name, whichever kind this is.
+    pub fn name(&self) -> &str {
+        match self {
+            Talk::Dialogue(name) | Talk::Cutscene(name) => name,
+        }
+    }
+}
+
 /// A small 'scripting' verb for the walkaround section: the one-off behaviours a
 /// map object can run when triggered (toggling a companion, sounding a piano
 /// key, spawning creatures…), dispatched by
@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::data::eggdata::PresetMove;
 use crate::data::metasprite::{MetaCell, MetaSprite};
-use crate::data::save::SaveData;
+use crate::data::save::{SaveData, SaveTest};
 use crate::data::sound::{SfxData, music::MusicTrack};
 use crate::data::tiled::{ImageLayer, TiledMap, TiledMapLayer};
 use crate::draw_state::BgColour;
@@ -13,7 +14,9 @@ use egg_render::{MapOptions, SpriteOptions};
 use egg_render::image::{IndexedImage, RgbaImage};
 use crate::world::animation::AnimFrame;
 use crate::world::camera::CameraBounds;
-use crate::world::interact::{InteractFn, Interaction};
+use crate::world::interact::{InteractFn, Interaction, Talk};
+use crate::world::player::PresetId;
+use crate::world::transition::Transition;
 
 /// Alpha at or above which a painted collision-mask pixel counts as **solid**.
 ///
@@ -940,14 +943,15 @@ impl Trigger {
         }
     }
     /// The trigger an effect of `effect`'s kind defaults to when none is
-    /// authored: warps to [`Any`](Self::Any), interactions to
-    /// [`Press`](Self::Press). The single source of truth shared by
-    /// [`MapObject::new`] and the `.tmj` codec (which serialises a trigger only
-    /// when it differs from this default, keeping existing files byte-stable).
+    /// authored: warps to [`Any`](Self::Any), interactions (and placed
+    /// creatures, which never fire) to [`Press`](Self::Press). The single
+    /// source of truth shared by [`MapObject::new`] and the `.tmj` codec (which
+    /// serialises a trigger only when it differs from this default, keeping
+    /// existing files byte-stable).
     pub fn default_for(effect: &ObjectEffect) -> Self {
         match effect {
             ObjectEffect::Warp(_) => Self::Any,
-            ObjectEffect::Interact(_) => Self::Press,
+            ObjectEffect::Interact(_) | ObjectEffect::Creature(_) => Self::Press,
         }
     }
 
@@ -997,6 +1001,11 @@ impl Trigger {
 /// - [`sets`](Self::sets) — a flag **set when the object fires** (the `sets`
 ///   property), the one-shot side effect.
 ///
+/// `if`/`unless` may instead hold a numeric comparison on a story counter —
+/// `visits >= 3`, a [`VarTest`](crate::data::save::VarTest) — so both are a
+/// parsed [`SaveTest`], read through [`SaveData::test`] like a dialogue `#if`;
+/// `sets` is always a flag.
+///
 /// The common one-shot is `unless X` + `sets X`: the object fires once, sets `X`,
 /// and its own gate then holds it off forever — persisted through the normal save
 /// flags. The two gates can name different flags than `sets` for open-ended
@@ -1005,36 +1014,39 @@ impl Trigger {
 /// objects and old maps are unaffected.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Gate {
-    /// Fires only when this flag is set. `None` = no requirement.
-    pub if_flag: Option<String>,
-    /// Fires only when this flag is clear. `None` = no bar.
-    pub unless_flag: Option<String>,
+    /// Fires only when this flag is set (or this comparison holds). `None` =
+    /// no requirement.
+    pub if_flag: Option<SaveTest>,
+    /// Fires only when this flag is clear (or this comparison fails). `None` =
+    /// no bar.
+    pub unless_flag: Option<SaveTest>,
     /// A flag set (true) when the object fires — the one-shot latch. `None` = no
     /// side effect.
     pub sets: Option<String>,
 }
 impl Gate {
     /// Whether an object with this gate may fire against the live `save`: its
-    /// `if` flag (if any) must be set and its `unless` flag (if any) clear. An
+    /// `if` condition (if any) must hold and its `unless` condition (if any)
+    /// must not — each a flag or a comparison (see [`SaveData::test`]). An
     /// empty gate always allows. The `sets` side effect is applied separately at
     /// fire time (see [`MapObject`]'s firing sites), not here.
     pub fn allows(&self, save: &SaveData) -> bool {
-        self.if_flag.as_deref().is_none_or(|f| save.flag(f))
-            && self.unless_flag.as_deref().is_none_or(|f| !save.flag(f))
+        self.if_flag.as_ref().is_none_or(|c| save.test(c))
+            && self.unless_flag.as_ref().is_none_or(|c| !save.test(c))
     }
     /// The authored `.tmj` properties this gate serialises to, in `if`, `unless`,
     /// `sets` order — only the set fields, so an ungated object emits nothing and
     /// its file stays byte-stable. The inverse of the gate parse in
     /// [`TiledObject::gate`](crate::data::tiled). Each pair is `(property name,
-    /// flag value)`.
-    pub fn properties(&self) -> impl Iterator<Item = (&'static str, &str)> {
+    /// value)`, a condition in its canonical spelling.
+    pub fn properties(&self) -> impl Iterator<Item = (&'static str, String)> {
         [
-            ("if", &self.if_flag),
-            ("unless", &self.unless_flag),
-            ("sets", &self.sets),
+            ("if", self.if_flag.as_ref().map(SaveTest::to_string)),
+            ("unless", self.unless_flag.as_ref().map(SaveTest::to_string)),
+            ("sets", self.sets.clone()),
         ]
         .into_iter()
-        .filter_map(|(name, flag)| flag.as_deref().map(|value| (name, value)))
+        .filter_map(|(name, value)| value.map(|value| (name, value)))
     }
 }
 
@@ -1056,6 +1068,8 @@ impl Gate {
 /// - warp **narration** ([`Warp::narration`]) is *orthogonal*: it doesn't change
 ///   when a warp fires, only what happens at fire time (show dialogue first, warp
 ///   once it closes).
+/// - the warp **[`Transition`]** is orthogonal likewise: it only changes how
+///   the landing plays out on screen (with input held until it finishes).
 ///
 /// Defaults preserve historical behaviour: the constructors set `trigger` from
 /// the effect kind ([`Trigger::default_for`]) — warps [`Trigger::Any`],
@@ -1097,11 +1111,58 @@ pub struct MapObject {
 }
 
 /// What a [`MapObject`] does when triggered: warp the player, or run an
-/// [`Interaction`] (dialogue / one-off function / nothing).
+/// [`Interaction`] (dialogue / one-off function / nothing). A
+/// [`Creature`](Self::Creature) never triggers — it marks where a creature
+/// starts out.
 #[derive(Clone, Debug)]
 pub enum ObjectEffect {
     Warp(Warp),
     Interact(Interaction),
+    Creature(PlacedCreature),
+}
+
+/// A creature authored into the map (a Tiled `type = "creature"` object),
+/// standing at the owning [`MapObject`]'s hitbox top-left. It is spawned
+/// among the map's creatures the first time the map is visited; after that
+/// the save's per-map creatures (wherever they have wandered to, whatever a
+/// cutscene has done to them) are what the map holds, and the object is only
+/// authoring data. Every field past the preset is an optional override of
+/// what the preset spawns with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlacedCreature {
+    pub preset: PresetId,
+    /// The [`Shell::id`](crate::world::player::Shell::id) a cutscene or
+    /// schedule addresses it by.
+    pub id: Option<String>,
+    /// Its wander behaviour, in place of the preset's own.
+    pub move_mode: Option<PresetMove>,
+    /// The `(dx, dy)` it stands facing until it first moves.
+    pub facing: Option<(i8, i8)>,
+    /// What interacting with it opens, in place of the preset's own talk.
+    pub talk: Option<Talk>,
+}
+
+impl PlacedCreature {
+    /// The facings a placement can be authored with, by the name a Tiled
+    /// `facing` property spells them — in the order the editor cycles them.
+    pub const FACINGS: [(&'static str, (i8, i8)); 4] =
+        [("down", (0, 1)), ("left", (-1, 0)), ("up", (0, -1)), ("right", (1, 0))];
+
+    /// A placed `preset` with nothing overridden.
+    pub fn new(preset: PresetId) -> Self {
+        Self {
+            preset,
+            id: None,
+            move_mode: None,
+            facing: None,
+            talk: None,
+        }
+    }
+    /// The name of the authored [`facing`](Self::facing), if it has one.
+    pub fn facing_name(&self) -> Option<&'static str> {
+        let dir = self.facing?;
+        Self::FACINGS.iter().find(|(_, d)| *d == dir).map(|(name, _)| *name)
+    }
 }
 
 impl MapObject {
@@ -1162,6 +1223,10 @@ impl MapObject {
             None,
         )
     }
+    /// A placed creature standing at `hitbox`'s top-left.
+    pub fn creature(hitbox: Hitbox, creature: PlacedCreature) -> Self {
+        Self::new(hitbox, ObjectEffect::Creature(creature), None)
+    }
     /// Attach an animated sprite drawn at the object's location.
     pub fn with_sprite(mut self, frames: Vec<AnimFrame>) -> Self {
         self.sprite = Some(frames);
@@ -1249,6 +1314,10 @@ pub struct Warp {
     /// `None` (the default) warps land instantly, exactly as before. Orthogonal
     /// to [`WarpMode`] and to the object's [`Trigger`].
     pub narration: Option<String>,
+    /// The screen effect played while this warp swaps maps (`transition` in
+    /// `.tmj`). [`Cut`](Transition::Cut), the default, lands instantly;
+    /// anything else holds input for the covering and uncovering frames.
+    pub transition: Transition,
 }
 
 impl Warp {
@@ -1261,6 +1330,7 @@ impl Warp {
             mode: WarpMode::Interact,
             sound: None,
             narration: None,
+            transition: Transition::Cut,
         }
     }
     pub fn with_flip(self, flip: Axis) -> Self {
@@ -1282,6 +1352,9 @@ impl Warp {
             ..self
         }
     }
+    pub fn with_transition(self, transition: Transition) -> Self {
+        Self { transition, ..self }
+    }
     pub fn target(&self) -> Vec2 {
         Vec2::new(self.to.x, self.to.y)
     }
@@ -1362,7 +1435,7 @@ mod tests {
         assert!(Gate::default().allows(&save), "empty gate always allows");
 
         let if_key = Gate {
-            if_flag: Some("has_key".into()),
+            if_flag: Some(SaveTest::parse("has_key")),
             ..Gate::default()
         };
         assert!(!if_key.allows(&save), "if-flag unset ⇒ blocked");
@@ -1370,7 +1443,7 @@ mod tests {
         assert!(if_key.allows(&save), "if-flag set ⇒ allowed");
 
         let unless_open = Gate {
-            unless_flag: Some("door_open".into()),
+            unless_flag: Some(SaveTest::parse("door_open")),
             ..Gate::default()
         };
         assert!(unless_open.allows(&save), "unless-flag clear ⇒ allowed");
@@ -1379,8 +1452,8 @@ mod tests {
 
         // Both conditions compose: needs has_key set AND door_open clear.
         let both = Gate {
-            if_flag: Some("has_key".into()),
-            unless_flag: Some("door_open".into()),
+            if_flag: Some(SaveTest::parse("has_key")),
+            unless_flag: Some(SaveTest::parse("door_open")),
             sets: Some("door_open".into()),
         };
         assert!(!both.allows(&save), "door_open set blocks despite has_key");
@@ -1388,6 +1461,25 @@ mod tests {
         assert!(both.allows(&save), "has_key set and door_open clear ⇒ allowed");
     }
 
+    /// A gate condition may be a counter comparison instead of a flag name:
+    /// `if visits >= 3` opens on the third visit, `unless visits > 4` shuts
+    /// again after the fifth.
+    #[test]
+    fn gate_allows_numeric_comparisons() {
+        let mut save = SaveData::default();
+        let gate = Gate {
+            if_flag: Some(SaveTest::parse("visits >= 3")),
+            unless_flag: Some(SaveTest::parse("visits > 4")),
+            ..Gate::default()
+        };
+        let mut open = Vec::new();
+        for _ in 0..6 {
+            save.add_var("visits", 1);
+            open.push(gate.allows(&save));
+        }
+        assert_eq!(open, [false, false, true, true, false, false]);
+    }
+
     /// A tiny self-contained modern map: one 4×4 tile layer (the collision
     /// layer) plus an empty object layer (which is what marks it as modern).
     fn synthetic_modern_map() -> TiledMap {
@@ -0,0 +1,442 @@
+//! Grid pathfinding over a map's collision: A* on a lattice of candidate
+//! positions for one body, clear where the body's hitbox overlaps no solid
+//! pixel of any collision layer — tile colliders and painted
+//! ([`PAINTED_SOLID_ALPHA`](crate::world::map::PAINTED_SOLID_ALPHA)) masks
+//! alike, since both answer through [`layer_collides`]. The cutscene runtime's
+//! `walk!`/`to!` motions follow its routes; anything else steering a shell
+//! around the room (NPC AI) calls [`find_path`] the same way.
+//!
+//! The lattice is anchored on the start position and spaced [`PATH_STEP`] px
+//! apart (narrower for a body smaller than that), so the route starts exactly
+//! where the body stands. Moving between two clear neighbours never crosses a
+//! solid pixel: the body's hitbox at least spans the spacing, so the two
+//! end-point boxes cover every box in between. Diagonals also need both
+//! orthogonal neighbours clear, so a route never cuts a corner `walk` would
+//! refuse.
+//!
+//! [`walk_flood`] answers the broader question — everywhere a shell can get to
+//! from a spot — by stepping [`Shell::walk`] itself a pixel at a time, so its
+//! answer is exactly the player's, ramps and corner rules included.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use egg_platform::NullConsole;
+use egg_render::geometry::{Hitbox, Vec2};
+
+use crate::data::tiled::TiledMap;
+use crate::world::map::{MapInfo, layer_collides};
+use crate::world::player::Shell;
+
+/// Lattice spacing, in pixels, for a body at least this big on both axes.
+pub const PATH_STEP: i16 = 4;
+
+/// Most lattice positions a single search will consider, so a vast open map
+/// can't stall a frame. Past this the search gives up as unreachable.
+const MAX_NODES: usize = 1 << 18;
+
+/// Most positions [`walk_flood`] will lay out for one search — about a
+/// 180×180-tile map. Every position is one pixel, so this is far more than
+/// [`MAX_NODES`], but a flood does only a few cheap probes per position.
+const MAX_FLOOD: usize = 1 << 21;
+
+/// Cost of one orthogonal lattice step. A diagonal costs one more: both take
+/// the same number of frames, but the nudge keeps routes from wandering
+/// diagonally when a straight line is just as fast.
+const ORTHO: u32 = 10;
+const DIAG: u32 = ORTHO + 1;
+
+/// Plan a route for a body with hitbox `body` (relative to its position, like
+/// [`Shell::local_hitbox`](crate::world::player::Shell::local_hitbox)) from
+/// `from` to `to` across `map`'s collision layers.
+///
+/// Returns the waypoints to visit in order, `from` excluded and `to` last;
+/// collinear lattice steps are merged, so each waypoint is a turn. Empty when
+/// `from == to`. `None` when no clear lattice position lies next to `to`, or
+/// the search runs out of map: the caller decides whether to walk straight
+/// instead. A map with no collision layers is a straight line.
+pub fn find_path(map: &MapInfo, body: Hitbox, from: Vec2, to: Vec2) -> Option<Vec<Vec2>> {
+    if from == to {
+        return Some(Vec::new());
+    }
+    let step = PATH_STEP.min(body.w).min(body.h).max(1);
+    let Some(area) = search_area(map, body, from, to) else {
+        return Some(vec![to]);
+    };
+    let grid = Lattice::new(area, from, step)?;
+
+    // `from` itself is always treated as clear: `walk` only probes the body's
+    // edges, so a shell may legally stand overlapping a thin solid sliver.
+    let mut clear = vec![None; grid.len()];
+    let start = grid.index(0, 0)?;
+    clear[start] = Some(true);
+    let mut is_clear = |grid: &Lattice, i: usize| -> bool {
+        *clear[i].get_or_insert_with(|| {
+            let (x, y) = grid.pos(i);
+            !body_collides(map, body.offset(Vec2::new(x, y)))
+        })
+    };
+
+    // The goal: whichever of the (up to four) lattice positions around `to`
+    // is clear and nearest it. The route ends there, then steps onto `to`.
+    let gx = (to.x - from.x).div_euclid(step);
+    let gy = (to.y - from.y).div_euclid(step);
+    let goal = [(gx, gy), (gx + 1, gy), (gx, gy + 1), (gx + 1, gy + 1)]
+        .into_iter()
+        .filter_map(|(i, j)| grid.index(i, j))
+        .filter(|&i| is_clear(&grid, i))
+        .min_by_key(|&i| {
+            let (x, y) = grid.pos(i);
+            ((x - to.x).abs().max((y - to.y).abs()), i)
+        })?;
+
+    let (goal_i, goal_j) = grid.coords(goal);
+    let heuristic = |i: usize| -> u32 {
+        let (ci, cj) = grid.coords(i);
+        let dx = u32::from((ci - goal_i).unsigned_abs());
+        let dy = u32::from((cj - goal_j).unsigned_abs());
+        ORTHO * dx.max(dy) + (DIAG - ORTHO) * dx.min(dy)
+    };
+    let mut cost = vec![u32::MAX; grid.len()];
+    let mut came_from = vec![usize::MAX; grid.len()];
+    let mut open = BinaryHeap::new();
+    cost[start] = 0;
+    open.push(Reverse((heuristic(start), 0u32, start)));
+    while let Some(Reverse((_, g, node))) = open.pop() {
+        if node == goal {
+            break;
+        }
+        if g > cost[node] {
+            continue;
+        }
+        let (ni, nj) = grid.coords(node);
+        for (di, dj) in [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let Some(next) = grid.index(ni + di, nj + dj) else {
+                continue;
+            };
+            if !is_clear(&grid, next) {
+                continue;
+            }
+            let diagonal = di != 0 && dj != 0;
+            if diagonal {
+                let corners = [grid.index(ni + di, nj), grid.index(ni, nj + dj)];
+                if !corners.into_iter().all(|c| c.is_some_and(|c| is_clear(&grid, c))) {
+                    continue;
+                }
+            }
+            let g = g + if diagonal { DIAG } else { ORTHO };
+            if g < cost[next] {
+                cost[next] = g;
+                came_from[next] = node;
+                open.push(Reverse((g + heuristic(next), g, next)));
+            }
+        }
+    }
+    if cost[goal] == u32::MAX {
+        return None;
+    }
+
+    // Walk back from the goal, keeping only the turns.
+    let mut nodes = vec![goal];
+    while let Some(&node) = nodes.last()
+        && node != start
+    {
+        nodes.push(came_from[node]);
+    }
+    nodes.reverse();
+    let mut route: Vec<Vec2> = Vec::new();
+    for pair in nodes.windows(3) {
+        let [a, b, c] = [pair[0], pair[1], pair[2]].map(|i| grid.coords(i));
+        if (b.0 - a.0, b.1 - a.1) != (c.0 - b.0, c.1 - b.1) {
+            let (x, y) = grid.pos(grid.index(b.0, b.1)?);
+            route.push(Vec2::new(x, y));
+        }
+    }
+    let (x, y) = grid.pos(goal);
+    if goal != start {
+        route.push(Vec2::new(x, y));
+    }
+    if route.last() != Some(&to) {
+        route.push(to);
+    }
+    Some(route)
+}
+
+/// Every position [`walk_flood`] reached, over the bounded area it searched.
+pub struct Reach {
+    /// The area's top-left position and size, in pixels.
+    origin: Vec2,
+    cols: usize,
+    rows: usize,
+    seen: Vec<bool>,
+    /// The reached positions, in visit order.
+    positions: Vec<Vec2>,
+}
+
+impl Reach {
+    /// Whether the shell can stand at `pos`.
+    pub fn contains(&self, pos: Vec2) -> bool {
+        self.index(pos).is_some_and(|i| self.seen[i])
+    }
+
+    /// Every reached position, in the order the flood found them (the start
+    /// first).
+    pub fn positions(&self) -> &[Vec2] {
+        &self.positions
+    }
+
+    fn index(&self, pos: Vec2) -> Option<usize> {
+        let (c, r) = (pos.x - self.origin.x, pos.y - self.origin.y);
+        (c >= 0 && r >= 0 && (c as usize) < self.cols && (r as usize) < self.rows)
+            .then(|| c as usize + r as usize * self.cols)
+    }
+}
+
+/// Flood-fill everywhere `shell` can walk to from `from` on `map` (whose store
+/// entry is `tiles`, as the walk loop passes it), one pixel per step in all
+/// eight directions, through [`Shell::walk`] — the player's own collision and
+/// ramp slides. The search keeps the shell's hitbox within the collision
+/// layers' bounds (as [`find_path`] does), so walking off an open map edge
+/// doesn't run forever; a map with no collision layers reaches just `from`.
+/// `None` when the area is too big to search (see [`MAX_FLOOD`]).
+pub fn walk_flood(
+    map: &MapInfo,
+    tiles: Option<&TiledMap>,
+    shell: &Shell,
+    from: Vec2,
+) -> Option<Reach> {
+    let body = shell.local_hitbox;
+    let (x0, y0, x1, y1) = search_area(map, body, from, from).unwrap_or((
+        i32::from(from.x),
+        i32::from(from.y),
+        i32::from(from.x),
+        i32::from(from.y),
+    ));
+    let (cols, rows) = ((x1 - x0 + 1) as usize, (y1 - y0 + 1) as usize);
+    if cols.saturating_mul(rows) > MAX_FLOOD {
+        return None;
+    }
+    let mut reach = Reach {
+        origin: Vec2::new(i16::try_from(x0).ok()?, i16::try_from(y0).ok()?),
+        cols,
+        rows,
+        seen: vec![false; cols * rows],
+        positions: Vec::new(),
+    };
+    let mut walker = shell.clone();
+    let mut console = NullConsole::new();
+    let start = reach.index(from)?;
+    reach.seen[start] = true;
+    reach.positions.push(from);
+    let mut next = 0;
+    while let Some(&pos) = reach.positions.get(next) {
+        next += 1;
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            walker.pos = pos;
+            let (mx, my) = walker.walk(&mut console, dx, dy, false, map, tiles);
+            let to = Vec2::new(pos.x + mx, pos.y + my);
+            if let Some(i) = reach.index(to)
+                && !reach.seen[i]
+            {
+                reach.seen[i] = true;
+                reach.positions.push(to);
+            }
+        }
+    }
+    Some(reach)
+}
+
+/// Whether any pixel of `hitbox` is solid on one of `map`'s collision layers.
+/// Stricter than `walk`'s edge probes, so a clear box is always walkable.
+pub fn body_collides(map: &MapInfo, hitbox: Hitbox) -> bool {
+    map.layers.iter().any(|layer| {
+        !layer.colliders.is_empty()
+            && layer.hitbox().touches(hitbox)
+            && (hitbox.y..=hitbox.ey())
+                .any(|y| (hitbox.x..=hitbox.ex()).any(|x| layer_collides(Vec2::new(x, y), layer)))
+    })
+}
+
+/// The pixel rectangle worth searching: every collision layer, plus the body
+/// at both ends (a route may start or end off the layers). `None` when the
+/// map has no collision layers at all.
+fn search_area(
+    map: &MapInfo,
+    body: Hitbox,
+    from: Vec2,
+    to: Vec2,
+) -> Option<(i32, i32, i32, i32)> {
+    let layers: Vec<Hitbox> = map
+        .layers
+        .iter()
+        .filter(|layer| !layer.colliders.is_empty())
+        .map(|layer| layer.hitbox())
+        .filter(|hitbox| hitbox.w > 0 && hitbox.h > 0)
+        .collect();
+    if layers.is_empty() {
+        return None;
+    }
+    let ends = [body.offset(from), body.offset(to)];
+    let boxes = layers.iter().chain(ends.iter());
+    let x0 = boxes.clone().map(|b| i32::from(b.x)).min()?;
+    let y0 = boxes.clone().map(|b| i32::from(b.y)).min()?;
+    let x1 = boxes.clone().map(|b| i32::from(b.x) + i32::from(b.w)).max()?;
+    let y1 = boxes.map(|b| i32::from(b.y) + i32::from(b.h)).max()?;
+    // Positions, not boxes: shift by the body's offset so its hitbox, not its
+    // origin, stays inside the area.
+    Some((
+        x0 - i32::from(body.x),
+        y0 - i32::from(body.y),
+        x1 - i32::from(body.x) - i32::from(body.w),
+        y1 - i32::from(body.y) - i32::from(body.h),
+    ))
+}
+
+/// The lattice of candidate positions `origin + (i, j) * step` covering a
+/// search area, flattened row-major.
+struct Lattice {
+    origin: Vec2,
+    step: i16,
+    /// Lattice coordinates of the top-left position.
+    min: (i16, i16),
+    cols: usize,
+    rows: usize,
+}
+
+impl Lattice {
+    fn new((x0, y0, x1, y1): (i32, i32, i32, i32), origin: Vec2, step: i16) -> Option<Self> {
+        let s = i32::from(step);
+        let lo = |edge: i32, o: i16| (edge - i32::from(o)).div_euclid(s).min(0);
+        let hi = |edge: i32, o: i16| (edge - i32::from(o)).div_euclid(s).max(0) + 1;
+        let (i0, j0) = (lo(x0, origin.x), lo(y0, origin.y));
+        let (i1, j1) = (hi(x1, origin.x), hi(y1, origin.y));
+        let (cols, rows) = ((i1 - i0 + 1) as usize, (j1 - j0 + 1) as usize);
+        if cols.saturating_mul(rows) > MAX_NODES {
+            return None;
+        }
+        Some(Self {
+            origin,
+            step,
+            min: (i16::try_from(i0).ok()?, i16::try_from(j0).ok()?),
+            cols,
+            rows,
+        })
+    }
+    fn len(&self) -> usize {
+        self.cols * self.rows
+    }
+    /// The flat index of lattice coordinate `(i, j)`, or `None` off the edge.
+    fn index(&self, i: i16, j: i16) -> Option<usize> {
+        let (c, r) = (i - self.min.0, j - self.min.1);
+        (c >= 0 && r >= 0 && (c as usize) < self.cols && (r as usize) < self.rows)
+            .then(|| c as usize + r as usize * self.cols)
+    }
+    fn coords(&self, index: usize) -> (i16, i16) {
+        (
+            (index % self.cols) as i16 + self.min.0,
+            (index / self.cols) as i16 + self.min.1,
+        )
+    }
+    /// The map position (pixels) of the lattice point at `index`.
+    fn pos(&self, index: usize) -> (i16, i16) {
+        let (i, j) = self.coords(index);
+        (self.origin.x + i * self.step, self.origin.y + j * self.step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::world::map::LayerInfo;
+    use egg_render::geometry::Collider;
+
+    /// A `w`×`h`-tile collision layer, solid on the tiles `solid` picks.
+    fn map_with(w: i16, h: i16, solid: impl Fn(i16, i16) -> bool) -> MapInfo {
+        let mut full = Collider::default();
+        for y in 0..8 {
+            for x in 0..8 {
+                full.set(x, y, true);
+            }
+        }
+        let colliders = (0..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .map(|(x, y)| if solid(x, y) { full.clone() } else { Collider::default() })
+            .collect();
+        MapInfo {
+            layers: vec![LayerInfo {
+                colliders,
+                ..LayerInfo::new(0, 0, w, h)
+            }],
+            ..MapInfo::default()
+        }
+    }
+
+    const BODY: Hitbox = Hitbox::new(0, 0, 8, 8);
+
+    /// Follow `route` from `from` one pixel (per axis) at a time, asserting
+    /// the body never overlaps a solid pixel on the way.
+    fn assert_clear_walk(map: &MapInfo, from: Vec2, route: &[Vec2]) {
+        let mut pos = from;
+        for &waypoint in route {
+            while pos != waypoint {
+                pos.x += (waypoint.x - pos.x).signum();
+                pos.y += (waypoint.y - pos.y).signum();
+                assert!(!body_collides(map, BODY.offset(pos)), "route crosses a wall at {pos:?}");
+            }
+        }
+    }
+
+    /// Open ground is a single straight leg; a map with no collision at all
+    /// doesn't even search.
+    #[test]
+    fn open_ground_is_a_straight_line() {
+        let map = map_with(8, 8, |_, _| false);
+        let to = Vec2::new(40, 16);
+        assert_eq!(find_path(&map, BODY, Vec2::new(0, 16), to), Some(vec![to]));
+        let to = Vec2::new(-300, 9);
+        assert_eq!(find_path(&MapInfo::default(), BODY, Vec2::new(0, 0), to), Some(vec![to]));
+        assert_eq!(find_path(&map, BODY, to, to), Some(Vec::new()));
+    }
+
+    /// A wall with a gap at the bottom: the route goes around through the
+    /// gap, never through the wall, and lands on the (off-lattice) target.
+    #[test]
+    fn routes_around_a_wall_through_the_gap() {
+        let map = map_with(10, 8, |x, y| x == 4 && y < 6);
+        let (from, to) = (Vec2::new(8, 10), Vec2::new(63, 9));
+        let route = find_path(&map, BODY, from, to).unwrap();
+        assert_eq!(route.last(), Some(&to));
+        assert!(route.len() > 1, "not a straight line: {route:?}");
+        assert!(route.iter().any(|p| p.y >= 48), "dips through the gap: {route:?}");
+        assert_clear_walk(&map, from, &route);
+    }
+
+    /// A target inside a sealed room (or inside the wall itself) is
+    /// unreachable.
+    #[test]
+    fn sealed_targets_are_unreachable() {
+        let map = map_with(10, 10, |x, y| (x == 5 && y <= 5) || (y == 5 && x >= 5));
+        assert_eq!(find_path(&map, BODY, Vec2::new(8, 8), Vec2::new(60, 8)), None);
+        let wall = map_with(10, 10, |x, _| x == 5);
+        assert_eq!(find_path(&wall, BODY, Vec2::new(8, 8), Vec2::new(41, 20)), None);
+    }
+
+    /// The flood covers the open side of a wall pixel-for-pixel, stays inside
+    /// the map, and never crosses the wall.
+    #[test]
+    fn walk_flood_fills_one_side_of_a_wall() {
+        let map = map_with(8, 4, |x, _| x == 4);
+        // The walk loop only collides on a map with a store entry.
+        let json = r#"{"width": 8, "height": 4, "tilesets": [], "layers": []}"#;
+        let tiles = crate::data::tiled::from_json(json.as_bytes()).unwrap();
+        let shell = Shell { local_hitbox: BODY, ..Shell::default() };
+
+        let reach = walk_flood(&map, Some(&tiles), &shell, Vec2::new(8, 8)).unwrap();
+        assert!(reach.contains(Vec2::new(0, 0)) && reach.contains(Vec2::new(24, 24)));
+        assert!(!reach.contains(Vec2::new(25, 8)), "the body would overlap the wall");
+        assert!(!reach.contains(Vec2::new(48, 8)), "the far side is walled off");
+        // Every pixel position of the 4×4-tile pocket, less the body's size.
+        assert_eq!(reach.positions().len(), 25 * 25);
+        assert_eq!(reach.positions()[0], Vec2::new(8, 8));
+    }
+}
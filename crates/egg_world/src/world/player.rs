@@ -25,7 +25,7 @@ use egg_platform::{ConsoleApi, ConsoleHelper};
 use crate::rand::Lcg64Xsh32;
 use crate::draw_state::DrawParams;
 use egg_render::{Flip, SpriteOptions};
-use crate::world::interact::Interaction;
+use crate::world::interact::{Interaction, Talk};
 use crate::world::map::{Axis, LayerInfo, MapInfo};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -113,9 +113,7 @@ impl SpriteAnimation {
         Self::from_sprite_frames(&frames)
     }
     pub fn with_flip(mut self, flip: Flip) -> Self {
-        self.frames
-            .iter_mut()
-            .for_each(|frame| frame.flip = flip.clone());
+        self.frames.iter_mut().for_each(|frame| frame.flip = flip);
         self
     }
     pub fn with_x_offset(mut self, x_offset: i32) -> Self {
@@ -256,6 +254,64 @@ pub enum MoveMode {
     Companion {
         slot: usize,
     },
+    /// Walks the named `#path` from the scene registry
+    /// ([`SceneFile::paths`](crate::data::scene::SceneFile::paths)) lap after
+    /// lap, `frame` into the current lap — the guard on their beat. The runs
+    /// are relative, so a route that doesn't end where it starts drifts
+    /// further every lap: patrols are authored as closed loops. A step is
+    /// taken whole or not at all (a diagonal blocked on one axis doesn't
+    /// slide along the other), and `frame` only advances on one taken, so a
+    /// patrol blocked part-way waits there and picks the lap up where it
+    /// stopped, rather than drifting off the loop.
+    /// An unknown path stands still.
+    Patrol {
+        path: String,
+        frame: u32,
+    },
+    /// Runs from the player while they're within `radius` pixels (hitbox
+    /// centre to centre), wandering otherwise (see [`flee_heading`]).
+    Flee {
+        radius: u16,
+    },
+    /// Walks up to the player while they're within `radius` pixels, stopping
+    /// once alongside, and wanders otherwise (see [`approach_heading`]).
+    Approach {
+        radius: u16,
+    },
+}
+
+/// How close (hitbox centre to centre, in pixels) a [`MoveMode::Approach`]
+/// creature comes before it stops: alongside the player, not on top of them.
+pub const APPROACH_STOP: u16 = 12;
+
+/// Whether `from` and `to` are within `radius` pixels of each other.
+fn within(from: Vec2, to: Vec2, radius: u16) -> bool {
+    let (dx, dy) = (i32::from(to.x) - i32::from(from.x), i32::from(to.y) - i32::from(from.y));
+    dx * dx + dy * dy <= i32::from(radius) * i32::from(radius)
+}
+
+/// The heading a [`MoveMode::Flee`] creature at `me` takes this step, away
+/// from the player at `player` (both hitbox centres) — or `None` when the
+/// player is out of `radius` and it should wander instead.
+pub fn flee_heading(me: Vec2, player: Vec2, radius: u16) -> Option<(i16, i16)> {
+    within(me, player, radius).then(|| {
+        let away = player.towards(&me);
+        (away.x, away.y)
+    })
+}
+
+/// The heading a [`MoveMode::Approach`] creature at `me` takes this step,
+/// toward the player at `player` — `(0, 0)` once within [`APPROACH_STOP`], or
+/// `None` when the player is out of `radius` and it should wander instead.
+pub fn approach_heading(me: Vec2, player: Vec2, radius: u16) -> Option<(i16, i16)> {
+    within(me, player, radius).then(|| {
+        if within(me, player, APPROACH_STOP) {
+            (0, 0)
+        } else {
+            let toward = me.towards(&player);
+            (toward.x, toward.y)
+        }
+    })
 }
 
 /// How a cutscene (and, later, the editor) addresses a live entity: by stable
@@ -397,6 +453,14 @@ pub struct Shell {
     /// visibly different shell from an unposed one, live-play effect or not.
     #[serde(skip)]
     pub pose: Option<String>,
+    /// The standing pose the creature's schedule slot holds it in (see
+    /// [`SlotDef::pose`](crate::data::schedule::SlotDef::pose)), drawn like
+    /// [`pose`](Self::pose) whenever no scene pose overrides it, and holding
+    /// the creature still. Unlike `pose` it outlives any scene, so it's saved
+    /// with the shell; the next map change re-sets it from whichever slot then
+    /// holds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot_pose: Option<String>,
     /// The archetype this shell is an instance of (its [`PresetId`]). The store
     /// key the *derived* `sprites` resolve from, and the handle entity
     /// persistence stores to rebuild a shell; an egg carries the preset it will
@@ -434,6 +498,13 @@ pub struct Shell {
     /// into its role, like `sprites`.
     #[serde(skip)]
     pub interaction: Option<Interaction>,
+    /// What interacting with this creature opens — its preset's `talk`,
+    /// stamped on at spawn, or a per-creature override from the map editor.
+    /// Unlike [`interaction`](Self::interaction) it is authored rather than
+    /// derived, so it persists with the shell. `None` for a creature with
+    /// nothing to say.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub talk: Option<Talk>,
     /// Stable identity for cutscene addressing ([`EntityId::Id`]): an authored
     /// map-creature name. `None` for the player, companions, and anonymous
     /// creatures (which resolve by role/slot instead).
@@ -466,11 +537,13 @@ impl PartialEq for Shell {
             // a posed shell isn't the same as an unposed one, even though
             // neither state is persisted.
             && self.pose == other.pose
+            && self.slot_pose == other.slot_pose
             && self.preset == other.preset
             && self.move_mode == other.move_mode
             && self.outline == other.outline
             && self.companions == other.companions
             && self.id == other.id
+            && self.talk == other.talk
         // `trail` and `interaction` are transient/derived (skipped in serde),
         // so they're excluded here too — like `sprites`.
     }
@@ -524,12 +597,13 @@ impl Shell {
             };
             return (sprite, 0);
         }
-        // standing pose (see `pose`) — draws its strip's frame 0 in place of the
-        // walk sprite until animated poses are worth the trouble. A pose naming a
-        // strip this shell's preset doesn't have falls through here to the walk
-        // sprite silently; the warning already fired when the pose was applied
-        // (see `cutscene::apply_pose`), not on every frame it's drawn.
-        if let Some(name) = &self.pose
+        // standing pose (see `pose`, else `slot_pose`) — draws its strip's frame
+        // 0 in place of the walk sprite until animated poses are worth the
+        // trouble. A pose naming a strip this shell's preset doesn't have falls
+        // through here to the walk sprite silently; the warning already fired
+        // when the pose was applied (see `cutscene::apply_pose`, or the
+        // validator for a schedule's), not on every frame it's drawn.
+        if let Some(name) = self.pose.as_ref().or(self.slot_pose.as_ref())
             && let Some(strip) = self.sprites.poses.get(name)
         {
             let mut sprite = strip.get_frame(0).clone();
@@ -613,6 +687,29 @@ impl Shell {
     pub fn hitbox(&self) -> Hitbox {
         self.local_hitbox.offset(self.pos)
     }
+    /// The middle of this shell's hitbox — where [`MoveMode::Flee`] and
+    /// [`MoveMode::Approach`] measure distances from.
+    pub fn centre(&self) -> Vec2 {
+        let hitbox = self.hitbox();
+        Vec2::new(hitbox.x + hitbox.w / 2, hitbox.y + hitbox.h / 2)
+    }
+    /// The box this shell's drawn body covers: a sprite-sized box centred on
+    /// the hitbox and rising from its feet. What the interact probe tests, so
+    /// petting the dog or talking to a creature isn't a hunt for its small
+    /// feet hitbox.
+    pub fn body(&self) -> Hitbox {
+        let (sprite, _) = self.sprite_options();
+        let (sw, sh) = (sprite.w as i16 * 8, sprite.h as i16 * 8);
+        let hb = self.hitbox();
+        Hitbox::new(hb.x + hb.w / 2 - sw / 2, hb.y + hb.h - sh, sw, sh)
+    }
+    /// A route from here to `to` around `map`'s collision, sized to this
+    /// shell's hitbox (see [`find_path`](crate::world::pathfind::find_path)):
+    /// the waypoints to walk through in order, `to` last. `None` when `to`
+    /// can't be reached.
+    pub fn route_to(&self, map: &MapInfo, to: Vec2) -> Option<Vec<Vec2>> {
+        crate::world::pathfind::find_path(map, self.local_hitbox, self.pos, to)
+    }
     pub fn apply_walk_direction(&mut self, mut dx: i16, mut dy: i16) -> (i16, i16) {
         if dx == 0 && dy == 0 {
             return (dx, dy);
@@ -828,12 +925,14 @@ impl Shell {
             flip_controls: Axis::None,
             pet_timer: None,
             pose: None,
+            slot_pose: None,
             sprites,
             move_mode,
             outline: Some(1),
             companions: Vec::new(),
             trail: CompanionTrail::new(),
             interaction: None,
+            talk: None,
             id: None,
         }
     }
@@ -1128,10 +1227,14 @@ mod tests {
         // A leader with one slot-0 companion: after the leader walks east, the
         // companion rides the oldest breadcrumb (the trail tail) — a fixed gap
         // behind, the dog-follow feel.
-        let mut leader = Shell::default();
-        leader.pos = Vec2::new(50, 50);
-        let mut dog = Shell::default();
-        dog.move_mode = MoveMode::Companion { slot: 0 };
+        let mut leader = Shell {
+            pos: Vec2::new(50, 50),
+            ..Shell::default()
+        };
+        let dog = Shell {
+            move_mode: MoveMode::Companion { slot: 0 },
+            ..Shell::default()
+        };
         leader.companions.push(dog);
         for _ in 0..20 {
             leader.face((1, 0));
@@ -1421,7 +1524,7 @@ mod tests {
 
     #[test]
     fn eight_way_grid_buckets_and_mirrors() {
-        let flip_of = |w: &WalkSprites, dir| w.dir_to_sprite(dir).get_frame(0).flip.clone();
+        let flip_of = |w: &WalkSprites, dir| w.dir_to_sprite(dir).get_frame(0).flip;
         let n = || Flip::None;
         let h = || Flip::Horizontal;
 
@@ -1503,4 +1606,17 @@ mod tests {
         shell.face((0, 1)); // straight down while last-facing left
         assert_eq!(shell.facing_dir(), (-1, 1)); // mirror held, vertical live
     }
+
+    #[test]
+    fn flee_and_approach_only_react_within_radius() {
+        let player = Vec2::new(50, 50);
+        // Out of radius, both fall back to wandering.
+        assert_eq!(flee_heading(Vec2::new(100, 50), player, 32), None);
+        assert_eq!(approach_heading(Vec2::new(100, 50), player, 32), None);
+        // In radius: a fleer heads straight away, an approacher straight in.
+        assert_eq!(flee_heading(Vec2::new(30, 60), player, 32), Some((-1, 1)));
+        assert_eq!(approach_heading(Vec2::new(30, 60), player, 32), Some((1, -1)));
+        // Close enough, the approacher stops rather than pushing into them.
+        assert_eq!(approach_heading(Vec2::new(44, 50), player, 32), Some((0, 0)));
+    }
 }
@@ -0,0 +1,335 @@
+//! Screen transitions for warps: how the old map hands the screen over to the
+//! new one. A warp's [`Transition`] is authored per object (the `.tmj`
+//! `transition` property); a non-[`Cut`](Transition::Cut) warp runs a
+//! [`WarpTransition`] that covers the screen over [`TRANSITION_FRAMES`], lands
+//! the warp on the fully covered frame, then uncovers the destination over as
+//! many again. The walkaround ticks it (holding all input meanwhile) and hands
+//! each frame's [`Cover`] to the draw record, where `composite_into` paints it
+//! over the finished frame (with the world's palette, which the
+//! [`Dissolve`](Transition::Dissolve) steps down).
+
+use egg_render::image::{Rgba, RgbaImage};
+
+use crate::world::map::Warp;
+
+/// Frames each half of a transition takes — covering, then uncovering.
+pub const TRANSITION_FRAMES: u16 = 20;
+
+/// The effect a warp plays while it swaps maps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Transition {
+    /// Swap instantly (the historical behaviour, and the default).
+    #[default]
+    Cut,
+    /// Blend every pixel toward black.
+    Fade,
+    /// Every colour steps down the palette, darkest entry by entry, to black.
+    Dissolve,
+    /// A black ring closes on the player, then opens on the landing point.
+    Iris,
+    /// A Bayer-dithered edge sweeps across the screen, left to right.
+    Dither,
+}
+
+impl Transition {
+    /// Every kind, in the map editor's cycle order.
+    pub const ALL: [Transition; 5] = [
+        Transition::Cut,
+        Transition::Fade,
+        Transition::Dissolve,
+        Transition::Iris,
+        Transition::Dither,
+    ];
+
+    /// The `.tmj` property value (and editor label) for this kind.
+    pub fn name(self) -> &'static str {
+        match self {
+            Transition::Cut => "cut",
+            Transition::Fade => "fade",
+            Transition::Dissolve => "dissolve",
+            Transition::Iris => "iris",
+            Transition::Dither => "dither",
+        }
+    }
+
+    /// Inverse of [`name`](Self::name), case-insensitive. `None` on anything
+    /// else.
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name().eq_ignore_ascii_case(s.trim()))
+    }
+
+    /// The next kind in [`ALL`](Self::ALL), wrapping.
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&kind| kind == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+/// One frame of a running transition, as `composite_into` paints it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cover {
+    pub kind: Transition,
+    /// How much of the screen is covered, 0 (none) to 256 (all black) — the
+    /// same fixed-point scale as
+    /// [`fade_palette_into`](crate::draw_state::fade_palette_into).
+    pub amount: u16,
+    /// The screen pixel the [`Iris`](Transition::Iris) closes on.
+    pub centre: (i32, i32),
+}
+
+/// 4×4 ordered-dither thresholds, 0..16.
+const BAYER: [[u16; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+impl Cover {
+    /// Paint this frame's cover over `image` in place, `palette` being the
+    /// one the frame was drawn with. A zero amount (or a
+    /// [`Cut`](Transition::Cut)) leaves it untouched; 256 blacks it out for
+    /// every kind, so the warp always lands on a fully hidden frame.
+    pub fn apply(&self, image: &mut RgbaImage, palette: &[[u8; 3]]) {
+        let amount = self.amount.min(256);
+        if amount == 0 || self.kind == Transition::Cut {
+            return;
+        }
+        let black = Rgba::new(0, 0, 0, 255);
+        let (w, h) = (image.width(), image.height());
+        match self.kind {
+            Transition::Cut => {}
+            Transition::Fade => {
+                for px in image.data_mut().chunks_exact_mut(4) {
+                    for c in &mut px[..3] {
+                        *c = ((u16::from(*c) * (256 - amount)) >> 8) as u8;
+                    }
+                }
+            }
+            Transition::Dissolve => {
+                // Snap each pixel to its palette entry, then walk it that many
+                // rungs down the palette sorted darkest first: one rung per
+                // 1/len of the cover, so the screen darkens in whole palette
+                // steps rather than blending.
+                let mut ramp = palette.to_vec();
+                ramp.sort_by_key(|&c| luma(c));
+                let rungs = usize::from(amount) * ramp.len() / 256;
+                for px in image.data_mut().chunks_exact_mut(4) {
+                    let stepped = if amount == 256 {
+                        None
+                    } else {
+                        nearest(&ramp, [px[0], px[1], px[2]])
+                            .map(|rung| ramp[rung.saturating_sub(rungs)])
+                    };
+                    px[..3].copy_from_slice(&stepped.unwrap_or([0; 3]));
+                }
+            }
+            Transition::Iris => {
+                // The open radius shrinks from the farthest corner to zero.
+                let (cx, cy) = self.centre;
+                let reach = [(0, 0), (w as i32, 0), (0, h as i32), (w as i32, h as i32)]
+                    .iter()
+                    .map(|&(x, y)| (x - cx).pow(2) + (y - cy).pow(2))
+                    .max()
+                    .unwrap_or(0);
+                let open = (reach as f32).sqrt() * f32::from(256 - amount) / 256.0;
+                let open = (open * open) as i32;
+                for y in 0..h {
+                    for x in 0..w {
+                        if (x as i32 - cx).pow(2) + (y as i32 - cy).pow(2) >= open {
+                            image.set_pixel(x, y, black);
+                        }
+                    }
+                }
+            }
+            Transition::Dither => {
+                // Column position (0..256) plus a dither offset (0..240):
+                // the sweep is fully clear at 0 and fully black at 256.
+                for y in 0..h {
+                    for x in 0..w {
+                        let dither = BAYER[y as usize % 4][x as usize % 4];
+                        let edge = x * 256 / w.max(1) + u32::from(dither) * 16;
+                        if edge < u32::from(amount) * 2 {
+                            image.set_pixel(x, y, black);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A colour's perceived brightness, for ordering the
+/// [`Dissolve`](Transition::Dissolve)'s palette ramp.
+fn luma([r, g, b]: [u8; 3]) -> u32 {
+    299 * u32::from(r) + 587 * u32::from(g) + 114 * u32::from(b)
+}
+
+/// The index of the `ramp` colour closest to `rgb`; `None` on an empty ramp.
+fn nearest(ramp: &[[u8; 3]], rgb: [u8; 3]) -> Option<usize> {
+    let dist = |c: [u8; 3]| -> i32 {
+        (0..3).map(|j| (i32::from(c[j]) - i32::from(rgb[j])).pow(2)).sum()
+    };
+    (0..ramp.len()).min_by_key(|&i| dist(ramp[i]))
+}
+
+/// A warp waiting out its [`Transition`]: covering the old map, then (once
+/// [`tick`](Self::tick) has handed the warp back to land) uncovering the new
+/// one.
+#[derive(Clone, Debug)]
+pub struct WarpTransition {
+    pub kind: Transition,
+    /// Frames run so far, up to `2 * TRANSITION_FRAMES`.
+    frame: u16,
+    /// The warp to land on the covered frame; `None` once it has.
+    warp: Option<Warp>,
+}
+
+impl WarpTransition {
+    pub fn new(kind: Transition, warp: Warp) -> Self {
+        Self {
+            kind,
+            frame: 0,
+            warp: Some(warp),
+        }
+    }
+
+    /// Advance one frame. Returns the warp on the frame the screen is fully
+    /// covered — the caller lands it then — and `None` on every other.
+    pub fn tick(&mut self) -> Option<Warp> {
+        self.frame = (self.frame + 1).min(2 * TRANSITION_FRAMES);
+        if self.frame >= TRANSITION_FRAMES {
+            self.warp.take()
+        } else {
+            None
+        }
+    }
+
+    /// Whether the destination is fully uncovered again.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= 2 * TRANSITION_FRAMES
+    }
+
+    /// This frame's coverage, 0–256: ramps up to 256 on the landing frame,
+    /// then back down.
+    pub fn amount(&self) -> u16 {
+        let covered = if self.frame <= TRANSITION_FRAMES {
+            self.frame
+        } else {
+            2 * TRANSITION_FRAMES - self.frame
+        };
+        covered * 256 / TRANSITION_FRAMES
+    }
+
+    /// This frame's [`Cover`], closing an iris on `centre` (screen pixels).
+    pub fn cover(&self, centre: (i32, i32)) -> Cover {
+        Cover {
+            kind: self.kind,
+            amount: self.amount(),
+            centre,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egg_render::geometry::Vec2;
+
+    /// A sixteen-step grey ramp, black to white, listed out of order so the
+    /// dissolve has to sort it.
+    const GREYS: [[u8; 3]; 16] = {
+        let mut greys = [[0; 3]; 16];
+        let mut i = 0;
+        while i < 16 {
+            let v = ((i * 7) % 16) as u8 * 17;
+            greys[i] = [v, v, v];
+            i += 1;
+        }
+        greys
+    };
+
+    /// Every kind round-trips through its `.tmj` name, and `next` cycles
+    /// through all of them.
+    #[test]
+    fn names_round_trip_and_cycle() {
+        for kind in Transition::ALL {
+            assert_eq!(Transition::parse(kind.name()), Some(kind));
+        }
+        assert_eq!(Transition::parse(" IRIS "), Some(Transition::Iris));
+        assert_eq!(Transition::parse("wipe"), None);
+        let mut kind = Transition::Cut;
+        for _ in 0..Transition::ALL.len() {
+            kind = kind.next();
+        }
+        assert_eq!(kind, Transition::Cut);
+    }
+
+    /// The warp comes back exactly once, on the fully covered frame, and the
+    /// transition finishes after as many frames again.
+    #[test]
+    fn warp_lands_once_on_the_covered_frame() {
+        let mut t = WarpTransition::new(Transition::Fade, Warp::new(None, Vec2::new(1, 2)));
+        let mut landed = Vec::new();
+        for frame in 1..=2 * TRANSITION_FRAMES {
+            if t.tick().is_some() {
+                landed.push(frame);
+                assert_eq!(t.amount(), 256);
+            }
+            assert_eq!(t.is_finished(), frame == 2 * TRANSITION_FRAMES);
+        }
+        assert_eq!(landed, [TRANSITION_FRAMES]);
+        assert_eq!(t.amount(), 0);
+    }
+
+    /// Full coverage blacks out the whole frame for every kind; zero leaves
+    /// it untouched.
+    #[test]
+    fn full_cover_is_black_for_every_kind() {
+        let white = Rgba::new(255, 255, 255, 255);
+        for kind in Transition::ALL.into_iter().skip(1) {
+            let mut image = RgbaImage::new(24, 16);
+            image.fill(white);
+            Cover { kind, amount: 0, centre: (12, 8) }.apply(&mut image, &GREYS);
+            assert_eq!(image.get_pixel(5, 5), white, "{kind:?} at 0");
+            Cover { kind, amount: 256, centre: (12, 8) }.apply(&mut image, &GREYS);
+            for i in 0..24 * 16 {
+                assert_eq!(image.get_pixel_index(i).0[..3], [0; 3], "{kind:?} pixel {i}");
+            }
+        }
+    }
+
+    /// Half way, the iris still shows its centre but not the corners, and the
+    /// dither wipe has covered the left edge but not the right.
+    #[test]
+    fn partial_covers_grow_from_where_they_should() {
+        let white = Rgba::new(255, 255, 255, 255);
+        let half = |kind| {
+            let mut image = RgbaImage::new(64, 32);
+            image.fill(white);
+            Cover { kind, amount: 128, centre: (32, 16) }.apply(&mut image, &GREYS);
+            image
+        };
+        let iris = half(Transition::Iris);
+        assert_eq!(iris.get_pixel(32, 16), white);
+        assert_ne!(iris.get_pixel(0, 0), white);
+        let dither = half(Transition::Dither);
+        assert_ne!(dither.get_pixel(0, 0), white);
+        assert_eq!(dither.get_pixel(63, 0), white);
+    }
+
+    /// The dissolve darkens in whole palette steps: half covered, white has
+    /// gone eight rungs down the ramp and an off-palette colour snaps to its
+    /// nearest entry first; an empty palette just goes black.
+    #[test]
+    fn dissolve_steps_down_the_palette() {
+        let dissolve = |amount, palette: &[[u8; 3]]| {
+            let mut image = RgbaImage::new(2, 1);
+            image.set_pixel(0, 0, Rgba::new(255, 255, 255, 255));
+            image.set_pixel(1, 0, Rgba::new(40, 30, 40, 255));
+            Cover { kind: Transition::Dissolve, amount, centre: (0, 0) }.apply(&mut image, palette);
+            [image.get_pixel(0, 0), image.get_pixel(1, 0)].map(|px| px.0[0])
+        };
+        assert_eq!(dissolve(0, &GREYS), [255, 40], "no cover leaves it alone");
+        assert_eq!(dissolve(16, &GREYS), [238, 17], "one rung down");
+        assert_eq!(dissolve(128, &GREYS), [119, 0], "eight rungs down");
+        assert_eq!(dissolve(128, &[]), [0, 0]);
+    }
+}
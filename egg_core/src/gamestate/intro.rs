@@ -60,8 +60,8 @@ pub fn draw_animation(t: u16, ctx: &mut Ctx<impl ConsoleApi>) -> bool {
                     // Unsigned modulo: `next_u32() as i32 % w` keeps the sign, so
                     // the `>= 0` guard below dropped ~half of each axis (~75% of
                     // stars). Reduce before the cast so every star lands on-screen.
-                    let x = (ctx.rng.next_u32() % fw as u32) as i32;
-                    let y = (ctx.rng.next_u32() % fh as u32) as i32;
+                    let x = (ctx.rng.next_u32() % fw) as i32;
+                    let y = (ctx.rng.next_u32() % fh) as i32;
                     if x >= 0 && y >= 0 && (x as u32) < fw && (y as u32) < fh {
                         fg.set_pixel(x as u32, y as u32, 12);
                     }
@@ -13,6 +13,7 @@ use crate::world::camera::CameraBounds;
 use crate::world::map::MapStore;
 
 use super::GameMode;
+use super::slots::{self, SlotCard, SlotRequest};
 use super::walkaround::WalkaroundState;
 use super::walkaround::inventory::InventoryUi;
 
@@ -22,6 +23,27 @@ pub struct MenuState {
     entries: Vec<MenuEntry>,
     draw_title: Option<&'static str>,
     back_entry: Option<MenuEntry>,
+    /// The slot screen's cards, one per save slot (`None` = empty), read when
+    /// the screen opens and re-read after each slot action.
+    slots: Vec<Option<SlotCard>>,
+    /// What picking a slot on the slot screen does right now.
+    slot_pick: SlotPick,
+    /// Slot I/O waiting for [`EggState`](crate::EggState) to carry out (see
+    /// [`take_slot_request`](Self::take_slot_request)).
+    slot_request: Option<SlotRequest>,
+}
+
+/// The slot screen's pending gesture: a plain pick loads, while Copy and Erase
+/// each take further picks (source then destination; slot then the same slot
+/// again to confirm).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum SlotPick {
+    #[default]
+    Load,
+    CopyFrom,
+    CopyTo(usize),
+    Erase,
+    EraseSure(usize),
 }
 impl Default for MenuState {
     fn default() -> Self {
@@ -35,8 +57,36 @@ impl MenuState {
             entries: vec![MenuEntry::Play, MenuEntry::Options],
             draw_title: Some("game_title"),
             back_entry: None,
+            slots: Vec::new(),
+            slot_pick: SlotPick::Load,
+            slot_request: None,
+        }
+    }
+    /// The title's slot screen, reached from Play: one entry per save slot,
+    /// then Copy and Erase, then Back.
+    pub fn slot_select(system: &mut impl ConsoleApi) -> Self {
+        let entries = (0..crate::data::save::SLOT_COUNT)
+            .map(MenuEntry::Slot)
+            .chain([MenuEntry::CopySlot, MenuEntry::EraseSlot, MenuEntry::MainMenu])
+            .collect();
+        Self {
+            entries,
+            draw_title: None,
+            back_entry: Some(MenuEntry::MainMenu),
+            slots: slots::read_cards(system),
+            ..Self::new()
+        }
+    }
+    /// Re-read the slot screen's cards after its slot I/O has been done.
+    pub fn refresh_slots(&mut self, system: &mut impl ConsoleApi) {
+        if !self.slots.is_empty() {
+            self.slots = slots::read_cards(system);
         }
     }
+    /// The slot I/O the slot screen asked for this frame, if any.
+    pub fn take_slot_request(&mut self) -> Option<SlotRequest> {
+        self.slot_request.take()
+    }
     pub fn inventory_options() -> Self {
         Self {
             entries: vec![
@@ -140,7 +190,10 @@ impl MenuState {
         let texts: Vec<String> = self
             .entries
             .iter()
-            .map(|e| e.text(ctx.script, ctx.save))
+            .map(|e| {
+                self.slot_text(ctx.script, e)
+                    .unwrap_or_else(|| e.text(ctx.script, ctx.save))
+            })
             .collect();
         // Centre the menu against the render target (the framebuffer being drawn
         // into), so it re-centres at any window size — and stays consistent
@@ -183,6 +236,11 @@ impl MenuState {
         walkaround_state: &mut WalkaroundState,
     ) -> Option<GameMode> {
         use MenuEntry::*;
+        // Backing out of a half-made Copy/Erase cancels it rather than leaving.
+        if index.is_none() && self.slot_pick != SlotPick::Load {
+            self.slot_pick = SlotPick::Load;
+            return None;
+        }
         let x = if let Some(index) = index {
             &mut self.entries[index]
         } else if let Some(entry) = &mut self.back_entry {
@@ -191,7 +249,44 @@ impl MenuState {
             return None;
         };
         match x {
-            Play => return Some(GameMode::Instructions),
+            Play => *self = MenuState::slot_select(ctx.system),
+            Slot(slot) => {
+                let slot = *slot;
+                let used = self.slots.get(slot).is_some_and(Option::is_some);
+                match self.slot_pick {
+                    SlotPick::Load => {
+                        self.slot_request = Some(SlotRequest::Load(slot));
+                        return Some(GameMode::Instructions);
+                    }
+                    SlotPick::CopyFrom | SlotPick::Erase if !used => {}
+                    SlotPick::CopyFrom => self.slot_pick = SlotPick::CopyTo(slot),
+                    SlotPick::CopyTo(from) if from != slot => {
+                        self.slot_request = Some(SlotRequest::Copy { from, to: slot });
+                        self.slot_pick = SlotPick::Load;
+                    }
+                    SlotPick::CopyTo(_) => {}
+                    SlotPick::EraseSure(sure) if sure == slot => {
+                        self.slot_request = Some(SlotRequest::Erase(slot));
+                        self.slot_pick = SlotPick::Load;
+                    }
+                    SlotPick::Erase | SlotPick::EraseSure(_) if used => {
+                        self.slot_pick = SlotPick::EraseSure(slot);
+                    }
+                    SlotPick::Erase | SlotPick::EraseSure(_) => {}
+                }
+            }
+            CopySlot => {
+                self.slot_pick = match self.slot_pick {
+                    SlotPick::CopyFrom | SlotPick::CopyTo(_) => SlotPick::Load,
+                    _ => SlotPick::CopyFrom,
+                }
+            }
+            EraseSlot => {
+                self.slot_pick = match self.slot_pick {
+                    SlotPick::Erase | SlotPick::EraseSure(_) => SlotPick::Load,
+                    _ => SlotPick::Erase,
+                }
+            }
             Options => {
                 self.index = 0;
                 self.draw_title = Some("options_title");
@@ -284,6 +379,7 @@ impl MenuState {
                         walk.inventory_ui.inventory = inventory;
                     }
                     6 => return Some(GameMode::DebugMenu),
+                    7 => walk.scrub_clock(ctx, 60),
                     _ => {}
                 }
             }
@@ -295,6 +391,43 @@ impl MenuState {
         };
         None
     }
+    /// The slot screen's entry text, which reads the menu's slot cards and
+    /// pending pick (not just the save, as [`MenuEntry::text`] does). `None`
+    /// for every other entry.
+    fn slot_text(&self, script: &Script, entry: &MenuEntry) -> Option<String> {
+        let pick = self.slot_pick;
+        let text = match entry {
+            MenuEntry::Slot(slot) => {
+                let name = format!("{} {}", script.label("slots_slot"), slot + 1);
+                let mut text = match self.slots.get(*slot).and_then(Option::as_ref) {
+                    Some(card) => format!(
+                        "{name}: {}, {} {}",
+                        card.summary.map_name.as_deref().unwrap_or("?"),
+                        card.summary.save_count,
+                        script.label("slots_saves"),
+                    ),
+                    None => format!("{name}: {}", script.label("slots_empty")),
+                };
+                if pick == SlotPick::CopyTo(*slot) {
+                    text = format!("{text} {}", script.label("slots_copy_source"));
+                } else if pick == SlotPick::EraseSure(*slot) {
+                    text = format!("{text} {}", script.label("slots_erase_sure"));
+                }
+                text
+            }
+            MenuEntry::CopySlot => script.label(match pick {
+                SlotPick::CopyFrom => "slots_copy_from",
+                SlotPick::CopyTo(_) => "slots_copy_to",
+                _ => "slots_copy",
+            }),
+            MenuEntry::EraseSlot => script.label(match pick {
+                SlotPick::Erase | SlotPick::EraseSure(_) => "slots_erase_pick",
+                _ => "slots_erase",
+            }),
+            _ => return None,
+        };
+        Some(text)
+    }
     pub fn exit_hover(&mut self, index: usize) {
         use MenuEntry::*;
         if let Reset(x) = &mut self.entries[index] {
@@ -312,6 +445,27 @@ impl MenuState {
         use crate::draw_state::LayerId::*;
         use crate::render::Canvas;
         use MenuEntry::*;
+        // A used slot previews its thumbnail, framed, above the entries.
+        if let Slot(slot) = self.entries[index]
+            && let Some(Some(SlotCard { thumbnail: Some(thumb), .. })) = self.slots.get(slot)
+        {
+            use crate::render::image::RgbaImage;
+            use crate::render::{EdgePolicy, Transform};
+            let c2 = draw_state.colour(2);
+            let (w, h) = draw_state.size();
+            let (tw, th) = (thumb.width() as i32, thumb.height() as i32);
+            let (x, y) = ((w - tw) / 2, 3 + (h - crate::platform::HEIGHT) / 2);
+            let canvas = draw_state.rgba(BG);
+            canvas.fill_rect(x - 1, y - 1, tw + 2, th + 2, c2);
+            canvas.blit::<RgbaImage>(
+                x,
+                y,
+                thumb,
+                EdgePolicy::Transparent,
+                Transform::IDENTITY,
+                |p| p.a() == 0,
+            );
+        }
         if let Reset(_) = self.entries[index] {
             let c2 = draw_state.colour(2);
             let c12 = draw_state.colour(12);
@@ -400,6 +554,13 @@ pub enum MenuEntry {
     MapTest,
     MapSelect(String),
     Walk,
+    /// A save slot on the slot screen (0-based); its text comes from
+    /// [`MenuState::slot_text`].
+    Slot(usize),
+    /// Start (or cancel) copying one slot over another.
+    CopySlot,
+    /// Start (or cancel) erasing a slot.
+    EraseSlot,
 }
 /// Render a toggle entry's label with its live on/off state — the menu's
 /// `[x]`/`[ ]` checkbox convention. The menu UI is rebuilt every frame, so the
@@ -438,6 +599,10 @@ impl MenuEntry {
             MapTest => script.label("menu_map_test"),
             Walk => script.label("menu_play"),
             MapSelect(name) => name.clone(),
+            // Drawn through `MenuState::slot_text`, which knows the cards.
+            Slot(slot) => format!("{} {}", script.label("slots_slot"), slot + 1),
+            CopySlot => script.label("slots_copy"),
+            EraseSlot => script.label("slots_erase"),
         }
     }
 }
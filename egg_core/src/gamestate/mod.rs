@@ -23,11 +23,14 @@ use crate::platform::ConsoleApi;
 pub use self::intro::IntroAnimation;
 pub use self::menu::MenuState;
 pub use self::scrubber::CutsceneScrubber;
+pub use self::smoke::{SmokeProblem, SmokeReport};
 pub use self::sprite_test::SpriteTest;
 
 mod intro;
 mod menu;
 pub mod scrubber;
+pub mod slots;
+pub mod smoke;
 mod sprite_test;
 pub mod walkaround;
 
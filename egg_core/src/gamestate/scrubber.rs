@@ -149,6 +149,7 @@ impl EggState {
                 save: &mut scratch,
                 items: &self.items,
                 presets: &self.presets,
+                schedules: &self.schedules,
                 font: &self.font,
             };
             base_world.arm_cutscene(&def, &mut ctx);
@@ -235,6 +236,7 @@ impl EggState {
                 save: &mut self.save,
                 items: &self.items,
                 presets: &self.presets,
+                schedules: &self.schedules,
                 font: &self.font,
             };
             scrubber
@@ -283,6 +285,7 @@ impl EggState {
             save: &mut scratch,
             items: &self.items,
             presets: &self.presets,
+            schedules: &self.schedules,
             font: &self.font,
         };
         scrubber.current = scrubber.replay.seek(scrubber.frame, &mut ctx);
@@ -379,9 +382,11 @@ mod tests {
     /// without disturbing the live world.
     #[test]
     fn open_scrubber_measures_and_parks_at_zero() {
-        let mut state = EggState::default();
-        state.scenes =
-            scene::parse("#cutscene town_path\n    move\n        player: walk 30 0 in 10").unwrap();
+        let mut state = EggState {
+            scenes: scene::parse("#cutscene town_path\n    move\n        player: walk 30 0 in 10")
+                .unwrap(),
+            ..EggState::default()
+        };
         let live_pos = state.walkaround.player_ref().pos;
 
         state.open_scrubber("town_path");
@@ -533,9 +538,10 @@ mod tests {
     /// [`drive_scrubber`](EggState::drive_scrubber) does, minus the draw pass.
     #[test]
     fn dragging_the_timeline_moves_the_playhead() {
-        let mut state = EggState::default();
-        state.scenes =
-            scene::parse("#cutscene t\n    move\n        player: walk 30 0 in 20").unwrap();
+        let mut state = EggState {
+            scenes: scene::parse("#cutscene t\n    move\n        player: walk 30 0 in 20").unwrap(),
+            ..EggState::default()
+        };
         state.open_scrubber("t");
         let mut scrubber = state.scrubber.take().expect("session opened");
         assert_eq!(scrubber.replay.total, 20);
@@ -550,10 +556,12 @@ mod tests {
 
         // A helper: one pointer frame at `(x, cy)` with the given button edge.
         let pointer = |scrubber: &mut CutsceneScrubber, x: i16, left: [bool; 2]| {
-            let mut mouse = MouseInput::default();
-            mouse.x = [x, x];
-            mouse.y = [cy, cy];
-            mouse.left = left;
+            let mouse = MouseInput {
+                x: [x, x],
+                y: [cy, cy],
+                left,
+                ..MouseInput::default()
+            };
             let on_track =
                 scrubber.build_timeline(screen).hit_at(0, 0, mouse.pos()) == Some(TimelineKey::Track);
             scrubber.scrub_pointer(&mouse, track, on_track);
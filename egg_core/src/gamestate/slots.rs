@@ -0,0 +1,72 @@
+//! Save slots: the title's slot screen (a [`MenuState`](super::MenuState)
+//! flavor) picks one of [`SLOT_COUNT`] saves to play, copy or erase. Each slot is
+//! a JSON save at [`slot_path`] plus a PNG thumbnail at [`slot_thumb_path`], all
+//! through the host's string-named file store — so a slot lands on disk natively
+//! and in `localStorage` on web with no slot-specific host code. The menu only
+//! *asks* for slot I/O (a [`SlotRequest`]); [`EggState`](crate::EggState) owns
+//! the live save and which slot it belongs to, so it carries the request out.
+
+use crate::data::save::{SAVE_PATH, SLOT_COUNT, SlotSummary, slot_path, slot_thumb_path};
+use crate::platform::ConsoleApi;
+use crate::render::image::RgbaImage;
+
+/// Thumbnail size: a quarter of the base 240×136 screen.
+pub const THUMB_WIDTH: u32 = 60;
+pub const THUMB_HEIGHT: u32 = 34;
+
+/// Slot I/O the slot screen wants done, drained by
+/// [`EggState::run`](crate::EggState::run) after the menu steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotRequest {
+    /// Make this the active slot, loading its save (an empty slot starts fresh).
+    Load(usize),
+    /// Overwrite slot `to` (save and thumbnail) with slot `from`.
+    Copy { from: usize, to: usize },
+    /// Delete the slot's save and thumbnail.
+    Erase(usize),
+}
+
+/// One used slot as the slot screen shows it.
+#[derive(Clone, Debug)]
+pub struct SlotCard {
+    pub summary: SlotSummary,
+    pub thumbnail: Option<RgbaImage>,
+}
+
+/// The stored save JSON for `slot`, if any. Slot 0 falls back to the legacy
+/// single save ([`SAVE_PATH`]) until it has been written itself, so progress
+/// from before slots existed shows up (and loads) there.
+pub fn read_slot(system: &mut impl ConsoleApi, slot: usize) -> Option<Vec<u8>> {
+    system
+        .read_file(&slot_path(slot))
+        .or_else(|| (slot == 0).then(|| system.read_file(SAVE_PATH)).flatten())
+}
+
+/// Every slot's card, `None` for an empty (or unreadable) slot.
+pub fn read_cards(system: &mut impl ConsoleApi) -> Vec<Option<SlotCard>> {
+    (0..SLOT_COUNT)
+        .map(|slot| {
+            let summary = SlotSummary::from_json(&read_slot(system, slot)?)?;
+            let thumbnail = system
+                .read_file(&slot_thumb_path(slot))
+                .and_then(|png| RgbaImage::decode_png(&png));
+            Some(SlotCard { summary, thumbnail })
+        })
+        .collect()
+}
+
+/// Shrink the finished frame to a [`THUMB_WIDTH`]×[`THUMB_HEIGHT`] thumbnail by
+/// nearest-neighbour sampling, whatever size the framebuffer has grown to.
+pub fn thumbnail(frame: &RgbaImage) -> RgbaImage {
+    let mut thumb = RgbaImage::new(THUMB_WIDTH, THUMB_HEIGHT);
+    if frame.width() == 0 || frame.height() == 0 {
+        return thumb;
+    }
+    for y in 0..THUMB_HEIGHT {
+        for x in 0..THUMB_WIDTH {
+            let (sx, sy) = (x * frame.width() / THUMB_WIDTH, y * frame.height() / THUMB_HEIGHT);
+            thumb.set_pixel(x, y, frame.get_pixel(sx, sy));
+        }
+    }
+    thumb
+}
@@ -0,0 +1,220 @@
+//! The cutscene smoke test: play every registered scene to its end, headless,
+//! and report the ones that don't get there cleanly.
+//!
+//! Each scene runs from the same snapshot of the live world + save, the way the
+//! [scrubber](super::scrubber) re-sims one: armed on a clone (so its `init_map`
+//! loads there, and a scene without one plays where the world stands), driven
+//! through a [`NullConsole`] with `A` held as a permanent rising edge so every
+//! `dialogue` beat auto-advances, on a throwaway save. Nothing leaks back into
+//! live state. A scene is flagged when it
+//! - is still playing at the frame cap ([`SmokeProblem::NeverFinished`]),
+//! - cancels — a required `?` motion stayed blocked ([`SmokeProblem::Cancelled`]),
+//! - names an actor that resolves to nothing when its step runs
+//!   ([`SmokeProblem::Unresolved`]) — playback silently skips those.
+//!
+//! Driven by the headless harness's `--smoke` and by the shipped-content
+//! integration test (`egg_core/tests/cutscene_smoke.rs`).
+
+use std::fmt;
+
+use crate::gamestate::walkaround::CutsceneTrace;
+use crate::platform::{EggInput, NullConsole};
+use crate::{Ctx, EggState};
+
+/// Default frame cap per scene: ten minutes at 60fps — far beyond any authored
+/// scene, short enough that a wedged one fails fast.
+pub const SMOKE_FRAME_CAP: usize = 36_000;
+
+/// One way a scene failed its smoke run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SmokeProblem {
+    /// Still playing when the frame cap hit.
+    NeverFinished,
+    /// A scene on the stack (the one run, or a sub-scene it `load`ed) was
+    /// cancelled on this frame — a required `?` motion stayed blocked.
+    Cancelled { frame: usize },
+    /// Actor names a step referenced that resolved to nothing, sorted.
+    Unresolved(Vec<String>),
+}
+
+impl fmt::Display for SmokeProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmokeProblem::NeverFinished => write!(f, "never finished"),
+            SmokeProblem::Cancelled { frame } => {
+                write!(f, "cancelled on frame {frame} (a required `?` motion was blocked)")
+            }
+            SmokeProblem::Unresolved(names) => {
+                write!(f, "unresolved actor(s): {}", names.join(", "))
+            }
+        }
+    }
+}
+
+/// The outcome of one scene's smoke run.
+#[derive(Clone, Debug)]
+pub struct SmokeReport {
+    /// The scene's registry name.
+    pub name: String,
+    /// Frames played — to the end, or the cap.
+    pub frames: usize,
+    /// Everything that went wrong, in the order it was found; empty when clean.
+    pub problems: Vec<SmokeProblem>,
+}
+
+impl SmokeReport {
+    /// Whether the scene played to its end with nothing to report.
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl EggState {
+    /// Smoke-test every scene in the registry, in name order, each capped at
+    /// `frame_cap` frames. See the [module docs](self).
+    pub fn smoke_cutscenes(&mut self, frame_cap: usize) -> Vec<SmokeReport> {
+        self.scenes
+            .names()
+            .iter()
+            .filter_map(|name| self.smoke_cutscene(name, frame_cap))
+            .collect()
+    }
+
+    /// Smoke-test the scene `name` against a snapshot of the live world + save,
+    /// capped at `frame_cap` frames. `None` for a name the registry lacks.
+    pub fn smoke_cutscene(&mut self, name: &str, frame_cap: usize) -> Option<SmokeReport> {
+        let def = self.scenes.get_cutscene_resolved(name)?;
+        let mut world = self.walkaround.clone();
+        world.cutscene.clear();
+        let mut scratch = self.save.clone();
+        let mut rng = self.rng.clone();
+        let mut console = NullConsole::new();
+        // A permanent `A` rising edge, as the scrubber holds it: every
+        // `dialogue` beat reads a fresh press and advances. The dpad stays
+        // neutral, so nothing interrupts and nothing skips.
+        let mut input = EggInput::new();
+        input.controllers[0].a = [true, false];
+        let mut ctx = Ctx {
+            draw: &mut self.draw_state,
+            system: &mut console,
+            input: &input,
+            maps: &mut self.maps,
+            rng: &mut rng,
+            script: &self.script,
+            scenes: &self.scenes,
+            save: &mut scratch,
+            items: &self.items,
+            presets: &self.presets,
+            schedules: &self.schedules,
+            font: &self.font,
+        };
+
+        world.arm_cutscene(&def, &mut ctx);
+        let mut trace = CutsceneTrace::default();
+        let mut problems = Vec::new();
+        let mut frames = 0;
+        let mut finished = false;
+        while frames < frame_cap {
+            let cancelled = trace.cancelled;
+            if !world.play_cutscene_traced(&mut ctx, &mut trace) {
+                finished = true;
+                break;
+            }
+            frames += 1;
+            if trace.cancelled > cancelled {
+                problems.push(SmokeProblem::Cancelled { frame: frames });
+            }
+        }
+        if !finished {
+            world.trace_live_cutscenes(&mut trace);
+            problems.push(SmokeProblem::NeverFinished);
+        }
+        if !trace.unresolved.is_empty() {
+            problems.push(SmokeProblem::Unresolved(trace.unresolved.into_iter().collect()));
+        }
+        Some(SmokeReport {
+            name: name.to_string(),
+            frames,
+            problems,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::scene;
+    use crate::geometry::{Collider, Vec2};
+    use crate::world::map::{LayerInfo, MapInfo};
+
+    fn state_with(src: &str) -> EggState {
+        EggState {
+            scenes: scene::parse(src).unwrap(),
+            ..EggState::default()
+        }
+    }
+
+    /// A scene that plays to its end is clean, its length matches what the
+    /// scrubber measures, and the live world never moves.
+    #[test]
+    fn a_scene_that_finishes_is_clean() {
+        let mut state = state_with("#cutscene t\n    move\n        player: walk 20 0 in 10");
+        let live = state.walkaround.player_ref().pos;
+
+        let report = state.smoke_cutscene("t", SMOKE_FRAME_CAP).expect("known scene");
+
+        assert!(report.is_clean(), "{:?}", report.problems);
+        assert_eq!(report.frames, 10, "measured the 10-frame walk");
+        assert_eq!(state.walkaround.player_ref().pos, live, "played on a snapshot");
+        assert!(state.smoke_cutscene("nope", 10).is_none());
+    }
+
+    /// Running out of frames, a blocked required move, and a name that binds to
+    /// nothing are each reported.
+    #[test]
+    fn each_failure_is_reported() {
+        let src = "#cutscene long\n    wait 100\n\
+                   #cutscene ghost\n    move\n        ghost: walk 5 5\n        player: face ghost\n\
+                   #cutscene stuck\n    move\n        player: walk 64 8?";
+        let mut state = state_with(src);
+        // A wall down tile column 4, right in the way of `stuck`'s straight walk.
+        let mut solid = Collider::default();
+        for y in 0..8 {
+            for x in 0..8 {
+                solid.set(x, y, true);
+            }
+        }
+        state.walkaround.current_map = MapInfo {
+            layers: vec![LayerInfo {
+                colliders: (0..9)
+                    .flat_map(|y| (0..12).map(move |x| (x, y)))
+                    .map(|(x, y)| if x == 4 && y < 4 { solid.clone() } else { Collider::default() })
+                    .collect(),
+                ..LayerInfo::new(0, 0, 12, 9)
+            }],
+            source: "room".into(),
+            ..MapInfo::default()
+        };
+        // Collision reads the current map's store entry alongside its layers.
+        let json = r#"{
+            "width": 2, "height": 2,
+            "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": []}]
+        }"#;
+        state.maps.insert("room", serde_json::from_str(json).unwrap());
+        state.walkaround.player().pos = Vec2::new(8, 8);
+
+        let reports = state.smoke_cutscenes(90);
+        let problems: Vec<(&str, &[SmokeProblem])> =
+            reports.iter().map(|r| (r.name.as_str(), &r.problems[..])).collect();
+        assert_eq!(
+            problems,
+            [
+                ("ghost", &[SmokeProblem::Unresolved(vec!["ghost".into()])][..]),
+                ("long", &[SmokeProblem::NeverFinished][..]),
+                // Reaches the wall after 17 frames, then sticks for six.
+                ("stuck", &[SmokeProblem::Cancelled { frame: 23 }][..]),
+            ]
+        );
+    }
+}
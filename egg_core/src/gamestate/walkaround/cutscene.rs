@@ -20,18 +20,26 @@
 //! [`WalkaroundState`] — a `load` step pushes a sub-cutscene, popped on finish —
 //! so map changes (a sub-cutscene's `init_map`) happen at cutscene boundaries
 //! with fresh requisition.
+//!
+//! An `if` chain is resolved the moment the scene reaches it: the chosen
+//! branch's steps are spliced into the content list in the chain's place (see
+//! [`Cutscene::splice_if`]), so everything downstream — stepping, skipping,
+//! the scrubber's beat cursor — only ever sees ordinary steps.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use crate::Ctx;
 use crate::data::scene::{
-    CameraTarget, Chain, CueHandler, CutsceneContent, CutsceneDef, EntityRef, GetEntity, Motion,
+    self, CameraTarget, Chain, Condition, CueHandler, CutsceneContent, CutsceneDef, EntityRef,
+    GetEntity, IfChain, Motion,
 };
 use crate::data::sound::music::MusicTrack;
 use crate::data::sound::{self};
 use crate::geometry::Vec2;
 use crate::platform::{ConsoleApi, ConsoleHelper, dpad_delta, just_pressed, pressed};
 use crate::world::camera::Shake;
+use crate::world::interact::Talk;
+use crate::world::pathfind::PATH_STEP;
 use crate::world::player::{EntityId, MoveMode, Shell};
 
 use super::{EntityPath, WalkaroundState};
@@ -54,6 +62,9 @@ pub struct Cutscene {
     table: HashMap<String, EntityId>,
     /// Names of transient `spawn`ed actors, removed from the world on finish.
     spawned: Vec<String>,
+    /// The steps still to play (and those already played), with every `if`
+    /// chain reached so far replaced by its chosen branch — see
+    /// [`splice_if`](Self::splice_if).
     content: Vec<CutsceneContent>,
     /// Index of the content step currently playing.
     step: usize,
@@ -90,6 +101,13 @@ pub struct Cutscene {
     /// [`cleanup`]: Self::cleanup
     /// [`spawned`]: Self::spawned
     posed: HashSet<EntityId>,
+    /// Actor names a step entered so far referenced (as a chain's actor, a
+    /// motion target, an `interact` side, or a `camera` subject) that resolved
+    /// to nothing in the live world at the time. Playback shrugs these off —
+    /// the motion is skipped, the camera stays put — so they're only recorded
+    /// for the cutscene smoke test to report (see
+    /// [`unresolved_actors`](Self::unresolved_actors)).
+    unresolved: BTreeSet<String>,
 }
 
 /// Progress of a `camera … over N` glide: the focus eases from `from` (the
@@ -133,6 +151,15 @@ enum StepState {
     Dialogue { opened: bool, close_pending: bool },
     /// Frames left to wait.
     Wait(u32),
+    /// A `warp … fade N` in flight, `elapsed` frames into its `2 * frames`
+    /// hold: the palette darkens for `frames` frames, the map swaps on the
+    /// frame `elapsed` reaches `frames`, then it brightens for `frames` more.
+    Warp {
+        map: String,
+        pos: Vec2,
+        frames: u32,
+        elapsed: u32,
+    },
     /// Finished — advance to the next step.
     Done,
 }
@@ -147,11 +174,23 @@ struct ChainProgress {
     /// Consecutive frames the current instruction made no progress (blocked).
     /// Trips [`STUCK_LIMIT`] for a required motion → the scene cancels.
     stuck: u16,
+    /// The route a `walk!`/`to!` instruction is following (unused by every
+    /// other motion). Reset with `elapsed` when the chain moves on.
+    route: Route,
+}
+
+/// A planned `walk!`/`to!` route ([`Shell::route_to`]): the waypoints still ahead,
+/// the next one *last*, and the target they lead to — `None` until planned.
+#[derive(Clone, Debug, Default)]
+struct Route {
+    goal: Option<Vec2>,
+    ahead: Vec<Vec2>,
 }
 
 /// One running `on NAME [wait]` handler for the current `dialogue` step: which
-/// handler (by index into that step's [`CueHandler`] list), how far through
-/// its own content it's gotten, and its live [`StepState`] — never
+/// handler (by index into that step's [`CueHandler`] list), its own copy of
+/// that handler's content (so an `if` in it can be spliced like a top-level
+/// one), how far through it it's gotten, and its live [`StepState`] — never
 /// `Dialogue`: [`Cutscene::enter_content`]'s `Interact` arm treats a
 /// handler's `interact` as always-instant rather than tracking a box it might
 /// open (see that method's doc), and a literal `dialogue`/`load` step is
@@ -164,8 +203,11 @@ struct ChainProgress {
 struct HandlerRun {
     /// Index into the current dialogue step's `handlers` list.
     handler_index: usize,
-    /// The next content-step index in `handlers[handler_index].content` to
-    /// enter. `>= content.len()` means this run has finished.
+    /// `handlers[handler_index].content`, cloned when the run fires, with
+    /// each `if` reached so far spliced to its chosen branch.
+    content: Vec<CutsceneContent>,
+    /// The next step index in [`content`](Self::content) to enter.
+    /// `>= content.len()` means this run has finished.
     step: usize,
     state: StepState,
 }
@@ -248,6 +290,7 @@ impl Cutscene {
             shake: None,
             handler_runs: Vec::new(),
             posed: HashSet::new(),
+            unresolved: BTreeSet::new(),
         }
     }
 
@@ -263,6 +306,41 @@ impl Cutscene {
         self.step
     }
 
+    /// Every actor name this scene has referenced so far that didn't resolve
+    /// when its step was entered, sorted. See the `unresolved` field.
+    pub(super) fn unresolved_actors(&self) -> impl Iterator<Item = &str> {
+        self.unresolved.iter().map(String::as_str)
+    }
+
+    /// Record each actor `content` names that doesn't resolve in `walkaround`
+    /// right now. Called on entering a step (top-level or handler), so a name
+    /// bound on a later map — after a `warp` — is judged where it's used.
+    fn note_unresolved(&mut self, content: &CutsceneContent, walkaround: &WalkaroundState) {
+        let names: Vec<&str> = match content {
+            CutsceneContent::Move(chains) => chains
+                .iter()
+                .flat_map(|chain| {
+                    std::iter::once(chain.actor.as_str()).chain(
+                        chain
+                            .instructions
+                            .iter()
+                            .filter_map(|instr| instr.motion.target_actor()),
+                    )
+                })
+                .collect(),
+            CutsceneContent::Interact { actor, target } => {
+                vec![actor.as_str(), target.as_str()]
+            }
+            CutsceneContent::Camera(CameraTarget::Actor(name), _) => vec![name.as_str()],
+            _ => Vec::new(),
+        };
+        for name in names {
+            if walkaround.resolve(&resolve_name(name, &self.table)).is_none() {
+                self.unresolved.insert(name.to_string());
+            }
+        }
+    }
+
     /// The map-pixel point this scene currently centres the camera on, or `None`
     /// to follow the player — the default focus, what the scene resets to when it
     /// ends, and the fallback when a `camera ACTOR` target can't be resolved.
@@ -334,6 +412,14 @@ impl Cutscene {
                         self.state = StepState::Pending;
                         return Outcome::Load(name);
                     }
+                    // Resolved in place, then re-enter whatever now sits at
+                    // this index (the branch's first step, or the step after
+                    // the chain when the branch is empty) on the same frame.
+                    CutsceneContent::If(chain) => {
+                        let step = self.step;
+                        Self::splice_if(&mut self.content, step, chain, ctx, walkaround);
+                        continue;
+                    }
                     _ => {
                         self.state = self.enter_content(ctx, walkaround, &content, false);
                     }
@@ -347,6 +433,8 @@ impl Cutscene {
             } else if let StepState::Wait(frames) = &mut self.state {
                 *frames = frames.saturating_sub(1);
                 *frames == 0
+            } else if matches!(self.state, StepState::Warp { .. }) {
+                self.advance_warp(ctx, walkaround)
             } else {
                 matches!(self.state, StepState::Done)
             };
@@ -394,6 +482,7 @@ impl Cutscene {
         content: &CutsceneContent,
         in_handler: bool,
     ) -> StepState {
+        self.note_unresolved(content, walkaround);
         match content {
             CutsceneContent::Move(chains) => {
                 let progress = chains
@@ -402,6 +491,7 @@ impl Cutscene {
                         instr: 0,
                         elapsed: 0,
                         stuck: 0,
+                        route: Route::default(),
                     })
                     .collect();
                 StepState::Move {
@@ -433,6 +523,32 @@ impl Cutscene {
                 ctx.save.set_flag(name, *value);
                 StepState::Done
             }
+            CutsceneContent::SetVar(name, value) => {
+                ctx.save.set_var(name, *value);
+                StepState::Done
+            }
+            CutsceneContent::AddVar(name, delta) => {
+                ctx.save.add_var(name, *delta);
+                StepState::Done
+            }
+            CutsceneContent::GiveItem(_) | CutsceneContent::TakeItem(_) => {
+                bag_step(content, walkaround);
+                StepState::Done
+            }
+            // Never reached with `in_handler` — `warp` can't nest inside an
+            // `on` (parse-rejected).
+            CutsceneContent::Warp { map, pos, fade } => match fade {
+                Some(frames) => StepState::Warp {
+                    map: map.clone(),
+                    pos: *pos,
+                    frames: *frames,
+                    elapsed: 0,
+                },
+                None => {
+                    self.warp_actors(ctx, walkaround, map, *pos);
+                    StepState::Done
+                }
+            },
             CutsceneContent::Camera(target, over) => {
                 // Retarget the scene camera; the per-frame centring in
                 // `play_cutscene` reads it back via `camera_focus`. A
@@ -466,9 +582,30 @@ impl Cutscene {
                 "Load is handled by the caller: the main loop keeps it for \
                  itself, and a handler body can never contain one (parse-rejected)"
             ),
+            CutsceneContent::If(_) => {
+                unreachable!("If is spliced to its chosen branch by the caller before entry")
+            }
         }
     }
 
+    /// Resolve the `if` chain at `content[at]` against the live save (and
+    /// bag) and splice its chosen branch's steps in the chain's place (an empty
+    /// branch just removes it). Shared by the main step loop, a handler run
+    /// (each over its own content list), and [`skip`](Self::skip). Deciding
+    /// at the moment the chain is reached — rather than once at launch — is
+    /// what lets an earlier `set` or answered `#choice` in the same scene
+    /// steer it.
+    fn splice_if<S: ConsoleApi>(
+        content: &mut Vec<CutsceneContent>,
+        at: usize,
+        chain: &IfChain,
+        ctx: &Ctx<S>,
+        walkaround: &WalkaroundState,
+    ) {
+        let chosen = chain.pick(|cond| condition_holds(cond, ctx, walkaround)).to_vec();
+        content.splice(at..=at, chosen);
+    }
+
     /// Advance one set of parallel chains (+ their progress) one frame — the
     /// shared core [`advance_move`](Self::advance_move) (the main `Move`
     /// state) and [`advance_handler_move`](Self::advance_handler_move) (a
@@ -501,6 +638,7 @@ impl Cutscene {
                 &ins.motion,
                 ins.time,
                 prog.elapsed,
+                &mut prog.route,
                 &self.table,
                 ctx,
                 walkaround,
@@ -519,6 +657,7 @@ impl Cutscene {
                 prog.instr += 1;
                 prog.elapsed = 0;
                 prog.stuck = 0;
+                prog.route = Route::default();
             }
         }
         chains
@@ -588,17 +727,14 @@ impl Cutscene {
         let handlers = self.current_handlers();
         self.handler_runs
             .iter()
-            .any(|run| handlers[run.handler_index].wait && run.step < handlers[run.handler_index].content.len())
+            .any(|run| handlers[run.handler_index].wait && run.step < run.content.len())
     }
 
     /// Whether every fired handler for the current dialogue step has
     /// finished (walked its `step` off the end of its own content list).
     /// Vacuously true when nothing has fired.
     fn handlers_finished(&self) -> bool {
-        let handlers = self.current_handlers();
-        self.handler_runs
-            .iter()
-            .all(|run| run.step >= handlers[run.handler_index].content.len())
+        self.handler_runs.iter().all(|run| run.step >= run.content.len())
     }
 
     /// Drain every `#cue` the box has banked since the last drain (see
@@ -621,8 +757,8 @@ impl Cutscene {
         if handlers.is_empty() {
             return;
         }
-        // Cloned so the tick loop below can freely call `&mut self` methods
-        // alongside it without borrowing `self.content`.
+        // Cloned so the drain loop below can push onto `self.handler_runs`
+        // without borrowing `self.content`.
         let handlers = handlers.to_vec();
         for cue in walkaround.dialogue.take_cues() {
             let Some(handler_index) = handlers.iter().position(|h| h.cue == cue) else {
@@ -634,38 +770,38 @@ impl Cutscene {
             }
             self.handler_runs.push(HandlerRun {
                 handler_index,
+                content: handlers[handler_index].content.clone(),
                 step: 0,
                 state: StepState::Pending,
             });
         }
         for i in 0..self.handler_runs.len() {
-            self.tick_handler_run(ctx, walkaround, i, &handlers);
+            self.tick_handler_run(ctx, walkaround, i);
         }
     }
 
     /// Tick handler run `i` one frame, through the same enter → advance →
     /// maybe-finish sequence [`step`](Self::step)'s main loop drives,
-    /// against `handlers[run.handler_index]`'s own content list (`handlers`
-    /// is the caller's clone — see
-    /// [`drain_cues_and_tick_handlers`](Self::drain_cues_and_tick_handlers)
-    /// — so this can freely call `&mut self` methods alongside it). Chains
-    /// through as many instant steps as complete in one frame, same as the
-    /// main loop; stops at the first step that doesn't finish this frame, or
-    /// at the handler's end.
+    /// against the run's own content list. Chains through as many instant
+    /// steps as complete in one frame, same as the main loop (an `if` is
+    /// spliced in place, exactly as there); stops at the first step that
+    /// doesn't finish this frame, or at the handler's end.
     fn tick_handler_run<S: ConsoleApi>(
         &mut self,
         ctx: &mut Ctx<S>,
         walkaround: &mut WalkaroundState,
         i: usize,
-        handlers: &[CueHandler],
     ) {
-        let handler = &handlers[self.handler_runs[i].handler_index];
         loop {
-            if self.handler_runs[i].step >= handler.content.len() {
+            let at = self.handler_runs[i].step;
+            let Some(content) = self.handler_runs[i].content.get(at).cloned() else {
                 return;
-            }
+            };
             if matches!(self.handler_runs[i].state, StepState::Pending) {
-                let content = handler.content[self.handler_runs[i].step].clone();
+                if let CutsceneContent::If(chain) = &content {
+                    Self::splice_if(&mut self.handler_runs[i].content, at, chain, ctx, walkaround);
+                    continue;
+                }
                 self.handler_runs[i].state = self.enter_content(ctx, walkaround, &content, true);
             }
             let done = match &self.handler_runs[i].state {
@@ -681,6 +817,7 @@ impl Cutscene {
                 StepState::Dialogue { .. } => unreachable!(
                     "a handler run never enters Dialogue state — see `enter_content`'s doc"
                 ),
+                StepState::Warp { .. } => unreachable!("`warp` is parse-rejected in a handler"),
                 StepState::Pending => unreachable!("just entered above"),
             };
             if self.aborted {
@@ -734,10 +871,9 @@ impl Cutscene {
                 unreachable!("Dialogue state ⇒ Dialogue content");
             };
             let key = key.clone();
-            let convo = ctx.get_dialogue(&key);
             walkaround
                 .dialogue
-                .set_messages(ctx.system, ctx.font, ctx.save, &convo);
+                .open(ctx.system, ctx.font, ctx.save, ctx.script, &key);
             self.state = StepState::Dialogue { opened: true, close_pending: false };
             self.drain_cues_and_tick_handlers(ctx, walkaround);
             return false;
@@ -798,9 +934,10 @@ impl Cutscene {
         finished
     }
 
-    /// Fire the `target` actor's intrinsic [`Shell::interaction`], with `actor`
-    /// as the initiator. An unresolvable target or one with no interaction logs
-    /// and is skipped.
+    /// Fire the `target` actor's intrinsic [`Shell::interaction`] — or, for a
+    /// creature without one, its [`Shell::talk`] — with `actor` as the
+    /// initiator. An unresolvable target or one with neither logs and is
+    /// skipped.
     fn fire_interact<S: ConsoleApi>(
         &self,
         ctx: &mut Ctx<S>,
@@ -811,7 +948,7 @@ impl Cutscene {
         let target_id = resolve_name(target, &self.table);
         let Some(interaction) = walkaround
             .resolve(&target_id)
-            .and_then(|s| s.interaction.clone())
+            .and_then(|s| s.interaction.clone().or_else(|| s.talk.as_ref().map(Talk::interaction)))
         else {
             log::info!("cutscene interact: `{target}` is absent or not interactive");
             return;
@@ -821,6 +958,84 @@ impl Cutscene {
         walkaround.inventory_ui.inventory = inventory;
     }
 
+    /// Tick a `warp … fade` step one frame, swapping maps on the darkest
+    /// frame. Returns `true` once the fade back in has finished.
+    fn advance_warp<S: ConsoleApi>(
+        &mut self,
+        ctx: &mut Ctx<S>,
+        walkaround: &mut WalkaroundState,
+    ) -> bool {
+        let StepState::Warp { map, pos, frames, elapsed } = &mut self.state else {
+            unreachable!("only called in Warp state");
+        };
+        *elapsed += 1;
+        let done = *elapsed >= 2 * *frames;
+        if *elapsed == *frames {
+            let (map, pos) = (map.clone(), *pos);
+            self.warp_actors(ctx, walkaround, &map, pos);
+        }
+        done
+    }
+
+    /// How far toward black a running `warp … fade` has the screen, on
+    /// [`fade_palette_into`](crate::draw_state::fade_palette_into)'s 0–256
+    /// scale: ramps up to 256 on the swap frame, then back down to 0. Zero
+    /// whenever no fade is in flight. Read each frame by
+    /// [`sync_scene_fade`](super::WalkaroundState::sync_scene_fade).
+    pub(super) fn fade_amount(&self) -> u16 {
+        let StepState::Warp { frames, elapsed, .. } = self.state else {
+            return 0;
+        };
+        let left = if elapsed <= frames { elapsed } else { 2 * frames - elapsed };
+        (left * 256 / frames) as u16
+    }
+
+    /// Move the player to `pos` on `map` through
+    /// [`load_named_map`](WalkaroundState::load_named_map) (schedules left
+    /// alone), persisting the map being left the way a `Warp` object does.
+    /// Every actor this scene binds by id travels along, keeping its offset
+    /// from the player (so a companion-in-scene doesn't get parked behind);
+    /// companions follow via the trail as usual. An unknown `map` logs and
+    /// leaves everyone put.
+    fn warp_actors<S: ConsoleApi>(
+        &self,
+        ctx: &mut Ctx<S>,
+        walkaround: &mut WalkaroundState,
+        map: &str,
+        pos: Vec2,
+    ) {
+        if !ctx.maps.is_modern(map) {
+            log::info!("cutscene warp: unknown map `{map}`");
+            return;
+        }
+        let bound: HashSet<&str> = self
+            .table
+            .values()
+            .filter_map(|id| match id {
+                EntityId::Id(id) => Some(id.as_str()),
+                _ => None,
+            })
+            .collect();
+        let (carried, stay): (Vec<Shell>, Vec<Shell>) = walkaround
+            .entities
+            .drain(1..)
+            .partition(|e| e.id.as_deref().is_some_and(|id| bound.contains(id)));
+        walkaround.entities.extend(stay);
+
+        let from = walkaround.player_ref().pos;
+        let player = walkaround.player();
+        player.pos = pos;
+        let dir = player.dir;
+        player.trail.fill(pos, dir);
+        player.update_companions();
+        walkaround.save(map, ctx.save);
+        walkaround.load_named_map(ctx, map, false);
+        for mut shell in carried {
+            shell.pos = shell.pos - from + pos;
+            walkaround.entities.push(shell);
+        }
+    }
+
     /// Remove this cutscene's transient `spawn`ed actors from the world and
     /// clear every `pose` it applied — run once, when it finishes or is
     /// skipped, so neither outlives the scene that created it.
@@ -868,6 +1083,12 @@ impl Cutscene {
                 }
             }
             CutsceneContent::SetFlag(name, value) => ctx.save.set_flag(name, *value),
+            CutsceneContent::SetVar(name, value) => ctx.save.set_var(name, *value),
+            CutsceneContent::AddVar(name, delta) => ctx.save.add_var(name, *delta),
+            CutsceneContent::GiveItem(_) | CutsceneContent::TakeItem(_) => {
+                bag_step(content, walkaround)
+            }
+            CutsceneContent::Warp { map, pos, .. } => self.warp_actors(ctx, walkaround, map, *pos),
             CutsceneContent::Sound(name) => {
                 if let Some(sfx) = sound::by_name(name) {
                     ctx.system.play_sound(sfx);
@@ -893,6 +1114,15 @@ impl Cutscene {
             CutsceneContent::Shake { .. } => self.shake = None,
             // A wait has no lasting effect, so fast-forwarding past it is a no-op.
             CutsceneContent::Wait(_) => {}
+            // Only a handler's chain lands here (the top-level `skip` loop
+            // splices its own): resolved against the save as it stands at
+            // this point of the skip, so earlier snapped `set`s count.
+            CutsceneContent::If(chain) => {
+                let chosen = chain.pick(|cond| condition_holds(cond, ctx, walkaround)).to_vec();
+                for sub in &chosen {
+                    self.skip_content(ctx, walkaround, sub);
+                }
+            }
             CutsceneContent::Dialogue { .. } | CutsceneContent::Load(_) => {
                 unreachable!("Dialogue/Load never reach skip_content — see this method's doc")
             }
@@ -922,7 +1152,7 @@ impl Cutscene {
         let mut fired: std::collections::HashSet<usize> =
             in_flight.iter().map(|r| r.handler_index).collect();
         for run in in_flight {
-            for content in &handlers[run.handler_index].content[run.step..] {
+            for content in &run.content[run.step..] {
                 self.skip_content(ctx, walkaround, content);
             }
         }
@@ -969,14 +1199,20 @@ impl Cutscene {
         // replay and an already-answered choice would be answered a *second*
         // time, with its first option, on top of the player's real pick.
         let mut live_box = matches!(self.state, StepState::Dialogue { opened: true, .. });
+        // A `warp … fade` already past its swap has moved everyone; only its
+        // fade-in is left, so step over it rather than warp a second time.
+        if let StepState::Warp { frames, elapsed, .. } = self.state
+            && elapsed >= frames
+        {
+            self.step += 1;
+        }
         while let Some(content) = self.content.get(self.step).cloned() {
             match &content {
                 CutsceneContent::Dialogue { key, handlers } => {
                     if !live_box {
-                        let convo = ctx.get_dialogue(key);
                         walkaround
                             .dialogue
-                            .set_messages(ctx.system, ctx.font, ctx.save, &convo);
+                            .open(ctx.system, ctx.font, ctx.save, ctx.script, key);
                     }
                     loop {
                         if walkaround.dialogue.is_choosing() {
@@ -991,6 +1227,14 @@ impl Cutscene {
                     walkaround.dialogue.close();
                     self.fire_and_snap_handlers(ctx, walkaround, handlers, &cues);
                 }
+                // Resolved against the save as the skip has left it so far, so
+                // the branch taken is the one full playback would take; the
+                // spliced steps are then skipped like any others.
+                CutsceneContent::If(chain) => {
+                    let step = self.step;
+                    Self::splice_if(&mut self.content, step, chain, ctx, walkaround);
+                    continue;
+                }
                 CutsceneContent::Load(name) => {
                     // Chase the sub-scene so its lasting side effects still land,
                     // then drop its transient actors — all in place, so nothing is
@@ -1027,6 +1271,46 @@ impl Cutscene {
     }
 }
 
+/// Whether an `if`/`elif` condition holds right now: a flag reads the live
+/// save, exactly as a `.eggtext` `#if` does at playback; `has ITEM` reads the
+/// live bag.
+fn condition_holds<S: ConsoleApi>(
+    condition: &Condition,
+    ctx: &Ctx<S>,
+    walkaround: &WalkaroundState,
+) -> bool {
+    match condition {
+        Condition::Flag(name) => ctx.save.flag(name),
+        Condition::Has(item) => walkaround.inventory_ui.inventory.find(item).is_some(),
+        Condition::Var(test) => test.holds(ctx.save),
+    }
+}
+
+/// Apply a `give`/`take` step to the player's bag — shared by live playback
+/// and [`Cutscene::skip`], so a skipped scene's inventory ends up the same.
+/// `give` goes through [`Inventory::add`](super::inventory::Inventory::add)
+/// like a `give_item` interaction does (a full bag is the same silent no-op);
+/// `take` removes the first slot holding the key via
+/// [`Inventory::take`](super::inventory::Inventory::take), and is a no-op when
+/// the bag holds none.
+fn bag_step(content: &CutsceneContent, walkaround: &mut WalkaroundState) {
+    let inventory = &mut walkaround.inventory_ui.inventory;
+    match content {
+        CutsceneContent::GiveItem(key) => {
+            if !inventory.add(key.clone()) {
+                log::info!("cutscene give: bag is full, `{key}` dropped");
+            }
+        }
+        CutsceneContent::TakeItem(key) => match inventory.find(key) {
+            Some(index) => {
+                inventory.take(index);
+            }
+            None => log::info!("cutscene take: bag holds no `{key}`"),
+        },
+        _ => unreachable!("bag_step only called for give/take"),
+    }
+}
+
 /// Resolve an actor name to an entity id: a bound/aliased name, else the
 /// reserved `player`/`companion N`, else a bare id (a map creature by `Shell::id`).
 fn resolve_name(name: &str, table: &HashMap<String, EntityId>) -> EntityId {
@@ -1073,7 +1357,7 @@ fn div_ceil(a: i32, b: i32) -> i32 {
 }
 
 /// The cardinal facing from `from` toward `to` (the dominant axis).
-fn facing_toward(from: Vec2, to: Vec2) -> (i8, i8) {
+pub(super) fn facing_toward(from: Vec2, to: Vec2) -> (i8, i8) {
     let dx = to.x - from.x;
     let dy = to.y - from.y;
     if dx.abs() >= dy.abs() {
@@ -1107,11 +1391,14 @@ fn apply_pose(shell: &mut Shell, actor: &str, name: &Option<String>) {
 /// Execute one frame of `motion` for the named `actor`. Returns `(finished,
 /// progressed)` — `progressed` is `false` only when a move is blocked, which
 /// drives the required-motion abort. An unresolvable actor logs and is skipped.
+/// `route` carries a `walk!`/`to!` plan across the instruction's frames.
+#[allow(clippy::too_many_arguments)]
 fn step_motion<S: ConsoleApi>(
     actor: &str,
     motion: &Motion,
     time: u16,
     elapsed: u16,
+    route: &mut Route,
     table: &HashMap<String, EntityId>,
     ctx: &mut Ctx<S>,
     walkaround: &mut WalkaroundState,
@@ -1157,6 +1444,11 @@ fn step_motion<S: ConsoleApi>(
             Some(tp) => move_toward(path, tp, time, elapsed, false, ctx, walkaround),
             None => (true, true),
         },
+        Motion::PathToPoint(p) => follow_route(path, *p, route, time, elapsed, ctx, walkaround),
+        Motion::PathToEntity(name) => match target_pos(name, walkaround) {
+            Some(tp) => follow_route(path, tp, route, time, elapsed, ctx, walkaround),
+            None => (true, true),
+        },
         Motion::MoveBesideHorizontal { target, gap } => {
             let actor_w = path.shell_ref(walkaround).local_hitbox.w;
             let Some((spot, facing)) = beside_spot(target, *gap, actor_w, table, walkaround) else {
@@ -1172,7 +1464,7 @@ fn step_motion<S: ConsoleApi>(
             (done, progressed)
         }
         Motion::Record { runs, noclip } => {
-            (replay_record(path, runs, *noclip, elapsed, ctx, walkaround), true)
+            (replay_record(path, runs, *noclip, time, elapsed, ctx, walkaround), true)
         }
         // Resolved into a `Motion::Record` at the registry boundary (see
         // `SceneFile::inline_paths`) before a def is ever launched — this arm
@@ -1214,30 +1506,82 @@ fn move_toward<S: ConsoleApi>(
         };
         let dx = remaining.x.signum() * remaining.x.abs().min(speed);
         let dy = remaining.y.signum() * remaining.y.abs().min(speed);
-        // Index the disjoint fields directly so `current_map` can be borrowed
-        // alongside the mutable shell (a `&mut Shell` from a method would not).
-        let tiles = ctx.maps.get(&walkaround.current_map.source);
-        let map = &walkaround.current_map;
-        let shell = match path {
-            EntityPath::Entity(i) => &mut walkaround.entities[i],
-            EntityPath::Companion(i, j) => &mut walkaround.entities[i].companions[j],
-        };
-        let before = shell.pos;
-        if noclip {
-            shell.face((dx.signum() as i8, dy.signum() as i8));
-            shell.pos.x += dx;
-            shell.pos.y += dy;
-            shell.animate_walk();
-        } else {
-            let (mdx, mdy) = shell.walk(ctx.system, dx, dy, false, map, tiles);
-            shell.apply_motion(mdx, mdy);
+        progressed = walk_shell(path, dx, dy, noclip, ctx, walkaround);
+    }
+    let arrived = path.shell_ref(walkaround).pos == target;
+    let done = if time > 0 {
+        elapsed + 1 >= time
+    } else {
+        arrived || elapsed + 1 >= NATURAL_CAP
+    };
+    (done, progressed)
+}
+
+/// Move `path`'s shell toward `target` one frame along a planned route — the
+/// `walk!`/`to!` counterpart of [`move_toward`], with the same timing and
+/// return. The route is planned on the first frame and re-planned once
+/// `target` strays more than a lattice step from its end (a `to!` target
+/// walking off); a smaller stray just moves the last waypoint. A target
+/// nothing can reach gets the straight line instead. A fixed `time` spreads
+/// the whole remaining route, not just the next leg, over the budget.
+fn follow_route<S: ConsoleApi>(
+    path: EntityPath,
+    target: Vec2,
+    route: &mut Route,
+    time: u16,
+    elapsed: u16,
+    ctx: &mut Ctx<S>,
+    walkaround: &mut WalkaroundState,
+) -> (bool, bool) {
+    let shell = path.shell_ref(walkaround);
+    let pos = shell.pos;
+    match route.goal {
+        Some(goal) if goal == target => {}
+        Some(goal) if chebyshev(goal, target) <= PATH_STEP && !route.ahead.is_empty() => {
+            route.ahead[0] = target;
+            route.goal = Some(target);
+        }
+        _ => {
+            let mut ahead = shell
+                .route_to(&walkaround.current_map, target)
+                .unwrap_or_else(|| vec![target]);
+            ahead.reverse();
+            *route = Route {
+                goal: Some(target),
+                ahead,
+            };
+        }
+    }
+    let (length, _) = route.ahead.iter().rev().fold((0i32, pos), |(sum, from), &to| {
+        (sum + i32::from(chebyshev(from, to)), to)
+    });
+    let mut progressed = length == 0;
+    // The pixels to cover this frame, spent leg by leg (a fast budgeted walk
+    // may turn a corner mid-frame).
+    let mut speed = if time > 0 {
+        let budget = time.saturating_sub(elapsed).max(1);
+        div_ceil(length, i32::from(budget)) as i16
+    } else {
+        1
+    };
+    while speed > 0
+        && let Some(&next) = route.ahead.last()
+    {
+        let at = path.shell_ref(walkaround).pos;
+        let remaining = Vec2::new(next.x - at.x, next.y - at.y);
+        let take = speed.min(chebyshev(at, next));
+        if take > 0 {
+            let dx = remaining.x.signum() * remaining.x.abs().min(take);
+            let dy = remaining.y.signum() * remaining.y.abs().min(take);
+            if !walk_shell(path, dx, dy, false, ctx, walkaround) {
+                break;
+            }
+            progressed = true;
+            speed -= take;
+        }
+        if path.shell_ref(walkaround).pos == next {
+            route.ahead.pop();
         }
-        // Blocked = the collision step left the shell exactly where it was.
-        progressed = shell.pos != before;
-        // A cutscene-driven actor does NOT drag its companions: during a scene
-        // every actor is an explicit puppet (the author moves the dog with its
-        // own chain if it should move), so e.g. `player: beside dog` walks the
-        // player to the *stationary* dog instead of the dog sliding to the player.
     }
     let arrived = path.shell_ref(walkaround).pos == target;
     let done = if time > 0 {
@@ -1248,6 +1592,48 @@ fn move_toward<S: ConsoleApi>(
     (done, progressed)
 }
 
+/// Step `path`'s shell by `(dx, dy)` — straight through walls when `noclip`,
+/// else through `walk`'s collision. Returns whether it moved (a blocked step
+/// leaves it exactly where it was).
+fn walk_shell<S: ConsoleApi>(
+    path: EntityPath,
+    dx: i16,
+    dy: i16,
+    noclip: bool,
+    ctx: &mut Ctx<S>,
+    walkaround: &mut WalkaroundState,
+) -> bool {
+    // Index the disjoint fields directly so `current_map` can be borrowed
+    // alongside the mutable shell (a `&mut Shell` from a method would not).
+    let tiles = ctx.maps.get(&walkaround.current_map.source);
+    let map = &walkaround.current_map;
+    let shell = match path {
+        EntityPath::Entity(i) => &mut walkaround.entities[i],
+        EntityPath::Companion(i, j) => &mut walkaround.entities[i].companions[j],
+    };
+    let before = shell.pos;
+    if noclip {
+        shell.face((dx.signum() as i8, dy.signum() as i8));
+        shell.pos.x += dx;
+        shell.pos.y += dy;
+        shell.animate_walk();
+    } else {
+        let (mdx, mdy) = shell.walk(ctx.system, dx, dy, false, map, tiles);
+        shell.apply_motion(mdx, mdy);
+    }
+    // A cutscene-driven actor does NOT drag its companions: during a scene
+    // every actor is an explicit puppet (the author moves the dog with its
+    // own chain if it should move), so e.g. `player: beside dog` walks the
+    // player to the *stationary* dog instead of the dog sliding to the player.
+    shell.pos != before
+}
+
+/// The Chebyshev (king-move) distance between two points — the frames a
+/// 1 px/frame walk takes, since a diagonal step moves both axes at once.
+fn chebyshev(a: Vec2, b: Vec2) -> i16 {
+    (a.x - b.x).abs().max((a.y - b.y).abs())
+}
+
 /// The point an actor (`actor_w` px wide) stands to be *beside* `target`, plus
 /// the facing to look at it: `gap` px off the target's head-side (its horizontal
 /// facing; a vertically-facing target → the actor's nearest side), feet aligned.
@@ -1280,31 +1666,27 @@ fn beside_spot(
     Some((spot, facing))
 }
 
-/// Replay a recorded RLE path one frame: hold the run that `elapsed` falls in.
-/// Finished once `elapsed` passes the total recorded frames.
+/// Replay a recorded RLE path one frame: take frame `elapsed`'s step — the
+/// run it falls in, or with a `time` budget the path resampled to that many
+/// frames ([`scene::rescaled_step`]). Finished once `elapsed` passes the
+/// budget (or, unbudgeted, the total recorded frames).
 fn replay_record<S: ConsoleApi>(
     path: EntityPath,
     runs: &[((i8, i8), u16)],
     noclip: bool,
+    time: u16,
     elapsed: u16,
     ctx: &mut Ctx<S>,
     walkaround: &mut WalkaroundState,
 ) -> bool {
-    let total: u16 = runs.iter().map(|(_, n)| n).sum();
-    if elapsed >= total {
+    let total = if time > 0 { u32::from(time) } else { scene::runs_frames(runs) };
+    if u32::from(elapsed) >= total {
         return true;
     }
-    // Find the run this frame falls in.
-    let mut acc = 0u16;
-    let mut dir = (0i8, 0i8);
-    for (d, n) in runs {
-        acc += n;
-        if elapsed < acc {
-            dir = *d;
-            break;
-        }
-    }
-    let (dx, dy) = (dir.0 as i16, dir.1 as i16);
+    let (dx, dy) = scene::rescaled_step(runs, total, u32::from(elapsed));
+    // Facing takes the heading only; a held (zero) frame keeps the last one,
+    // so a stretched path doesn't flicker its facing between steps.
+    let dir = (dx.signum() as i8, dy.signum() as i8);
     let tiles = ctx.maps.get(&walkaround.current_map.source);
     let map = &walkaround.current_map;
     let shell = match path {
@@ -1312,22 +1694,25 @@ fn replay_record<S: ConsoleApi>(
         EntityPath::Companion(i, j) => &mut walkaround.entities[i].companions[j],
     };
     if noclip {
-        shell.face(dir);
+        if dir != (0, 0) {
+            shell.face(dir);
+        }
         shell.pos.x += dx;
         shell.pos.y += dy;
         shell.animate_walk();
     } else {
-        shell.apply_walk_direction(dx, dy);
+        shell.apply_walk_direction(dir.0.into(), dir.1.into());
         let (mdx, mdy) = shell.walk(ctx.system, dx, dy, false, map, tiles);
         shell.apply_motion(mdx, mdy);
     }
     // No companion drag during a cutscene (see `move_toward`).
-    elapsed + 1 >= total
+    u32::from(elapsed) + 1 >= total
 }
 
 /// Snap a motion to its end state for the skip path: point moves and teleports
-/// jump to their point; a `record` jumps by its cumulative RLE displacement;
-/// entity-relative motions (`to`/`beside`/`face`) resolve against the target's
+/// (routed `walk!` too) jump to their point; a `record` jumps by its cumulative
+/// RLE displacement; entity-relative motions (`to`/`to!`/`beside`/`face`)
+/// resolve against the target's
 /// live (skip-time) position; faces apply. An entity-relative motion whose target
 /// is unresolvable at skip time leaves the actor in place (best-effort).
 fn snap_motion(
@@ -1340,7 +1725,10 @@ fn snap_motion(
         return;
     };
     match motion {
-        Motion::MoveToPoint(p) | Motion::MoveToPointNoclip(p) | Motion::Teleport(p) => {
+        Motion::MoveToPoint(p)
+        | Motion::MoveToPointNoclip(p)
+        | Motion::PathToPoint(p)
+        | Motion::Teleport(p) => {
             let shell = path.shell_mut(walkaround);
             shell.pos = *p;
             shell.animate_stop();
@@ -1350,7 +1738,7 @@ fn snap_motion(
             path.shell_mut(walkaround).face((*dx, *dy));
         }
         Motion::Pose(name) => apply_pose(path.shell_mut(walkaround), actor, name),
-        Motion::MoveToEntity(name) => {
+        Motion::MoveToEntity(name) | Motion::PathToEntity(name) => {
             // Resolve the target's position before borrowing the actor mutably.
             if let Some(tp) = walkaround.resolve(&resolve_name(name, table)).map(|s| s.pos) {
                 let shell = path.shell_mut(walkaround);
@@ -1377,7 +1765,9 @@ fn snap_motion(
         }
         Motion::Record { runs, .. } => {
             // The path's net displacement is the sum of each run's (heading ×
-            // frames); best-effort (collision isn't re-walked at snap time).
+            // frames) — an `in N` budget only retimes the route, never moves
+            // its end point (see `scene::rescaled_step`), so it doesn't enter
+            // here. Best-effort (collision isn't re-walked at snap time).
             let dx: i16 = runs.iter().map(|(d, n)| d.0 as i16 * *n as i16).sum();
             let dy: i16 = runs.iter().map(|(d, n)| d.1 as i16 * *n as i16).sum();
             let last_dir = runs.iter().rev().map(|(d, _)| *d).find(|d| *d != (0, 0));
@@ -1427,6 +1817,7 @@ mod tests {
         save: SaveData,
         items: crate::data::eggdata::GameItems,
         presets: crate::data::eggdata::Presets,
+        schedules: crate::data::schedule::Schedules,
         font: crate::render::Font,
         walk: WalkaroundState,
     }
@@ -1443,6 +1834,7 @@ mod tests {
                 save: SaveData::default(),
                 items: crate::data::eggdata::GameItems::default(),
                 presets: crate::data::eggdata::Presets::builtin(),
+                schedules: crate::data::schedule::Schedules::default(),
                 font: crate::render::Font::blank(),
                 walk: WalkaroundState::new(),
             }
@@ -1463,6 +1855,7 @@ mod tests {
                     save: &mut self.save,
                     items: &self.items,
                     presets: &self.presets,
+                    schedules: &self.schedules,
                     font: &self.font,
                 };
                 f(&mut ctx, &mut walk)
@@ -1573,10 +1966,12 @@ mod tests {
             .clone();
         let mut h = Harness::new();
         h.walk.player().pos = Vec2::new(50, 50);
-        let mut dog = crate::world::player::Shell::default();
-        dog.pos = Vec2::new(50, 66);
-        dog.dir = (1, 0);
-        dog.move_mode = MoveMode::Companion { slot: 0 };
+        let dog = crate::world::player::Shell {
+            pos: Vec2::new(50, 66),
+            dir: (1, 0),
+            move_mode: MoveMode::Companion { slot: 0 },
+            ..Default::default()
+        };
         h.walk.player().companions.push(dog);
 
         let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
@@ -1672,9 +2067,11 @@ mod tests {
             .clone();
         let mut h = Harness::new();
         // A live creature already carries the id the scene `spawn`s under.
-        let mut creature = crate::world::player::Shell::default();
-        creature.id = Some("dog".to_string());
-        creature.pos = Vec2::new(99, 99);
+        let creature = crate::world::player::Shell {
+            id: Some("dog".to_string()),
+            pos: Vec2::new(99, 99),
+            ..Default::default()
+        };
         h.walk.spawn_shell(creature);
 
         let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
@@ -1738,6 +2135,125 @@ mod tests {
         );
     }
 
+    /// A budgeted `path NAME in N` lands on its recorded end point on exactly
+    /// frame N — compressed or stretched — and the scrubber's re-sim and the
+    /// skip snap agree with live playback on both the timing and the landing.
+    #[test]
+    fn budgeted_path_lands_on_its_frame_everywhere() {
+        for budget in [6usize, 40] {
+            let src = format!(
+                "#path r\n    1 0 10 0 1 5\n#cutscene t\n    move\n        \
+                 player: path noclip r in {budget}"
+            );
+            let def = scene::parse(&src).unwrap().get_cutscene_resolved("t").unwrap();
+            let mut h = Harness::new();
+            h.walk.player().pos = Vec2::new(0, 0);
+            let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+            h.walk.cutscene.push(cs.clone());
+            let total = h.frame(|ctx, w| w.replay_cutscene(100, ctx).total);
+            assert_eq!(total, budget, "scrubber measures the budget");
+            let end = h.frame(|ctx, w| w.sim_cutscene_to(total, ctx));
+            assert_eq!(end.player_ref().pos, Vec2::new(10, 5));
+
+            let mut skipped = cs.clone();
+            h.frame(|ctx, w| skipped.skip(ctx, w));
+            assert_eq!(h.walk.player_ref().pos, Vec2::new(10, 5), "skip snap");
+
+            h.walk.player().pos = Vec2::new(0, 0);
+            for frame in 1..=budget {
+                let outcome = h.frame(|ctx, w| cs.step(ctx, w));
+                assert_eq!(matches!(outcome, Outcome::Finished), frame == budget, "frame {frame}");
+            }
+            assert_eq!(h.walk.player_ref().pos, Vec2::new(10, 5), "live playback");
+        }
+    }
+
+    /// A 12×9-tile room split by a solid wall down tile column 4, open only
+    /// below row 4, loaded as the current map under the store name `room`.
+    fn install_walled_room(h: &mut Harness) {
+        use crate::geometry::Collider;
+        use crate::world::map::{LayerInfo, MapInfo};
+        install_map(h, "room");
+        let mut solid = Collider::default();
+        for y in 0..8 {
+            for x in 0..8 {
+                solid.set(x, y, true);
+            }
+        }
+        let colliders = (0..9)
+            .flat_map(|y| (0..12).map(move |x| (x, y)))
+            .map(|(x, y)| if x == 4 && y < 4 { solid.clone() } else { Collider::default() })
+            .collect();
+        h.walk.current_map = MapInfo {
+            layers: vec![LayerInfo {
+                colliders,
+                ..LayerInfo::new(0, 0, 12, 9)
+            }],
+            source: "room".into(),
+            ..MapInfo::default()
+        };
+    }
+
+    /// A straight `walk` into the wall sticks, so its required move cancels
+    /// the scene; `walk!` routes down through the gap and arrives, both at
+    /// natural speed and on the exact frame of an `in N` budget — live and in
+    /// the scrubber's re-sim.
+    #[test]
+    fn routed_walk_goes_around_a_wall() {
+        let run = |src: &str| {
+            let mut h = Harness::new();
+            install_walled_room(&mut h);
+            h.walk.player().pos = Vec2::new(8, 8);
+            let def = scene::parse(src).unwrap().get_cutscene("t").unwrap().clone();
+            let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+            let (mut frames, mut lowest) = (0, 0);
+            let outcome = loop {
+                frames += 1;
+                let outcome = h.frame(|ctx, w| cs.step(ctx, w));
+                lowest = lowest.max(h.walk.player_ref().pos.y);
+                if !matches!(outcome, Outcome::Running) || frames > 300 {
+                    break outcome;
+                }
+            };
+            (outcome, frames, lowest, h.walk.player_ref().pos)
+        };
+        let (outcome, ..) = run("#cutscene t\n    move\n        player: walk 64 8?");
+        assert!(matches!(outcome, Outcome::Cancelled), "straight walk sticks");
+
+        let (outcome, _, lowest, end) = run("#cutscene t\n    move\n        player: walk! 64 8?");
+        assert!(matches!(outcome, Outcome::Finished));
+        assert_eq!(end, Vec2::new(64, 8));
+        assert!(lowest >= 32, "went below the wall (lowest y {lowest})");
+
+        let src = "#cutscene t\n    move\n        player: walk! 64 8 in 90";
+        let (outcome, frames, _, end) = run(src);
+        assert!(matches!(outcome, Outcome::Finished));
+        assert_eq!((frames, end), (90, Vec2::new(64, 8)));
+
+        let mut h = Harness::new();
+        install_walled_room(&mut h);
+        h.walk.player().pos = Vec2::new(8, 8);
+        let def = scene::parse(src).unwrap().get_cutscene("t").unwrap().clone();
+        let cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        h.walk.cutscene.push(cs);
+        assert_eq!(h.frame(|ctx, w| w.replay_cutscene(200, ctx).total), 90);
+        let end = h.frame(|ctx, w| w.sim_cutscene_to(90, ctx));
+        assert_eq!(end.player_ref().pos, Vec2::new(64, 8));
+    }
+
+    /// `to!` chases a target that moves mid-walk (here teleported to the far
+    /// side of the wall by its own chain) and ends on it.
+    #[test]
+    fn routed_to_follows_a_moving_target() {
+        let mut h = Harness::new();
+        install_walled_room(&mut h);
+        h.walk.player().pos = Vec2::new(8, 8);
+        let src = "#cutscene t\n    spawn goal critter 16 50\n    move\n        \
+                   player: to! goal\n        goal: face 0 1 in 10; teleport 72 16";
+        let end = run_to_end(&mut h, src);
+        assert_eq!(end, Vec2::new(72, 16));
+    }
+
     /// Skipping snaps an entity-relative move (`to NAME`) to its target's live
     /// (skip-time) position (was left un-snapped at the start).
     #[test]
@@ -1856,6 +2372,322 @@ mod tests {
         );
     }
 
+    /// Run a cutscene built from `src`'s scene `t` to completion (or a frame
+    /// cap), returning the player's final position.
+    fn run_to_end(h: &mut Harness, src: &str) -> Vec2 {
+        let def = scene::parse(src)
+            .unwrap()
+            .get_cutscene("t")
+            .unwrap()
+            .clone();
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        for _ in 0..200 {
+            if matches!(h.frame(|ctx, w| cs.step(ctx, w)), Outcome::Finished) {
+                break;
+            }
+        }
+        assert!(cs.is_finished(), "scene ran to its end");
+        h.walk.player_ref().pos
+    }
+
+    /// An `if` is decided the moment the scene reaches it, against the live
+    /// save: a `set` earlier in the same scene steers it, `elif`/`else` fall
+    /// through in order, and `not` flips a test.
+    #[test]
+    fn if_chain_reads_the_live_save_when_reached() {
+        const SRC: &str = "#cutscene t\n\
+             \x20   if a\n\
+             \x20       set b true\n\
+             \x20   if not b\n\
+             \x20       move\n\
+             \x20           player: teleport 1 0\n\
+             \x20   elif c\n\
+             \x20       move\n\
+             \x20           player: teleport 2 0\n\
+             \x20   else\n\
+             \x20       move\n\
+             \x20           player: teleport 3 0";
+        let mut h = Harness::new();
+        assert_eq!(
+            run_to_end(&mut h, SRC),
+            Vec2::new(1, 0),
+            "nothing set ⇒ `if not b`"
+        );
+
+        let mut h = Harness::new();
+        h.save.set_flag("a", true);
+        assert_eq!(
+            run_to_end(&mut h, SRC),
+            Vec2::new(3, 0),
+            "`a` sets `b` ⇒ falls to `else`"
+        );
+
+        let mut h = Harness::new();
+        h.save.set_flag("a", true);
+        h.save.set_flag("c", true);
+        assert_eq!(
+            run_to_end(&mut h, SRC),
+            Vec2::new(2, 0),
+            "`b` and `c` ⇒ `elif c`"
+        );
+    }
+
+    /// A branch with a frame-consuming step plays it like any other step, and
+    /// a chain with no matching branch (and no `else`) costs no frame.
+    #[test]
+    fn if_chain_branches_play_as_ordinary_steps() {
+        let def = scene::parse(
+            "#cutscene t\n    if a\n        wait 3\n    if b\n        wait 50\n    wait 2",
+        )
+        .unwrap()
+        .get_cutscene("t")
+        .unwrap()
+        .clone();
+        let mut h = Harness::new();
+        h.save.set_flag("a", true);
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        let mut frames = 0;
+        loop {
+            frames += 1;
+            if matches!(h.frame(|ctx, w| cs.step(ctx, w)), Outcome::Finished) {
+                break;
+            }
+            assert!(frames < 100, "scene never finished");
+        }
+        assert_eq!(
+            frames, 4,
+            "wait 3 + wait 2 sharing the hand-off frame; `if b` skipped"
+        );
+    }
+
+    /// Skipping resolves each chain against the save as the skip has left it,
+    /// so the skipped scene lands in the same state full playback would.
+    #[test]
+    fn skip_resolves_if_chains_like_full_playback() {
+        let def = scene::parse(
+            "#cutscene t\n\
+             \x20   wait 30\n\
+             \x20   set seen true\n\
+             \x20   if seen\n\
+             \x20       move\n\
+             \x20           player: walk 40 0\n\
+             \x20   else\n\
+             \x20       move\n\
+             \x20           player: walk 0 40",
+        )
+        .unwrap()
+        .get_cutscene("t")
+        .unwrap()
+        .clone();
+        let mut h = Harness::new();
+        h.walk.player().pos = Vec2::new(0, 0);
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        h.frame(|ctx, w| cs.step(ctx, w));
+        h.frame(|ctx, w| cs.skip(ctx, w));
+        assert!(cs.is_finished());
+        assert_eq!(
+            h.walk.player_ref().pos,
+            Vec2::new(40, 0),
+            "snapped to the `if seen` branch's end"
+        );
+    }
+
+    /// `give`/`take` go through the bag's own add/take: a `give` lands in
+    /// the first free slot and a later `has` sees it, a `take` of an item the
+    /// bag doesn't hold is a no-op, and a `give` into a full bag changes
+    /// nothing — the same as a `give_item` interaction.
+    #[test]
+    fn give_take_and_has_drive_the_bag() {
+        const SRC: &str = "#cutscene t\n\
+             \x20   give chegg\n\
+             \x20   if has chegg\n\
+             \x20       move\n\
+             \x20           player: teleport 1 0\n\
+             \x20   take chegg\n\
+             \x20   take chegg";
+        let mut h = Harness::new();
+        h.walk.inventory_ui.inventory.items = [const { None }; 8];
+        h.walk.inventory_ui.inventory.items[1] = Some("ff".into());
+        assert_eq!(run_to_end(&mut h, SRC), Vec2::new(1, 0), "`has` saw the give");
+        assert_eq!(
+            h.walk.inventory_ui.inventory.to_save(),
+            [None, Some("ff".into()), None, None, None, None, None, None],
+            "given then taken; the second take (and the untouched `ff`) is a no-op"
+        );
+
+        let mut h = Harness::new();
+        h.walk.inventory_ui.inventory.items = std::array::from_fn(|_| Some("lm".into()));
+        let before = h.walk.inventory_ui.inventory.to_save();
+        assert_eq!(
+            run_to_end(&mut h, "#cutscene t\n    give chegg"),
+            h.walk.player_ref().pos
+        );
+        assert_eq!(h.walk.inventory_ui.inventory.to_save(), before, "full bag: nothing lost");
+    }
+
+    /// A skip applies `give`/`take` in order and resolves a `has` against the
+    /// bag as the skip has left it, matching full playback.
+    #[test]
+    fn skip_applies_bag_steps_like_full_playback() {
+        let def = scene::parse(
+            "#cutscene t\n\
+             \x20   wait 30\n\
+             \x20   give chegg\n\
+             \x20   if has chegg\n\
+             \x20       move\n\
+             \x20           player: walk 40 0",
+        )
+        .unwrap()
+        .get_cutscene("t")
+        .unwrap()
+        .clone();
+        let mut h = Harness::new();
+        h.walk.inventory_ui.inventory.items = [const { None }; 8];
+        h.walk.player().pos = Vec2::new(0, 0);
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        h.frame(|ctx, w| cs.step(ctx, w));
+        h.frame(|ctx, w| cs.skip(ctx, w));
+        assert_eq!(h.walk.inventory_ui.inventory.get(0), Some("chegg"));
+        assert_eq!(h.walk.player_ref().pos, Vec2::new(40, 0));
+    }
+
+    /// Install an empty 2x2-tile map named `name` in the harness's store — an
+    /// (empty) object layer is enough for `load_map_by_name` to accept it.
+    fn install_map(h: &mut Harness, name: &str) {
+        let json = r#"{
+            "width": 2, "height": 2,
+            "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": []}]
+        }"#;
+        let map: crate::data::tiled::TiledMap = serde_json::from_str(json).unwrap();
+        h.maps.insert(name, map);
+    }
+
+    /// A bare `warp` swaps maps in one frame: the player lands on the target,
+    /// a scene-bound actor comes along at its offset from the player, and a
+    /// bystander the scene never named is parked with the map it stood on.
+    #[test]
+    fn warp_carries_bound_actors_and_parks_the_rest() {
+        let mut h = Harness::new();
+        install_map(&mut h, "town");
+        h.walk.player().pos = Vec2::new(10, 10);
+        h.walk.entities.push(Shell {
+            id: Some("bystander".into()),
+            ..Shell::default()
+        });
+        let def = scene::parse("#cutscene t\n    spawn a critter 14 6\n    warp town 50 50")
+            .unwrap()
+            .get_cutscene("t")
+            .unwrap()
+            .clone();
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        let a = cs.resolve_actor("a");
+        assert!(matches!(h.frame(|ctx, w| cs.step(ctx, w)), Outcome::Finished));
+        assert_eq!(h.walk.current_map.source, "town");
+        assert_eq!(h.walk.player_ref().pos, Vec2::new(50, 50));
+        assert_eq!(h.walk.resolve(&a).map(|e| e.pos), Some(Vec2::new(54, 46)));
+        let parked = EntityId::Id("bystander".into());
+        assert!(h.walk.resolve(&parked).is_none(), "left behind on the old map");
+    }
+
+    /// `fade N` holds the step for 2N frames, darkest (and swapping maps) on
+    /// frame N; the walkaround darkens the palette from a snapshot and puts it
+    /// back exactly once the fade is over.
+    #[test]
+    fn warp_fade_swaps_on_the_darkest_frame() {
+        let mut h = Harness::new();
+        install_map(&mut h, "town");
+        let def = scene::parse("#cutscene t\n    warp town 50 50 fade 4")
+            .unwrap()
+            .get_cutscene("t")
+            .unwrap()
+            .clone();
+        let base = h.draw.palettes[0].clone();
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        let mut amounts = Vec::new();
+        for frame in 1..=8 {
+            let outcome = h.frame(|ctx, w| {
+                let outcome = cs.step(ctx, w);
+                w.cutscene = vec![cs.clone()];
+                w.sync_scene_fade(ctx);
+                w.cutscene.clear();
+                outcome
+            });
+            amounts.push(cs.fade_amount());
+            assert_eq!(h.walk.current_map.source == "town", frame >= 4, "frame {frame}");
+            assert_eq!(matches!(outcome, Outcome::Finished), frame == 8, "frame {frame}");
+            if frame == 4 {
+                assert!(h.draw.palettes[0].iter().take(16).all(|c| *c == [0; 3]), "black");
+            }
+        }
+        assert_eq!(amounts, [64, 128, 192, 256, 192, 128, 64, 0]);
+        assert_eq!(h.draw.palettes[0], base, "palette restored");
+    }
+
+    /// Skipping mid-fade still lands the warp, but never a second time once
+    /// the swap has happened; an unknown map leaves everyone where they stood.
+    #[test]
+    fn skip_and_unknown_map_warps() {
+        let src = "#cutscene t\n    warp town 50 50 fade 4";
+        let def = scene::parse(src).unwrap().get_cutscene("t").unwrap().clone();
+
+        let mut h = Harness::new();
+        install_map(&mut h, "town");
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        h.frame(|ctx, w| cs.step(ctx, w));
+        h.frame(|ctx, w| cs.skip(ctx, w));
+        assert_eq!(h.walk.player_ref().pos, Vec2::new(50, 50), "skip before the swap");
+
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        for _ in 0..5 {
+            h.frame(|ctx, w| cs.step(ctx, w));
+        }
+        h.walk.player().pos = Vec2::new(3, 3);
+        h.frame(|ctx, w| cs.skip(ctx, w));
+        assert_eq!(h.walk.player_ref().pos, Vec2::new(3, 3), "skip after the swap");
+
+        let mut h = Harness::new();
+        h.walk.player().pos = Vec2::new(10, 10);
+        let end = run_to_end(&mut h, "#cutscene t\n    warp nowhere 50 50");
+        assert_eq!(end, Vec2::new(10, 10));
+    }
+
+    /// An `if` inside an `on` handler is resolved when the handler reaches
+    /// it, against the save as it stands then.
+    #[test]
+    fn if_chain_inside_a_handler_resolves_when_reached() {
+        let mut h = Harness::new();
+        install_script(
+            &mut h,
+            "#flag lit\n#dialogue talk\n    #cue go\n    Hello there.",
+        );
+        h.save.set_flag("lit", true);
+        let def = scene::parse(
+            "#cutscene t\n\
+             \x20   dialogue talk\n\
+             \x20       on go\n\
+             \x20           if lit\n\
+             \x20               move\n\
+             \x20                   player: teleport 7 7\n\
+             \x20           else\n\
+             \x20               move\n\
+             \x20                   player: teleport 9 9",
+        )
+        .unwrap()
+        .get_cutscene("t")
+        .unwrap()
+        .clone();
+        let mut cs = h.frame(|ctx, w| Cutscene::launch(&def, ctx, w));
+        for _ in 0..5 {
+            h.frame(|ctx, w| cs.step(ctx, w));
+        }
+        assert_eq!(
+            h.walk.player_ref().pos,
+            Vec2::new(7, 7),
+            "handler took the `if lit` branch"
+        );
+    }
+
     /// A `#cue` fires its `on` handler, and the handler's `move` completes
     /// while the dialogue box is still open (never pressed A) — proving cues
     /// and handlers tick independently of, and concurrently with, the box.
@@ -47,6 +47,11 @@ impl Inventory {
     pub fn get(&self, index: usize) -> Option<&str> {
         self.items.get(index).and_then(|s| s.as_deref())
     }
+    /// The first slot holding item `key`, or `None` if the bag has none — the
+    /// index [`take`](Self::take) wants when removing an item by key.
+    pub fn find(&self, key: &str) -> Option<usize> {
+        self.items.iter().position(|slot| slot.as_deref() == Some(key))
+    }
     /// Place item `key` in the first empty slot. Returns `true` if it fit,
     /// `false` if the inventory is full — the caller decides what a full
     /// inventory means (today: nothing happens), so this never panics or drops
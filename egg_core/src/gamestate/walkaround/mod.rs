@@ -1,12 +1,12 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::Ctx;
 use crate::data::save::SaveData;
-use crate::data::scene::CutsceneDef;
+use crate::data::scene::{self, CutsceneDef};
 use crate::data::sound;
 use crate::debug::DebugInfo;
-use crate::draw_state::BgColour;
-use crate::geometry::{Collider, Hitbox, Vec2};
+use crate::draw_state::{BgColour, fade_palette_into};
+use crate::geometry::{Collider, Vec2};
 use crate::platform::{ConsoleApi, ConsoleHelper, ScanCode, dpad_delta, just_pressed, pressed};
 use crate::draw_state::DrawParams;
 use crate::render::{PrintOptions, print_to_with_font};
@@ -16,7 +16,8 @@ use crate::world::camera::{Camera, Shake};
 use crate::world::interact::{InteractFn, Interaction};
 use crate::world::map::{Axis, MapInfo, MapObject, ObjectEffect, Trigger, map_by_name};
 use crate::world::particles::{Particle, ParticleDraw, ParticleList};
-use crate::world::player::{EntityId, MoveMode, PresetId, Shell};
+use crate::world::player::{EntityId, MoveMode, PresetId, Shell, approach_heading, flee_heading};
+use crate::world::transition::{Transition, WarpTransition};
 use crate::gamestate::GameMode;
 use log::info;
 
@@ -68,6 +69,8 @@ pub struct WalkaroundState {
     /// them instead of bleeding into the next one. Persisted through the save
     /// ([`SaveData::map_entities`](crate::data::save::SaveData::map_entities)),
     /// written in [`save`](Self::save) and restored in [`load_pmem`](Self::load_pmem).
+    /// Having an entry at all means the map's placed creatures have been
+    /// spawned (see [`seed_placed_creatures`](Self::seed_placed_creatures)).
     map_entities: BTreeMap<String, Vec<Shell>>,
     pub map_animations: Vec<Animation>,
     pub camera: Camera,
@@ -113,6 +116,12 @@ pub struct WalkaroundState {
     /// the warp's hitbox with the box open can't re-fire it. Cleared on apply and
     /// defensively in [`load_map`](Self::load_map).
     pending_warp: Option<crate::world::map::Warp>,
+    /// A warp playing its screen [`Transition`]: set by
+    /// [`apply_warp`](Self::apply_warp) for any non-cut warp, ticked at the top
+    /// of [`step`](Self::step) — which holds all input and the world until it
+    /// finishes — and drawn through
+    /// [`DrawState::cover`](crate::draw_state::DrawState::cover).
+    transition: Option<WarpTransition>,
     /// Armed by [`load_map`](Self::load_map): the just-loaded map hasn't yet had
     /// its map-enter hook scanned. [`step`](Self::step) consumes it once — after
     /// the cutscene/editor/overlay guards — to launch the first `Enter`-triggered
@@ -121,13 +130,23 @@ pub struct WalkaroundState {
     /// (warp, save-load, debug jump, initial spawn) funnels through `load_map`,
     /// this fires the hook exactly once per load however the map was entered.
     pending_enter_scan: bool,
-    /// The day/night state currently painted into the palette, or `None` before
-    /// the first paint. [`step`](Self::step) reconciles it against the
-    /// [`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG) save flag each frame
-    /// (see [`sync_day_night_palette`](Self::sync_day_night_palette)) and repaints
-    /// only on a change — so a dialogue/gate/cutscene flip of the flag swaps
-    /// day↔night live, while a one-off debug palette (Digit8's B/W) is left alone.
-    day_night_shown: Option<bool>,
+    /// The day↔night blend currently painted into the palette (a
+    /// [`Clock::night_amount`](crate::data::clock::Clock::night_amount)), or
+    /// `None` before the first paint. [`step`](Self::step) reconciles it
+    /// against the save's clock each frame (see
+    /// [`sync_day_night_palette`](Self::sync_day_night_palette)) and repaints
+    /// only on a change — so dusk and dawn fade the world in steps, while a
+    /// one-off debug palette (Digit8's B/W) holds until the blend next moves.
+    day_night_shown: Option<u16>,
+    /// The world palette as it stood before a cutscene `warp … fade` started
+    /// darkening it, or `None` when no fade is on screen. Restored verbatim
+    /// the frame the fade ends (see [`sync_scene_fade`](Self::sync_scene_fade)).
+    fade_base: Option<Vec<[u8; 3]>>,
+    /// The creature the player is talking to (an index into `entities`), held
+    /// still and facing them while the dialogue its [`Shell::talk`] opened is
+    /// up. Set when a talk fires, dropped by [`step`](Self::step) once the box
+    /// closes and by [`load_map`](Self::load_map).
+    talker: Option<usize>,
 }
 impl Default for WalkaroundState {
     fn default() -> Self {
@@ -157,10 +176,25 @@ pub(crate) struct CutsceneReplay {
     pub beats: Vec<usize>,
     /// Frames between consecutive keyframes.
     stride: usize,
-    /// The snapshot ladder: `keyframes[i]` is the world at frame `i * stride`.
-    /// `keyframes[0]` is the armed frame-0 snapshot; the last rung is the largest
-    /// multiple of `stride` not past [`total`](Self::total). Never empty.
-    keyframes: Vec<WalkaroundState>,
+    /// The snapshot ladder: `keyframes[i]` is the world *and save* at frame
+    /// `i * stride`. `keyframes[0]` is the armed frame-0 snapshot; the last rung
+    /// is the largest multiple of `stride` not past [`total`](Self::total).
+    /// Never empty. The save rides along because a scene reads it back — an
+    /// `if` resolves against flags an earlier step set — so resuming from a rung
+    /// with the frame-0 save would take the wrong branch.
+    keyframes: Vec<(WalkaroundState, SaveData)>,
+}
+
+/// What [`WalkaroundState::play_cutscene_traced`] saw of the scenes it
+/// retired: how many were cancelled (a blocked required `?` motion, or a player
+/// interrupt), and every actor name they referenced that resolved to nothing.
+/// The cutscene smoke test's window into a run; normal play keeps no trace.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CutsceneTrace {
+    /// Scenes that ended [`Cancelled`](cutscene::Outcome::Cancelled).
+    pub cancelled: usize,
+    /// Unresolved actor names, across every retired scene.
+    pub unresolved: BTreeSet<String>,
 }
 
 impl CutsceneReplay {
@@ -169,10 +203,13 @@ impl CutsceneReplay {
     /// Because the re-sim is deterministic this is identical to
     /// [`sim_cutscene_to`](WalkaroundState::sim_cutscene_to) for every frame, just
     /// cheaper; a `frame` past the scene end clamps to the last rung's tail, as
-    /// the naive path does.
+    /// the naive path does. Overwrites `ctx.save` with the rung's save first (the
+    /// caller hands in a scratch copy), leaving it at the save as of `frame`.
     pub(crate) fn seek<S: ConsoleApi>(&self, frame: usize, ctx: &mut Ctx<S>) -> WalkaroundState {
         let rung = (frame / self.stride).min(self.keyframes.len() - 1);
-        self.keyframes[rung].sim_cutscene_to(frame - rung * self.stride, ctx)
+        let (world, save) = &self.keyframes[rung];
+        ctx.save.clone_from(save);
+        world.sim_cutscene_to(frame - rung * self.stride, ctx)
     }
 }
 
@@ -197,8 +234,11 @@ impl WalkaroundState {
             default_map_colliders: Vec::new(),
             inside_objects: Vec::new(),
             pending_warp: None,
+            transition: None,
             pending_enter_scan: false,
             day_night_shown: None,
+            fade_base: None,
+            talker: None,
         }
     }
 
@@ -261,6 +301,12 @@ impl WalkaroundState {
         actors
     }
 
+    /// Whether a warp transition or a scene's `warp … fade` is covering the
+    /// screen, wholly or in part.
+    pub fn screen_covered(&self) -> bool {
+        self.transition.is_some() || self.fade_base.is_some()
+    }
+
     /// Resolve an [`EntityId`] against the live entity tree: the player is
     /// `entities[0]`, a companion is `entities[0].companions[slot]`, and an
     /// [`EntityId::Id`] is the first shell whose [`Shell::id`] matches. `None`
@@ -377,6 +423,10 @@ impl WalkaroundState {
         // Defensive: a debug map switch mid-narration must not carry a pending
         // teleport onto the new map.
         self.pending_warp = None;
+        // Likewise a warp transition still running: the map it was covering is
+        // gone, and the warp it would land now lands nowhere.
+        self.transition = None;
+        self.talker = None;
         // Arm the map-enter hook scan for the next `step`: a freshly loaded map
         // gets one chance to launch its `Enter`-triggered cutscene. Set on every
         // load (warp, save-load, debug jump, initial spawn), so the hook composes
@@ -417,13 +467,126 @@ impl WalkaroundState {
     /// edit them, and a map save round-trips them). Taken pickups are instead
     /// skipped at use-time by [`object_taken`](Self::object_taken): the walk loop
     /// won't fire their interaction and the world draw won't draw their sprite.
+    ///
+    /// A map's placed creatures are spawned the first time it's entered (see
+    /// [`seed_placed_creatures`](Self::seed_placed_creatures)), and scheduled
+    /// creatures are set down where their schedules say first (see
+    /// [`follow_schedules`](Self::follow_schedules)), so the swap in
+    /// [`load_map`](Self::load_map) brings in whoever should be here now.
     pub fn load_map_by_name<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, name: &str) {
+        self.load_named_map(ctx, name, true);
+    }
+
+    /// [`load_map_by_name`](Self::load_map_by_name), with `follow_schedules`
+    /// false for a cutscene `warp`, which leaves schedules alone: the scene is
+    /// staging the destination itself, and a scheduled creature it carries
+    /// along mustn't be moved (or spawned a second time) behind its back.
+    /// They catch up at the next map change.
+    pub(crate) fn load_named_map<S: ConsoleApi>(
+        &mut self,
+        ctx: &mut Ctx<S>,
+        name: &str,
+        follow_schedules: bool,
+    ) {
         let Some(map_info) = map_by_name(&ctx.draw.indexed_sprites, name, ctx.maps) else {
             info!("load_map_by_name: unknown map {name:?}");
             return;
         };
+        self.seed_placed_creatures(ctx, name);
+        if follow_schedules {
+            self.follow_schedules(ctx, name);
+        }
         self.load_map(ctx.system, map_info);
     }
+
+    /// Spawn map `name`'s placed creatures (its Tiled `creature` objects, see
+    /// [`PlacedCreature`](crate::world::map::PlacedCreature)) into its parked
+    /// list the first time anything arrives there — the player, or a
+    /// scheduled creature set down ahead of them. A map that already has a
+    /// parked list (even an empty one) was seeded before, and so was the map
+    /// the player is on: from then on the save's creatures are what the map
+    /// holds, wherever they've wandered to. A placed creature whose id has a
+    /// schedule is left to [`follow_schedules`](Self::follow_schedules), so it
+    /// isn't made twice.
+    fn seed_placed_creatures<S: ConsoleApi>(&mut self, ctx: &Ctx<S>, name: &str) {
+        if name == self.current_map.source || self.map_entities.contains_key(name) {
+            return;
+        }
+        let Some(map) = ctx.maps.get(name) else {
+            return;
+        };
+        let mut placed = Vec::new();
+        for object in map.parse_objects() {
+            let ObjectEffect::Creature(creature) = &object.effect else {
+                continue;
+            };
+            if creature.id.as_deref().is_some_and(|id| ctx.schedules.get(id).is_some()) {
+                continue;
+            }
+            let at = Vec2::new(object.hitbox.x, object.hitbox.y);
+            match ctx.presets.place(creature, at) {
+                Some(shell) => placed.push(shell),
+                None => log::warn!("map `{name}`: unknown creature preset `{}`", creature.preset),
+            }
+        }
+        self.map_entities.insert(name.to_string(), placed);
+    }
+
+    /// Move every scheduled creature (see [`crate::data::schedule`]) to its
+    /// current slot as the player leaves for `dest` — the one moment nobody
+    /// is watching. Each is lifted out of wherever it is (the map being left,
+    /// or one it's parked on), or spawned from its schedule's preset the first
+    /// time, and set down at the slot's point in the slot's pose: parked on
+    /// the slot's map, or — if that's the map being left — among its live
+    /// entities, which [`load_map`](Self::load_map) then parks. Nothing in
+    /// between is simulated; the creature is just there when the player walks
+    /// in. A creature no slot claims stays where it is but drops its last
+    /// slot's pose, going back to its preset's movement; a reload of the
+    /// current map moves no one.
+    fn follow_schedules<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, dest: &str) {
+        let here = self.current_map.source.clone();
+        if dest == here {
+            return;
+        }
+        for (id, schedule) in ctx.schedules.iter() {
+            let Some(slot) = schedule.slot(ctx.save) else {
+                let parked = self.map_entities.values_mut().flatten();
+                for shell in self.entities.iter_mut().skip(1).chain(parked) {
+                    if shell.id.as_deref() == Some(id) {
+                        shell.slot_pose = None;
+                    }
+                }
+                continue;
+            };
+            let is_id = |shell: &Shell| shell.id.as_deref() == Some(id);
+            let live = self.entities.iter().skip(1).position(is_id).map(|i| self.entities.remove(i + 1));
+            let found = live.or_else(|| {
+                self.map_entities.values_mut().find_map(|shells| {
+                    let i = shells.iter().position(is_id)?;
+                    Some(shells.remove(i))
+                })
+            });
+            let mut shell = match found {
+                Some(shell) => shell,
+                None => {
+                    let Some(mut shell) = ctx.presets.spawn(&schedule.preset) else {
+                        log::warn!("schedule `{id}`: unknown preset `{}`", schedule.preset);
+                        continue;
+                    };
+                    shell.id = Some(id.to_string());
+                    shell
+                }
+            };
+            shell.pos = slot.pos();
+            shell.slot_pose = slot.pose.clone();
+            if slot.map == here {
+                self.entities.push(shell);
+            } else {
+                self.seed_placed_creatures(ctx, &slot.map);
+                self.map_entities.entry(slot.map.clone()).or_default().push(shell);
+            }
+        }
+    }
     pub fn cam_x(&self) -> i32 {
         self.camera.pos.x.into()
     }
@@ -473,22 +636,13 @@ impl WalkaroundState {
     ) -> Option<&'static str> {
         match interact {
             InteractFn::ToggleDog => {
-                let (ppos, pdir) = (self.player_ref().pos, self.player_ref().dir);
-                // Seed the player's trail at its own position so a freshly
-                // summoned dog snaps to it rather than the stale tail.
-                self.player().trail.fill(ppos, pdir);
                 let dog = PresetId::dog();
                 if self.player_ref().companions.iter().any(|c| c.preset == dog) {
                     self.player().companions.retain(|c| c.preset != dog);
                     system.play_sound(sound::alert_down());
                     Some("dog_relinquished")
                 } else {
-                    let slot = self.player_ref().companions.len();
-                    let mut shell = presets.spawn(&dog).unwrap_or_default();
-                    shell.move_mode = MoveMode::Companion { slot };
-                    shell.interaction = Some(crate::world::player::pet_marker());
-                    shell.pos = ppos;
-                    self.player().companions.push(shell);
+                    self.give_dog(presets);
                     system.play_sound(sound::equip_obtained());
                     Some("dog_obtained")
                 }
@@ -549,6 +703,22 @@ impl WalkaroundState {
         }
     }
 
+    /// Summon the dog as the player's next companion, standing on the player —
+    /// the `ToggleDog` pickup's effect, minus its sound. Also how the cutscene
+    /// smoke test stages the party the dog's own scenes expect.
+    pub fn give_dog(&mut self, presets: &crate::data::eggdata::Presets) {
+        let (ppos, pdir) = (self.player_ref().pos, self.player_ref().dir);
+        // Seed the player's trail at its own position so a freshly summoned dog
+        // snaps to it rather than the stale tail.
+        self.player().trail.fill(ppos, pdir);
+        let slot = self.player_ref().companions.len();
+        let mut shell = presets.spawn(&PresetId::dog()).unwrap_or_default();
+        shell.move_mode = MoveMode::Companion { slot };
+        shell.interaction = Some(crate::world::player::pet_marker());
+        shell.pos = ppos;
+        self.player().companions.push(shell);
+    }
+
     /// Plays a cued cutscene until finished, then removes it from the cue.
     /// Pressing B fast-forwards it: [`Cutscene::skip`](cutscene::Cutscene::skip)
     /// applies every remaining stage's end state + side effects safely, so a
@@ -556,6 +726,16 @@ impl WalkaroundState {
     /// [`Ctx`] (not just the console) because a `dialogue` step resolves its key
     /// against `ctx.script` and drives the box through `ctx.save`.
     fn play_cutscene<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>) -> bool {
+        self.play_cutscene_traced(ctx, &mut CutsceneTrace::default())
+    }
+
+    /// [`play_cutscene`](Self::play_cutscene), noting into `trace` how each
+    /// scene it pops ended and which actors it couldn't resolve.
+    pub(crate) fn play_cutscene_traced<S: ConsoleApi>(
+        &mut self,
+        ctx: &mut Ctx<S>,
+        trace: &mut CutsceneTrace,
+    ) -> bool {
         if self.cutscene.is_empty() {
             return false;
         }
@@ -579,7 +759,11 @@ impl WalkaroundState {
             // Reached the end, or cancelled (interrupt / blocked required move):
             // clean up its transient actors, and when the whole stack is done hand
             // companions back where they are (no snap to a stale breadcrumb).
-            cutscene::Outcome::Finished | cutscene::Outcome::Cancelled => {
+            outcome @ (cutscene::Outcome::Finished | cutscene::Outcome::Cancelled) => {
+                if matches!(outcome, cutscene::Outcome::Cancelled) {
+                    trace.cancelled += 1;
+                }
+                trace.unresolved.extend(top.unresolved_actors().map(str::to_string));
                 top.cleanup(self);
                 if self.cutscene.is_empty() {
                     self.reseat_companion_trails();
@@ -674,7 +858,7 @@ impl WalkaroundState {
         let mut world = self.clone();
         // Frame 0 is the armed snapshot: the first rung of the ladder and the
         // opening beat's start.
-        let mut keyframes = vec![world.clone()];
+        let mut keyframes = vec![(world.clone(), ctx.save.clone())];
         let mut beats = vec![0usize];
         let mut last_beat = world.base_beat();
         let mut frames = 0;
@@ -689,7 +873,7 @@ impl WalkaroundState {
                 last_beat = beat;
             }
             if frames.is_multiple_of(stride) {
-                keyframes.push(world.clone());
+                keyframes.push((world.clone(), ctx.save.clone()));
             }
             if frames >= Self::SCRUB_MAX_FRAMES {
                 break;
@@ -712,6 +896,14 @@ impl WalkaroundState {
         self.cutscene.push(cs);
     }
 
+    /// Note into `trace` the unresolved actors of every scene still on the
+    /// stack — what a run cut off by a frame cap never got to retire.
+    pub(crate) fn trace_live_cutscenes(&self, trace: &mut CutsceneTrace) {
+        for cs in &self.cutscene {
+            trace.unresolved.extend(cs.unresolved_actors().map(str::to_string));
+        }
+    }
+
     /// Adds a shell and returns its index
     pub fn spawn_shell(&mut self, shell: Shell) -> usize {
         self.entities.push(shell);
@@ -767,12 +959,11 @@ impl WalkaroundState {
             .current_map_name
             .unwrap_or_else(|| "bedroom".to_string());
         self.load_map_by_name(ctx, &name);
-        // Restore the whole player entity. A modern save carries it (position +
-        // nested companions + state); an older save only had a position, so place
-        // the freshly-built default player there instead. Either way rebuild the
-        // derived sprites, re-derive the dog's serde-skipped pet interaction, and
-        // seed the trail so companions regroup on the player next step.
-        let (legacy_x, legacy_y) = (save.player_x, save.player_y);
+        // Restore the whole player entity (position + nested companions +
+        // state; a save from before it was persisted gets one in migration, see
+        // `SaveData::from_json`). Rebuild the derived sprites, re-derive the
+        // dog's serde-skipped pet interaction, and seed the trail so companions
+        // regroup on the player next step.
         if let Some(mut player) = save.player {
             player.reattach_sprites(ctx.presets);
             let dog = PresetId::dog();
@@ -782,8 +973,6 @@ impl WalkaroundState {
                 }
             }
             self.entities[0] = player;
-        } else {
-            self.player().pos = Vec2::new(legacy_x, legacy_y);
         }
         let (ppos, pdir) = (self.player_ref().pos, self.player_ref().dir);
         self.player().trail.fill(ppos, pdir);
@@ -816,13 +1005,11 @@ impl WalkaroundState {
     ) {
         match interaction {
             Interaction::Dialogue(key) => {
-                let convo = ctx.get_dialogue(key);
-                self.dialogue.set_messages(ctx.system, ctx.font, ctx.save, &convo);
+                self.dialogue.open(ctx.system, ctx.font, ctx.save, ctx.script, key);
             }
             Interaction::Func(x) => {
                 if let Some(key) = self.execute_interact_fn(x, ctx.system, inventory, ctx.presets) {
-                    let convo = ctx.get_dialogue(key);
-                    self.dialogue.set_messages(ctx.system, ctx.font, ctx.save, &convo);
+                    self.dialogue.open(ctx.system, ctx.font, ctx.save, ctx.script, key);
                 }
             }
             Interaction::Cutscene(name) => {
@@ -842,12 +1029,24 @@ impl WalkaroundState {
         }
     }
 
-    /// Apply a warp's teleport: move the player, set the destination control-flip,
-    /// refill the companion trail, and (for a cross-map warp) save + load the
-    /// destination. Does **not** play the warp sound — that fires once at trigger
-    /// time (see [`fire_warp`](Self::fire_warp)), so the narrated and un-narrated
-    /// paths play it at the same moment and the deferred apply stays silent.
+    /// Apply a warp's teleport: at once for a [`Cut`](Transition::Cut), otherwise
+    /// by starting its [`transition`](Self::transition), which lands it (through
+    /// [`land_warp`](Self::land_warp)) on the frame the screen is fully covered.
+    /// Does **not** play the warp sound — that fires once at trigger time (see
+    /// [`fire_warp`](Self::fire_warp)), so the narrated and un-narrated paths play
+    /// it at the same moment and the deferred apply stays silent.
     fn apply_warp<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, warp: crate::world::map::Warp) {
+        if warp.transition == Transition::Cut {
+            self.land_warp(ctx, warp);
+        } else {
+            self.transition = Some(WarpTransition::new(warp.transition, warp));
+        }
+    }
+
+    /// Land a warp: move the player, set the destination control-flip, refill
+    /// the companion trail, and (for a cross-map warp) save + load the
+    /// destination.
+    fn land_warp<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, warp: crate::world::map::Warp) {
         self.player().pos = warp.target();
         self.player().flip_controls = warp.flip;
         let (ppos, pdir) = (self.player_ref().pos, self.player_ref().dir);
@@ -874,8 +1073,7 @@ impl WalkaroundState {
             ctx.system.play_sound(sound.clone());
         }
         if let Some(key) = warp.narration.clone() {
-            let convo = ctx.get_dialogue(&key);
-            self.dialogue.set_messages(ctx.system, ctx.font, ctx.save, &convo);
+            self.dialogue.open(ctx.system, ctx.font, ctx.save, ctx.script, &key);
             self.pending_warp = Some(warp);
         } else {
             self.apply_warp(ctx, warp);
@@ -1053,42 +1251,84 @@ impl WalkaroundState {
         self.inventory_ui.inventory.to_save()
     }
 
-    /// Reconcile the world palette with the day/night save flag
-    /// ([`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG)): paint
-    /// [`NIGHT_16`](crate::platform::NIGHT_16) when it is set, else
-    /// [`SWEETIE_16`](crate::platform::SWEETIE_16). Change-gated against
-    /// [`day_night_shown`](Self::day_night_shown), so it repaints only when the
-    /// flag actually flips — making a dialogue `#set is_night …`, an object gate,
-    /// or a cutscene `set` step swap the world live, while leaving a one-off debug
-    /// palette (Digit8's B/W) in place until day/night genuinely changes. Cheap
-    /// enough to call every frame; the guard is what keeps it from stomping.
+    /// Bring the [`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG) flag up
+    /// to date with the save's clock, which the world's frames have been
+    /// ticking. (The other way round needs nothing here: writing the flag —
+    /// a dialogue `#set is_night …`, an object gate, a cutscene `set` step —
+    /// winds the clock itself, see
+    /// [`SaveData::set_flag`](crate::data::save::SaveData::set_flag).)
+    fn sync_clock(&mut self, ctx: &mut Ctx<impl ConsoleApi>) {
+        let night = ctx.save.clock.is_night();
+        ctx.save.set_flag(crate::data::save::IS_NIGHT_FLAG, night);
+    }
+
+    /// Reconcile the world palette with the save's clock: paint
+    /// [`SWEETIE_16`](crate::platform::SWEETIE_16) faded toward
+    /// [`NIGHT_16`](crate::platform::NIGHT_16) by its
+    /// [`night_amount`](crate::data::clock::Clock::night_amount) — all day by
+    /// day, all night by night, a blend through dawn and dusk. Change-gated
+    /// against [`day_night_shown`](Self::day_night_shown), so it repaints only
+    /// when the blend actually moves, leaving a one-off debug palette
+    /// (Digit8's B/W) in place until then. Cheap enough to call every frame;
+    /// the guard is what keeps it from stomping.
     fn sync_day_night_palette(&mut self, ctx: &mut Ctx<impl ConsoleApi>) {
-        let night = ctx.save.flag(crate::data::save::IS_NIGHT_FLAG);
-        if self.day_night_shown != Some(night) {
-            self.day_night_shown = Some(night);
-            ctx.draw.set_palette(if night {
-                &crate::platform::NIGHT_16
-            } else {
-                &crate::platform::SWEETIE_16
-            });
+        let amount = ctx.save.clock.night_amount();
+        if self.day_night_shown != Some(amount) {
+            self.paint_day_night(ctx, amount);
+            // A repaint mid-fade changes the base a running fade darkens from;
+            // drop the stale snapshot so the next one takes the new palette.
+            self.fade_base = None;
+        }
+    }
+
+    fn paint_day_night(&mut self, ctx: &mut Ctx<impl ConsoleApi>, amount: u16) {
+        use crate::platform::{NIGHT_16, SWEETIE_16};
+        self.day_night_shown = Some(amount);
+        fade_palette_into(&mut ctx.draw.palettes[0], &SWEETIE_16, &NIGHT_16, amount);
+    }
+
+    /// Darken the world palette toward black by the top cutscene's
+    /// [`fade_amount`](Cutscene::fade_amount) — the screen side of a
+    /// `warp … fade`. The first darkened frame snapshots the palette into
+    /// [`fade_base`](Self::fade_base) and every later frame fades from that,
+    /// so the fade never compounds; once the amount drops back to zero (the
+    /// fade ended, or the scene was skipped or cancelled mid-fade) the
+    /// snapshot is put back exactly. Called from [`step`](Self::step) after
+    /// the cutscene has ticked; the scrubber's replays never fade.
+    fn sync_scene_fade(&mut self, ctx: &mut Ctx<impl ConsoleApi>) {
+        let amount = self.cutscene.last().map_or(0, Cutscene::fade_amount);
+        if amount == 0 {
+            if let Some(base) = self.fade_base.take() {
+                ctx.draw.palettes[0] = base;
+            }
+            return;
         }
+        let base = self.fade_base.get_or_insert_with(|| ctx.draw.palettes[0].clone());
+        fade_palette_into(&mut ctx.draw.palettes[0], base, &[[0; 3]; 16], amount);
     }
 
-    /// Set the day/night state directly: record it in the
+    /// Set the day/night state directly: wind the clock to nightfall or
+    /// morning (if it isn't night or day already), record it in the
     /// [`IS_NIGHT_FLAG`](crate::data::save::IS_NIGHT_FLAG) save flag and repaint
     /// the world palette to match, at once. The immediate path used by the debug
     /// palette toggles (walkaround Digit6/Digit7, the debug menu's palette entries)
     /// so they still flip day↔night even from the B/W debug view; ordinary
-    /// day/night changes go through the flag alone and are picked up next frame by
+    /// day/night changes go through the flag or the clock alone and reach the
+    /// palette next frame, through
     /// [`sync_day_night_palette`](Self::sync_day_night_palette).
     pub fn set_day_night<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, night: bool) {
         ctx.save.set_flag(crate::data::save::IS_NIGHT_FLAG, night);
-        self.day_night_shown = Some(night);
-        ctx.draw.set_palette(if night {
-            &crate::platform::NIGHT_16
-        } else {
-            &crate::platform::SWEETIE_16
-        });
+        self.scrub_clock(ctx, 0);
+    }
+
+    /// Move the clock by `minutes` (either way) and bring the flag and the
+    /// palette along at once — the debug time scrub (walkaround Digit9/Digit0,
+    /// the debug menu's clock entry).
+    pub fn scrub_clock<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, minutes: i32) {
+        ctx.save.clock.advance_minutes(minutes);
+        self.sync_clock(ctx);
+        let amount = ctx.save.clock.night_amount();
+        self.paint_day_night(ctx, amount);
     }
 
     pub fn step<S: ConsoleApi>(&mut self, ctx: &mut Ctx<S>, editor_open: bool) -> Option<GameMode> {
@@ -1108,12 +1348,38 @@ impl WalkaroundState {
 
         self.particles.step();
 
-        // Keep the world's day/night palette in step with the save flag before
-        // anything early-returns, so a `#set is_night …` fired from a running
-        // cutscene or an open dialogue box repaints the world (next frame) too.
+        // Keep the clock, the `is_night` flag and the world's palette in step
+        // before anything early-returns, so a `#set is_night …` fired from a
+        // running cutscene or an open dialogue box repaints the world (next
+        // frame) too.
+        self.sync_clock(ctx);
         self.sync_day_night_palette(ctx);
 
-        if self.play_cutscene(ctx) {
+        // A warp transition owns the frame outright: input is held and the world
+        // waits while the screen covers, the warp lands, and the destination
+        // uncovers. Only the camera keeps following, so the landing is framed
+        // before it comes into view. It's held out of `self` while the warp
+        // lands, so the landing's `load_map` (which drops any transition a
+        // debug jump or save load interrupts) doesn't cut it short.
+        if let Some(mut transition) = self.transition.take() {
+            if let Some(warp) = transition.tick() {
+                self.land_warp(ctx, warp);
+            }
+            if !transition.is_finished() {
+                self.transition = Some(transition);
+            }
+            self.center_with_shake(
+                self.player_ref().pos.x + 4,
+                self.player_ref().pos.y - 2,
+                ctx.system.width() as i16,
+                ctx.system.height() as i16,
+            );
+            return None;
+        }
+
+        let in_cutscene = self.play_cutscene(ctx);
+        self.sync_scene_fade(ctx);
+        if in_cutscene {
             return None;
         }
 
@@ -1155,12 +1421,17 @@ impl WalkaroundState {
             }
         }
 
+        // Time passes only while the world itself runs: not under a cutscene,
+        // the editor, the bag or a warp transition.
+        ctx.save.clock.tick();
+
         if ctx.input.keyp(ScanCode::Digit5) && ctx.input.key(ScanCode::Ctrl) {
             self.load_pmem(ctx);
         }
-        // Digit6/Digit7 toggle day/night through the save flag (so the change
-        // persists and dialogue/gates see it); Digit8 is a one-off B/W debug view
-        // the day/night sync deliberately leaves alone.
+        // Digit6/Digit7 jump to day/night through the clock (so the change
+        // persists and dialogue/gates see it), and Digit9/Digit0 scrub it an
+        // hour back/forward; Digit8 is a one-off B/W debug view the day/night
+        // sync leaves alone until the blend next moves.
         if ctx.input.keyp(ScanCode::Digit6) {
             self.set_day_night(ctx, false);
         }
@@ -1170,6 +1441,12 @@ impl WalkaroundState {
         if ctx.input.keyp(ScanCode::Digit8) {
             ctx.draw.set_palette(&crate::platform::B_W);
         }
+        if ctx.input.keyp(ScanCode::Digit9) {
+            self.scrub_clock(ctx, -60);
+        }
+        if ctx.input.keyp(ScanCode::Digit0) {
+            self.scrub_clock(ctx, 60);
+        }
 
         // Get keyboard inputs
         let (mut dx, mut dy) = (0, 0);
@@ -1255,22 +1532,51 @@ impl WalkaroundState {
             // for the whole Walking state (`walking`) so the sprite cycles
             // smoothly rather than flickering on the idle ticks between moves.
             Amble { vx: i16, vy: i16, walking: bool },
+            // A patrol's step this frame, and the lap length its frame wraps at.
+            Patrol { vx: i16, vy: i16, lap: u32 },
             Hatch(PresetId),
         }
-        for shell in self.entities.iter_mut() {
+        // Where flee/approach creatures measure the player from: as they stood
+        // at the start of the step, before anyone moved.
+        let player_centre = self.player_ref().centre();
+        // A talking creature waits out its dialogue; once the box is down it
+        // goes back to its business.
+        if !self.dialogue.is_active() {
+            self.talker = None;
+        }
+        for (i, shell) in self.entities.iter_mut().enumerate() {
+            let centre = shell.centre();
             let act = match &mut shell.move_mode {
                 MoveMode::Player => Act::Player,
+                // A creature its schedule holds in a pose stands still rather
+                // than sliding around in it, as does one mid-talk. (A scene's
+                // `pose` leaves movement to the scene.)
+                _ if shell.slot_pose.is_some() || self.talker == Some(i) => Act::Drive(0, 0),
                 MoveMode::Wander => {
-                    let (vx, vy) = if ctx.rng.rand_u8() < 25 {
-                        (
-                            (ctx.rng.rand_u8() % 3) as i16 - 1,
-                            (ctx.rng.rand_u8() % 3) as i16 - 1,
-                        )
-                    } else {
-                        (shell.dir.0.into(), shell.dir.1.into())
-                    };
+                    let (vx, vy) = wander_heading(shell.dir, ctx.rng);
+                    Act::Drive(vx, vy)
+                }
+                // Reacting to the player draws nothing from the RNG; only the
+                // wander they fall back to out of range does, exactly as
+                // `Wander` would.
+                MoveMode::Flee { radius } => {
+                    let (vx, vy) = flee_heading(centre, player_centre, *radius)
+                        .unwrap_or_else(|| wander_heading(shell.dir, ctx.rng));
+                    Act::Drive(vx, vy)
+                }
+                MoveMode::Approach { radius } => {
+                    let (vx, vy) = approach_heading(centre, player_centre, *radius)
+                        .unwrap_or_else(|| wander_heading(shell.dir, ctx.rng));
                     Act::Drive(vx, vy)
                 }
+                MoveMode::Patrol { path, frame } => match ctx.scenes.paths.get(path.as_str()) {
+                    Some(runs) if scene::runs_frames(runs) > 0 => {
+                        let lap = scene::runs_frames(runs);
+                        let (vx, vy) = scene::rescaled_step(runs, lap, *frame % lap);
+                        Act::Patrol { vx, vy, lap }
+                    }
+                    _ => Act::Drive(0, 0),
+                },
                 MoveMode::Egg {
                     timer,
                     hatches_into,
@@ -1304,6 +1610,21 @@ impl WalkaroundState {
                     let (dx, dy) = shell.walk(ctx.system, vx, vy, false, &self.current_map, tiles);
                     shell.apply_motion(dx, dy);
                 }
+                // A patrol takes its step whole or not at all: blocked (even on
+                // one axis of a diagonal, which `walk` would slide along) it
+                // waits where it is, then resumes the route from the same
+                // point, so it stays on its loop.
+                Act::Patrol { vx, vy, lap } => {
+                    let (dx, dy) = shell.walk(ctx.system, vx, vy, false, &self.current_map, tiles);
+                    if (dx, dy) == (vx, vy) {
+                        shell.apply_motion(dx, dy);
+                        if let MoveMode::Patrol { frame, .. } = &mut shell.move_mode {
+                            *frame = (*frame + 1) % lap;
+                        }
+                    } else {
+                        shell.apply_motion(0, 0);
+                    }
+                }
                 Act::Amble { vx, vy, walking } => {
                     let (dx, dy) = shell.walk(ctx.system, vx, vy, false, &self.current_map, tiles);
                     shell.pos.x += dx;
@@ -1462,23 +1783,32 @@ impl WalkaroundState {
             // A removable object is consumed by the interaction: record it taken
             // (by stable id) and drop it from the live map so it vanishes now.
             self.take_object(i, ctx.save);
+        } else if interact
+            && let Some((i, talk)) =
+                self.entities.iter().enumerate().skip(1).find_map(|(i, creature)| {
+                    let talk = creature.talk.as_ref()?;
+                    interact_hitbox.touches(creature.body()).then(|| (i, talk.interaction()))
+                })
+        {
+            // No map object matched, but the player faces a creature with
+            // something to say: it turns to them and opens its talk — held there
+            // (see `talker`) until the box closes.
+            let player = self.player_ref().centre();
+            let creature = &mut self.entities[i];
+            creature.face(cutscene::facing_toward(creature.centre(), player));
+            self.talker = Some(i);
+            let mut inventory = std::mem::take(&mut self.inventory_ui.inventory);
+            self.fire_interaction(ctx, &talk, &mut inventory);
+            self.inventory_ui.inventory = inventory;
         } else if interact {
-            // No map object matched: fall back to the player's companions. If the
+            // Nothing else matched: fall back to the player's companions. If the
             // facing hitbox is on a pettable companion (the dog), launch the pet
             // cutscene — it walks the player up via a `beside` move, then fires the
             // dog's intrinsic pet. Lowest precedence, press-only.
             let pettable = self.player_ref().companions.iter().any(|c| {
-                if c.interaction.is_none() {
-                    return false;
-                }
                 // Hit-test the companion's drawn *body* (its sprite footprint), not
-                // its small feet hitbox, so petting isn't finicky. Centre a
-                // sprite-sized box on the hitbox, rising from its feet.
-                let (sprite, _) = c.sprite_options();
-                let (sw, sh) = (sprite.w as i16 * 8, sprite.h as i16 * 8);
-                let hb = c.hitbox();
-                let body = Hitbox::new(hb.x + hb.w / 2 - sw / 2, hb.y + hb.h - sh, sw, sh);
-                interact_hitbox.touches(body)
+                // its small feet hitbox, so petting isn't finicky.
+                c.interaction.is_some() && interact_hitbox.touches(c.body())
             });
             if pettable && let Some(def) = ctx.scenes.get_cutscene_resolved("pet_dog") {
                 let cutscene = Cutscene::launch(&def, ctx, self);
@@ -1507,6 +1837,11 @@ impl WalkaroundState {
         // editor dependency (the scrubber ghost-draws a world with no editor at
         // all).
         self.draw_world(ctx, self.camera.pos, debug_info);
+        // A running warp transition covers the frame at composite time; its iris
+        // closes on the point the camera follows.
+        let focus = self.player_ref().pos - self.camera.pos;
+        let centre = (i32::from(focus.x) + 4, i32::from(focus.y) - 2);
+        ctx.draw.cover = self.transition.as_ref().map(|t| t.cover(centre));
         WalkaroundState::composite_into(ctx.draw, ctx.system.output_image());
         // The bag overlay: drawn last, over the just-composited world, so it
         // reads as an inventory on top of the (frozen) world rather than its own
@@ -1652,6 +1987,8 @@ impl WalkaroundState {
                 let colour = match object.effect {
                     ObjectEffect::Warp(_) => 12,
                     ObjectEffect::Interact(_) => 14,
+                    // The creature itself is drawn; where it started isn't news.
+                    ObjectEffect::Creature(_) => continue,
                 };
                 ctx.draw.stroke_hitbox(
                     BG,
@@ -1687,9 +2024,11 @@ impl WalkaroundState {
     }
 
     /// Composite the finished walkaround frame (left in `draw_state.rgba(BG)` by
-    /// [`draw_world`](Self::draw_world)) onto `output`. Kept separate from the
-    /// world build so the caller chooses the destination surface — the main
-    /// window uses `system.output_image()`, an extra view its own framebuffer.
+    /// [`draw_world`](Self::draw_world)) onto `output`, then paint any warp
+    /// transition [`cover`](crate::draw_state::DrawState::cover) over it. Kept
+    /// separate from the world build so the caller chooses the destination
+    /// surface — the main window uses `system.output_image()`, an extra view its
+    /// own framebuffer (whose draw state never carries a cover).
     pub fn composite_into(
         draw_state: &mut crate::draw_state::DrawState,
         output: &mut crate::render::image::RgbaImage,
@@ -1706,6 +2045,20 @@ impl WalkaroundState {
             Transform::IDENTITY,
             |p| p.a() == 0,
         );
+        if let Some(cover) = &draw_state.cover {
+            cover.apply(output, &draw_state.palettes[0]);
+        }
+    }
+}
+
+/// The memoryless NPC wander's heading for a shell facing `dir`: a small
+/// chance to re-pick a random one, otherwise keep going. [`MoveMode::Wander`]'s
+/// every step, and what a flee/approach creature does with no player near.
+fn wander_heading(dir: (i8, i8), rng: &mut crate::rand::Lcg64Xsh32) -> (i16, i16) {
+    if rng.rand_u8() < 25 {
+        ((rng.rand_u8() % 3) as i16 - 1, (rng.rand_u8() % 3) as i16 - 1)
+    } else {
+        (dir.0.into(), dir.1.into())
     }
 }
 
@@ -1713,6 +2066,7 @@ impl WalkaroundState {
 mod tests {
     use super::*;
     use crate::world::map::{Gate, LayerInfo, MapObject, Trigger, Warp};
+    use crate::world::interact::Talk;
     use crate::geometry::Hitbox;
     use crate::platform::test_console::TestConsole;
 
@@ -2081,6 +2435,58 @@ mod tests {
         assert_eq!(walk.entities.len(), 2, "gate allows once has_key is set");
     }
 
+    /// Interacting with a creature that has a `talk` opens it: the creature
+    /// turns to face the player and stands still until the box closes, then
+    /// goes back to its business.
+    #[test]
+    fn talking_creature_faces_player_and_waits_out_the_dialogue() {
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let script = crate::data::script::eggtext::parse("#dialogue chirp\n    Chirp!\n")
+            .expect("test script parses");
+        parts.script.set_base(script, &crate::data::portraits::Portraits::builtin());
+        let mut walk = WalkaroundState::new();
+        let map = MapInfo {
+            source: "town".to_string(),
+            layers: vec![LayerInfo::DEFAULT_LAYER],
+            ..MapInfo::default()
+        };
+        walk.load_map(&mut console, map);
+        walk.player().pos = Vec2::new(40, 40);
+        walk.player().dir = (0, 1);
+        walk.inventory_ui.state = InventoryUiState::Close;
+        let mut critter = parts.presets.spawn(&PresetId::critter()).unwrap();
+        critter.pos = Vec2::new(40, 44);
+        critter.move_mode = MoveMode::Wander;
+        critter.face((1, 0));
+        critter.talk = Some(Talk::Dialogue("chirp".into()));
+        walk.entities.push(critter);
+
+        parts.input.controllers[0].a = [true, false];
+        with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        parts.input.controllers[0].a = [false, false];
+        assert!(walk.dialogue.is_active(), "the talk opened its dialogue");
+        assert_eq!(walk.entities[1].dir, (0, -1), "the creature turned to the player");
+
+        let held = walk.entities[1].pos;
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..60 {
+                walk.step(ctx, false);
+            }
+        });
+        assert!(walk.dialogue.is_active());
+        assert_eq!(walk.entities[1].pos, held, "it waits out the dialogue");
+        assert_eq!(walk.entities[1].dir, (0, -1));
+
+        walk.dialogue.close();
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..60 {
+                walk.step(ctx, false);
+            }
+        });
+        assert_ne!(walk.entities[1].pos, held, "then it wanders off again");
+    }
+
     /// A one-shot object (`unless done` + `sets done`) fires once, latches its
     /// flag, and its own gate then holds it off — and the flag persists across a
     /// JSON save round-trip so it stays blocked on a fresh load. The whole loop:
@@ -2247,6 +2653,268 @@ mod tests {
         );
     }
 
+    /// A scheduled creature is set down at its slot as the player changes
+    /// map: spawned the first time, parked on a map the player isn't on, and
+    /// moved off the map the player leaves when its slot says so — so it's
+    /// where the clock says whenever the player walks in. A posed slot holds
+    /// it still, and the pose survives a save; with no slot claiming it, it
+    /// stays put unposed; a reload of the current map moves no one.
+    #[test]
+    fn schedules_place_creatures_at_map_changes() {
+        use crate::data::clock::Clock;
+        use crate::data::schedule::Schedules;
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        for name in ["town", "house", "field"] {
+            let json = r#"{
+                "width": 2, "height": 2,
+                "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+                "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": []}]
+            }"#;
+            let map: crate::data::tiled::TiledMap = serde_json::from_str(json).unwrap();
+            parts.maps.insert(name, map);
+        }
+        let file = crate::data::eggdata::parse(
+            "[schedules.may]\n\
+             preset = \"may\"\n\
+             [[schedules.may.slots]]\n\
+             hours = [8, 18]\n\
+             map = \"town\"\n\
+             at = [16, 8]\n\
+             [[schedules.may.slots]]\n\
+             unless = \"may_away\"\n\
+             map = \"house\"\n\
+             at = [4, 4]\n\
+             pose = \"slump\"\n",
+        )
+        .unwrap();
+        parts.schedules = Schedules::from_data(&file);
+        let may = |walk: &WalkaroundState| {
+            walk.entities[1..].iter().find(|s| s.id.as_deref() == Some("may")).cloned()
+        };
+        let load = |walk: &mut WalkaroundState, parts: &mut CtxParts, console: &mut TestConsole, name| {
+            with_ctx(console, parts, |ctx| walk.load_map_by_name(ctx, name));
+        };
+
+        // Noon: spawned into town, off screen while the player is in the house.
+        parts.save.clock = Clock::at(12, 0);
+        load(&mut walk, &mut parts, &mut console, "house");
+        assert!(may(&walk).is_none());
+        load(&mut walk, &mut parts, &mut console, "town");
+        let shell = may(&walk).expect("may is in town at noon");
+        assert_eq!(shell.pos, Vec2::new(16, 8));
+        assert_eq!(shell.preset, PresetId::may());
+        assert_eq!(shell.slot_pose, None);
+
+        // Evening falls while the player watches her: she stays put on a
+        // reload, then goes home as the player leaves.
+        parts.save.clock = Clock::at(20, 0);
+        load(&mut walk, &mut parts, &mut console, "town");
+        assert!(may(&walk).is_some(), "a reload moves no one");
+        load(&mut walk, &mut parts, &mut console, "house");
+        let shell = may(&walk).expect("may is home in the evening");
+        assert_eq!(shell.pos, Vec2::new(4, 4));
+        assert_eq!(shell.slot_pose.as_deref(), Some("slump"));
+        let held = shell.pos;
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..60 {
+                walk.step(ctx, false);
+            }
+        });
+        assert_eq!(may(&walk).unwrap().pos, held, "posed, she stands still");
+        let saved: Shell = serde_json::from_str(&serde_json::to_string(&shell).unwrap()).unwrap();
+        assert_eq!(saved.slot_pose.as_deref(), Some("slump"), "the pose is saved with her");
+
+        // Away, no slot claims her: she's left in the house, unposed.
+        parts.save.set_flag("may_away", true);
+        load(&mut walk, &mut parts, &mut console, "field");
+        let parked = walk.map_entities["house"].iter().find(|s| s.id.as_deref() == Some("may"));
+        let parked = parked.expect("unclaimed, she stays in the house");
+        assert_eq!(parked.pos, held);
+        assert_eq!(parked.slot_pose, None, "and drops the pose");
+
+        // Morning, leaving the field: she's lifted off the house map and
+        // back into town, exactly once.
+        parts.save.set_flag("may_away", false);
+        parts.save.clock = Clock::at(9, 0);
+        load(&mut walk, &mut parts, &mut console, "town");
+        assert_eq!(may(&walk).unwrap().pos, Vec2::new(16, 8));
+        let count: usize = walk.map_entities.values().flatten().chain(&walk.entities)
+            .filter(|s| s.id.as_deref() == Some("may"))
+            .count();
+        assert_eq!(count, 1, "never duplicated");
+    }
+
+    /// A map's Tiled `creature` objects spawn its creatures the first time
+    /// it's entered, with the placement's overrides; after that the parked
+    /// (and saved) creatures are the map's, so a creature that wandered off
+    /// isn't put back and a second one isn't made.
+    #[test]
+    fn placed_creatures_spawn_on_first_visit_then_the_save_wins() {
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        let town = r#"{
+            "width": 2, "height": 2,
+            "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": [
+                {"id": 3, "x": 24, "y": 16, "width": 0, "height": 0, "type": "creature",
+                 "properties": [
+                    {"name": "preset", "type": "string", "value": "critter"},
+                    {"name": "id", "type": "string", "value": "pip"},
+                    {"name": "facing", "type": "string", "value": "left"},
+                    {"name": "description", "type": "string", "value": "pip_hello"}
+                 ]}
+            ]}]
+        }"#;
+        let field = r#"{
+            "width": 2, "height": 2,
+            "tilesets": [{"firstgid": 1, "source": "tiles.tsj"}],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": []}]
+        }"#;
+        parts.maps.insert("town", serde_json::from_str(town).unwrap());
+        parts.maps.insert("field", serde_json::from_str(field).unwrap());
+        let load = |walk: &mut WalkaroundState, parts: &mut CtxParts, console: &mut TestConsole, name| {
+            with_ctx(console, parts, |ctx| walk.load_map_by_name(ctx, name));
+        };
+
+        load(&mut walk, &mut parts, &mut console, "town");
+        assert_eq!(walk.entities.len(), 2, "the placed creature is spawned");
+        let pip = &walk.entities[1];
+        assert_eq!(pip.preset, PresetId::critter());
+        assert_eq!(pip.id.as_deref(), Some("pip"));
+        assert_eq!(pip.pos, Vec2::new(24, 16));
+        assert_eq!(pip.dir, (-1, 0));
+        assert_eq!(pip.talk, Some(Talk::Dialogue("pip_hello".into())));
+
+        // It wanders; leaving and coming back finds it where it went.
+        walk.entities[1].pos = Vec2::new(60, 40);
+        load(&mut walk, &mut parts, &mut console, "field");
+        load(&mut walk, &mut parts, &mut console, "town");
+        assert_eq!(walk.entities.len(), 2, "not spawned a second time");
+        assert_eq!(walk.entities[1].pos, Vec2::new(60, 40));
+
+        // A save made there restores that, not the placement.
+        walk.save("town", &mut parts.save);
+        let mut reloaded = WalkaroundState::new();
+        with_ctx(&mut console, &mut parts, |ctx| reloaded.load_pmem(ctx));
+        assert_eq!(reloaded.entities.len(), 2);
+        assert_eq!(reloaded.entities[1].pos, Vec2::new(60, 40));
+
+        // Gone from the save (say a cutscene carried it off) stays gone.
+        walk.entities.truncate(1);
+        load(&mut walk, &mut parts, &mut console, "field");
+        load(&mut walk, &mut parts, &mut console, "town");
+        assert_eq!(walk.entities.len(), 1, "an emptied map stays empty");
+    }
+
+    /// A `Patrol` creature walks its `#path` as a loop, coming back round to
+    /// where it started each lap; flee and approach creatures in radius move
+    /// away from / toward the player.
+    #[test]
+    fn patrol_flee_and_approach_move_creatures() {
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        parts.scenes.paths.insert(
+            "beat".to_string(),
+            vec![((1, 0), 8), ((0, 1), 8), ((-1, 0), 8), ((0, -1), 8)],
+        );
+        walk.entities[0].pos = Vec2::new(100, 100);
+        let creature = |pos, move_mode| Shell { pos, move_mode, ..Shell::default() };
+        walk.entities.push(creature(
+            Vec2::new(20, 20),
+            MoveMode::Patrol { path: "beat".into(), frame: 0 },
+        ));
+        walk.entities.push(creature(Vec2::new(110, 100), MoveMode::Flee { radius: 32 }));
+        walk.entities.push(creature(Vec2::new(130, 100), MoveMode::Approach { radius: 48 }));
+
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..16 {
+                walk.step(ctx, false);
+            }
+        });
+        assert_ne!(walk.entities[1].pos, Vec2::new(20, 20), "half a lap is away from home");
+        assert!(walk.entities[2].pos.x > 110, "the fleer backs off east");
+        assert!(walk.entities[3].pos.x < 130, "the approacher closes in from the east");
+
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..16 {
+                walk.step(ctx, false);
+            }
+        });
+        assert_eq!(walk.entities[1].pos, Vec2::new(20, 20), "a full lap comes home");
+    }
+
+    /// A patrol whose route runs into a wall doesn't count the blocked frames
+    /// as walked, nor slide a blocked diagonal along its free axis: it waits
+    /// at the wall, and once the wall is gone it finishes the lap from there
+    /// and still comes home.
+    #[test]
+    fn blocked_patrol_resumes_its_lap() {
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        parts.scenes.paths.insert("beat".to_string(), vec![((1, 0), 16), ((-1, 0), 16)]);
+        parts.scenes.paths.insert("slope".to_string(), vec![((1, 1), 16), ((-1, -1), 16)]);
+        parts.maps.insert("yard", crate::data::tiled::TiledMap::default());
+        let mut solid = Collider::default();
+        for y in 0..8 {
+            for x in 0..8 {
+                solid.set(x, y, true);
+            }
+        }
+        // A wall on tile column 2, a few pixels short of the patrol's turn.
+        let colliders = (0..8)
+            .flat_map(|y| (0..8).map(move |x| (x, y)))
+            .map(|(x, _)| if x == 2 { solid.clone() } else { Collider::default() })
+            .collect();
+        walk.current_map = MapInfo {
+            source: "yard".into(),
+            layers: vec![LayerInfo { colliders, ..LayerInfo::new(0, 0, 8, 8) }],
+            ..MapInfo::default()
+        };
+        walk.entities[0].pos = Vec2::new(0, 48);
+        let patrol = |y, path: &str| Shell {
+            pos: Vec2::new(0, y),
+            move_mode: MoveMode::Patrol { path: path.into(), frame: 0 },
+            ..Shell::default()
+        };
+        // One along the wall's face, one on a diagonal the wall blocks only
+        // on x (which `walk` alone would slide down along).
+        walk.entities.push(patrol(16, "beat"));
+        walk.entities.push(patrol(24, "slope"));
+        let frame = |walk: &WalkaroundState, i: usize| match walk.entities[i].move_mode {
+            MoveMode::Patrol { frame, .. } => frame,
+            _ => unreachable!(),
+        };
+
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..40 {
+                walk.step(ctx, false);
+            }
+        });
+        let stopped = walk.entities[1].pos;
+        assert!(stopped.x < 16, "the wall stops it short of the turn");
+        assert_eq!(frame(&walk, 1), u32::from(stopped.x as u16), "only walked frames count");
+        let sloped = walk.entities[2].pos;
+        assert!(sloped.x < 16, "the wall stops the diagonal too");
+        assert_eq!(sloped.y - 24, sloped.x, "no sliding along the free axis");
+        assert_eq!(frame(&walk, 2), u32::from(sloped.x as u16));
+
+        walk.current_map.layers[0].colliders.clear();
+        let (left, sloped_left) = (32 - frame(&walk, 1), 32 - frame(&walk, 2));
+        assert_eq!(left, sloped_left, "both waited at the same point of the lap");
+        with_ctx(&mut console, &mut parts, |ctx| {
+            for _ in 0..left {
+                walk.step(ctx, false);
+            }
+        });
+        assert_eq!(walk.entities[1].pos, Vec2::new(0, 16), "the finished lap comes home");
+        assert_eq!(walk.entities[2].pos, Vec2::new(0, 24), "the diagonal one too");
+    }
+
     /// `sync_map_animations` mirrors live editor edits into the cached object
     /// animations: it patches frames in place (keeping the playback cursor) and
     /// rebuilds only when the set of sprited objects changes.
@@ -2343,12 +3011,16 @@ mod tests {
     #[test]
     fn entity_id_resolves_player_companion_and_id() {
         let mut walk = WalkaroundState::new();
-        let mut dog = Shell::default();
-        dog.move_mode = MoveMode::Companion { slot: 0 };
-        dog.preset = PresetId::dog();
+        let dog = Shell {
+            move_mode: MoveMode::Companion { slot: 0 },
+            preset: PresetId::dog(),
+            ..Shell::default()
+        };
         walk.player().companions.push(dog);
-        let mut critter = Shell::default();
-        critter.id = Some("critter_a".to_string());
+        let critter = Shell {
+            id: Some("critter_a".to_string()),
+            ..Shell::default()
+        };
         walk.entities.push(critter);
 
         assert_eq!(
@@ -2386,6 +3058,7 @@ mod tests {
         save: SaveData,
         items: GameItems,
         presets: crate::data::eggdata::Presets,
+        schedules: crate::data::schedule::Schedules,
         font: crate::render::Font,
     }
     impl CtxParts {
@@ -2400,6 +3073,7 @@ mod tests {
                 save: SaveData::default(),
                 items: GameItems::default(),
                 presets: crate::data::eggdata::Presets::builtin(),
+                schedules: crate::data::schedule::Schedules::default(),
                 font: crate::render::Font::blank(),
             }
         }
@@ -2424,6 +3098,7 @@ mod tests {
             save: &mut parts.save,
             items: &parts.items,
             presets: &parts.presets,
+            schedules: &parts.schedules,
             font: &parts.font,
         };
         f(&mut ctx)
@@ -2456,6 +3131,57 @@ mod tests {
         assert!(walk.inventory_ui.is_open(), "the bag overlay is now open");
     }
 
+    /// A warp with a screen transition holds input for its whole run, lands the
+    /// player on the fully covered frame (loading the destination map without
+    /// cutting the uncover short), and paints its cover at composite time; a
+    /// cut warp still lands at once, and any other map load drops a transition
+    /// mid-run.
+    #[test]
+    fn warp_transition_holds_input_and_lands_when_covered() {
+        use crate::world::transition::{TRANSITION_FRAMES, Transition};
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        walk.load_map(&mut console, map_with_objects(vec![]));
+        walk.inventory_ui.state = InventoryUiState::Close;
+        walk.player().pos = Vec2::new(0, 0);
+        let town = r#"{
+            "width": 2, "height": 2, "tilesets": [],
+            "layers": [{"type": "objectgroup", "name": "Object Layer 1", "objects": []}]
+        }"#;
+        parts.maps.insert("town", serde_json::from_str(town).unwrap());
+
+        let warp = Warp::new(Some("town"), Vec2::new(40, 24));
+        with_ctx(&mut console, &mut parts, |ctx| {
+            walk.apply_warp(ctx, warp.clone().with_transition(Transition::Iris))
+        });
+        assert_eq!(walk.player_ref().pos, Vec2::new(0, 0), "not landed yet");
+        for frame in 1..=2 * TRANSITION_FRAMES {
+            press_b(&mut parts);
+            with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+            assert!(!walk.inventory_ui.is_open(), "input held on frame {frame}");
+            let landed = walk.player_ref().pos == Vec2::new(40, 24);
+            assert_eq!(landed, frame >= TRANSITION_FRAMES, "frame {frame}");
+            if frame == TRANSITION_FRAMES {
+                with_ctx(&mut console, &mut parts, |ctx| walk.draw(ctx, &DebugInfo::default()));
+                assert_eq!(parts.draw.cover.map(|c| c.amount), Some(256));
+            }
+        }
+        assert!(walk.transition.is_none(), "finished");
+        assert_eq!(walk.current_map.source, "town");
+
+        let cut = warp.clone().with_transition(Transition::Cut);
+        with_ctx(&mut console, &mut parts, |ctx| walk.apply_warp(ctx, cut));
+        assert!(walk.transition.is_none(), "a cut never starts a transition");
+
+        with_ctx(&mut console, &mut parts, |ctx| {
+            walk.apply_warp(ctx, warp.with_transition(Transition::Fade))
+        });
+        assert!(walk.transition.is_some());
+        walk.load_map(&mut console, map_with_objects(vec![]));
+        assert!(walk.transition.is_none(), "a map load drops the running transition");
+    }
+
     /// While the bag overlay is open it freezes the walkaround sim: a `step` with
     /// a movement input held neither moves the player nor advances a wandering
     /// creature — the overlay guard early-returns before the entity loop. (The
@@ -2789,6 +3515,61 @@ mod tests {
         assert_eq!(parts.draw.palettes[0][0], crate::platform::SWEETIE_16[0]);
     }
 
+    /// The clock drives the palette: mid-dusk paints the halfway blend and
+    /// sets `is_night`, each world frame ticks the clock, and Digit0 scrubs
+    /// it an hour on — into full night.
+    #[test]
+    fn clock_blends_palette_and_scrubs() {
+        use crate::data::clock::{Clock, FRAMES_PER_MINUTE};
+        use crate::data::save::IS_NIGHT_FLAG;
+        use crate::draw_state::fade_colour_into;
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        walk.load_map(&mut console, map_with_objects(vec![]));
+
+        parts.save.clock = Clock::at(19, 0);
+        with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        let mut halfway = [0; 3];
+        let (day, night) = (crate::platform::SWEETIE_16[0], crate::platform::NIGHT_16[0]);
+        fade_colour_into(&mut halfway, day, night, 128);
+        assert_eq!(parts.draw.palettes[0][0], halfway);
+        assert!(parts.save.flag(IS_NIGHT_FLAG), "19:00 is past the middle of dusk");
+
+        for _ in 1..FRAMES_PER_MINUTE {
+            with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        }
+        assert_eq!(parts.save.clock, Clock::at(19, 1), "one frame per step");
+
+        parts.input = crate::platform::EggInput::new();
+        parts.input.press_key(ScanCode::Digit0);
+        with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        assert_eq!(parts.save.clock.hour(), 20);
+        assert_eq!(parts.draw.palettes[0][0], night);
+    }
+
+    /// Clearing `is_night` by hand (as a `#set is_night false` would) at
+    /// night winds the clock to morning rather than being overwritten.
+    #[test]
+    fn writing_the_night_flag_winds_the_clock() {
+        use crate::data::clock::Clock;
+        use crate::data::save::IS_NIGHT_FLAG;
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let mut walk = WalkaroundState::new();
+        walk.load_map(&mut console, map_with_objects(vec![]));
+
+        parts.save.clock = Clock::at(23, 0);
+        with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        assert!(parts.save.flag(IS_NIGHT_FLAG));
+
+        parts.save.set_flag(IS_NIGHT_FLAG, false);
+        with_ctx(&mut console, &mut parts, |ctx| walk.step(ctx, false));
+        assert!(!parts.save.flag(IS_NIGHT_FLAG), "the write sticks");
+        assert_eq!(parts.save.clock.hour(), 7);
+        assert_eq!(parts.draw.palettes[0][0], crate::platform::SWEETIE_16[0]);
+    }
+
     /// The background fill resolves the map's own colour — an index through the
     /// live palette, a literal RGB verbatim — and the walkaround `bg_colour`
     /// override (the runtime art/animation seam) wins over the map while set,
@@ -2952,4 +3733,45 @@ mod tests {
             assert_eq!(replay.total, 12, "10-frame move + 3-frame wait sharing frame 10");
         });
     }
+
+    /// An `if` reached mid-scene reads a flag an earlier step set, and a seek
+    /// that resumes from a rung *past* that `set` still takes the same branch
+    /// as the naive replay from frame 0 — the ladder carries the save along
+    /// with the world, so no rung resumes against the frame-0 save.
+    #[test]
+    fn snapshot_ladder_seek_resolves_branches_against_the_rung_save() {
+        let mut console = TestConsole::new();
+        let mut parts = CtxParts::new();
+        let walk = armed_scene(
+            "#cutscene t
+                 set seen true
+                 wait 10
+                 if seen
+                     move
+                         player: teleport 50 0
+                 else
+                     move
+                         player: teleport 99 0
+                 wait 5",
+            &mut console,
+            &mut parts,
+        );
+        with_ctx(&mut console, &mut parts, |ctx| {
+            let replay = walk.replay_cutscene(4, ctx);
+            for frame in 0..=replay.total {
+                *ctx.save = SaveData::default();
+                let via_ladder = replay.seek(frame, ctx).player_ref().pos;
+                *ctx.save = SaveData::default();
+                let naive = walk.sim_cutscene_to(frame, ctx).player_ref().pos;
+                assert_eq!(via_ladder, naive, "ladder seek diverges at frame {frame}");
+            }
+            *ctx.save = SaveData::default();
+            let end = replay.seek(replay.total, ctx);
+            assert_eq!(
+                end.player_ref().pos,
+                Vec2::new(50, 0),
+                "the `if seen` branch ran"
+            );
+        });
+    }
 }
@@ -16,6 +16,7 @@
 
 pub mod debug;
 pub mod gamestate;
+pub mod recorder;
 /// The primitives crate ([`egg_render`]) re-exported under its historical
 /// in-crate paths, so `crate::geometry::…` / `crate::render::…` (and the
 /// host's `egg_core::geometry`/`egg_core::render`) keep resolving after the
@@ -52,12 +53,13 @@ pub use egg_ui as ui;
 
 use crate::data::eggdata::{GameItems, Presets};
 use crate::data::portraits::Portraits;
-use crate::data::save::{SAVE_PATH, SaveData};
+use crate::data::save::{SAVE_PATH, SaveData, slot_path, slot_thumb_path};
 use crate::data::scene::{CutsceneDef, SceneFile};
+use crate::data::schedule::Schedules;
 use crate::data::script::Script;
-use crate::data::script::message::Message;
 use crate::debug::DebugInfo;
 use crate::draw_state::DrawState;
+use crate::gamestate::slots::{self, SlotRequest};
 use crate::gamestate::walkaround::WalkaroundState;
 use crate::gamestate::{CutsceneScrubber, GameMode, Instructions, IntroAnimation, MenuState, SpriteTest};
 use crate::platform::{ConsoleApi, EggInput};
@@ -110,6 +112,9 @@ pub struct Ctx<'a, S: ConsoleApi> {
     /// The loaded creature registry (preset defs by [`PresetId`](crate::world::player::PresetId)).
     /// Loaded game data like [`items`](Self::items); read-only here.
     pub presets: &'a Presets,
+    /// The loaded NPC schedules (see [`Schedules`]), by creature id. Loaded
+    /// game data like [`presets`](Self::presets); read-only here.
+    pub schedules: &'a Schedules,
     /// The loaded bitmap [`Font`], threaded in as game data rather than a console
     /// service. The text-drawing convenience methods on `Ctx` ([`print_to`] &c.)
     /// render with it; a headless console needs no font at all.
@@ -178,12 +183,10 @@ impl<S: ConsoleApi> Ctx<'_, S> {
         self.script.list(key)
     }
 
-    /// An inventory item's display name — element 0 of its `item_<key>` list.
+    /// An inventory item's display name (see [`Script::item_name`]) — the same
+    /// lookup a dialogue `{item:KEY}` placeholder resolves through.
     pub fn item_name(&self, key: &str) -> String {
-        self.list(&format!("item_{key}"))
-            .into_iter()
-            .next()
-            .unwrap_or_default()
+        self.script.item_name(key)
     }
 
     /// An inventory item's description — element 1 of its `item_<key>` list.
@@ -194,13 +197,6 @@ impl<S: ConsoleApi> Ctx<'_, S> {
             .unwrap_or_default()
     }
 
-    /// A dialogue conversation by key. Any `#if` in it comes back as an
-    /// unpicked branch carrier — it picks by the player's flags at *playback*
-    /// time, in the dialogue box (see [`Script::get_dialogue`]), not here.
-    pub fn get_dialogue(&self, key: &str) -> Vec<Message> {
-        self.script.get_dialogue(key)
-    }
-
     /// A cutscene definition by name from the loaded registry, or `None` if
     /// undefined (see [`SceneFile::get_cutscene`]). RAW — a caller about to
     /// launch it wants [`Self::get_cutscene_resolved`] instead.
@@ -243,6 +239,10 @@ pub struct EggState {
     /// [`Ctx::presets`]. Defaults to the embedded built-ins ([`Presets::builtin`]);
     /// [`load_data`](Self::load_data) re-derives it from the runtime `data.toml`.
     pub presets: Presets,
+    /// The loaded NPC schedules, threaded into every state via
+    /// [`Ctx::schedules`]. Empty until [`load_data`](Self::load_data) installs
+    /// the runtime `data.toml`'s.
+    pub schedules: Schedules,
     /// The loaded dialogue-portrait registry (sprite cells + offset per script
     /// name). Defaults to the embedded built-ins ([`Portraits::builtin`]);
     /// [`load_data`](Self::load_data) re-derives it from the runtime `data.toml`
@@ -286,6 +286,18 @@ pub struct EggState {
     /// The last [`SaveData`] flushed to storage. [`flush_save`](Self::flush_save)
     /// diffs the live save against this so it only writes when something changed.
     last_flushed_save: SaveData,
+    /// The save slot [`save`](Self::save) belongs to — where
+    /// [`load_save`](Self::load_save) reads it from and
+    /// [`flush_save`](Self::flush_save) writes it back. Slot 0 until the title's
+    /// slot screen picks another (see [`SlotRequest`]).
+    active_slot: usize,
+    /// The game saved since the slot's thumbnail was last taken, and the
+    /// picture is waiting for a frame nothing covers (see
+    /// [`flush_save`](Self::flush_save)).
+    thumbnail_pending: bool,
+    /// Whether a warp transition or `warp … fade` covered the frame the last
+    /// flush ran after — the frame the host has composited by the next one.
+    screen_was_covered: bool,
     /// The open cutscene scrubber, if any (see [`CutsceneScrubber`]). A fullscreen
     /// editor modal: while it's `Some`, [`step_mode`](Self::step_mode) drives + draws
     /// it and skips the normal sim. Opened via the editor's `pending_scrub` request.
@@ -310,7 +322,14 @@ impl EggState {
                 .load_inventory(&self.save.inventory, &self.items);
         }
         self.time += 1;
-        if let Some(mode) = self.step_mode(system, input, editor_open) {
+        let mode = self.step_mode(system, input, editor_open);
+        // Carry out any slot I/O the slot screen asked for before `enter` can
+        // rebuild the menu — a `Load` lands the slot's save ahead of the mode
+        // that plays it.
+        if let Some(request) = self.menu.take_slot_request() {
+            self.apply_slot_request(system, request);
+        }
+        if let Some(mode) = mode {
             self.enter(mode);
         }
         // Serialise the live inventory into the save before it is flushed, so an
@@ -348,6 +367,7 @@ impl EggState {
                 save: &mut self.save,
                 items: &self.items,
                 presets: &self.presets,
+                schedules: &self.schedules,
                 font: &self.font,
             };
             match self.gamestate {
@@ -399,7 +419,9 @@ impl EggState {
         }
     }
 
-    /// Load the persisted save from the host's file store, once. Mirrors the
+    /// Load the [active slot](Self::active_slot)'s save from the host's file
+    /// store, once (slot 0 falling back to the legacy single save, see
+    /// [`slots::read_slot`]). Mirrors the
     /// old host loader's tone: a missing/unreadable/garbage save logs and falls
     /// back to the existing (default) `save`. Either way the last-flushed copy
     /// is seeded so the first [`flush_save`](Self::flush_save) doesn't rewrite
@@ -411,12 +433,15 @@ impl EggState {
             return false;
         }
         self.save_loaded = true;
-        if let Some(bytes) = system.read_file(SAVE_PATH) {
+        if let Some(bytes) = slots::read_slot(system, self.active_slot) {
             // `from_json` (not a bare `from_slice`) so a save written with the old
             // `is_night` bool migrates that state onto the modern flag on load.
             match SaveData::from_json(&bytes) {
                 Ok(data) => self.save = data,
-                Err(e) => log::error!("Failed to parse save ({SAVE_PATH}): {e}"),
+                Err(e) => {
+                    let path = slot_path(self.active_slot);
+                    log::error!("Failed to parse save ({path}): {e}");
+                }
             }
         }
         self.last_flushed_save = self.save.clone();
@@ -424,11 +449,12 @@ impl EggState {
     }
 
     /// Load the game-data file (`assets/data/data.toml`) from the host's file
-    /// store, once, installing the item/preset/portrait registries it defines (a
-    /// full replace, so the file is the source of truth) and re-baking any
-    /// already-installed dialogue against the fresh portrait registry (see
-    /// [`Script::reresolve_portraits`]) — a script installed before this runs
-    /// (e.g. the boot-time base language) would otherwise keep stale portraits.
+    /// store, once, installing the item/preset/portrait/schedule registries it
+    /// defines (a full replace, so the file is the source of truth) and
+    /// re-baking any already-installed dialogue against the fresh portrait
+    /// registry (see [`Script::reresolve_portraits`]) — a script installed
+    /// before this runs (e.g. the boot-time base language) would otherwise
+    /// keep stale portraits.
     /// Mirrors [`load_save`](Self::load_save): a missing file is ignored
     /// silently and a malformed one logs, either way leaving the built-in
     /// [`GameItems::default`]/[`Presets::builtin`]/[`Portraits::builtin`] in
@@ -457,6 +483,7 @@ impl EggState {
             Ok(data) => {
                 self.items = GameItems::from_data(&data.items);
                 self.presets = eggdata::Presets::from_data(&data);
+                self.schedules = Schedules::from_data(&data);
                 self.portraits = Portraits::from_data(&data);
                 // Portrait names were baked into `Message`s when the script was
                 // installed; re-bake them now against the fresh registry so a
@@ -478,20 +505,107 @@ impl EggState {
         self.load_data(system);
     }
 
-    /// Flush the save to the host's file store when it differs from the last
-    /// value written. A serialisation failure logs and skips — a failed save
-    /// never crashes the game.
+    /// Flush the save to the active slot in the host's file store when it
+    /// differs from the last value written. When the game has saved since (a
+    /// new [`save_count`](SaveData::save_count)) the slot's thumbnail is
+    /// retaken from the finished frame too, so it shows where the player last
+    /// saved rather than whichever menu last toggled an option. The
+    /// [`clock`](SaveData::clock) moves every world frame, so on its own it
+    /// only flushes when the hour turns; any other change takes the current
+    /// time along with it. A serialisation failure logs and skips — a failed
+    /// save never crashes the game.
+    ///
+    /// The game saves as a warp lands, on the frame its transition (or a
+    /// scene's `warp … fade`) has the screen fully covered, so the thumbnail
+    /// waits for the first finished frame nothing covered.
     pub fn flush_save(&mut self, system: &mut impl platform::ConsoleApi) {
-        if self.save == self.last_flushed_save {
-            return;
+        let clock = std::mem::replace(&mut self.save.clock, self.last_flushed_save.clock);
+        let unchanged = self.save == self.last_flushed_save;
+        self.save.clock = clock;
+        if !unchanged || clock.hour() != self.last_flushed_save.clock.hour() {
+            match serde_json::to_string_pretty(&self.save) {
+                Ok(json) => {
+                    system.write_file(&slot_path(self.active_slot), json.as_bytes());
+                    self.thumbnail_pending |=
+                        self.save.save_count != self.last_flushed_save.save_count;
+                    self.last_flushed_save = self.save.clone();
+                }
+                Err(e) => log::error!("Failed to serialise save data: {e}"),
+            }
+        }
+        // The host composites after `run`, so the output holds the frame drawn
+        // as of the previous flush.
+        if self.thumbnail_pending && !self.screen_was_covered {
+            let thumb = slots::thumbnail(system.output_image()).encode_png();
+            system.write_file(&slot_thumb_path(self.active_slot), &thumb);
+            self.thumbnail_pending = false;
         }
-        match serde_json::to_string_pretty(&self.save) {
-            Ok(json) => {
-                system.write_file(SAVE_PATH, json.as_bytes());
-                self.last_flushed_save = self.save.clone();
+        self.screen_was_covered = self.walkaround.screen_covered();
+    }
+
+    /// The save slot the live [`save`](Self::save) belongs to.
+    pub fn active_slot(&self) -> usize {
+        self.active_slot
+    }
+
+    /// Carry out a [`SlotRequest`] from the slot screen, then refresh the
+    /// screen's cards. Whatever touches the active slot keeps the live save in
+    /// step with it: loading flushes the outgoing slot first, copying onto the
+    /// active slot reloads it, and erasing it resets to a fresh save — each
+    /// rebuilding the live inventory, which `run` otherwise syncs back over the
+    /// save every frame. An empty slot starts from the default save but keeps
+    /// the player's text-size and door preferences.
+    pub fn apply_slot_request(
+        &mut self,
+        system: &mut impl platform::ConsoleApi,
+        request: SlotRequest,
+    ) {
+        match request {
+            SlotRequest::Load(slot) => {
+                self.flush_save(system);
+                self.active_slot = slot;
+                self.reload_active_slot(system);
+            }
+            SlotRequest::Copy { from, to } => {
+                let Some(bytes) = slots::read_slot(system, from) else {
+                    return;
+                };
+                system.write_file(&slot_path(to), &bytes);
+                match system.read_file(&slot_thumb_path(from)) {
+                    Some(png) => system.write_file(&slot_thumb_path(to), &png),
+                    None => system.remove_file(&slot_thumb_path(to)),
+                }
+                if to == self.active_slot {
+                    self.reload_active_slot(system);
+                }
+            }
+            SlotRequest::Erase(slot) => {
+                system.remove_file(&slot_path(slot));
+                system.remove_file(&slot_thumb_path(slot));
+                if slot == 0 {
+                    // Otherwise the legacy save would resurface as slot 0.
+                    system.remove_file(SAVE_PATH);
+                }
+                if slot == self.active_slot {
+                    self.reload_active_slot(system);
+                }
             }
-            Err(e) => log::error!("Failed to serialise save data: {e}"),
         }
+        self.menu.refresh_slots(system);
+    }
+
+    /// Replace the live save with the active slot's stored one (or a fresh
+    /// save carrying over the player's preferences, when the slot is empty).
+    fn reload_active_slot(&mut self, system: &mut impl platform::ConsoleApi) {
+        let prefs = (self.save.small_text_on, self.save.manual_doors);
+        self.save = SaveData::default();
+        (self.save.small_text_on, self.save.manual_doors) = prefs;
+        self.save_loaded = false;
+        // A thumbnail still waiting belongs to the slot just left.
+        self.thumbnail_pending = false;
+        self.load_save(system);
+        self.walkaround
+            .load_inventory(&self.save.inventory, &self.items);
     }
     /// Install the loaded cutscene registry (parsed from
     /// `assets/data/main.eggscene`). Called once at startup by the host's asset
@@ -534,6 +648,7 @@ impl Default for EggState {
             maps: MapStore::default(),
             items: GameItems::default(),
             presets: Presets::builtin(),
+            schedules: Schedules::default(),
             portraits: Portraits::builtin(),
             font: Font::blank(),
             rng: Lcg64Xsh32::default(),
@@ -544,6 +659,9 @@ impl Default for EggState {
             save_loaded: false,
             data_loaded: false,
             last_flushed_save: SaveData::default(),
+            active_slot: 0,
+            thumbnail_pending: false,
+            screen_was_covered: false,
             scrubber: None,
         }
     }
@@ -555,7 +673,8 @@ mod tests {
     use crate::data::save::SAVE_PATH;
     use crate::platform::test_console::TestConsole;
 
-    /// `flush_save` writes the save (as pretty JSON, under [`SAVE_PATH`]) only
+    /// `flush_save` writes the save (as pretty JSON, under the active slot's
+    /// [`slot_path`]) only
     /// when it differs from the last flush — an unchanged save is a no-op so the
     /// per-frame flush doesn't rewrite the file constantly.
     #[test]
@@ -565,23 +684,33 @@ mod tests {
 
         // A fresh, unchanged save (matching last_flushed) writes nothing.
         state.flush_save(&mut console);
-        assert!(!console.files.contains_key(SAVE_PATH));
+        assert!(!console.files.contains_key(&slot_path(0)));
 
         // After a change, the next flush writes parseable JSON.
         state.save.egg_count = 7;
         state.flush_save(&mut console);
-        let bytes = console.files.get(SAVE_PATH).expect("flush wrote the save");
+        let bytes = console.files.get(&slot_path(0)).expect("flush wrote the save");
         let written: SaveData = serde_json::from_slice(bytes).expect("valid json");
         assert_eq!(written.egg_count, 7);
 
         // No further change -> no rewrite (clear the file, flush, stays absent).
-        console.files.remove(SAVE_PATH);
+        console.files.remove(&slot_path(0));
         state.flush_save(&mut console);
-        assert!(!console.files.contains_key(SAVE_PATH));
+        assert!(!console.files.contains_key(&slot_path(0)));
+
+        // The clock ticking within the hour isn't worth a write; turning the
+        // hour is.
+        state.save.clock.advance_minutes(1);
+        state.flush_save(&mut console);
+        assert!(!console.files.contains_key(&slot_path(0)));
+        state.save.clock.advance_minutes(60);
+        state.flush_save(&mut console);
+        assert!(console.files.contains_key(&slot_path(0)));
     }
 
     /// `load_save` installs a valid pre-existing file and runs once; garbage in
-    /// the store logs and leaves the default save in place.
+    /// the store logs and leaves the default save in place. The file here is
+    /// the legacy [`SAVE_PATH`], which slot 0 falls back to.
     #[test]
     fn load_save_installs_valid_file_and_falls_back_on_garbage() {
         // Valid file -> installed into `save`.
@@ -730,7 +859,7 @@ mod tests {
         // Serialise-before-flush (the line `run` performs) then write to storage.
         source.save.inventory = source.walkaround.inventory_ui.inventory.to_save();
         source.flush_save(&mut console);
-        assert!(console.files.contains_key(SAVE_PATH), "save was written");
+        assert!(console.files.contains_key(&slot_path(0)), "save was written");
 
         // A fresh state with a *cleared* inventory loads the stored save and
         // repopulates from it (the lines `run` performs on the load frame).
@@ -784,7 +913,7 @@ mod tests {
         // (`flush_save` is diff-gated against the last flush).
         state.save.inventory = state.walkaround.inventory_ui.inventory.to_save();
         state.flush_save(&mut console);
-        assert!(console.files.contains_key(SAVE_PATH), "dirty save written");
+        assert!(console.files.contains_key(&slot_path(0)), "dirty save written");
 
         // Drive the menu through its public `click` API, exactly as `step_mode`
         // does: open Options (installs the sub-screen whose entries end in Reset),
@@ -812,6 +941,7 @@ mod tests {
                     save: &mut state.save,
                     items: &state.items,
                     presets: &state.presets,
+                    schedules: &state.schedules,
                     font: &state.font,
                 };
                 returned = menu.click(Some(index), &mut ctx, &mut walk);
@@ -843,7 +973,7 @@ mod tests {
             SaveData::default().inventory,
             "post-sync save inventory is the starting items, not the stale ones"
         );
-        let bytes = console.files.get(SAVE_PATH).expect("erase flushed to disk");
+        let bytes = console.files.get(&slot_path(0)).expect("erase flushed to disk");
         let on_disk: SaveData = serde_json::from_slice(bytes).expect("valid json");
         assert_eq!(
             on_disk,
@@ -851,4 +981,113 @@ mod tests {
             "the erased default is what persists across a restart"
         );
     }
+
+    /// Click menu entry `index` the way `step_mode` does, then carry out any
+    /// slot request it made the way `run` does.
+    fn click_menu(
+        state: &mut EggState,
+        console: &mut TestConsole,
+        index: Option<usize>,
+    ) -> Option<GameMode> {
+        let input = EggInput::new();
+        let mut walk = std::mem::take(&mut state.walkaround);
+        let mut menu = std::mem::take(&mut state.menu);
+        let mode = {
+            let mut ctx = Ctx {
+                draw: &mut state.draw_state,
+                system: &mut *console,
+                input: &input,
+                maps: &mut state.maps,
+                rng: &mut state.rng,
+                script: &state.script,
+                scenes: &state.scenes,
+                save: &mut state.save,
+                items: &state.items,
+                presets: &state.presets,
+                schedules: &state.schedules,
+                font: &state.font,
+            };
+            menu.click(index, &mut ctx, &mut walk)
+        };
+        state.walkaround = walk;
+        state.menu = menu;
+        if let Some(request) = state.menu.take_slot_request() {
+            state.apply_slot_request(console, request);
+        }
+        mode
+    }
+
+    /// The slot screen end to end: Play opens it, picking a slot makes it the
+    /// active one (loading its save), Copy takes a source then a destination,
+    /// and Erase takes a slot then the same slot again — erasing slot 0 takes
+    /// the legacy save it falls back to with it.
+    #[test]
+    fn slot_screen_loads_copies_and_erases() {
+        let mut console = TestConsole::new();
+        let stored = |egg_count| {
+            let save = SaveData { egg_count, save_count: 1, ..SaveData::default() };
+            serde_json::to_vec(&save).unwrap()
+        };
+        console.files.insert(SAVE_PATH.to_string(), stored(42));
+        console.files.insert(slot_path(1), stored(5));
+        let mut state = EggState::default();
+        state.load_save(&mut console);
+        assert_eq!((state.active_slot(), state.save.egg_count), (0, 42), "legacy save");
+
+        // Play -> the slot screen (slots 0..3, Copy, Erase, Back); pick slot 1.
+        state.menu = MenuState::new();
+        assert_eq!(click_menu(&mut state, &mut console, Some(0)), None);
+        let mode = click_menu(&mut state, &mut console, Some(1));
+        assert_eq!(mode, Some(GameMode::Instructions));
+        assert_eq!((state.active_slot(), state.save.egg_count), (1, 5));
+        state.save.egg_count = 6;
+        state.flush_save(&mut console);
+        let written: SaveData = serde_json::from_slice(&console.files[&slot_path(1)]).unwrap();
+        assert_eq!(written.egg_count, 6, "flushes go to the active slot");
+
+        // Copy 1 -> 2: Copy, source, destination.
+        state.menu = MenuState::slot_select(&mut console);
+        for index in [3, 1, 2] {
+            click_menu(&mut state, &mut console, Some(index));
+        }
+        assert_eq!(console.files.get(&slot_path(2)), console.files.get(&slot_path(1)));
+
+        // Erase 0: Erase, the slot, the slot again. Backing out mid-way cancels.
+        for index in [Some(4), Some(0), None] {
+            click_menu(&mut state, &mut console, index);
+        }
+        assert!(console.files.contains_key(SAVE_PATH), "cancelled, so nothing erased");
+        for index in [4, 0, 0] {
+            click_menu(&mut state, &mut console, Some(index));
+        }
+        assert!(!console.files.contains_key(SAVE_PATH));
+        assert!(slots::read_slot(&mut console, 0).is_none());
+        assert_eq!(state.save.egg_count, 6, "erasing another slot leaves the live save");
+
+        // Erasing the active slot resets the live save.
+        for index in [4, 1, 1] {
+            click_menu(&mut state, &mut console, Some(index));
+        }
+        assert_eq!(state.save, SaveData::default());
+    }
+
+    /// A flush that follows an in-game save (a new `save_count`) retakes the
+    /// slot's thumbnail from the output frame; other flushes leave it alone.
+    #[test]
+    fn thumbnail_is_written_when_the_game_saves() {
+        let mut console = TestConsole::new();
+        let mut state = EggState::default();
+        state.save.small_text_on = true;
+        state.flush_save(&mut console);
+        assert!(!console.files.contains_key(&slot_thumb_path(0)), "an option toggle");
+
+        state.save.save_count += 1;
+        state.flush_save(&mut console);
+        let png = console.files.get(&slot_thumb_path(0)).expect("thumbnail written");
+        let thumb = crate::render::image::RgbaImage::decode_png(png).expect("our own PNG");
+        assert_eq!(
+            (thumb.width(), thumb.height()),
+            (slots::THUMB_WIDTH, slots::THUMB_HEIGHT)
+        );
+    }
 }
@@ -0,0 +1,261 @@
+//! Live-play recording: capture the windowed game's per-frame input as a
+//! headless harness script, so a bug found by playing can be replayed exactly
+//! with `egg_game_headless`. The Bevy host owns a [`Recorder`] while recording
+//! (toggled by a hotkey) and hands it each frame's [`EggInput`] in the same
+//! funnel that steps the sim; on stop it writes [`script`](Recorder::script)
+//! to disk and logs the matching [`args`](Recorder::args).
+//!
+//! The replay reproduces the *start* the harness can rebuild — map, player
+//! position, save flags and counters, map-editor focus and RNG seed — then the
+//! recorded input frame for frame. Controller one's buttons and the held
+//! keyboard keys are recorded; the cursor and typed text are not (they only
+//! drive the editors), and neither is any save state beyond the flags and
+//! counters (the clock, the inventory).
+//!
+//! The start map is loaded fresh, so its creatures stand where the map places
+//! them rather than where they'd wandered to when recording began, and any
+//! scene or schedule mid-way through starts over. A recording begun after
+//! creatures have moved diverges from the live play as soon as one of them
+//! matters (a collision, a talk, a schedule slot); start recording right
+//! after entering the map for an exact replay.
+
+use crate::EggState;
+use crate::platform::{EggInput, ScanCode};
+use crate::rand::Lcg64Xsh32;
+
+/// The PCG stream (PCG's default) a seeded run puts the game's RNG on: the
+/// harness's `--seed N` and [`Recorder::start`] both seed it with `N` as the
+/// state, so a recording replays under the seed it logged.
+pub const DEFAULT_STREAM: u64 = 0x0a02_bdbf_7bb3_c0a7;
+
+/// The harness script's `key` names, each with its [`ScanCode`]: the letters,
+/// digits, arrow keys, and the named editing/function/punctuation keys the
+/// in-game text fields and hotkeys read. A key's first entry is its canonical name — the one
+/// [`scancode_name`] gives back (later entries are aliases).
+pub const KEY_NAMES: [(&str, ScanCode); 82] = {
+    use ScanCode::*;
+    [
+        ("a", A),
+        ("b", B),
+        ("c", C),
+        ("d", D),
+        ("e", E),
+        ("f", F),
+        ("g", G),
+        ("h", H),
+        ("i", I),
+        ("j", J),
+        ("k", K),
+        ("l", L),
+        ("m", M),
+        ("n", N),
+        ("o", O),
+        ("p", P),
+        ("q", Q),
+        ("r", R),
+        ("s", S),
+        ("t", T),
+        ("u", U),
+        ("v", V),
+        ("w", W),
+        ("x", X),
+        ("y", Y),
+        ("z", Z),
+        ("0", Digit0),
+        ("1", Digit1),
+        ("2", Digit2),
+        ("3", Digit3),
+        ("4", Digit4),
+        ("5", Digit5),
+        ("6", Digit6),
+        ("7", Digit7),
+        ("8", Digit8),
+        ("9", Digit9),
+        ("minus", Minus),
+        ("equals", Equals),
+        ("leftbracket", LeftBracket),
+        ("rightbracket", RightBracket),
+        ("backslash", Backslash),
+        ("semicolon", Semicolon),
+        ("apostrophe", Apostrophe),
+        ("grave", Grave),
+        ("comma", Comma),
+        ("period", Period),
+        ("slash", Slash),
+        ("space", Space),
+        ("tab", Tab),
+        ("return", Return),
+        ("enter", Return),
+        ("backspace", Backspace),
+        ("delete", Delete),
+        ("del", Delete),
+        ("insert", Insert),
+        ("pageup", PageUp),
+        ("pagedown", PageDown),
+        ("home", Home),
+        ("end", End),
+        ("up", Up),
+        ("down", Down),
+        ("left", Left),
+        ("right", Right),
+        ("capslock", CapsLock),
+        ("ctrl", Ctrl),
+        ("control", Ctrl),
+        ("shift", Shift),
+        ("alt", Alt),
+        ("escape", Escape),
+        ("esc", Escape),
+        ("f1", F1),
+        ("f2", F2),
+        ("f3", F3),
+        ("f4", F4),
+        ("f5", F5),
+        ("f6", F6),
+        ("f7", F7),
+        ("f8", F8),
+        ("f9", F9),
+        ("f10", F10),
+        ("f11", F11),
+        ("f12", F12),
+    ]
+};
+
+/// The canonical `key` name of a [`ScanCode`], if it has one.
+pub fn scancode_name(sc: ScanCode) -> Option<&'static str> {
+    KEY_NAMES.iter().find(|(_, k)| *k == sc).map(|&(n, _)| n)
+}
+
+/// Controller one's buttons as `hold` names, in
+/// [`Controller`](crate::platform::Controller) field order.
+const BUTTON_NAMES: [&str; 8] = ["up", "down", "left", "right", "a", "b", "x", "y"];
+
+/// A recording in progress: where it started, and the input since as a
+/// run-length encoded list of script lines.
+pub struct Recorder {
+    map: String,
+    pos: (i16, i16),
+    flags: Vec<String>,
+    vars: Vec<(String, i32)>,
+    seed: u64,
+    editor: bool,
+    /// The recorded script body: `hold`/`wait` runs, plus `editor on|off`
+    /// wherever the map editor's focus changed.
+    lines: Vec<Line>,
+    frames: u64,
+}
+
+/// One script line of a recording.
+#[derive(Debug, PartialEq, Eq)]
+enum Line {
+    /// `held` (`hold` inputs, empty for a `wait`) kept for `frames` frames.
+    Run { held: Vec<String>, frames: u64 },
+    Editor(bool),
+}
+
+impl Recorder {
+    /// Start recording from the game as it stands. Re-seeds the game's RNG
+    /// with `seed` (the same stream `--seed` uses), so the replay's random
+    /// rolls match the recording's from this frame on.
+    pub fn start(state: &mut EggState, seed: u64, editor_open: bool) -> Self {
+        state.rng = Lcg64Xsh32::new(seed, DEFAULT_STREAM);
+        let walk = &state.walkaround;
+        let pos = walk.player_ref().pos;
+        Self {
+            map: walk.current_map.source.clone(),
+            pos: (pos.x, pos.y),
+            flags: state.save.flags.iter().cloned().collect(),
+            vars: state.save.vars.iter().map(|(name, value)| (name.clone(), *value)).collect(),
+            seed,
+            editor: editor_open,
+            lines: Vec::new(),
+            frames: 0,
+        }
+    }
+
+    /// Record one frame's input, as the sim is about to see it.
+    pub fn record(&mut self, input: &EggInput, editor_open: bool) {
+        if editor_open != self.last_editor() {
+            self.lines.push(Line::Editor(editor_open));
+        }
+        let pad = &input.controllers[0];
+        let buttons = [pad.up, pad.down, pad.left, pad.right, pad.a, pad.b, pad.x, pad.y];
+        let keys = KEY_NAMES
+            .iter()
+            .filter(|&&(name, sc)| input.keyboard[sc.index()] && scancode_name(sc) == Some(name))
+            .map(|(name, _)| format!("key:{name}"));
+        let held: Vec<String> = BUTTON_NAMES
+            .iter()
+            .zip(buttons)
+            .filter(|(_, down)| down[0])
+            .map(|(name, _)| name.to_string())
+            .chain(keys)
+            .collect();
+        match self.lines.last_mut() {
+            Some(Line::Run { held: last, frames }) if *last == held => *frames += 1,
+            _ => self.lines.push(Line::Run { held, frames: 1 }),
+        }
+        self.frames += 1;
+    }
+
+    /// Frames recorded so far.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// The map editor's focus as the script leaves it so far.
+    fn last_editor(&self) -> bool {
+        self.lines
+            .iter()
+            .rev()
+            .find_map(|line| match line {
+                Line::Editor(on) => Some(*on),
+                Line::Run { .. } => None,
+            })
+            .unwrap_or(self.editor)
+    }
+
+    /// The `egg_game_headless` arguments that rebuild the recording's start,
+    /// less the `--script` itself.
+    pub fn args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--map".to_string(),
+            self.map.clone(),
+            "--pos".to_string(),
+            format!("{},{}", self.pos.0, self.pos.1),
+            "--seed".to_string(),
+            self.seed.to_string(),
+        ];
+        for flag in &self.flags {
+            args.extend(["--flag".to_string(), flag.clone()]);
+        }
+        for (name, value) in &self.vars {
+            args.extend(["--var".to_string(), format!("{name}={value}")]);
+        }
+        if self.editor {
+            args.push("--editor".to_string());
+        }
+        args
+    }
+
+    /// The recording as a harness script, `script_path` being where it will
+    /// be saved: a comment header with the full replay command line, then the
+    /// input.
+    pub fn script(&self, script_path: &str) -> String {
+        let mut out = format!(
+            "# Recorded live play, {} frame(s). Replay with:\n# egg_game_headless {} --script {}\n",
+            self.frames,
+            self.args().join(" "),
+            script_path
+        );
+        for line in &self.lines {
+            match line {
+                Line::Run { held, frames } if held.is_empty() => {
+                    out += &format!("wait {frames}\n");
+                }
+                Line::Run { held, frames } => out += &format!("hold {} {frames}\n", held.join("+")),
+                Line::Editor(on) => out += if *on { "editor on\n" } else { "editor off\n" },
+            }
+        }
+        out
+    }
+}
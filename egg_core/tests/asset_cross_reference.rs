@@ -19,9 +19,10 @@ use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use egg_core::data::eggdata::Presets;
+use egg_core::data::eggdata::{self, GameItems, Presets};
 use egg_core::data::portraits::Portraits;
 use egg_core::data::scene;
+use egg_core::data::schedule::Schedules;
 use egg_core::data::script::eggtext;
 use egg_core::data::tiled;
 use egg_core::data::validate::{self, ENGINE_DIALOGUE_ROOTS};
@@ -54,6 +55,21 @@ fn load_scenes() -> scene::SceneFile {
     main.merge(recorded).expect("merge main.eggscene + recorded.eggscene")
 }
 
+/// The item registry from the shipped `data/data.toml` — the one the game
+/// boots with, so a scene's `give`/`take`/`has` key is checked against the
+/// items that actually ship rather than the compiled-in fallback.
+fn load_items() -> GameItems {
+    let src = fs::read_to_string(data_dir().join("data.toml")).expect("read data.toml");
+    let data = eggdata::parse(&src).expect("parse data.toml");
+    GameItems::from_data(&data.items)
+}
+
+/// The shipped NPC schedules, from the same `data.toml`.
+fn load_schedules() -> Schedules {
+    let src = fs::read_to_string(data_dir().join("data.toml")).expect("read data.toml");
+    Schedules::from_data(&eggdata::parse(&src).expect("parse data.toml"))
+}
+
 /// Every `*.eggtext` language overlay under `assets/script` besides the base
 /// (`en.eggtext`), parsed and keyed by language name — mirrors
 /// `egg_game_headless::harness::script_overlay_stems`, so the CLI `--check`
@@ -113,6 +129,8 @@ fn shipped_assets_have_no_dangling_references() {
         &maps,
         &Portraits::builtin(),
         &Presets::builtin(),
+        &load_schedules(),
+        &load_items(),
         ENGINE_DIALOGUE_ROOTS,
     );
 
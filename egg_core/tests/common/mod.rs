@@ -0,0 +1,75 @@
+//! The shipped-world boot the integration tests that *play* the world share:
+//! maps with their image layers, the sprite sheet the tile colliders are cut
+//! from, `data.toml`, the script and the merged scene registry, read the way
+//! the headless harness's `boot` reads them — so collision is the game's own.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use egg_core::EggState;
+use egg_core::data::eggdata::{self, GameItems, Presets};
+use egg_core::data::portraits::Portraits;
+use egg_core::data::scene;
+use egg_core::data::script::eggtext;
+use egg_core::data::tiled;
+use egg_core::render::image::RgbaImage;
+
+/// The map a new game opens on.
+pub const START_MAP: &str = "bedroom";
+
+fn assets_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets")
+}
+
+fn decode_png(path: &Path) -> RgbaImage {
+    let rgba = image::open(path)
+        .unwrap_or_else(|e| panic!("decode {}: {e}", path.display()))
+        .to_rgba8();
+    let (w, h) = (rgba.width(), rgba.height());
+    RgbaImage::from_vec(rgba.into_raw(), w, h)
+}
+
+/// A world booted from the shipped assets, no map loaded yet.
+pub fn boot() -> EggState {
+    let mut state = EggState::default();
+    let assets = assets_dir();
+
+    let data = fs::read_to_string(assets.join("data/data.toml")).expect("read data.toml");
+    let data = eggdata::parse(&data).expect("parse data.toml");
+    state.items = GameItems::from_data(&data.items);
+    state.presets = Presets::from_data(&data);
+    state.portraits = Portraits::from_data(&data);
+
+    let sheet = decode_png(&assets.join("sprites/sheet.png"));
+    state.draw_state.indexed_sprites = sheet.to_indexed(&state.draw_state.palettes[0]);
+    state.draw_state.rgba_sprites = sheet;
+
+    for entry in fs::read_dir(assets.join("maps")).expect("read assets/maps") {
+        let path = entry.expect("dir entry").path();
+        if path.extension().and_then(|e| e.to_str()) != Some("tmj") {
+            continue;
+        }
+        let stem = path.file_stem().unwrap().to_str().unwrap().to_string();
+        let bytes = fs::read(&path).expect("read tmj");
+        let mut map = tiled::from_json(&bytes).unwrap_or_else(|e| panic!("parse {stem}.tmj: {e}"));
+        let rel_paths: Vec<String> =
+            map.image_layer_paths().iter().map(|s| s.to_string()).collect();
+        for rel in rel_paths {
+            map.attach_image(&rel, decode_png(&assets.join("maps").join(&rel)));
+        }
+        state.maps.insert(stem, map);
+    }
+
+    let script = fs::read_to_string(assets.join("script/en.eggtext")).expect("read en.eggtext");
+    let script = eggtext::parse(&script).expect("parse en.eggtext");
+    state.script.set_base(script, &state.portraits);
+
+    let main = fs::read_to_string(assets.join(scene::MAIN_SCENE_PATH)).expect("read main.eggscene");
+    let main = scene::parse(&main).expect("parse main.eggscene");
+    let recorded = match fs::read_to_string(assets.join(scene::RECORDED_SCENE_PATH)) {
+        Ok(src) => scene::parse(&src).expect("parse recorded.eggscene"),
+        Err(_) => scene::SceneFile::default(),
+    };
+    state.set_scenes(main.merge(recorded).expect("merge main + recorded scenes"));
+    state
+}
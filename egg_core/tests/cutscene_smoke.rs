@@ -0,0 +1,60 @@
+//! Play every *shipped* cutscene to its end, headless: each scene in the merged
+//! `main.eggscene` + `recorded.eggscene` registry is launched against its
+//! `init_map` (or, lacking one, the new-game bedroom) and auto-advanced through
+//! a `NullConsole` until it finishes or hits the frame cap — see
+//! [`egg_core::gamestate::smoke`]. Fails on any scene that never finishes,
+//! cancels on a blocked required `?` motion, or names an actor that resolves
+//! to nothing: the mistakes a parse and a cross-reference can't see, because
+//! they only show up when the scene actually runs against a real map.
+//!
+//! Boots the real world through the shared [`common::boot`], so collision —
+//! what a required move trips on — is the game's own.
+
+mod common;
+
+use common::START_MAP;
+use egg_core::gamestate::smoke::SMOKE_FRAME_CAP;
+use egg_core::platform::{EggInput, NullConsole};
+use egg_core::{Ctx, EggState};
+
+/// The shipped world standing in the start map with the dog in tow — the
+/// party the dog's own scenes (`pet_dog`) are triggered from.
+fn start() -> EggState {
+    let mut state = common::boot();
+    let mut console = NullConsole::new();
+    let input = EggInput::new();
+    let mut ctx = Ctx {
+        draw: &mut state.draw_state,
+        system: &mut console,
+        input: &input,
+        maps: &mut state.maps,
+        rng: &mut state.rng,
+        script: &state.script,
+        scenes: &state.scenes,
+        save: &mut state.save,
+        items: &state.items,
+        presets: &state.presets,
+        schedules: &state.schedules,
+        font: &state.font,
+    };
+    state.walkaround.load_map_by_name(&mut ctx, START_MAP);
+    state.walkaround.give_dog(&state.presets);
+    state
+}
+
+#[test]
+fn every_shipped_cutscene_plays_to_its_end() {
+    let mut state = start();
+    assert!(state.maps.contains(START_MAP), "no `{START_MAP}` map shipped");
+
+    let reports = state.smoke_cutscenes(SMOKE_FRAME_CAP);
+    assert!(!reports.is_empty(), "no cutscenes shipped");
+
+    let mut failures = String::new();
+    for report in &reports {
+        for problem in &report.problems {
+            failures += &format!("  {}: {problem}\n", report.name);
+        }
+    }
+    assert!(failures.is_empty(), "cutscene(s) failed their smoke run:\n{failures}");
+}
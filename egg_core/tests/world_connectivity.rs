@@ -0,0 +1,34 @@
+//! Walk the *shipped* world: flood-fill every map from each way onto it with
+//! the player's hitbox and the walk loop's own collision, and fail on any
+//! landing that leaves no warp in walking reach — a one-way trap the player
+//! can't get out of (see [`egg_core::data::validate::check_connectivity`]).
+//! Unreachable objects and maps are warnings, printed but not fatal: debug
+//! rooms and stray set dressing live there.
+//!
+//! Boots the maps with their image layers and the sprite sheet the tile
+//! colliders are cut from through the shared [`common::boot`] — collision is
+//! the whole point, so an objects-only parse (as `warp_destinations.rs` uses)
+//! won't do.
+
+mod common;
+
+use common::START_MAP;
+use egg_core::data::validate::check_connectivity;
+
+#[test]
+fn no_landing_traps_the_player() {
+    let state = common::boot();
+    assert!(state.maps.contains(START_MAP), "no `{START_MAP}` map shipped");
+
+    let report = check_connectivity(
+        &state.maps,
+        &state.draw_state.indexed_sprites,
+        &state.scenes,
+        START_MAP,
+        state.walkaround.player_ref(),
+    );
+    for warning in &report.warnings {
+        eprintln!("warning: {warning}");
+    }
+    assert!(report.is_clean(), "the world has one-way traps:\n{report}");
+}
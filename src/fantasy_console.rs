@@ -175,14 +175,21 @@ impl ConsoleApi for FantasyConsole {
     /// goes to the host's user-data backend, everything else to the authoring
     /// `assets/` tree.
     ///
-    /// * **user data, native** — the file `save.json` in the working directory,
-    ///   rewritten in place (no `.bak`: it changes constantly).
+    /// * **user data, native** — the file at `path` in the working directory
+    ///   (`save.json`, or the slot files under `save/`, created on first
+    ///   write), rewritten in place (no `.bak`: it changes constantly).
     /// * **assets, native** — `assets/<path>`, backing up any existing file to
     ///   `<path>.bak` first. The engine only hands over relative forward-slash
     ///   paths; anything absolute or escaping the data root is refused.
     #[cfg(not(target_arch = "wasm32"))]
     fn write_file(&mut self, path: &str, bytes: &[u8]) {
         if is_user_data(path) {
+            if let Some(parent) = std::path::Path::new(path).parent()
+                && !parent.as_os_str().is_empty()
+                && let Err(e) = std::fs::create_dir_all(parent)
+            {
+                info!("write_file: mkdir {} failed: {e}", parent.display());
+            }
             if let Err(e) = std::fs::write(path, bytes) {
                 info!("Failed to write save file {path}: {e}");
             }
@@ -210,8 +217,10 @@ impl ConsoleApi for FantasyConsole {
             Err(e) => info!("write_file: failed to write {}: {e}", dest.display()),
         }
     }
-    /// Web build: every write lands in `localStorage`. The save (user data) keeps
-    /// its historical raw-path key (`"save.json"`) byte-for-byte. Asset-namespace
+    /// Web build: every write lands in `localStorage`. User data keeps its
+    /// historical raw-path key (`"save.json"`, `"save/slot0.json"`) when it is
+    /// text; binary user data (a slot's PNG thumbnail) goes base64-encoded under
+    /// [`USER_DATA_B64_PREFIX`] instead. Asset-namespace
     /// writes persist as overrides that survive a reload: text (maps, eggtext,
     /// eggscene, editor layout, `data.toml`) verbatim under
     /// [`ASSET_OVERRIDE_PREFIX`], and binary (image-layer PNGs, or any future
@@ -223,7 +232,13 @@ impl ConsoleApi for FantasyConsole {
     fn write_file(&mut self, path: &str, bytes: &[u8]) {
         let Some(storage) = local_storage() else { return };
         if is_user_data(path) {
-            if let Err(e) = storage.set_item(path, &String::from_utf8_lossy(bytes)) {
+            let b64_key = format!("{USER_DATA_B64_PREFIX}{path}");
+            let (key, stale_key, value) = match std::str::from_utf8(bytes) {
+                Ok(text) => (path.to_string(), b64_key, text.to_string()),
+                Err(_) => (b64_key, path.to_string(), crate::base64::encode(bytes)),
+            };
+            let _ = storage.remove_item(&stale_key);
+            if let Err(e) = storage.set_item(&key, &value) {
                 info!("Failed to persist {path} to localStorage (quota?): {e:?}");
             }
             return;
@@ -254,8 +269,19 @@ impl ConsoleApi for FantasyConsole {
     /// [`write_file`](Self::write_file) already leaves behind, so a deletion
     /// stays recoverable rather than destructive. Missing file / IO error:
     /// logged and swallowed, same tone as `write_file`.
+    ///
+    /// User data (an erased save slot) is deleted outright instead: erasing a
+    /// slot is the player's explicit, confirmed choice.
     #[cfg(not(target_arch = "wasm32"))]
     fn remove_file(&mut self, path: &str) {
+        if is_user_data(path) {
+            if std::path::Path::new(path).exists()
+                && let Err(e) = std::fs::remove_file(path)
+            {
+                info!("remove_file: failed to delete {path}: {e}");
+            }
+            return;
+        }
         let Some(dest) = asset_path(path) else {
             info!("remove_file: refusing non-relative path {path:?}");
             return;
@@ -274,10 +300,15 @@ impl ConsoleApi for FantasyConsole {
     /// binary key (whichever form a prior [`write_file`](Self::write_file)
     /// used) — so a retired asset falls back to the bundled fetch (or nothing,
     /// once removed upstream too) instead of resurrecting a stale override on
-    /// the next load.
+    /// the next load. User data drops both its raw and base64 key.
     #[cfg(target_arch = "wasm32")]
     fn remove_file(&mut self, path: &str) {
         let Some(storage) = local_storage() else { return };
+        if is_user_data(path) {
+            let _ = storage.remove_item(path);
+            let _ = storage.remove_item(&format!("{USER_DATA_B64_PREFIX}{path}"));
+            return;
+        }
         let _ = storage.remove_item(&format!("{ASSET_OVERRIDE_PREFIX}{path}"));
         let _ = storage.remove_item(&format!("{ASSET_OVERRIDE_B64_PREFIX}{path}"));
     }
@@ -303,15 +334,22 @@ impl ConsoleApi for FantasyConsole {
         let dest = asset_path(path)?;
         std::fs::read(&dest).ok()
     }
-    /// Web build: user data is read from `localStorage` by its raw path key. For
+    /// Web build: user data is read from `localStorage` by its raw path key, or
+    /// decoded from its [`USER_DATA_B64_PREFIX`] key when it was binary. For
     /// asset paths, a persisted editor override ([`asset_override`]) is preferred —
     /// the bundled copy is served by Bevy's async loader (HTTP), not through this
     /// call, so absent an override there's nothing to return here (`None`).
     #[cfg(target_arch = "wasm32")]
     fn read_file(&mut self, path: &str) -> Option<Vec<u8>> {
         if is_user_data(path) {
-            return match local_storage()?.get_item(path) {
-                Ok(json) => json.map(String::into_bytes),
+            let storage = local_storage()?;
+            return match storage.get_item(path) {
+                Ok(Some(json)) => Some(json.into_bytes()),
+                Ok(None) => storage
+                    .get_item(&format!("{USER_DATA_B64_PREFIX}{path}"))
+                    .ok()
+                    .flatten()
+                    .and_then(|b64| crate::base64::decode(&b64)),
                 Err(e) => {
                     info!("Failed to read save from localStorage: {e:?}");
                     None
@@ -406,6 +444,12 @@ fn local_storage() -> Option<web_sys::Storage> {
 #[cfg(target_arch = "wasm32")]
 const ASSET_OVERRIDE_PREFIX: &str = "egg-asset:";
 
+/// `localStorage` key prefix for **binary** user data (a save slot's PNG
+/// thumbnail), stored base64 like [`ASSET_OVERRIDE_B64_PREFIX`]. Text user data
+/// (the saves themselves) keeps its raw path key.
+#[cfg(target_arch = "wasm32")]
+const USER_DATA_B64_PREFIX: &str = "egg-save-b64:";
+
 /// `localStorage` key prefix for **binary** asset overrides, stored base64
 /// (see [`crate::base64`]) because a JS string can't hold raw bytes. The
 /// text/binary split is per write — [`asset_override`] checks both.
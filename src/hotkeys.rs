@@ -21,9 +21,9 @@ use crate::{EggGame, ScaleMode, views};
 ///
 /// Registers:
 /// * `Update`: [`primary_hotkeys`] (window/screen modes, `F8` view spawning,
-///   pause/single-step, and the debug/cheat toggles). The `Update` schedule is
-///   required for correctness — see the module docs on why edge-triggered keys
-///   must not live in `FixedUpdate`.
+///   `F9` live-play recording, pause/single-step, and the debug/cheat
+///   toggles). The `Update` schedule is required for correctness — see the
+///   module docs on why edge-triggered keys must not live in `FixedUpdate`.
 pub struct HotkeysPlugin;
 
 impl Plugin for HotkeysPlugin {
@@ -32,6 +32,45 @@ impl Plugin for HotkeysPlugin {
     }
 }
 
+/// Where [`toggle_recording`] saves finished recordings, relative to the
+/// working directory (the repo root under `cargo run`).
+#[cfg(not(target_arch = "wasm32"))]
+const RECORDINGS_DIR: &str = "recordings";
+
+/// Start a live-play recording, or stop the running one and write it out as
+/// `recordings/rec-<unix seconds>.script`, logging the `egg_game_headless`
+/// command line that replays it. The fresh seed comes from the game's own RNG
+/// (the recorder re-seeds the game with it), so no clock is needed for it.
+#[cfg(not(target_arch = "wasm32"))]
+fn toggle_recording(game: &mut EggGame) {
+    let g = &mut *game;
+    let Some(recorder) = g.recorder.take() else {
+        let seed = u64::from(g.state.rng.next_u32()) << 32 | u64::from(g.state.rng.next_u32());
+        g.recorder = Some(egg_core::recorder::Recorder::start(
+            &mut g.state,
+            seed,
+            g.map_viewer.focused,
+        ));
+        info!("Recording live play (F9 to stop)");
+        return;
+    };
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let path = format!("{RECORDINGS_DIR}/rec-{stamp}.script");
+    let written = std::fs::create_dir_all(RECORDINGS_DIR)
+        .and_then(|()| std::fs::write(&path, recorder.script(&path)));
+    match written {
+        Ok(()) => info!(
+            "Recorded {} frame(s) to {path}; replay with: \
+             cargo run -p egg_game_headless -- {} --script {path}",
+            recorder.frames(),
+            recorder.args().join(" ")
+        ),
+        Err(e) => warn!("failed to write recording {path}: {e}"),
+    }
+}
+
 /// Primary-window hotkeys: window/screen modes, F8 view spawning, pause, and
 /// the debug/cheat toggles. The same suppression rules `step_state` applies to
 /// its held keys (editor typing, window focus, editor-owns-keyboard) come from
@@ -110,6 +149,16 @@ pub fn primary_hotkeys(
         );
     }
 
+    // F9 starts/stops recording live play as a headless harness script (see
+    // [`toggle_recording`]). Walkaround only, like F8: the harness replays
+    // from a walkaround start.
+    #[cfg(not(target_arch = "wasm32"))]
+    if keys.just_pressed(KeyCode::F9)
+        && (game.recorder.is_some() || matches!(game.state.gamestate, GameMode::Walkaround))
+    {
+        toggle_recording(&mut game);
+    }
+
     // Everything below is a letter/digit key, so it's suppressed while any map
     // editor is capturing typed text — dialogue labels like "town_lamppost"
     // must not toggle pause or fire the m/n/k/l shortcuts.
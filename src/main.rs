@@ -57,6 +57,11 @@ pub struct EggGame {
     /// `WalkaroundState`) so `egg_core` no longer depends on the editor crate;
     /// [`MapViewer::primary`] persists this one's dock layout to disk.
     pub map_viewer: MapViewer,
+    /// The live-play recording in progress, if any (toggled with `F9` in
+    /// [`hotkeys::primary_hotkeys`]). Fed the primary input in
+    /// [`run`](Self::run), so it sees exactly the frames the sim steps.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub recorder: Option<egg_core::recorder::Recorder>,
 }
 impl EggGame {
     /// Drive one simulation frame, then — while the primary map editor is focused —
@@ -78,6 +83,10 @@ impl EggGame {
         // holds `&mut system` for host effects. The editor's focus freezes the
         // world sim (the map editor takes over input while it's open).
         let g = &mut *self;
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(recorder) = &mut g.recorder {
+            recorder.record(&g.input, g.map_viewer.focused);
+        }
         run_frame(&mut g.state, &mut g.system, &g.input, &mut g.map_viewer, g.text_mode);
     }
 }
@@ -96,6 +105,8 @@ impl Default for EggGame {
             text_mode: false,
             text_editor: TextEditor::default(),
             map_viewer: MapViewer::primary(),
+            #[cfg(not(target_arch = "wasm32"))]
+            recorder: None,
         }
     }
 }
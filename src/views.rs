@@ -638,6 +638,7 @@ pub fn update_views(
                         save: &mut g.state.save,
                         items: &g.state.items,
                         presets: &g.state.presets,
+                        schedules: &g.state.schedules,
                         font: &g.state.font,
                     };
                     g.state.walkaround.load_map_by_name(&mut ctx, &name);
@@ -757,6 +758,7 @@ pub fn update_views(
                     save: &mut g.state.save,
                     items: &g.state.items,
                     presets: &g.state.presets,
+                    schedules: &g.state.schedules,
                     font: &g.state.font,
                 };
                 if let Some(scrub) = g.state.scrubber.as_ref() {